@@ -2,17 +2,31 @@
 //!
 //! This module contains the core application state and logic for the Hurl TUI.
 
-use anyhow::Result;
-use crossterm::event::{KeyCode, KeyEvent, KeyModifiers, MouseEvent};
+use anyhow::{Context, Result};
+use crossterm::event::{KeyCode, KeyEvent, KeyModifiers, MouseButton, MouseEvent, MouseEventKind};
+use ratatui::layout::{Position, Rect};
+use ratatui::text::Span;
 use ratatui::widgets::ListState;
-use std::collections::HashMap;
-use std::path::PathBuf;
-
-use crate::config::Config;
+use std::collections::{BTreeMap, HashMap, HashSet};
+use std::path::{Path, PathBuf};
+use std::sync::atomic::AtomicBool;
+use std::sync::Arc;
+use tokio::sync::{mpsc, oneshot, Semaphore};
+use tokio::task::AbortHandle;
+
+use crate::config::{Action, Config, KeyMap};
+use crate::diff::{body_diff, hunk_starts, side_by_side, DiffLine, DiffRow};
 use crate::effects::{presets, EffectId, EffectManager};
-use crate::parser::HurlFile;
-use crate::runner::{ExecutionResult, Runner};
-use crate::ui::{EditorTab, ResponseTab};
+use crate::events::Event;
+use crate::parser::{HurlEntry, HurlFile};
+use crate::runner::{parse_cookie_jar, Cookie, ExecutionResult, HurlVersion, Runner, SyntaxError};
+use crate::ui::theme::Theme;
+use crate::ui::{response_json_rows, BodyFormat, EditorTab, JsonRow, LayoutPreset, ResponseTab};
+use crate::undo::UndoStack;
+use crate::util::{breadcrumb_dir_at, collapsible_json_paths};
+
+/// Start line of an entry and the first and last line of its request body
+pub type EntryLines = (usize, Option<(usize, usize)>);
 
 /// Directories to skip when scanning for .hurl files
 /// These are common build output, dependency, and cache directories
@@ -74,12 +88,75 @@ const IGNORED_DIRECTORIES: &[&str] = &[
     "dist",
 ];
 
+/// File in the working directory with extra ignore patterns, one per line
+pub const IGNORE_FILE: &str = ".hurl-tui-ignore";
+
+/// Directory names skipped while scanning: `IGNORED_DIRECTORIES` plus the patterns of `IGNORE_FILE`
+#[derive(Debug, Clone)]
+pub struct IgnorePatterns(Vec<glob::Pattern>);
+
+impl IgnorePatterns {
+    /// `IGNORED_DIRECTORIES` plus the given extra patterns (invalid ones are dropped)
+    pub fn new(patterns: &[String]) -> Self {
+        let merged = IGNORED_DIRECTORIES
+            .iter()
+            .map(|name| glob::Pattern::escape(name))
+            .chain(patterns.iter().cloned())
+            .filter_map(|pattern| glob::Pattern::new(&pattern).ok())
+            .collect();
+        Self(merged)
+    }
+
+    /// `IGNORED_DIRECTORIES` plus the patterns of the ignore file in `dir`
+    pub fn load(dir: &Path) -> Self {
+        Self::new(&read_ignore_file(dir))
+    }
+
+    /// Whether a directory name matches a pattern (case-insensitive for cross-platform)
+    fn matches(&self, name: &str) -> bool {
+        let options = glob::MatchOptions {
+            case_sensitive: false,
+            ..Default::default()
+        };
+        self.0
+            .iter()
+            .any(|pattern| pattern.matches_with(name, options))
+    }
+}
+
+impl Default for IgnorePatterns {
+    fn default() -> Self {
+        Self::new(&[])
+    }
+}
+
+/// Read the patterns of the ignore file in `dir` (`#` starts a comment)
+pub fn read_ignore_file(dir: &Path) -> Vec<String> {
+    let Ok(content) = std::fs::read_to_string(dir.join(IGNORE_FILE)) else {
+        return Vec::new();
+    };
+    content
+        .lines()
+        .map(|line| line.split('#').next().unwrap_or("").trim())
+        .map(|line| line.trim_end_matches('/'))
+        .filter(|line| !line.is_empty())
+        .map(String::from)
+        .collect()
+}
+
+/// Layout version of the state file, bumped when a persisted field is renamed or removed
+const STATE_SCHEMA_VERSION: u32 = 1;
+
 /// Serializable state for persistence
 #[derive(Debug, Clone, serde::Serialize, serde::Deserialize, Default)]
 struct PersistedState {
+    /// Layout version (0 for files written before versioning)
+    #[serde(default)]
+    schema_version: u32,
     /// Last opened file path
     last_opened_file: Option<String>,
     /// File tree index
+    #[serde(default)]
     file_tree_index: usize,
     /// Execution results per file (keyed by relative path)
     #[serde(default)]
@@ -102,6 +179,70 @@ struct PersistedState {
     /// Whether to show the response panel (default true)
     #[serde(default = "default_show_response")]
     show_response: bool,
+    /// Files open as editor tabs
+    #[serde(default)]
+    open_tabs: Vec<String>,
+    /// Panel layout preset
+    #[serde(default)]
+    layout: LayoutPreset,
+    /// Bookmarked files, in slot order
+    #[serde(default)]
+    bookmarks: Vec<PathBuf>,
+    /// Files pinned to the top of the file browser
+    #[serde(default)]
+    pinned_files: Vec<PathBuf>,
+    /// Executed `:` commands, oldest first
+    #[serde(default)]
+    command_history: Vec<String>,
+}
+
+/// Upgrade a state file written by an older version, one schema version at a time
+///
+/// Fields that still cannot be read afterwards are dropped so the rest of the state survives.
+/// A state file written by a newer version keeps its `schema_version` and is not migrated.
+fn migrate_state(mut raw: serde_json::Value) -> PersistedState {
+    let Some(object) = raw.as_object_mut() else {
+        tracing::warn!("State is not a JSON object, using defaults");
+        return PersistedState::default();
+    };
+    let mut version = object
+        .get("schema_version")
+        .and_then(|v| v.as_u64())
+        .unwrap_or(0) as u32;
+    while version < STATE_SCHEMA_VERSION {
+        tracing::info!(
+            "Migrating state from schema version {} to {}",
+            version,
+            version + 1
+        );
+        match version {
+            // Files written before versioning already have the version 1 layout
+            0 => {}
+            // Every bump of `STATE_SCHEMA_VERSION` adds a step here renaming or converting
+            // the fields it changed in `object`
+            _ => unreachable!("no state migration from schema version {}", version),
+        }
+        version += 1;
+    }
+    object.insert("schema_version".to_string(), version.into());
+    match serde_json::from_value(raw.clone()) {
+        Ok(state) => state,
+        Err(e) => {
+            tracing::warn!("Failed to read migrated state: {}", e);
+            let Some(object) = raw.as_object_mut() else {
+                return PersistedState::default();
+            };
+            object.retain(|key, value| {
+                let field = serde_json::json!({ key.as_str(): value });
+                let readable = serde_json::from_value::<PersistedState>(field).is_ok();
+                if !readable {
+                    tracing::warn!("Dropping unreadable state field `{}`", key);
+                }
+                readable
+            });
+            serde_json::from_value(raw).unwrap_or_default()
+        }
+    }
 }
 
 /// Default sidebar width percentage
@@ -145,6 +286,28 @@ pub enum AppMode {
     Command,
     Filter,
     Rename,
+    ConfirmDelete,
+    ConfirmSave,
+    GlobalSearch,
+    CookieJar,
+    EnvEditor,
+    History,
+    Snippets,
+    GotoLine,
+    Stats,
+    Bookmarks,
+    FocusedEntry,
+    VariableOverride,
+    Compare,
+    ResponseSearch,
+    CommandHistorySearch,
+}
+
+/// Field being edited inline in the environment editor popup
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum EnvEditorField {
+    Name,
+    Value,
 }
 
 /// Vim sub-mode when in Editing mode
@@ -153,6 +316,7 @@ pub enum VimMode {
     #[default]
     Normal, // Navigation and commands (hjkl, etc.)
     Insert, // Text input mode
+    Visual, // Line selection mode
 }
 
 /// File tree entry
@@ -164,6 +328,10 @@ pub struct FileEntry {
     pub is_expanded: bool,
     pub depth: usize,
     pub children: Vec<FileEntry>,
+    /// Size in bytes (0 for directories)
+    pub file_size: u64,
+    /// Last modification time (`UNIX_EPOCH` when unavailable)
+    pub modified: std::time::SystemTime,
 }
 
 impl FileEntry {
@@ -172,7 +340,15 @@ impl FileEntry {
             .file_name()
             .map(|n| n.to_string_lossy().to_string())
             .unwrap_or_default();
-        let is_dir = path.is_dir();
+        let metadata = std::fs::metadata(&path).ok();
+        let is_dir = metadata.as_ref().is_some_and(|m| m.is_dir());
+        let file_size = match &metadata {
+            Some(m) if m.is_file() => m.len(),
+            _ => 0,
+        };
+        let modified = metadata
+            .and_then(|m| m.modified().ok())
+            .unwrap_or(std::time::UNIX_EPOCH);
 
         Self {
             path,
@@ -181,6 +357,8 @@ impl FileEntry {
             is_expanded: false,
             depth,
             children: Vec::new(),
+            file_size,
+            modified,
         }
     }
 }
@@ -204,10 +382,91 @@ pub struct HistoryEntry {
     pub success: bool,
 }
 
+/// Duration statistics of the runs of one file in the history
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub struct RequestStats {
+    pub avg_ms: f64,
+    pub min_ms: u64,
+    pub max_ms: u64,
+    pub p95_ms: u64,
+    /// Share of successful runs (0.0 - 1.0)
+    pub success_rate: f32,
+    pub run_count: usize,
+}
+
+/// Editor state kept for a tab while another tab is shown
+struct TabState {
+    content: Vec<String>,
+    cursor: (usize, usize),
+    scroll: usize,
+    dirty: bool,
+}
+
+/// Progress of a collection run (all visible .hurl files)
+struct CollectionRun {
+    /// Results sent back by the background runner tasks
+    rx: mpsc::UnboundedReceiver<(PathBuf, Result<ExecutionResult>)>,
+    total: usize,
+    done: usize,
+    passed: usize,
+}
+
+/// Cookie jar file in the working directory, used when `use_cookie_jar` is enabled
+pub const COOKIE_JAR_FILE: &str = ".hurl-tui-cookies.txt";
+
+/// A line matching the global search query
+#[derive(Debug, Clone)]
+pub struct GlobalSearchResult {
+    pub path: PathBuf,
+    /// 0-based line number
+    pub line: usize,
+    /// Byte column of the match
+    pub col: usize,
+    pub text: String,
+}
+
+/// Files shown in each section above the file tree (pinned files, bookmarks)
+pub const FILE_SECTION_MAX_ENTRIES: usize = 5;
+
+/// Rows taken by a file section above the tree: its shown entries and a divider
+pub fn file_section_rows(entries: usize) -> u16 {
+    if entries == 0 {
+        0
+    } else {
+        entries.min(FILE_SECTION_MAX_ENTRIES) as u16 + 1
+    }
+}
+
+/// Maximum number of global search results shown
+const GLOBAL_SEARCH_LIMIT: usize = 200;
+
+/// Pause in typing after which the global search starts
+const GLOBAL_SEARCH_DEBOUNCE: std::time::Duration = std::time::Duration::from_millis(200);
+
+/// How long a masked .env value stays visible after pressing Enter on it
+const ENV_REVEAL_DURATION: std::time::Duration = std::time::Duration::from_secs(3);
+
+/// How long the target line stays highlighted after a jump
+const JUMP_HIGHLIGHT_DURATION: std::time::Duration = std::time::Duration::from_secs(1);
+
+/// How long the detected hurl version is shown at startup
+const HURL_VERSION_STATUS_DURATION: std::time::Duration = std::time::Duration::from_secs(2);
+
+/// How long the "Auto-saved" message is shown
+const AUTO_SAVE_STATUS_DURATION: std::time::Duration = std::time::Duration::from_secs(1);
+
+/// Columns scrolled by Shift+Left/Right without word wrap
+const H_SCROLL_STEP: usize = 4;
+
+/// How long error messages stay in the status bar (at least)
+const ERROR_STATUS_TIMEOUT_SECS: u64 = 10;
+
 /// Status message level
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub enum StatusLevel {
     Info,
+    /// Background notices (e.g. auto-save), shown dimmed
+    Muted,
     Success,
     Warning,
     Error,
@@ -218,6 +477,9 @@ pub struct App {
     /// Application configuration
     pub config: Config,
 
+    /// Key bindings for configurable actions (built from `config.keys`)
+    pub keymap: KeyMap,
+
     /// Working directory
     pub working_dir: PathBuf,
 
@@ -236,6 +498,27 @@ pub struct App {
     /// File tree entries
     pub file_tree: Vec<FileEntry>,
 
+    /// Whether the file tree is being scanned in the background
+    pub file_tree_loading: bool,
+
+    /// Directories skipped while scanning the working directory
+    pub ignore_patterns: IgnorePatterns,
+
+    /// Expanded folders to restore once the background scan finishes
+    pending_expanded_folders: Vec<String>,
+
+    /// The state file was written by a newer version and is left untouched
+    state_from_newer_version: bool,
+
+    /// Sender for events produced by background tasks (e.g. `Event::FileTreeLoaded`)
+    event_tx: Option<mpsc::UnboundedSender<Event>>,
+
+    /// Stops the event handler from reading input while `$EDITOR` runs
+    input_pause: Option<Arc<AtomicBool>>,
+
+    /// Whether the terminal must be cleared and redrawn (after `$EDITOR` exits)
+    full_redraw: bool,
+
     /// Index of selected file in the flattened tree
     pub file_tree_index: usize,
 
@@ -245,6 +528,9 @@ pub struct App {
     /// Currently open hurl file
     pub current_file: Option<HurlFile>,
 
+    /// Method of the first entry of each .hurl file opened so far (file browser badges)
+    pub file_method_cache: HashMap<PathBuf, String>,
+
     /// Current file path
     pub current_file_path: Option<PathBuf>,
 
@@ -257,15 +543,91 @@ pub struct App {
     /// Editor scroll offset
     pub editor_scroll: usize,
 
+    /// Number of editor lines that fit on screen (updated on resize and render)
+    pub editor_visible_height: usize,
+
+    /// Index of the selected entry in the current file (for running a single entry)
+    pub selected_entry: usize,
+
+    /// Undo/redo history for the editor
+    undo_stack: UndoStack,
+
+    /// Whether the editor content has changes that are not saved to disk
+    pub editor_dirty: bool,
+
+    /// When the editor was last auto-saved, or first had unsaved edits since then
+    last_auto_save: Option<std::time::Instant>,
+
+    /// Files opened with Enter, cycled with `[` / `]` in the editor panel
+    pub open_tabs: Vec<PathBuf>,
+
+    /// Index into `open_tabs` of the tab shown in the editor
+    pub active_tab: usize,
+
+    /// Editor state of tabs that are not currently shown (keyed by path)
+    tab_states: HashMap<PathBuf, TabState>,
+
+    /// Cursor position where the visual selection was started
+    visual_anchor: Option<(usize, usize)>,
+
+    /// First key of a two-key editor sequence (`gg`, `gc`) waiting for the second one
+    pub pending_key: Option<char>,
+
+    /// Position of the bracket matching the one under the editor cursor
+    pub matching_bracket: Option<(usize, usize)>,
+
+    /// Lines yanked or deleted in the editor (`y`, `d`), put back with `p`/`P`
+    pub editor_yank_buffer: Vec<String>,
+
+    /// Request bodies folded in the editor (`z`) as entry index -> request line.
+    /// A fold no longer applies once the request line of its entry changes.
+    pub editor_folds: HashMap<usize, String>,
+
+    /// Highlighted spans of editor lines already rendered, cleared on every content change
+    pub editor_line_cache: BTreeMap<usize, Vec<Span<'static>>>,
+
+    /// Start line and request body lines of each entry, cleared with `editor_line_cache`
+    pub editor_entry_cache: Option<Vec<EntryLines>>,
+
+    /// Lines reported by `hurl --dry-run` after the last save, marked in the gutter
+    pub editor_error_lines: HashSet<usize>,
+
+    /// Pending syntax check of the saved file: (file, error lines)
+    syntax_check_rx: Option<oneshot::Receiver<(PathBuf, Result<Option<SyntaxError>>)>>,
+
+    /// Search query typed after `/` in the vim normal mode
+    pub editor_search_query: String,
+
+    /// Positions (line, column) of all matches of `editor_search_query`
+    pub editor_search_matches: Vec<(usize, usize)>,
+
+    /// Whether the editor search bar is taking input
+    pub editor_search_input: bool,
+
     /// Last execution result
     pub execution_result: Option<ExecutionResult>,
 
+    /// Result of the run before the last one, compared in the response Diff tab
+    pub previous_execution_result: Option<ExecutionResult>,
+
     /// Execution results per file (keyed by relative path from working_dir)
     file_execution_states: HashMap<String, ExecutionResult>,
 
     /// Whether a request is currently running
     pub is_running: bool,
 
+    /// Handle used to cancel the request running in the background (`Ctrl+c`)
+    pub abort_handle: Option<AbortHandle>,
+
+    /// Result of the background request: (file, result, duration)
+    request_rx: Option<oneshot::Receiver<(PathBuf, Result<ExecutionResult>, std::time::Duration)>>,
+
+    /// Retries of the background request after connection errors
+    retry_rx: Option<mpsc::UnboundedReceiver<u32>>,
+
+    /// Collection run in progress (started with `X`)
+    collection_run: Option<CollectionRun>,
+
     /// Spinner animation frame (for progress indicator)
     pub spinner_frame: usize,
 
@@ -287,21 +649,154 @@ pub struct App {
     /// History selection index
     pub history_index: usize,
 
+    /// Bookmarked files, shown above the file tree (`'1`-`'9` jump to the first nine)
+    pub bookmarks: Vec<PathBuf>,
+
+    /// Selection in the bookmark manager popup
+    pub bookmark_index: usize,
+
+    /// Files pinned to the very top of the file browser (`Ctrl+p`)
+    pub pinned_files: Vec<PathBuf>,
+
+    /// Whether `'` was pressed and a bookmark slot digit is expected next
+    bookmark_jump_pending: bool,
+
     /// Search query
     pub search_query: String,
 
+    /// Query typed in the global search popup (Ctrl+f)
+    pub global_search_query: String,
+
+    /// Results of the global search for the current query
+    pub global_search_results: Vec<GlobalSearchResult>,
+
+    /// Selected global search result
+    pub global_search_index: usize,
+
+    /// Pending background search (replaced, and so cancelled, on every query change)
+    global_search_rx: Option<oneshot::Receiver<Vec<GlobalSearchResult>>>,
+
+    /// Last query change; the search starts once typing pauses for `GLOBAL_SEARCH_DEBOUNCE`
+    global_search_typed_at: Option<std::time::Instant>,
+
+    /// Cookies in the cookie jar file with their line index (`:cookies`, Ctrl+k)
+    pub cookie_jar: Vec<(usize, Cookie)>,
+
+    /// Selected cookie in the cookie jar popup
+    pub cookie_jar_index: usize,
+
+    /// Variables passed to hurl as `--variable` on every run, overriding the environment (`:setvar`)
+    pub runtime_variable_overrides: HashMap<String, String>,
+
+    /// Selected override in the variable override popup
+    pub variable_override_index: usize,
+
+    /// Diff of the previous and current response bodies, computed once per run
+    /// (`None` without a previous run or when the bodies are too large to diff)
+    pub body_diff: Option<Vec<DiffLine>>,
+
+    /// Side-by-side diff of the previous and current response bodies (`:compare`)
+    pub compare_rows: Vec<DiffRow>,
+
+    /// Scroll offsets of the previous (left) and current (right) compare panels
+    pub compare_scroll: (usize, usize),
+
+    /// Whether the current (right) compare panel has focus
+    pub compare_right_focused: bool,
+
+    /// Working copy of the environment variables in the env editor popup (Ctrl+e)
+    pub env_editor_vars: Vec<(String, String)>,
+
+    /// Selected variable in the env editor popup
+    pub env_editor_index: usize,
+
+    /// Field being edited inline, if any
+    pub env_editor_editing: Option<EnvEditorField>,
+
+    /// Selected snippet in the snippet popup (`Ctrl+s` in insert mode)
+    pub snippet_index: usize,
+
+    /// Variable names offered for the `{{` being typed in a request URL (insert mode)
+    pub url_completions: Vec<String>,
+
+    /// Completion inserted with Tab/Shift+Tab (None until the first Tab)
+    pub url_completion_index: Option<usize>,
+
+    /// Input buffer for the field being edited
+    pub env_editor_input: String,
+
+    /// Whether the working copy differs from the .env file
+    pub env_editor_modified: bool,
+
     /// Filter query for file browser
     pub filter_query: String,
 
+    /// Whether the file browser filter is a regex (toggled with Ctrl+x in filter mode)
+    pub filter_is_regex: bool,
+
+    /// Last filter regex that compiled (kept while the typed pattern is invalid)
+    filter_regex: Option<regex::Regex>,
+
     /// Command input
     pub command_input: String,
 
+    /// Whether command mode was entered from the editor (return there afterwards)
+    command_from_editor: bool,
+
+    /// Executed commands, oldest first (bounded by `general.max_history`)
+    pub command_history: Vec<String>,
+
+    /// Entry recalled with Up/Down (`command_history.len()` when not browsing)
+    pub command_history_index: usize,
+
+    /// Input typed before browsing the history, restored when Down goes past the newest entry
+    command_history_draft: String,
+
+    /// Query of the `Ctrl+r` command history search
+    pub command_history_query: String,
+
+    /// Selected entry in the command history search results
+    pub command_history_search_index: usize,
+
+    /// Input of the `Ctrl+g` go-to-line popup (`line` or `line:col`)
+    pub goto_line_input: String,
+
+    /// Whether the go-to-line popup was opened from editing mode
+    goto_line_from_editor: bool,
+
+    /// Line briefly highlighted after a jump, and since when
+    pub editor_highlight_line: Option<(usize, std::time::Instant)>,
+
+    /// Entry the editor is zoomed to (`zf`)
+    pub editor_focus_entry: Option<usize>,
+
     /// Status message to display
     pub status_message: Option<(String, StatusLevel)>,
 
+    /// When `status_message` was set; it is cleared after `status_message_timeout_secs`
+    status_message_timestamp: Option<std::time::Instant>,
+
+    /// How long the current status message stays, overriding the configured timeout
+    status_message_duration: Option<std::time::Duration>,
+
+    /// Message shown in the status bar until the app exits, when no other message is shown
+    pub status_notice: Option<(String, StatusLevel)>,
+
     /// Runner instance
     runner: Runner,
 
+    /// Pending `hurl --version` check started by `check_hurl_version`
+    hurl_version_rx: Option<oneshot::Receiver<Result<HurlVersion>>>,
+
+    /// Colors the UI is drawn with (`ui.theme` in the config, switched with `:theme`)
+    pub active_theme: Theme,
+
+    /// Proxy set with `:proxy` for this session (not saved)
+    pub proxy_override: Option<String>,
+
+    /// TLS verification is skipped (starts from the config, toggled with Alt+i)
+    pub insecure_override: bool,
+
     /// Response scroll offset
     pub response_scroll: usize,
 
@@ -320,14 +815,34 @@ pub struct App {
     /// Note: This is separate from the system clipboard used for text copying.
     pub clipboard_file: Option<PathBuf>,
 
+    /// Whether `clipboard_file` was cut ('x') rather than copied,
+    /// in which case pasting moves the file instead of duplicating it.
+    pub clipboard_is_cut: bool,
+
     /// Input buffer for rename operation.
     /// Contains the new filename being typed by the user during rename mode.
     pub rename_input: String,
 
+    /// Cursor position (byte offset) within `rename_input`.
+    pub rename_cursor: usize,
+
     /// The file path being renamed.
     /// Stores the original path of the file when user initiates rename with 'n'.
     rename_target: Option<PathBuf>,
 
+    /// Directory in which a new file is being created.
+    /// Set when the user presses 'N'; rename mode then creates instead of renaming.
+    pub new_file_dir: Option<PathBuf>,
+
+    /// Whether rename mode is prompting for a new directory name (`Ctrl+n`).
+    pub rename_is_new_dir: bool,
+
+    /// The file awaiting delete confirmation (shown in the confirm overlay).
+    pub delete_target: Option<PathBuf>,
+
+    /// File to open once the unsaved-changes prompt is answered, and whether to focus the editor
+    pub pending_open: Option<(PathBuf, bool)>,
+
     /// Effect manager for animations
     pub effect_manager: EffectManager,
 
@@ -340,6 +855,51 @@ pub struct App {
     /// Current response tab (Body, Headers, Raw)
     pub response_tab: ResponseTab,
 
+    /// Detected format of the response body
+    pub response_body_format: BodyFormat,
+
+    /// Show each NDJSON object on a single line
+    pub ndjson_collapsed: bool,
+
+    /// Panel shown alone over the whole content area (toggled with Ctrl+z)
+    pub fullscreen_panel: Option<ActivePanel>,
+
+    /// Long response body lines wrap (toggled with Alt+w); otherwise they scroll horizontally
+    pub response_word_wrap: bool,
+
+    /// Columns the response body is scrolled to the right (without word wrap)
+    pub response_h_scroll: usize,
+
+    /// Long editor lines wrap (toggled with Alt+w); otherwise they scroll horizontally
+    pub editor_word_wrap: bool,
+
+    /// Columns the editor is scrolled to the right (without word wrap)
+    pub editor_h_scroll: usize,
+
+    /// JSON arrays of the response body shown as their first elements only (JSONPath-like keys)
+    pub response_collapsed_paths: HashSet<String>,
+
+    /// Pretty-printed rows of a JSON response body, rebuilt when the response or a collapse changes
+    pub response_json_rows: Option<Vec<JsonRow>>,
+
+    /// Toggle lines of collapsible arrays in view as (line, path), set when the body is drawn
+    pub response_collapse_toggles: Vec<(usize, String)>,
+
+    /// Text searched for in the response body (`/` in the response panel)
+    pub response_search_query: String,
+
+    /// Matches of the response search as (line, column), set when the body is drawn
+    pub response_search_matches: Vec<(usize, usize)>,
+
+    /// The query or the displayed body changed since `response_search_matches` was set
+    pub response_search_stale: bool,
+
+    /// Match of the response search that `n` / `N` last moved to
+    pub response_search_index: usize,
+
+    /// Pretty-print JSON response bodies (raw body as received when false)
+    pub response_pretty_print: bool,
+
     /// Current editor tab (Hurl, Output)
     pub editor_tab: EditorTab,
 
@@ -355,6 +915,9 @@ pub struct App {
     /// Sidebar width percentage (10-50)
     pub sidebar_width: u16,
 
+    /// Panel layout preset (cycled with Alt+l)
+    pub current_layout: LayoutPreset,
+
     /// Whether to show the assertions panel
     pub show_assertions: bool,
 
@@ -363,29 +926,101 @@ pub struct App {
 
     /// Whether to show the response panel
     pub show_response: bool,
+
+    /// Areas of the visible panels from the last draw, used for mouse hit-testing
+    pub panel_areas: Vec<(ActivePanel, Rect)>,
+
+    /// Areas of the response tab titles from the last draw, used for mouse clicks
+    pub response_tab_areas: Vec<(ResponseTab, Rect)>,
+
+    /// Time and position of the last left click (for double-click detection)
+    last_click: Option<(std::time::Instant, u16, u16)>,
+
+    /// Line of an open .env file whose secret value is temporarily shown, and since when
+    pub env_revealed: Option<(usize, std::time::Instant)>,
 }
 
 impl App {
-    /// Create a new application instance
-    pub fn new(config: Config, working_dir: PathBuf) -> Result<Self> {
+    /// Create a new application instance, optionally starting in the given environment (`--env`)
+    pub fn new(config: Config, working_dir: PathBuf, env: Option<String>) -> Result<Self> {
+        let undo_limit = config.editor.editor_history_limit;
+        let insecure = config.general.insecure;
+        let mut runner = Runner::new()
+            .with_retry(config.general.retry_count, config.general.retry_delay_ms)
+            .with_proxy(
+                config.general.proxy.clone(),
+                config.general.no_proxy.clone(),
+            )
+            .with_script_dir(
+                config
+                    .general
+                    .script_dir
+                    .as_ref()
+                    .map(|dir| working_dir.join(dir)),
+            )
+            .with_max_body_bytes(config.general.max_body_display_bytes)
+            .with_insecure(insecure);
+        if config.general.use_cookie_jar {
+            runner = runner.with_cookie_jar(working_dir.join(COOKIE_JAR_FILE));
+        }
+        let theme = Theme::named(&config.ui.theme);
+        let keymap = KeyMap::from_config(&config.keys);
+        let effect_manager = EffectManager::new().with_animations(
+            config.ui.animations_enabled,
+            config.ui.animation_speed_multiplier,
+        );
         let mut app = Self {
             config,
+            keymap,
             working_dir: working_dir.clone(),
             quit: false,
             active_panel: ActivePanel::FileBrowser,
             mode: AppMode::Normal,
             vim_mode: VimMode::Normal,
             file_tree: Vec::new(),
+            file_tree_loading: false,
+            ignore_patterns: IgnorePatterns::default(),
+            pending_expanded_folders: Vec::new(),
+            state_from_newer_version: false,
+            event_tx: None,
+            input_pause: None,
+            full_redraw: false,
             file_tree_index: 0,
             file_tree_state: ListState::default().with_selected(Some(0)),
             current_file: None,
+            file_method_cache: HashMap::new(),
             current_file_path: None,
             editor_content: Vec::new(),
             editor_cursor: (0, 0),
             editor_scroll: 0,
+            editor_visible_height: 20,
+            selected_entry: 0,
+            editor_dirty: false,
+            last_auto_save: None,
+            open_tabs: Vec::new(),
+            active_tab: 0,
+            tab_states: HashMap::new(),
+            undo_stack: UndoStack::new(undo_limit),
+            visual_anchor: None,
+            pending_key: None,
+            matching_bracket: None,
+            editor_yank_buffer: Vec::new(),
+            editor_folds: HashMap::new(),
+            editor_line_cache: BTreeMap::new(),
+            editor_entry_cache: None,
+            editor_error_lines: HashSet::new(),
+            syntax_check_rx: None,
+            editor_search_query: String::new(),
+            editor_search_matches: Vec::new(),
+            editor_search_input: false,
             execution_result: None,
+            previous_execution_result: None,
             file_execution_states: HashMap::new(),
             is_running: false,
+            abort_handle: None,
+            request_rx: None,
+            retry_rx: None,
+            collection_run: None,
             spinner_frame: 0,
             variables: Vec::new(),
             current_environment: String::new(),
@@ -393,30 +1028,100 @@ impl App {
             current_env_file: None,
             history: Vec::new(),
             history_index: 0,
+            bookmarks: Vec::new(),
+            bookmark_index: 0,
+            pinned_files: Vec::new(),
+            bookmark_jump_pending: false,
             search_query: String::new(),
+            global_search_query: String::new(),
+            global_search_results: Vec::new(),
+            global_search_index: 0,
+            global_search_rx: None,
+            global_search_typed_at: None,
+            cookie_jar: Vec::new(),
+            cookie_jar_index: 0,
+            runtime_variable_overrides: HashMap::new(),
+            variable_override_index: 0,
+            body_diff: None,
+            compare_rows: Vec::new(),
+            compare_scroll: (0, 0),
+            compare_right_focused: false,
+            env_editor_vars: Vec::new(),
+            env_editor_index: 0,
+            env_editor_editing: None,
+            snippet_index: 0,
+            url_completions: Vec::new(),
+            url_completion_index: None,
+            env_editor_input: String::new(),
+            env_editor_modified: false,
             filter_query: String::new(),
+            filter_is_regex: false,
+            filter_regex: None,
             command_input: String::new(),
+            command_from_editor: false,
+            command_history: Vec::new(),
+            command_history_index: 0,
+            command_history_draft: String::new(),
+            command_history_query: String::new(),
+            command_history_search_index: 0,
+            goto_line_input: String::new(),
+            goto_line_from_editor: false,
+            editor_highlight_line: None,
+            editor_focus_entry: None,
             status_message: None,
-            runner: Runner::new(),
+            status_message_timestamp: None,
+            status_message_duration: None,
+            status_notice: None,
+            runner,
+            hurl_version_rx: None,
+            active_theme: theme.as_ref().copied().unwrap_or(Theme::HACKER),
+            proxy_override: None,
+            insecure_override: insecure,
             response_scroll: 0,
             assertions_scroll: 0,
             show_help: false,
             output: None,
             clipboard_file: None,
+            clipboard_is_cut: false,
             rename_input: String::new(),
+            rename_cursor: 0,
             rename_target: None,
-            effect_manager: EffectManager::new(),
+            new_file_dir: None,
+            rename_is_new_dir: false,
+            delete_target: None,
+            pending_open: None,
+            effect_manager,
             previous_panel: ActivePanel::FileBrowser,
             previous_show_help: false,
             response_tab: ResponseTab::Body,
+            response_body_format: BodyFormat::default(),
+            ndjson_collapsed: false,
+            fullscreen_panel: None,
+            response_word_wrap: true,
+            response_h_scroll: 0,
+            editor_word_wrap: true,
+            editor_h_scroll: 0,
+            response_collapsed_paths: HashSet::new(),
+            response_json_rows: None,
+            response_collapse_toggles: Vec::new(),
+            response_search_query: String::new(),
+            response_search_matches: Vec::new(),
+            response_search_stale: false,
+            response_search_index: 0,
+            response_pretty_print: true,
             editor_tab: EditorTab::Hurl,
             output_scroll: 0,
             preview_content: Vec::new(),
             preview_scroll: 0,
             sidebar_width: default_sidebar_width(),
+            current_layout: LayoutPreset::default(),
             show_assertions: true,
             show_editor: true,
             show_response: true,
+            panel_areas: Vec::new(),
+            response_tab_areas: Vec::new(),
+            last_click: None,
+            env_revealed: None,
         };
 
         // Load file tree and restore state (including expanded folders and sidebar width)
@@ -430,14 +1135,35 @@ impl App {
             app.current_env_file
         );
 
-        // Restore selected environment from persisted state (must happen after load_environments)
-        app.restore_selected_environment();
+        // An environment given with --env takes precedence over the persisted one
+        if let Some(name) = env {
+            if app.environments.contains(&name) {
+                app.current_environment = name;
+            } else {
+                app.set_status(
+                    &format!(
+                        "Environment '{}' not found, using the first available one",
+                        name
+                    ),
+                    StatusLevel::Warning,
+                );
+                app.current_environment = app.environments.first().cloned().unwrap_or_default();
+            }
+            let _ = app.load_current_environment_variables();
+        } else {
+            // Restore selected environment from persisted state (must happen after load_environments)
+            app.restore_selected_environment();
+        }
         tracing::debug!(
             "After restore_selected_environment: env={}, file={:?}",
             app.current_environment,
             app.current_env_file
         );
 
+        if let Err(e) = theme {
+            app.set_status(&format!("{:#}", e), StatusLevel::Warning);
+        }
+
         // Don't save state on startup - only save on user actions
         // This prevents overwriting the persisted state with defaults
 
@@ -452,14 +1178,7 @@ impl App {
         let state_content = std::fs::read_to_string(&state_path).ok();
         tracing::debug!("State file content exists: {}", state_content.is_some());
         
-        let persisted_state = state_content
-            .and_then(|content| {
-                let result = serde_json::from_str::<PersistedState>(&content);
-                if let Err(ref e) = result {
-                    tracing::warn!("Failed to parse state: {}", e);
-                }
-                result.ok()
-            });
+        let persisted_state = state_content.and_then(|content| Self::parse_state(&content));
 
         let mut restored = false;
 
@@ -512,9 +1231,7 @@ impl App {
                     self.variables.push(Variable {
                         name: key.trim().to_string(),
                         value: value.trim().to_string(),
-                        is_secret: key.to_lowercase().contains("secret")
-                            || key.to_lowercase().contains("password")
-                            || key.to_lowercase().contains("token"),
+                        is_secret: is_secret_key(key),
                     });
                 }
             }
@@ -604,7 +1321,11 @@ impl App {
 
     /// Save the current state (last opened file and execution states)
     fn save_state(&self) {
+        if self.state_from_newer_version {
+            return;
+        }
         let state = PersistedState {
+            schema_version: STATE_SCHEMA_VERSION,
             last_opened_file: self
                 .current_file_path
                 .as_ref()
@@ -615,11 +1336,29 @@ impl App {
                 .current_env_file
                 .as_ref()
                 .map(|p| p.to_string_lossy().to_string()),
-            expanded_folders: self.collect_expanded_folders(),
+            expanded_folders: if self.file_tree_loading {
+                self.pending_expanded_folders.clone()
+            } else {
+                self.collect_expanded_folders()
+            },
             sidebar_width: self.sidebar_width,
             show_assertions: self.show_assertions,
             show_editor: self.show_editor,
             show_response: self.show_response,
+            layout: self.current_layout,
+            open_tabs: self
+                .open_tabs
+                .iter()
+                .map(|p| p.to_string_lossy().to_string())
+                .collect(),
+            bookmarks: self.bookmarks.clone(),
+            pinned_files: self.pinned_files.clone(),
+            command_history: self
+                .command_history
+                .iter()
+                .filter(|command| is_persisted_command(command))
+                .cloned()
+                .collect(),
         };
 
         tracing::debug!(
@@ -633,26 +1372,40 @@ impl App {
         }
     }
 
+    /// Parse the state file, migrating it when it was written by an older version
+    fn parse_state(content: &str) -> Option<PersistedState> {
+        match serde_json::from_str::<serde_json::Value>(content) {
+            Ok(raw) => Some(migrate_state(raw)),
+            Err(e) => {
+                tracing::warn!("Failed to parse state: {}", e);
+                None
+            }
+        }
+    }
+
     /// Load file tree and restore all persisted state
     fn load_file_tree_and_restore_state(&mut self) -> Result<()> {
         let state_path = self.get_state_file_path();
         let persisted_state = std::fs::read_to_string(&state_path)
             .ok()
-            .and_then(|content| serde_json::from_str::<PersistedState>(&content).ok());
+            .and_then(|content| Self::parse_state(&content));
 
-        // Load file tree
-        self.file_tree = Self::load_directory_children(&self.working_dir, 0)?;
+        // The file tree itself is loaded by `scan_file_tree` once the event loop runs
+        self.ignore_patterns = IgnorePatterns::load(&self.working_dir);
 
-        // Restore expanded folders if we have persisted state, otherwise auto-expand
         if let Some(ref state) = persisted_state {
-            if !state.expanded_folders.is_empty() {
-                // Restore saved expanded state
-                self.restore_expanded_folders(&state.expanded_folders);
-            } else {
-                // No saved state, auto-expand directories with .hurl files
-                self.auto_expand_hurl_directories();
+            if state.schema_version > STATE_SCHEMA_VERSION {
+                tracing::warn!(
+                    "State file has schema version {} (newer than {}), it will not be updated",
+                    state.schema_version,
+                    STATE_SCHEMA_VERSION
+                );
+                self.state_from_newer_version = true;
             }
 
+            // Restored when the scan finishes (directories with .hurl files are auto-expanded otherwise)
+            self.pending_expanded_folders = state.expanded_folders.clone();
+
             // Restore file execution states
             self.file_execution_states = state.file_execution_states.clone();
 
@@ -663,6 +1416,28 @@ impl App {
             self.show_assertions = state.show_assertions;
             self.show_editor = state.show_editor;
             self.show_response = state.show_response;
+            self.current_layout = state.layout;
+
+            // Restore open tabs, dropping files that no longer exist
+            self.open_tabs = state
+                .open_tabs
+                .iter()
+                .map(PathBuf::from)
+                .filter(|p| p.exists())
+                .collect();
+            self.bookmarks = state
+                .bookmarks
+                .iter()
+                .filter(|p| p.exists())
+                .cloned()
+                .collect();
+            self.pinned_files = state
+                .pinned_files
+                .iter()
+                .filter(|p| p.exists())
+                .cloned()
+                .collect();
+            self.command_history = state.command_history.clone();
 
             // Note: Environment restoration happens in restore_selected_environment()
             // after load_environments() populates the environments list
@@ -673,10 +1448,8 @@ impl App {
                 if path.exists() {
                     let _ = self.preview_file(&path);
 
-                    // Try to restore file tree index
-                    let max = self.get_visible_file_count().saturating_sub(1);
-                    self.file_tree_index = state.file_tree_index.min(max);
-                    self.file_tree_state.select(Some(self.file_tree_index));
+                    // Clamped to the tree once it is loaded
+                    self.file_tree_index = state.file_tree_index;
 
                     self.set_status(
                         &format!(
@@ -689,18 +1462,85 @@ impl App {
                     );
                 }
             }
-        } else {
-            // No persisted state, auto-expand directories with .hurl files
-            self.auto_expand_hurl_directories();
         }
 
         Ok(())
     }
 
+    /// Set the sender used by background tasks to report back to the event loop
+    pub fn set_event_sender(&mut self, tx: mpsc::UnboundedSender<Event>) {
+        self.event_tx = Some(tx);
+    }
+
+    /// Set the flag that pauses input reading (see `EventHandler::input_pause`)
+    pub fn set_input_pause(&mut self, flag: Arc<AtomicBool>) {
+        self.input_pause = Some(flag);
+    }
+
+    /// Whether the whole terminal must be redrawn; resets the request
+    pub fn take_full_redraw(&mut self) -> bool {
+        std::mem::take(&mut self.full_redraw)
+    }
+
+    /// Scan the working directory in the background, keeping the expanded folders.
+    /// The result arrives as `Event::FileTreeLoaded`.
+    pub fn scan_file_tree(&mut self) {
+        if self.file_tree_loading {
+            return;
+        }
+        if !self.file_tree.is_empty() {
+            self.pending_expanded_folders = self.collect_expanded_folders();
+        }
+        // The ignore file may have changed
+        self.ignore_patterns = IgnorePatterns::load(&self.working_dir);
+
+        let Some(tx) = self.event_tx.clone() else {
+            let entries =
+                Self::load_directory_children(&self.working_dir, 0, &self.ignore_patterns)
+                    .unwrap_or_default();
+            self.handle_file_tree_loaded(entries);
+            return;
+        };
+        self.file_tree_loading = true;
+        let working_dir = self.working_dir.clone();
+        let ignore = self.ignore_patterns.clone();
+        tokio::task::spawn_blocking(move || {
+            let entries =
+                Self::load_directory_children(&working_dir, 0, &ignore).unwrap_or_default();
+            let _ = tx.send(Event::FileTreeLoaded(entries));
+        });
+    }
+
+    /// Replace the file tree with the result of `scan_file_tree`
+    pub fn handle_file_tree_loaded(&mut self, entries: Vec<FileEntry>) {
+        let refreshed = !self.file_tree.is_empty();
+        self.file_tree_loading = false;
+        self.file_tree = entries;
+
+        let expanded = std::mem::take(&mut self.pending_expanded_folders);
+        if expanded.is_empty() {
+            self.auto_expand_hurl_directories();
+        } else {
+            self.restore_expanded_folders(&expanded);
+        }
+
+        let max = self.get_visible_file_count().saturating_sub(1);
+        self.file_tree_index = self.file_tree_index.min(max);
+        self.file_tree_state.select(Some(self.file_tree_index));
+
+        if refreshed {
+            self.set_status("File tree refreshed", StatusLevel::Info);
+        }
+    }
+
     /// Restore expanded folders from persisted state
     fn restore_expanded_folders(&mut self, expanded_paths: &[String]) {
-        let working_dir = self.working_dir.clone();
-        Self::restore_expanded_recursive(&mut self.file_tree, expanded_paths, &working_dir);
+        Self::restore_expanded_recursive(
+            &mut self.file_tree,
+            expanded_paths,
+            &self.working_dir,
+            &self.ignore_patterns,
+        );
     }
 
     /// Recursively restore expanded state for folders
@@ -708,6 +1548,7 @@ impl App {
         entries: &mut [FileEntry],
         expanded_paths: &[String],
         working_dir: &PathBuf,
+        ignore: &IgnorePatterns,
     ) {
         for entry in entries.iter_mut() {
             if entry.is_dir {
@@ -724,19 +1565,30 @@ impl App {
                     // Load children if not already loaded
                     if entry.children.is_empty() {
                         if let Ok(children) =
-                            App::load_directory_children(&entry.path, entry.depth + 1)
+                            App::load_directory_children(&entry.path, entry.depth + 1, ignore)
                         {
                             entry.children = children;
                         }
                     }
 
                     // Recursively restore children
-                    Self::restore_expanded_recursive(&mut entry.children, expanded_paths, working_dir);
+                    Self::restore_expanded_recursive(
+                        &mut entry.children,
+                        expanded_paths,
+                        working_dir,
+                        ignore,
+                    );
                 }
             }
         }
     }
 
+    /// Save the state and stop the event loop
+    fn exit(&mut self) {
+        self.save_state();
+        self.quit = true;
+    }
+
     /// Check if the application should quit
     pub fn should_quit(&self) -> bool {
         self.quit
@@ -749,11 +1601,33 @@ impl App {
 
     /// Handle tick event (called periodically)
     pub fn on_tick(&mut self) {
-        // Advance spinner animation when running
-        if self.is_running {
+        // Advance spinner animation when running or scanning
+        if self.is_running || self.collection_run.is_some() || self.file_tree_loading {
             self.spinner_frame = self.spinner_frame.wrapping_add(1);
         }
 
+        self.poll_request();
+        self.poll_collection_run();
+        self.poll_global_search();
+        self.poll_syntax_check();
+        self.poll_hurl_version();
+        self.auto_save();
+
+        // Mask a revealed .env value again once its time is up
+        if self
+            .env_revealed
+            .is_some_and(|(_, since)| since.elapsed() >= ENV_REVEAL_DURATION)
+        {
+            self.env_revealed = None;
+        }
+        if self
+            .editor_highlight_line
+            .is_some_and(|(_, since)| since.elapsed() >= JUMP_HIGHLIGHT_DURATION)
+        {
+            self.editor_highlight_line = None;
+        }
+        self.expire_status_message();
+
         // Note: Effect timing is handled in the render loop via effect_manager.tick()
     }
 
@@ -761,12 +1635,23 @@ impl App {
     pub async fn handle_key_event(&mut self, key: KeyEvent) -> Result<()> {
         // Global shortcuts (work in any mode)
         match (key.modifiers, key.code) {
+            // Cancel a running request before quitting
+            (KeyModifiers::CONTROL, KeyCode::Char('c')) if self.is_running => {
+                self.cancel_request();
+                return Ok(());
+            }
             (KeyModifiers::CONTROL, KeyCode::Char('c')) => {
-                self.quit = true;
+                self.exit();
                 return Ok(());
             }
             (KeyModifiers::CONTROL, KeyCode::Char('q')) => {
-                self.quit = true;
+                self.exit();
+                return Ok(());
+            }
+            (KeyModifiers::CONTROL, KeyCode::Char('z'))
+                if matches!(self.mode, AppMode::Normal | AppMode::Editing) =>
+            {
+                self.toggle_fullscreen();
                 return Ok(());
             }
             _ => {}
@@ -777,43 +1662,122 @@ impl App {
             AppMode::Normal => self.handle_normal_mode_key(key).await?,
             AppMode::Editing => self.handle_editing_mode_key(key)?,
             AppMode::Search => self.handle_search_mode_key(key)?,
+            AppMode::ResponseSearch => self.handle_response_search_mode_key(key),
             AppMode::Command => self.handle_command_mode_key(key)?,
+            AppMode::CommandHistorySearch => self.handle_command_history_search_key(key),
             AppMode::Filter => self.handle_filter_mode_key(key)?,
             AppMode::Rename => self.handle_rename_mode_key(key)?,
+            AppMode::ConfirmDelete => self.handle_confirm_delete_mode_key(key)?,
+            AppMode::ConfirmSave => self.handle_confirm_save_mode_key(key)?,
+            AppMode::GlobalSearch => self.handle_global_search_mode_key(key)?,
+            AppMode::CookieJar => self.handle_cookie_jar_mode_key(key)?,
+            AppMode::EnvEditor => self.handle_env_editor_mode_key(key)?,
+            AppMode::History => self.handle_history_mode_key(key)?,
+            AppMode::Snippets => self.handle_snippets_mode_key(key)?,
+            AppMode::GotoLine => self.handle_goto_line_mode_key(key)?,
+            AppMode::Bookmarks => self.handle_bookmarks_mode_key(key)?,
+            AppMode::FocusedEntry => self.handle_focused_entry_mode_key(key)?,
+            AppMode::VariableOverride => self.handle_variable_override_mode_key(key)?,
+            AppMode::Compare => self.handle_compare_mode_key(key),
+            AppMode::Stats => {
+                if matches!(key.code, KeyCode::Esc | KeyCode::Char('q')) {
+                    self.mode = AppMode::Normal;
+                }
+            }
+        }
+
+        // Switching to another panel leaves fullscreen
+        if self
+            .fullscreen_panel
+            .is_some_and(|panel| panel != self.active_panel)
+        {
+            self.fullscreen_panel = None;
         }
 
         Ok(())
     }
 
-    /// Handle key events in normal mode
-    async fn handle_normal_mode_key(&mut self, key: KeyEvent) -> Result<()> {
-        match key.code {
-            // Quit
-            KeyCode::Char('q') => {
+    /// Run an action bound through the key map
+    async fn run_action(&mut self, action: Action) -> Result<()> {
+        match action {
+            Action::Quit => {
                 if self.show_help {
                     self.show_help = false;
                 } else {
-                    self.quit = true;
+                    self.exit();
                 }
             }
-
-            // Help
-            KeyCode::Char('?') => {
+            Action::Help => {
                 self.show_help = !self.show_help;
                 if self.show_help {
                     self.trigger_help_overlay_effect();
                 }
             }
-
-            // Panel navigation
-            KeyCode::Tab => {
-                self.next_panel();
-            }
-            KeyCode::BackTab => {
-                self.previous_panel();
+            Action::Run => {
+                self.run_current_request()?;
             }
-
-            // Vim-style navigation
+            Action::Edit => {
+                if self.current_file.is_some() {
+                    self.mode = AppMode::Editing;
+                    self.active_panel = ActivePanel::Editor;
+                    self.update_matching_bracket();
+                }
+            }
+            Action::Save => {
+                self.save_current_file()?;
+            }
+        }
+        Ok(())
+    }
+
+    /// Handle key events in normal mode
+    async fn handle_normal_mode_key(&mut self, key: KeyEvent) -> Result<()> {
+        // `'` followed by a slot digit jumps to a bookmark
+        if std::mem::take(&mut self.bookmark_jump_pending) {
+            if let KeyCode::Char(c @ '1'..='9') = key.code {
+                self.jump_to_bookmark(c as usize - '1' as usize);
+            }
+            return Ok(());
+        }
+
+        // Configurable keys take precedence over the built-in ones
+        if let Some(action) = self.keymap.action(&key) {
+            return self.run_action(action).await;
+        }
+
+        match key.code {
+            // Panel navigation
+            KeyCode::Tab => {
+                self.next_panel();
+            }
+            KeyCode::BackTab => {
+                self.previous_panel();
+            }
+
+            // Cycle layout presets
+            KeyCode::Char('l') if key.modifiers.contains(KeyModifiers::ALT) => {
+                self.current_layout = self.current_layout.next();
+                self.save_state();
+                self.set_status(
+                    &format!("Layout: {}", self.current_layout.name()),
+                    StatusLevel::Info,
+                );
+            }
+
+            // Toggle word wrap of the active panel
+            KeyCode::Char('w') if key.modifiers.contains(KeyModifiers::ALT) => {
+                self.toggle_word_wrap();
+            }
+
+            // Horizontal scrolling of the active panel (without word wrap)
+            KeyCode::Left if key.modifiers.contains(KeyModifiers::SHIFT) => {
+                self.scroll_horizontally(-(H_SCROLL_STEP as isize));
+            }
+            KeyCode::Right if key.modifiers.contains(KeyModifiers::SHIFT) => {
+                self.scroll_horizontally(H_SCROLL_STEP as isize);
+            }
+
+            // Vim-style navigation
             KeyCode::Char('h') => {
                 self.active_panel = ActivePanel::FileBrowser;
             }
@@ -825,6 +1789,49 @@ impl App {
                 }
             }
 
+            // Show the cookie jar
+            KeyCode::Char('k') if key.modifiers.contains(KeyModifiers::CONTROL) => {
+                self.open_cookie_jar();
+            }
+
+            // Edit the variables of the current environment
+            KeyCode::Char('e') if key.modifiers.contains(KeyModifiers::CONTROL) => {
+                self.open_env_editor();
+            }
+
+            // Edit the current file in $EDITOR
+            KeyCode::Char('b') if key.modifiers.contains(KeyModifiers::CONTROL) => {
+                if let Err(e) = self.open_in_external_editor() {
+                    self.set_status(
+                        &format!("External editor failed: {}", e),
+                        StatusLevel::Error,
+                    );
+                }
+            }
+
+            // Toggle TLS certificate verification (Ctrl+i arrives as Tab in most terminals)
+            KeyCode::Char('i') if key.modifiers.contains(KeyModifiers::ALT) => {
+                self.toggle_insecure();
+            }
+
+            // Toggle pretty-printing of the response body
+            KeyCode::Char('p')
+                if key.modifiers.contains(KeyModifiers::CONTROL)
+                    && self.active_panel == ActivePanel::Response =>
+            {
+                self.response_pretty_print = !self.response_pretty_print;
+                self.response_scroll = 0;
+                self.update_response_json_rows();
+            }
+
+            // Pin the selected file to the top of the file browser
+            KeyCode::Char('p')
+                if key.modifiers.contains(KeyModifiers::CONTROL)
+                    && self.active_panel == ActivePanel::FileBrowser =>
+            {
+                self.toggle_pin();
+            }
+
             // Vertical navigation
             KeyCode::Char('j') | KeyCode::Down => {
                 self.navigate_down();
@@ -855,17 +1862,56 @@ impl App {
                 self.toggle_expand();
             }
 
-            // Run request
-            KeyCode::Char('r') => {
-                self.run_current_request().await?;
+            // Run only the selected entry
+            KeyCode::Char('r') if key.modifiers.contains(KeyModifiers::CONTROL) => {
+                self.run_selected_entry()?;
             }
 
-            // Edit mode
-            KeyCode::Char('e') => {
-                if self.current_file.is_some() {
-                    self.mode = AppMode::Editing;
-                    self.active_panel = ActivePanel::Editor;
-                }
+            // Search in all .hurl files
+            KeyCode::Char('f') if key.modifiers.contains(KeyModifiers::CONTROL) => {
+                self.mode = AppMode::GlobalSearch;
+                self.global_search_query.clear();
+                self.global_search_results.clear();
+                self.global_search_index = 0;
+                self.global_search_rx = None;
+            }
+
+            // Toggle animations
+            KeyCode::Char('a') if key.modifiers.contains(KeyModifiers::CONTROL) => {
+                let enabled = !self.effect_manager.is_enabled();
+                self.effect_manager.set_enabled(enabled);
+                let state = if enabled { "on" } else { "off" };
+                self.set_status(&format!("Animations {}", state), StatusLevel::Info);
+            }
+
+            // Select previous/next entry in the current file
+            KeyCode::Char('{') => {
+                self.select_entry(-1);
+            }
+            KeyCode::Char('}') => {
+                self.select_entry(1);
+            }
+
+            // Search the response body when the response panel is focused
+            KeyCode::Char('/') if self.active_panel == ActivePanel::Response => {
+                self.response_tab = ResponseTab::Body;
+                self.response_search_query.clear();
+                self.response_search_index = 0;
+                self.response_search_stale = true;
+                self.mode = AppMode::ResponseSearch;
+            }
+            KeyCode::Char(c @ ('n' | 'N'))
+                if self.active_panel == ActivePanel::Response
+                    && !self.response_search_query.is_empty() =>
+            {
+                self.response_search_jump(c == 'n');
+            }
+            KeyCode::Esc
+                if self.active_panel == ActivePanel::Response
+                    && !self.response_search_query.is_empty() =>
+            {
+                self.response_search_query.clear();
+                self.response_search_stale = true;
             }
 
             // Search
@@ -883,16 +1929,14 @@ impl App {
             // Clear filter
             KeyCode::Char('F') => {
                 self.filter_query.clear();
+                self.filter_regex = None;
                 self.file_tree_index = 0;
                 self.file_tree_state.select(Some(0));
                 self.set_status("Filter cleared", StatusLevel::Info);
             }
 
             // Command mode
-            KeyCode::Char(':') => {
-                self.mode = AppMode::Command;
-                self.command_input.clear();
-            }
+            KeyCode::Char(':') => self.open_command_line(false),
 
             // Toggle variables panel
             KeyCode::Char('v') => {
@@ -905,8 +1949,12 @@ impl App {
 
             // Refresh file tree
             KeyCode::Char('R') => {
-                self.refresh_file_tree()?;
-                self.set_status("File tree refreshed", StatusLevel::Info);
+                self.scan_file_tree();
+            }
+
+            // Go to line
+            KeyCode::Char('g') if key.modifiers.contains(KeyModifiers::CONTROL) => {
+                self.open_goto_line(false);
             }
 
             // Go to top
@@ -924,6 +1972,25 @@ impl App {
                 self.cycle_environment();
             }
 
+            // Bookmarks
+            KeyCode::Char('m') if self.active_panel == ActivePanel::FileBrowser => {
+                self.toggle_bookmark();
+            }
+            KeyCode::Char('M') => {
+                self.bookmark_index = 0;
+                self.mode = AppMode::Bookmarks;
+            }
+            KeyCode::Char('\'') => {
+                self.bookmark_jump_pending = true;
+                self.set_status("Jump to bookmark (1-9)", StatusLevel::Info);
+            }
+
+            // Run history
+            KeyCode::Char('H') => {
+                self.history_index = 0;
+                self.mode = AppMode::History;
+            }
+
             // Copy file path (y = yank path)
             KeyCode::Char('y') => {
                 self.copy_current_file_path();
@@ -934,6 +2001,11 @@ impl App {
                 self.copy_response();
             }
 
+            // Cut file (x in the file browser)
+            KeyCode::Char('x') if self.active_panel == ActivePanel::FileBrowser => {
+                self.cut_file_to_clipboard();
+            }
+
             // Copy request content (x = extract request)
             KeyCode::Char('x') => {
                 self.copy_request_content();
@@ -969,11 +2041,38 @@ impl App {
                 self.paste_file_from_clipboard();
             }
 
+            // New directory (Ctrl+n, next to the selected entry)
+            KeyCode::Char('n')
+                if key.modifiers.contains(KeyModifiers::CONTROL)
+                    && self.active_panel == ActivePanel::FileBrowser =>
+            {
+                self.start_create_directory();
+            }
+
             // Rename file (n = name/rename file)
             KeyCode::Char('n') => {
                 self.start_rename();
             }
 
+            // New file (N = new hurl file in the selected directory)
+            KeyCode::Char('N') if self.active_panel == ActivePanel::FileBrowser => {
+                self.start_new_file();
+            }
+
+            // Delete file (asks for confirmation first)
+            KeyCode::Delete if self.active_panel == ActivePanel::FileBrowser => {
+                self.delete_selected_file();
+            }
+
+            // Collapse/expand NDJSON objects in the response body
+            KeyCode::Char('z') if self.active_panel == ActivePanel::Response => {
+                if matches!(self.response_body_format, BodyFormat::Ndjson(_)) {
+                    self.ndjson_collapsed = !self.ndjson_collapsed;
+                    self.response_scroll = 0;
+                    self.response_search_stale = true;
+                }
+            }
+
             // Tab switching with number keys
             KeyCode::Char('1') => {
                 if self.active_panel == ActivePanel::Response {
@@ -1002,13 +2101,59 @@ impl App {
                     self.editor_tab = EditorTab::Preview;
                 }
             }
+            KeyCode::Char('4') => {
+                if self.active_panel == ActivePanel::Response {
+                    self.response_tab = ResponseTab::Cookies;
+                    self.response_scroll = 0;
+                }
+            }
+            KeyCode::Char('5') => {
+                if self.active_panel == ActivePanel::Response {
+                    self.response_tab = ResponseTab::Timing;
+                    self.response_scroll = 0;
+                }
+            }
+            KeyCode::Char('6') => {
+                if self.active_panel == ActivePanel::Response {
+                    self.response_tab = ResponseTab::Request;
+                    self.response_scroll = 0;
+                }
+            }
+            KeyCode::Char('7') => {
+                if self.active_panel == ActivePanel::Response {
+                    if self.previous_execution_result.is_some() {
+                        self.response_tab = ResponseTab::Diff;
+                        self.response_scroll = 0;
+                    } else {
+                        self.set_status("Run the request twice to see a diff", StatusLevel::Info);
+                    }
+                }
+            }
 
-            // Sidebar resize
+            // Close the active tab
+            KeyCode::Char('w') if key.modifiers.contains(KeyModifiers::CONTROL) => {
+                self.close_active_tab();
+            }
+
+            // Tab switching in the editor, sidebar resize elsewhere
             KeyCode::Char('[') => {
-                self.resize_sidebar(-2);
+                if self.active_panel == ActivePanel::Editor {
+                    self.switch_tab(-1);
+                } else {
+                    self.resize_sidebar(-2);
+                }
             }
             KeyCode::Char(']') => {
-                self.resize_sidebar(2);
+                if self.active_panel == ActivePanel::Editor {
+                    self.switch_tab(1);
+                } else {
+                    self.resize_sidebar(2);
+                }
+            }
+
+            // Run all visible .hurl files (X = execute all)
+            KeyCode::Char('X') => {
+                self.run_all_files_in_directory()?;
             }
 
             // Toggle assertions panel visibility
@@ -1034,16 +2179,141 @@ impl App {
 
     /// Handle key events in editing mode (vim-style)
     fn handle_editing_mode_key(&mut self, key: KeyEvent) -> Result<()> {
+        if self.editor_search_input {
+            self.handle_editor_search_key(key);
+            return Ok(());
+        }
+
+        // Ctrl+s in insert mode picks a snippet instead of saving
+        if self.vim_mode == VimMode::Insert
+            && key.modifiers.contains(KeyModifiers::CONTROL)
+            && key.code == KeyCode::Char('s')
+        {
+            if self.config.snippets.is_empty() {
+                self.set_status("No snippets configured", StatusLevel::Warning);
+            } else {
+                self.snippet_index = 0;
+                self.url_completions.clear();
+                self.mode = AppMode::Snippets;
+            }
+            return Ok(());
+        }
+
+        // A save key with a modifier also works while editing (plain keys are vim commands)
+        if key
+            .modifiers
+            .intersects(KeyModifiers::CONTROL | KeyModifiers::ALT)
+            && self.keymap.action(&key) == Some(Action::Save)
+        {
+            return self.save_current_file();
+        }
+
         match self.vim_mode {
-            VimMode::Normal => self.handle_vim_normal_mode(key),
-            VimMode::Insert => self.handle_vim_insert_mode(key),
+            VimMode::Normal => self.handle_vim_normal_mode(key)?,
+            VimMode::Insert => self.handle_vim_insert_mode(key)?,
+            VimMode::Visual => self.handle_vim_visual_mode(key)?,
+        }
+
+        // Keep search highlights in sync with edits
+        if !self.editor_search_query.is_empty() {
+            self.update_editor_search_matches();
+        }
+        self.update_matching_bracket();
+        Ok(())
+    }
+
+    /// Find the bracket matching the one under the cursor (normal and insert mode only)
+    fn update_matching_bracket(&mut self) {
+        self.matching_bracket = match self.vim_mode {
+            VimMode::Normal | VimMode::Insert => find_matching_bracket(
+                &self.editor_content,
+                self.editor_cursor.0,
+                self.editor_cursor.1,
+            ),
+            VimMode::Visual => None,
+        };
+    }
+
+    /// Handle keys typed into the editor search bar
+    fn handle_editor_search_key(&mut self, key: KeyEvent) {
+        match key.code {
+            KeyCode::Esc => self.clear_editor_search(),
+            KeyCode::Enter => {
+                self.editor_search_input = false;
+                if self.editor_search_query.is_empty() {
+                    return;
+                }
+                let count = self.editor_search_matches.len();
+                if count == 0 {
+                    self.set_status(
+                        &format!("Pattern not found: {}", self.editor_search_query),
+                        StatusLevel::Warning,
+                    );
+                } else {
+                    self.set_status(&format!("{} match(es)", count), StatusLevel::Info);
+                }
+            }
+            KeyCode::Backspace => {
+                self.editor_search_query.pop();
+                self.update_editor_search_matches();
+            }
+            KeyCode::Char(c) => {
+                self.editor_search_query.push(c);
+                self.update_editor_search_matches();
+                // Incremental search: jump to the first match from the cursor
+                if let Some(&pos) = self
+                    .editor_search_matches
+                    .iter()
+                    .find(|&&pos| pos >= self.editor_cursor)
+                    .or(self.editor_search_matches.first())
+                {
+                    self.editor_cursor = pos;
+                    self.ensure_cursor_visible();
+                }
+            }
+            _ => {}
         }
     }
 
     /// Handle vim normal mode keys (navigation and commands)
     fn handle_vim_normal_mode(&mut self, key: KeyEvent) -> Result<()> {
+        // Second key of `gg` / `gc` / `]]` / `[[` / `za` / `zf`; any other key cancels
+        // the sequence and is handled as usual
+        if let Some(pending) = self.pending_key.take() {
+            match (pending, key.code) {
+                ('g', KeyCode::Char('g')) => {
+                    self.editor_move_to_first_line();
+                    return Ok(());
+                }
+                ('g', KeyCode::Char('c')) => {
+                    self.toggle_comment();
+                    return Ok(());
+                }
+                (']', KeyCode::Char(']')) => {
+                    self.next_entry_start();
+                    return Ok(());
+                }
+                ('[', KeyCode::Char('[')) => {
+                    self.prev_entry_start();
+                    return Ok(());
+                }
+                ('z', KeyCode::Char('a')) => {
+                    self.toggle_body_fold();
+                    return Ok(());
+                }
+                ('z', KeyCode::Char('f')) => {
+                    self.focus_entry();
+                    return Ok(());
+                }
+                _ => {}
+            }
+        }
+
         match key.code {
-            // Exit editing mode
+            // Clear the search first, then exit editing mode
+            KeyCode::Esc if !self.editor_search_query.is_empty() => {
+                self.clear_editor_search();
+            }
             KeyCode::Esc => {
                 self.mode = AppMode::Normal;
                 self.vim_mode = VimMode::Normal;
@@ -1065,8 +2335,12 @@ impl App {
             KeyCode::Char('^') => self.editor_move_to_first_non_whitespace(),
 
             // File navigation
-            KeyCode::Char('g') => self.editor_move_to_first_line(),
+            KeyCode::Char('g') if key.modifiers.contains(KeyModifiers::CONTROL) => {
+                self.open_goto_line(true);
+            }
+            KeyCode::Char('g') => self.pending_key = Some('g'),
             KeyCode::Char('G') => self.editor_move_to_last_line(),
+            KeyCode::Char(c @ (']' | '[')) => self.pending_key = Some(c),
 
             // Word navigation
             KeyCode::Char('w') => self.editor_move_word_forward(),
@@ -1102,15 +2376,54 @@ impl App {
             KeyCode::Char('u') if key.modifiers.contains(KeyModifiers::CONTROL) => {
                 self.editor_page_up();
             }
+            KeyCode::PageUp => self.editor_page_up(),
+            KeyCode::PageDown => self.editor_page_down(),
+
+            // Duplicate the entry under the cursor
             KeyCode::Char('d') if key.modifiers.contains(KeyModifiers::CONTROL) => {
-                self.editor_page_down();
+                self.duplicate_hurl_entry();
+            }
+
+            // Enter visual mode
+            KeyCode::Char('v') => {
+                self.visual_anchor = Some(self.editor_cursor);
+                self.vim_mode = VimMode::Visual;
             }
 
+            // Command line (e.g. `:42` to jump to a line)
+            KeyCode::Char(':') => self.open_command_line(true),
+
+            // Search
+            KeyCode::Char('/') => {
+                self.editor_search_query.clear();
+                self.editor_search_matches.clear();
+                self.editor_search_input = true;
+            }
+            KeyCode::Char('n') => self.editor_search_jump(true),
+            KeyCode::Char('N') => self.editor_search_jump(false),
+
+            // Undo/redo
+            KeyCode::Char('r') if key.modifiers.contains(KeyModifiers::CONTROL) => {
+                self.editor_redo();
+            }
+            KeyCode::Char('u') => self.editor_undo(),
+
             // Delete commands
             KeyCode::Char('x') => self.editor_delete_char(),
             KeyCode::Char('d') => self.editor_delete_line(),
             KeyCode::Char('D') => self.editor_delete_to_end(),
 
+            // Yank and put
+            KeyCode::Char('y') => self.editor_yank_line(),
+            KeyCode::Char('p') => self.editor_put_lines(true),
+            KeyCode::Char('P') => self.editor_put_lines(false),
+
+            // Fold the request body of the entry under the cursor
+            KeyCode::Char('z') => self.pending_key = Some('z'),
+
+            // Briefly show a masked .env value
+            KeyCode::Enter => self.reveal_env_value(),
+
             _ => {}
         }
 
@@ -1119,6 +2432,21 @@ impl App {
 
     /// Handle vim insert mode keys (text input)
     fn handle_vim_insert_mode(&mut self, key: KeyEvent) -> Result<()> {
+        // Tab/Shift+Tab cycle through the URL completions, any other key dismisses them
+        if !self.url_completions.is_empty() {
+            match key.code {
+                KeyCode::Tab | KeyCode::BackTab => {
+                    self.cycle_url_completion(key.code == KeyCode::Tab);
+                    return Ok(());
+                }
+                KeyCode::Esc => {
+                    self.url_completions.clear();
+                    return Ok(());
+                }
+                _ => self.url_completions.clear(),
+            }
+        }
+
         match key.code {
             KeyCode::Esc => {
                 self.vim_mode = VimMode::Normal;
@@ -1141,6 +2469,144 @@ impl App {
             _ => {}
         }
 
+        if matches!(key.code, KeyCode::Char(_) | KeyCode::Backspace) {
+            self.update_url_completions();
+        }
+
+        Ok(())
+    }
+
+    /// Offer the environment variables and captures matching the `{{` typed before the cursor
+    fn update_url_completions(&mut self) {
+        let (line, col) = self.editor_cursor;
+        let Some(content) = self.editor_content.get(line) else {
+            return;
+        };
+        let before = &content[..col.min(content.len())];
+
+        let captures = self
+            .current_file
+            .iter()
+            .flat_map(|file| file.entries.iter())
+            .flat_map(|entry| entry.captures.iter())
+            .map(|capture| Variable {
+                name: capture.name.clone(),
+                value: String::new(),
+                is_secret: false,
+            });
+        let variables: Vec<Variable> = self.variables.iter().cloned().chain(captures).collect();
+
+        self.url_completions = url_completion_candidates(before, &variables);
+        self.url_completion_index = None;
+    }
+
+    /// Replace the name after `{{` with the next (or previous) completion
+    fn cycle_url_completion(&mut self, forward: bool) {
+        let count = self.url_completions.len();
+        let index = match (self.url_completion_index, forward) {
+            (None, true) => 0,
+            (None, false) => count - 1,
+            (Some(i), true) => (i + 1) % count,
+            (Some(i), false) => (i + count - 1) % count,
+        };
+
+        let (line, col) = self.editor_cursor;
+        let Some(start) = self.editor_content[line][..col].rfind("{{").map(|i| i + 2) else {
+            return;
+        };
+        // One undo step for the whole completion
+        if self.url_completion_index.is_none() {
+            self.record_undo();
+        }
+        let name = &self.url_completions[index];
+        self.editor_content[line].replace_range(start..col, name);
+        self.editor_cursor.1 = start + name.len();
+        self.editor_line_cache.remove(&line);
+        self.editor_entry_cache = None;
+        self.url_completion_index = Some(index);
+    }
+
+    /// Handle key events in the snippet popup; returns to insert mode when closed
+    fn handle_snippets_mode_key(&mut self, key: KeyEvent) -> Result<()> {
+        match key.code {
+            KeyCode::Esc => {
+                self.mode = AppMode::Editing;
+            }
+            KeyCode::Char('j') | KeyCode::Down => {
+                let last = self.config.snippets.len().saturating_sub(1);
+                self.snippet_index = (self.snippet_index + 1).min(last);
+            }
+            KeyCode::Char('k') | KeyCode::Up => {
+                self.snippet_index = self.snippet_index.saturating_sub(1);
+            }
+            KeyCode::Enter => {
+                self.mode = AppMode::Editing;
+                if let Some(snippet) = self.config.snippets.get(self.snippet_index).cloned() {
+                    self.editor_insert_text(&snippet.content);
+                    if !self.editor_search_query.is_empty() {
+                        self.update_editor_search_matches();
+                    }
+                    self.update_matching_bracket();
+                    self.set_status(
+                        &format!("Inserted snippet: {}", snippet.name),
+                        StatusLevel::Info,
+                    );
+                }
+            }
+            _ => {}
+        }
+
+        Ok(())
+    }
+
+    /// Handle vim visual mode keys (line selection)
+    fn handle_vim_visual_mode(&mut self, key: KeyEvent) -> Result<()> {
+        let pending = self.pending_key.take();
+        match key.code {
+            // Extend selection
+            KeyCode::Char('h') | KeyCode::Left => {
+                self.editor_move_cursor_left();
+                return Ok(());
+            }
+            KeyCode::Char('j') | KeyCode::Down => {
+                self.editor_move_cursor_down();
+                return Ok(());
+            }
+            KeyCode::Char('k') | KeyCode::Up => {
+                self.editor_move_cursor_up();
+                return Ok(());
+            }
+            KeyCode::Char('l') | KeyCode::Right => {
+                self.editor_move_cursor_right();
+                return Ok(());
+            }
+
+            KeyCode::Char('y') => {
+                self.editor_yank_selection();
+                self.collapse_cursor_to_selection_start();
+            }
+            KeyCode::Char('d') => {
+                self.editor_yank_selection();
+                self.editor_delete_selection();
+            }
+            KeyCode::Char('p') | KeyCode::Char('P') => self.editor_put_over_selection(),
+
+            // `gc` toggles the comment on every selected line
+            KeyCode::Char('g') => {
+                self.pending_key = Some('g');
+                return Ok(());
+            }
+            KeyCode::Char('c') if pending == Some('g') => {
+                self.toggle_comment();
+                self.collapse_cursor_to_selection_start();
+            }
+
+            // Esc or any other key exits visual mode
+            _ => {}
+        }
+
+        self.visual_anchor = None;
+        self.vim_mode = VimMode::Normal;
         Ok(())
     }
 
@@ -1167,57 +2633,109 @@ impl App {
         Ok(())
     }
 
-    /// Handle key events in command mode
-    fn handle_command_mode_key(&mut self, key: KeyEvent) -> Result<()> {
+    /// Handle keys typed into the response search bar; matches are highlighted as the
+    /// query is typed and `Enter` scrolls to the first one below the top of the view
+    fn handle_response_search_mode_key(&mut self, key: KeyEvent) {
         match key.code {
             KeyCode::Esc => {
+                self.response_search_query.clear();
+                self.response_search_stale = true;
                 self.mode = AppMode::Normal;
-                self.command_input.clear();
             }
             KeyCode::Enter => {
-                self.execute_command()?;
                 self.mode = AppMode::Normal;
-            }
-            KeyCode::Backspace => {
-                self.command_input.pop();
+                if self.response_search_query.is_empty() {
+                    return;
+                }
+                let scroll = self.response_scroll;
+                match self
+                    .response_search_matches
+                    .iter()
+                    .position(|&(line, _)| line >= scroll)
+                    .or((!self.response_search_matches.is_empty()).then_some(0))
+                {
+                    Some(index) => self.select_response_match(index),
+                    None => {
+                        let message = format!("Pattern not found: {}", self.response_search_query);
+                        self.set_status(&message, StatusLevel::Warning);
+                    }
+                }
+            }
+            KeyCode::Backspace => {
+                self.response_search_query.pop();
+                self.response_search_stale = true;
             }
             KeyCode::Char(c) => {
-                self.command_input.push(c);
+                self.response_search_query.push(c);
+                self.response_search_stale = true;
             }
             _ => {}
         }
+    }
 
-        Ok(())
+    /// Move to the next (or previous) response search match, wrapping around
+    fn response_search_jump(&mut self, forward: bool) {
+        let count = self.response_search_matches.len();
+        if count == 0 {
+            let message = format!("Pattern not found: {}", self.response_search_query);
+            self.set_status(&message, StatusLevel::Warning);
+            return;
+        }
+
+        let index = self.response_search_index.min(count - 1);
+        let index = if forward {
+            (index + 1) % count
+        } else {
+            (index + count - 1) % count
+        };
+        self.select_response_match(index);
     }
 
-    /// Handle key events in filter mode
-    fn handle_filter_mode_key(&mut self, key: KeyEvent) -> Result<()> {
+    /// Make a response search match the current one and scroll it into view
+    fn select_response_match(&mut self, index: usize) {
+        let Some(&(line, _)) = self.response_search_matches.get(index) else {
+            return;
+        };
+        self.response_search_index = index;
+        // Keep a little context above the match
+        self.response_scroll = line.saturating_sub(2);
+
+        let count = self.response_search_matches.len();
+        self.set_status(&format!("Match {}/{}", index + 1, count), StatusLevel::Info);
+    }
+
+    /// Handle key events in the global search popup
+    fn handle_global_search_mode_key(&mut self, key: KeyEvent) -> Result<()> {
         match key.code {
             KeyCode::Esc => {
                 self.mode = AppMode::Normal;
-                // Keep the filter active, don't clear it
+                self.global_search_rx = None;
+                self.global_search_typed_at = None;
             }
             KeyCode::Enter => {
-                self.mode = AppMode::Normal;
-                if self.filter_query.is_empty() {
-                    self.set_status("Filter cleared", StatusLevel::Info);
-                } else {
-                    let count = self.get_visible_file_count();
-                    self.set_status(
-                        &format!("Filter: {} ({} files)", self.filter_query, count),
-                        StatusLevel::Info,
-                    );
+                if let Some(result) = self
+                    .global_search_results
+                    .get(self.global_search_index)
+                    .cloned()
+                {
+                    self.mode = AppMode::Normal;
+                    self.open_global_search_result(&result);
                 }
             }
+            KeyCode::Down => {
+                let last = self.global_search_results.len().saturating_sub(1);
+                self.global_search_index = (self.global_search_index + 1).min(last);
+            }
+            KeyCode::Up => {
+                self.global_search_index = self.global_search_index.saturating_sub(1);
+            }
             KeyCode::Backspace => {
-                self.filter_query.pop();
-                self.file_tree_index = 0;
-                self.file_tree_state.select(Some(0));
+                self.global_search_query.pop();
+                self.schedule_global_search();
             }
             KeyCode::Char(c) => {
-                self.filter_query.push(c);
-                self.file_tree_index = 0;
-                self.file_tree_state.select(Some(0));
+                self.global_search_query.push(c);
+                self.schedule_global_search();
             }
             _ => {}
         }
@@ -1225,34 +2743,25 @@ impl App {
         Ok(())
     }
 
-    /// Handle key events in rename mode.
-    ///
-    /// Allows user to type a new filename for the selected file.
-    /// - Enter: Execute the rename operation
-    /// - Esc: Cancel and return to normal mode
-    /// - Backspace: Delete last character
-    /// - Any char: Append to rename input
-    fn handle_rename_mode_key(&mut self, key: KeyEvent) -> Result<()> {
+    /// Handle key events in the run history popup
+    fn handle_history_mode_key(&mut self, key: KeyEvent) -> Result<()> {
         match key.code {
-            KeyCode::Esc => {
-                // Cancel rename operation
-                self.mode = AppMode::Normal;
-                self.rename_input.clear();
-                self.rename_target = None;
-                self.set_status("Rename cancelled", StatusLevel::Info);
-            }
-            KeyCode::Enter => {
-                // Execute the rename
-                self.execute_rename();
+            KeyCode::Esc | KeyCode::Char('q') => {
                 self.mode = AppMode::Normal;
             }
-            KeyCode::Backspace => {
-                self.rename_input.pop();
+            KeyCode::Char('j') | KeyCode::Down => {
+                let last = self.history.len().saturating_sub(1);
+                self.history_index = (self.history_index + 1).min(last);
             }
-            KeyCode::Char(c) => {
-                // Only allow valid filename characters
-                if c != '/' && c != '\\' && c != '\0' {
-                    self.rename_input.push(c);
+            KeyCode::Char('k') | KeyCode::Up => {
+                self.history_index = self.history_index.saturating_sub(1);
+            }
+            KeyCode::Enter => self.open_history_entry(),
+            KeyCode::Char('d') | KeyCode::Delete => {
+                if self.history_index < self.history.len() {
+                    self.history.remove(self.history_index);
+                    self.history_index =
+                        self.history_index.min(self.history.len().saturating_sub(1));
                 }
             }
             _ => {}
@@ -1261,276 +2770,359 @@ impl App {
         Ok(())
     }
 
-    /// Handle mouse events
-    pub fn handle_mouse_event(&mut self, _mouse: MouseEvent) {
-        // Mouse support can be implemented here
-        // For now, we focus on keyboard navigation
+    /// Bookmark the selected .hurl file, or remove its bookmark
+    fn toggle_bookmark(&mut self) {
+        let Some(path) = self
+            .get_selected_file_entry()
+            .filter(|e| e.path.extension().is_some_and(|ext| ext == "hurl"))
+            .map(|e| e.path.clone())
+        else {
+            self.set_status("Only .hurl files can be bookmarked", StatusLevel::Warning);
+            return;
+        };
+        let name = path
+            .file_name()
+            .map(|n| n.to_string_lossy().to_string())
+            .unwrap_or_default();
+
+        if let Some(index) = self.bookmarks.iter().position(|p| p == &path) {
+            self.bookmarks.remove(index);
+            self.set_status(&format!("Removed bookmark: {}", name), StatusLevel::Info);
+        } else {
+            self.bookmarks.push(path);
+            self.set_status(
+                &format!("Bookmarked {} ('{})", name, self.bookmarks.len()),
+                StatusLevel::Success,
+            );
+        }
+        self.save_state();
     }
 
-    /// Handle terminal resize
-    pub fn handle_resize(&mut self, _width: u16, _height: u16) {
-        // Recalculate layout if needed
+    /// Pin the selected .hurl file to the top of the file browser, or unpin it
+    fn toggle_pin(&mut self) {
+        let Some(path) = self
+            .get_selected_file_entry()
+            .filter(|e| e.path.extension().is_some_and(|ext| ext == "hurl"))
+            .map(|e| e.path.clone())
+        else {
+            self.set_status("Only .hurl files can be pinned", StatusLevel::Warning);
+            return;
+        };
+        let name = path
+            .file_name()
+            .map(|n| n.to_string_lossy().to_string())
+            .unwrap_or_default();
+
+        if let Some(index) = self.pinned_files.iter().position(|p| p == &path) {
+            self.pinned_files.remove(index);
+            self.set_status(&format!("Unpinned: {}", name), StatusLevel::Info);
+        } else {
+            self.pinned_files.push(path);
+            self.set_status(&format!("Pinned: {}", name), StatusLevel::Success);
+        }
+        self.save_state();
     }
 
-    /// Navigate to next panel
-    fn next_panel(&mut self) {
-        let old_panel = self.active_panel;
-        self.active_panel = match self.active_panel {
-            ActivePanel::FileBrowser => {
-                if self.show_editor {
-                    ActivePanel::Editor
-                } else if self.show_response {
-                    ActivePanel::Response
-                } else {
-                    ActivePanel::Variables
-                }
-            }
-            ActivePanel::Editor => {
-                if self.show_response {
-                    ActivePanel::Response
-                } else {
-                    ActivePanel::Variables
-                }
-            }
-            ActivePanel::Response => {
-                if self.show_assertions {
-                    ActivePanel::Assertions
-                } else {
-                    ActivePanel::Variables
-                }
-            }
-            ActivePanel::Assertions => ActivePanel::Variables,
-            ActivePanel::Variables => ActivePanel::FileBrowser,
+    /// Open the bookmark in `slot` (0-based)
+    fn jump_to_bookmark(&mut self, slot: usize) {
+        let Some(path) = self.bookmarks.get(slot).cloned() else {
+            self.set_status(&format!("No bookmark {}", slot + 1), StatusLevel::Warning);
+            return;
         };
-        if old_panel != self.active_panel {
-            self.trigger_panel_focus_effect();
+        if let Err(e) = self.open_file(&path) {
+            self.set_status(&format!("Failed to open file: {}", e), StatusLevel::Error);
         }
     }
 
-    /// Navigate to previous panel
-    fn previous_panel(&mut self) {
-        let old_panel = self.active_panel;
-        self.active_panel = match self.active_panel {
-            ActivePanel::FileBrowser => ActivePanel::Variables,
-            ActivePanel::Editor => ActivePanel::FileBrowser,
-            ActivePanel::Response => {
-                if self.show_editor {
-                    ActivePanel::Editor
-                } else {
-                    ActivePanel::FileBrowser
-                }
+    /// Handle key events in the bookmark manager popup
+    fn handle_bookmarks_mode_key(&mut self, key: KeyEvent) -> Result<()> {
+        let last = self.bookmarks.len().saturating_sub(1);
+        match key.code {
+            KeyCode::Esc | KeyCode::Char('q') => {
+                self.mode = AppMode::Normal;
             }
-            ActivePanel::Assertions => ActivePanel::Response,
-            ActivePanel::Variables => {
-                if self.show_assertions {
-                    ActivePanel::Assertions
-                } else if self.show_response {
-                    ActivePanel::Response
-                } else if self.show_editor {
-                    ActivePanel::Editor
-                } else {
-                    ActivePanel::FileBrowser
+            KeyCode::Char('j') | KeyCode::Down => {
+                self.bookmark_index = (self.bookmark_index + 1).min(last);
+            }
+            KeyCode::Char('k') | KeyCode::Up => {
+                self.bookmark_index = self.bookmark_index.saturating_sub(1);
+            }
+            // Move the selected bookmark down/up
+            KeyCode::Char('J') if self.bookmark_index < last => {
+                self.bookmarks
+                    .swap(self.bookmark_index, self.bookmark_index + 1);
+                self.bookmark_index += 1;
+                self.save_state();
+            }
+            KeyCode::Char('K') if self.bookmark_index > 0 => {
+                self.bookmarks
+                    .swap(self.bookmark_index, self.bookmark_index - 1);
+                self.bookmark_index -= 1;
+                self.save_state();
+            }
+            KeyCode::Char('d') | KeyCode::Delete => {
+                if self.bookmark_index < self.bookmarks.len() {
+                    self.bookmarks.remove(self.bookmark_index);
+                    self.bookmark_index = self
+                        .bookmark_index
+                        .min(self.bookmarks.len().saturating_sub(1));
+                    self.save_state();
                 }
             }
-        };
-        if old_panel != self.active_panel {
-            self.trigger_panel_focus_effect();
+            KeyCode::Enter => {
+                self.mode = AppMode::Normal;
+                self.jump_to_bookmark(self.bookmark_index);
+            }
+            _ => {}
         }
+
+        Ok(())
     }
 
-    /// Trigger panel focus effect - stored area will be updated during render
-    fn trigger_panel_focus_effect(&mut self) {
-        // The effect area will be set during render based on current layout
-        // For now we use a placeholder area - the UI module will update it
-        let effect = presets::panel_focus();
-        self.effect_manager.add_effect(
-            EffectId::PanelFocus(self.active_panel),
-            effect,
-            ratatui::layout::Rect::default(), // Will be updated during render
-        );
+    /// Duration statistics of the runs of `path` in this session
+    pub fn compute_stats_for_file(&self, path: &PathBuf) -> RequestStats {
+        let mut durations: Vec<u64> = Vec::new();
+        let mut successes = 0;
+        for entry in self.history.iter().filter(|e| &e.file_path == path) {
+            durations.push(entry.duration_ms);
+            if entry.success {
+                successes += 1;
+            }
+        }
+        if durations.is_empty() {
+            return RequestStats::default();
+        }
+
+        durations.sort_unstable();
+        let run_count = durations.len();
+        // Nearest-rank percentile
+        let p95_index = (run_count * 95).div_ceil(100).saturating_sub(1);
+        RequestStats {
+            avg_ms: durations.iter().sum::<u64>() as f64 / run_count as f64,
+            min_ms: durations[0],
+            max_ms: durations[run_count - 1],
+            p95_ms: durations[p95_index],
+            success_rate: successes as f32 / run_count as f32,
+            run_count,
+        }
     }
 
-    /// Trigger execution start effect (pulse on response panel)
-    fn trigger_execution_start_effect(&mut self) {
-        let effect = presets::execution_pulse();
-        self.effect_manager.add_effect(
-            EffectId::ExecutionStart,
-            effect,
-            ratatui::layout::Rect::default(), // Will be set to response panel during render
-        );
+    /// Open the statistics popup for the current file
+    fn open_stats(&mut self) {
+        let Some(path) = self.current_file_path.as_ref() else {
+            self.set_status("No file loaded", StatusLevel::Warning);
+            return;
+        };
+        if !self.history.iter().any(|e| &e.file_path == path) {
+            self.set_status("No runs of this file yet", StatusLevel::Info);
+            return;
+        }
+        self.mode = AppMode::Stats;
     }
 
-    /// Trigger execution complete effect (success or error flash)
-    fn trigger_execution_complete_effect(&mut self, success: bool) {
-        let effect = if success {
-            presets::success_flash()
+    /// Open the file of the selected history entry and show its cached result.
+    /// Only the latest result of each file is cached, so older runs show that one.
+    fn open_history_entry(&mut self) {
+        let Some(entry) = self.history.get(self.history_index).cloned() else {
+            return;
+        };
+
+        let relative_path = self.get_relative_path(&entry.file_path);
+        let Some(result) = self.file_execution_states.get(&relative_path).cloned() else {
+            self.set_status("Result is no longer cached", StatusLevel::Warning);
+            return;
+        };
+
+        if self.current_file_path.as_ref() != Some(&entry.file_path) {
+            if let Err(e) = self.open_file(&entry.file_path) {
+                self.set_status(&format!("Failed to open file: {}", e), StatusLevel::Error);
+                return;
+            }
+        }
+
+        self.mode = AppMode::Normal;
+        self.execution_result = Some(result);
+        self.previous_execution_result = None;
+        self.update_body_diff();
+        self.reset_response_collapse();
+        if self.response_tab == ResponseTab::Diff {
+            self.response_tab = ResponseTab::Body;
+        }
+        self.response_scroll = 0;
+        self.assertions_scroll = 0;
+        self.active_panel = ActivePanel::Response;
+
+        let is_latest = !self.history[..self.history_index]
+            .iter()
+            .any(|e| e.file_path == entry.file_path);
+        let message = if is_latest {
+            format!("Loaded result: {}", relative_path)
         } else {
-            presets::error_flash()
+            format!("Loaded latest cached result: {}", relative_path)
         };
-        self.effect_manager.add_effect(
-            EffectId::ExecutionComplete,
-            effect,
-            ratatui::layout::Rect::default(), // Will be set to response panel during render
-        );
+        self.set_status(&message, StatusLevel::Info);
     }
 
-    /// Trigger help overlay animation
-    fn trigger_help_overlay_effect(&mut self) {
-        let effect = presets::dissolve_in();
-        self.effect_manager.add_effect(
-            EffectId::HelpOverlay,
-            effect,
-            ratatui::layout::Rect::default(), // Will be set to help area during render
-        );
+    /// Load the cookie jar file and show it in a popup
+    fn open_cookie_jar(&mut self) {
+        let Ok(content) = std::fs::read_to_string(self.working_dir.join(COOKIE_JAR_FILE)) else {
+            let message = if self.config.general.use_cookie_jar {
+                "No cookies stored yet"
+            } else {
+                "Cookie jar disabled (set use_cookie_jar = true in [general])"
+            };
+            self.set_status(message, StatusLevel::Warning);
+            return;
+        };
+
+        self.cookie_jar = parse_cookie_jar(&content);
+        self.cookie_jar_index = 0;
+        self.mode = AppMode::CookieJar;
     }
 
-    /// Navigate down in current panel
-    fn navigate_down(&mut self) {
-        match self.active_panel {
-            ActivePanel::FileBrowser => {
-                let max = self.get_visible_file_count().saturating_sub(1);
-                if self.file_tree_index < max {
-                    self.file_tree_index += 1;
-                    self.file_tree_state.select(Some(self.file_tree_index));
-                    self.auto_preview_selected_file();
-                }
-            }
-            ActivePanel::Editor => {
-                match self.editor_tab {
-                    EditorTab::Hurl => {
-                        if self.editor_scroll < self.editor_content.len().saturating_sub(1) {
-                            self.editor_scroll += 1;
-                        }
-                    }
-                    EditorTab::Output => {
-                        self.output_scroll += 1;
-                    }
-                    EditorTab::Preview => {
-                        if self.preview_scroll < self.preview_content.len().saturating_sub(1) {
-                            self.preview_scroll += 1;
-                        }
-                    }
-                }
+    /// Handle key events in the cookie jar popup
+    fn handle_cookie_jar_mode_key(&mut self, key: KeyEvent) -> Result<()> {
+        match key.code {
+            KeyCode::Esc | KeyCode::Char('q') => {
+                self.mode = AppMode::Normal;
             }
-            ActivePanel::Response => {
-                self.response_scroll += 1;
+            KeyCode::Char('j') | KeyCode::Down => {
+                let last = self.cookie_jar.len().saturating_sub(1);
+                self.cookie_jar_index = (self.cookie_jar_index + 1).min(last);
             }
-            ActivePanel::Assertions => {
-                self.assertions_scroll += 1;
+            KeyCode::Char('k') | KeyCode::Up => {
+                self.cookie_jar_index = self.cookie_jar_index.saturating_sub(1);
             }
+            KeyCode::Char('d') | KeyCode::Delete => self.delete_jar_cookie(),
             _ => {}
         }
-    }
 
-    /// Navigate up in current panel
-    fn navigate_up(&mut self) {
-        match self.active_panel {
-            ActivePanel::FileBrowser => {
-                let old_index = self.file_tree_index;
-                self.file_tree_index = self.file_tree_index.saturating_sub(1);
-                self.file_tree_state.select(Some(self.file_tree_index));
-                if self.file_tree_index != old_index {
-                    self.auto_preview_selected_file();
-                }
-            }
-            ActivePanel::Editor => {
-                match self.editor_tab {
-                    EditorTab::Hurl => {
-                        self.editor_scroll = self.editor_scroll.saturating_sub(1);
-                    }
-                    EditorTab::Output => {
-                        self.output_scroll = self.output_scroll.saturating_sub(1);
-                    }
-                    EditorTab::Preview => {
-                        self.preview_scroll = self.preview_scroll.saturating_sub(1);
-                    }
-                }
-            }
-            ActivePanel::Response => {
-                self.response_scroll = self.response_scroll.saturating_sub(1);
+        Ok(())
+    }
+
+    /// Remove the selected cookie from the cookie jar file
+    fn delete_jar_cookie(&mut self) {
+        let Some((line, cookie)) = self.cookie_jar.get(self.cookie_jar_index).cloned() else {
+            return;
+        };
+
+        let path = self.working_dir.join(COOKIE_JAR_FILE);
+        let result = std::fs::read_to_string(&path).and_then(|content| {
+            let kept: Vec<&str> = content
+                .lines()
+                .enumerate()
+                .filter(|(index, _)| *index != line)
+                .map(|(_, l)| l)
+                .collect();
+            let mut content = kept.join("\n");
+            content.push('\n');
+            std::fs::write(&path, &content)?;
+            Ok(content)
+        });
+
+        match result {
+            Ok(content) => {
+                self.cookie_jar = parse_cookie_jar(&content);
+                self.cookie_jar_index = self
+                    .cookie_jar_index
+                    .min(self.cookie_jar.len().saturating_sub(1));
+                self.set_status(
+                    &format!("Deleted cookie: {}", cookie.name),
+                    StatusLevel::Success,
+                );
             }
-            ActivePanel::Assertions => {
-                self.assertions_scroll = self.assertions_scroll.saturating_sub(1);
+            Err(e) => {
+                self.set_status(&format!("Delete failed: {}", e), StatusLevel::Error);
             }
-            _ => {}
         }
     }
 
-    /// Page down
-    fn page_down(&mut self) {
-        for _ in 0..10 {
-            self.navigate_down();
+    /// Open the environment editor popup with the variables of the current .env file
+    fn open_env_editor(&mut self) {
+        if self.current_env_file.is_none() {
+            self.set_status("No environment selected", StatusLevel::Warning);
+            return;
         }
-    }
 
-    /// Page up
-    fn page_up(&mut self) {
-        for _ in 0..10 {
-            self.navigate_up();
-        }
+        self.env_editor_vars = self
+            .variables
+            .iter()
+            .map(|v| (v.name.clone(), v.value.clone()))
+            .collect();
+        self.env_editor_index = 0;
+        self.env_editor_editing = None;
+        self.env_editor_input.clear();
+        self.env_editor_modified = false;
+        self.mode = AppMode::EnvEditor;
     }
 
-    /// Go to top
-    fn go_to_top(&mut self) {
-        match self.active_panel {
-            ActivePanel::FileBrowser => {
-                self.file_tree_index = 0;
-                self.file_tree_state.select(Some(0));
-            }
-            ActivePanel::Editor => {
-                match self.editor_tab {
-                    EditorTab::Hurl => self.editor_scroll = 0,
-                    EditorTab::Output => self.output_scroll = 0,
-                    EditorTab::Preview => self.preview_scroll = 0,
+    /// Handle key events in the environment editor popup
+    fn handle_env_editor_mode_key(&mut self, key: KeyEvent) -> Result<()> {
+        if let Some(field) = self.env_editor_editing {
+            match key.code {
+                KeyCode::Esc => {
+                    self.env_editor_editing = None;
+                    // Drop a variable that was added but never named
+                    let unnamed = self
+                        .env_editor_vars
+                        .get(self.env_editor_index)
+                        .map_or(false, |(name, _)| name.is_empty());
+                    if unnamed {
+                        self.env_editor_vars.remove(self.env_editor_index);
+                        self.env_editor_index = self.env_editor_index.saturating_sub(1);
+                    }
+                }
+                KeyCode::Enter => self.commit_env_editor_input(field),
+                KeyCode::Backspace => {
+                    self.env_editor_input.pop();
                 }
+                KeyCode::Char(c) => {
+                    if field == EnvEditorField::Value || !(c == '=' || c.is_whitespace()) {
+                        self.env_editor_input.push(c);
+                    }
+                }
+                _ => {}
             }
-            ActivePanel::Response => self.response_scroll = 0,
-            ActivePanel::Assertions => self.assertions_scroll = 0,
-            _ => {}
+            return Ok(());
         }
-    }
 
-    /// Go to bottom
-    fn go_to_bottom(&mut self) {
-        match self.active_panel {
-            ActivePanel::FileBrowser => {
-                self.file_tree_index = self.get_visible_file_count().saturating_sub(1);
-                self.file_tree_state.select(Some(self.file_tree_index));
+        match key.code {
+            KeyCode::Char('s') if key.modifiers.contains(KeyModifiers::CONTROL) => {
+                self.save_env_editor();
             }
-            ActivePanel::Editor => {
-                match self.editor_tab {
-                    EditorTab::Hurl => {
-                        self.editor_scroll = self.editor_content.len().saturating_sub(1);
-                    }
-                    EditorTab::Output => {
-                        // Output scroll doesn't have a known max, just set high
-                        self.output_scroll = usize::MAX / 2;
-                    }
-                    EditorTab::Preview => {
-                        self.preview_scroll = self.preview_content.len().saturating_sub(1);
-                    }
+            KeyCode::Esc | KeyCode::Char('q') => {
+                self.mode = AppMode::Normal;
+                if self.env_editor_modified {
+                    self.set_status("Variable changes discarded", StatusLevel::Info);
                 }
             }
-            _ => {}
-        }
-    }
-
-    /// Handle enter key
-    async fn handle_enter(&mut self) -> Result<()> {
-        match self.active_panel {
-            ActivePanel::FileBrowser => {
-                let selected = self.get_selected_file_entry().cloned();
-                if let Some(entry) = selected {
-                    if entry.is_dir {
-                        self.toggle_expand();
-                    } else if entry.path.extension().map_or(false, |e| e == "hurl") {
-                        self.open_file(&entry.path)?;
-                    }
+            KeyCode::Char('j') | KeyCode::Down => {
+                let last = self.env_editor_vars.len().saturating_sub(1);
+                self.env_editor_index = (self.env_editor_index + 1).min(last);
+            }
+            KeyCode::Char('k') | KeyCode::Up => {
+                self.env_editor_index = self.env_editor_index.saturating_sub(1);
+            }
+            KeyCode::Enter => {
+                if let Some((_, value)) = self.env_editor_vars.get(self.env_editor_index) {
+                    self.env_editor_input = value.clone();
+                    self.env_editor_editing = Some(EnvEditorField::Value);
                 }
             }
-            ActivePanel::Editor => {
-                // Run the current request
-                self.run_current_request().await?;
+            KeyCode::Char('n') => {
+                self.env_editor_vars.push((String::new(), String::new()));
+                self.env_editor_index = self.env_editor_vars.len() - 1;
+                self.env_editor_input.clear();
+                self.env_editor_editing = Some(EnvEditorField::Name);
+            }
+            KeyCode::Char('d') | KeyCode::Delete => {
+                if self.env_editor_index < self.env_editor_vars.len() {
+                    self.env_editor_vars.remove(self.env_editor_index);
+                    self.env_editor_index = self
+                        .env_editor_index
+                        .min(self.env_editor_vars.len().saturating_sub(1));
+                    self.env_editor_modified = true;
+                }
             }
             _ => {}
         }
@@ -1538,652 +3130,3375 @@ impl App {
         Ok(())
     }
 
-    /// Toggle directory expansion
-    fn toggle_expand(&mut self) {
-        let index = self.file_tree_index;
-        if let Some(entry) = self.get_selected_file_entry_mut(index) {
-            if entry.is_dir {
-                entry.is_expanded = !entry.is_expanded;
-                if entry.is_expanded && entry.children.is_empty() {
-                    // Load children
-                    if let Ok(children) =
-                        Self::load_directory_children(&entry.path, entry.depth + 1)
-                    {
-                        entry.children = children;
-                    }
+    /// Apply the inline input to the selected variable.
+    /// A new variable's name is followed by editing its value.
+    fn commit_env_editor_input(&mut self, field: EnvEditorField) {
+        let input = std::mem::take(&mut self.env_editor_input);
+        let index = self.env_editor_index;
+
+        match field {
+            EnvEditorField::Name => {
+                if input.is_empty() {
+                    self.set_status("Variable name cannot be empty", StatusLevel::Warning);
+                    return;
+                }
+                let taken = self
+                    .env_editor_vars
+                    .iter()
+                    .enumerate()
+                    .any(|(i, (name, _))| i != index && *name == input);
+                if taken {
+                    self.set_status(
+                        &format!("Variable already exists: {}", input),
+                        StatusLevel::Warning,
+                    );
+                    self.env_editor_input = input;
+                    return;
                 }
+                self.env_editor_vars[index].0 = input;
+                self.env_editor_editing = Some(EnvEditorField::Value);
+            }
+            EnvEditorField::Value => {
+                self.env_editor_vars[index].1 = input;
+                self.env_editor_editing = None;
             }
         }
-        // Save expanded state
-        self.save_state();
+        self.env_editor_modified = true;
     }
 
-    /// Get the count of visible files in the tree (respects filter)
-    fn get_visible_file_count(&self) -> usize {
-        self.get_visible_files().len()
+    /// Write the edited variables back to the current .env file and reload it
+    fn save_env_editor(&mut self) {
+        let Some(env_file) = self.current_env_file.clone() else {
+            return;
+        };
+
+        let content = std::fs::read_to_string(&env_file).unwrap_or_default();
+        let updated = update_env_content(&content, &self.env_editor_vars);
+        if let Err(e) = std::fs::write(&env_file, updated) {
+            self.set_status(&format!("Save failed: {}", e), StatusLevel::Error);
+            return;
+        }
+
+        let _ = self.load_environment_from_file(&env_file);
+        self.update_preview_content();
+        self.env_editor_modified = false;
+        self.mode = AppMode::Normal;
+        self.set_status(
+            &format!(
+                "Saved {} variables to {}",
+                self.variables.len(),
+                env_file.file_name().unwrap_or_default().to_string_lossy()
+            ),
+            StatusLevel::Success,
+        );
     }
 
-    /// Get selected file entry
-    fn get_selected_file_entry(&self) -> Option<&FileEntry> {
-        let target = self.file_tree_index;
-        let mut index = 0;
-        Self::find_entry(&self.file_tree, &mut index, target)
+    /// Restart the global search once typing pauses; results of the previous query are dropped
+    fn schedule_global_search(&mut self) {
+        self.global_search_rx = None;
+        self.global_search_typed_at = Some(std::time::Instant::now());
     }
 
-    fn find_entry<'a>(
-        entries: &'a [FileEntry],
-        index: &mut usize,
-        target: usize,
-    ) -> Option<&'a FileEntry> {
-        for entry in entries {
-            if *index == target {
-                return Some(entry);
-            }
-            *index += 1;
-            if entry.is_expanded {
-                if let Some(found) = Self::find_entry(&entry.children, index, target) {
-                    return Some(found);
-                }
-            }
+    /// Search the query in every .hurl file under the working directory in the background.
+    /// The open file is searched in its editor content so unsaved edits are found too.
+    /// Results are collected in `on_tick`.
+    pub fn global_search(&mut self) {
+        self.global_search_results.clear();
+        self.global_search_index = 0;
+        if self.global_search_query.is_empty() {
+            self.global_search_rx = None;
+            return;
         }
-        None
+
+        let (tx, rx) = oneshot::channel();
+        let dir = self.working_dir.clone();
+        let ignore = self.ignore_patterns.clone();
+        let query = self.global_search_query.clone();
+        let open_file = self
+            .current_file_path
+            .clone()
+            .map(|path| (path, self.editor_content.clone()));
+        tokio::task::spawn_blocking(move || {
+            let _ = tx.send(search_hurl_files(&dir, &ignore, &query, open_file));
+        });
+        self.global_search_rx = Some(rx);
     }
 
-    /// Get selected file entry (mutable)
-    fn get_selected_file_entry_mut(&mut self, target: usize) -> Option<&mut FileEntry> {
-        let mut index = 0;
-        Self::find_entry_mut(&mut self.file_tree, &mut index, target)
+    /// Run `hurl --version` in the background; the result is applied in `on_tick`
+    pub fn check_hurl_version(&mut self) {
+        let (tx, rx) = oneshot::channel();
+        let runner = self.runner.clone();
+        tokio::spawn(async move {
+            let _ = tx.send(runner.check_hurl_version().await);
+        });
+        self.hurl_version_rx = Some(rx);
     }
 
-    fn find_entry_mut<'a>(
-        entries: &'a mut [FileEntry],
-        index: &mut usize,
-        target: usize,
-    ) -> Option<&'a mut FileEntry> {
-        for entry in entries {
-            if *index == target {
-                return Some(entry);
+    /// Apply a finished hurl version check: only flags this version supports are passed from now on
+    fn poll_hurl_version(&mut self) {
+        let Some(rx) = self.hurl_version_rx.as_mut() else {
+            return;
+        };
+        let result = match rx.try_recv() {
+            Ok(result) => result,
+            Err(oneshot::error::TryRecvError::Empty) => return,
+            Err(oneshot::error::TryRecvError::Closed) => {
+                self.hurl_version_rx = None;
+                return;
             }
-            *index += 1;
-            if entry.is_expanded {
-                if let Some(found) = Self::find_entry_mut(&mut entry.children, index, target) {
-                    return Some(found);
+        };
+        self.hurl_version_rx = None;
+
+        match result {
+            Ok(version) => {
+                self.runner = self.runner.clone().with_hurl_version(Some(version));
+                // Startup warnings (theme, config) stay visible
+                if self.status_message.is_none() {
+                    let (major, minor, patch) = version;
+                    self.set_status_for(
+                        &format!("hurl v{}.{}.{} detected", major, minor, patch),
+                        StatusLevel::Info,
+                        HURL_VERSION_STATUS_DURATION,
+                    );
                 }
             }
+            Err(e) => self.set_status(&e.to_string(), StatusLevel::Warning),
         }
-        None
     }
 
-    /// Refresh the file tree (preserves expanded state)
-    pub fn refresh_file_tree(&mut self) -> Result<()> {
-        // Collect currently expanded folders before refreshing
-        let expanded = self.collect_expanded_folders();
-
-        // Reload the file tree
-        self.file_tree = Self::load_directory_children(&self.working_dir, 0)?;
-
-        // Restore expanded state
-        if !expanded.is_empty() {
-            self.restore_expanded_folders(&expanded);
-        } else {
-            // No folders were expanded, auto-expand directories with .hurl files
-            self.auto_expand_hurl_directories();
+    /// Collect the results of a finished global search
+    fn poll_global_search(&mut self) {
+        if self
+            .global_search_typed_at
+            .is_some_and(|typed_at| typed_at.elapsed() >= GLOBAL_SEARCH_DEBOUNCE)
+        {
+            self.global_search_typed_at = None;
+            self.global_search();
         }
 
-        Ok(())
+        let Some(rx) = self.global_search_rx.as_mut() else {
+            return;
+        };
+        match rx.try_recv() {
+            Ok(results) => {
+                self.global_search_results = results;
+                self.global_search_index = 0;
+                self.global_search_rx = None;
+            }
+            Err(oneshot::error::TryRecvError::Empty) => {}
+            Err(oneshot::error::TryRecvError::Closed) => self.global_search_rx = None,
+        }
     }
 
-    /// Auto-expand directories that contain .hurl files
-    fn auto_expand_hurl_directories(&mut self) {
-        Self::expand_entries_with_hurl(&mut self.file_tree);
+    /// Validate a saved file with `hurl --dry-run` in the background.
+    /// The result is collected in `on_tick`.
+    fn check_syntax(&mut self, path: PathBuf) {
+        let runner = self.runner.clone();
+        let env_file = self.current_env_file.clone();
+        let overrides = self.runtime_variable_overrides.clone();
+        let (tx, rx) = oneshot::channel();
+        tokio::spawn(async move {
+            let result = runner
+                .check_syntax(&path, env_file.as_ref(), &overrides)
+                .await;
+            let _ = tx.send((path, result));
+        });
+        self.syntax_check_rx = Some(rx);
     }
 
-    /// Recursively expand directory entries that contain .hurl files
-    fn expand_entries_with_hurl(entries: &mut [FileEntry]) {
-        for entry in entries.iter_mut() {
-            if entry.is_dir {
-                // Load children if not already loaded
-                if entry.children.is_empty() {
-                    if let Ok(children) = App::load_directory_children(&entry.path, entry.depth + 1)
-                    {
-                        entry.children = children;
-                    }
-                }
+    /// Mark the lines of a finished syntax check in the editor
+    fn poll_syntax_check(&mut self) {
+        let Some(rx) = self.syntax_check_rx.as_mut() else {
+            return;
+        };
+        let (path, result) = match rx.try_recv() {
+            Ok(finished) => finished,
+            Err(oneshot::error::TryRecvError::Empty) => return,
+            Err(oneshot::error::TryRecvError::Closed) => {
+                self.syntax_check_rx = None;
+                return;
+            }
+        };
+        self.syntax_check_rx = None;
 
-                // Check if this directory or its children contain .hurl files
-                let has_hurl = entry
-                    .children
-                    .iter()
-                    .any(|c| !c.is_dir || Self::dir_contains_hurl(&c.path));
+        // Another file was opened in the meantime
+        if self.current_file_path.as_ref() != Some(&path) {
+            return;
+        }
 
-                if has_hurl {
-                    entry.is_expanded = true;
-                    // Recursively expand children
-                    Self::expand_entries_with_hurl(&mut entry.children);
-                }
+        match result {
+            Ok(None) => {
+                self.editor_error_lines.clear();
+                self.set_status("✓ Syntax OK", StatusLevel::Success);
+            }
+            Ok(Some(error)) if !error.lines.is_empty() => {
+                self.set_status(
+                    &format!("Syntax error on line {}", error.lines[0] + 1),
+                    StatusLevel::Error,
+                );
+                self.editor_error_lines = error.lines.into_iter().collect();
+            }
+            Ok(Some(error)) => {
+                self.editor_error_lines.clear();
+                self.set_status(
+                    &format!("Syntax check failed: {}", error.message),
+                    StatusLevel::Warning,
+                );
+            }
+            Err(e) => {
+                self.editor_error_lines.clear();
+                self.set_status(&format!("Syntax check failed: {}", e), StatusLevel::Warning);
             }
         }
     }
 
-    /// Check if a directory should be skipped during scanning
-    /// Returns true for common build output, dependency, and cache directories
-    fn should_skip_directory(path: &PathBuf) -> bool {
-        path.file_name()
-            .map(|name| {
-                let name = name.to_string_lossy();
-                // Skip hidden directories
-                if name.starts_with('.') {
-                    return true;
-                }
-                // Skip known unnecessary directories (case-insensitive for cross-platform)
-                let name_lower = name.to_lowercase();
-                IGNORED_DIRECTORIES
-                    .iter()
-                    .any(|&ignored| ignored.to_lowercase() == name_lower)
-            })
-            .unwrap_or(false)
-    }
-
-    /// Check if a directory contains any .hurl files (recursively)
-    fn dir_contains_hurl(path: &PathBuf) -> bool {
-        // Skip unnecessary directories
-        if Self::should_skip_directory(path) {
-            return false;
-        }
-
-        if let Ok(read_dir) = std::fs::read_dir(path) {
-            for entry in read_dir.flatten() {
-                let path = entry.path();
-                if path.is_file() && path.extension().map_or(false, |e| e == "hurl") {
-                    return true;
-                }
-                if path.is_dir() && Self::dir_contains_hurl(&path) {
-                    return true;
-                }
+    /// Open the file of a global search result at the matching line, highlighting the query
+    fn open_global_search_result(&mut self, result: &GlobalSearchResult) {
+        if self.current_file_path.as_ref() != Some(&result.path) {
+            if let Err(e) = self.open_file(&result.path) {
+                self.set_status(&format!("Failed to open file: {}", e), StatusLevel::Error);
+                return;
             }
         }
-        false
-    }
 
-    /// Load children of a directory
-    fn load_directory_children(path: &PathBuf, depth: usize) -> Result<Vec<FileEntry>> {
-        let mut entries = Vec::new();
+        self.editor_search_query = self.global_search_query.clone();
+        self.update_editor_search_matches();
+        self.goto_line(result.line + 1, Some(result.col + 1));
+    }
 
-        if let Ok(read_dir) = std::fs::read_dir(path) {
-            for entry in read_dir.flatten() {
-                let path = entry.path();
+    /// Enter command mode with an empty command line
+    fn open_command_line(&mut self, from_editor: bool) {
+        self.mode = AppMode::Command;
+        self.command_from_editor = from_editor;
+        self.command_input.clear();
+        self.command_history_index = self.command_history.len();
+    }
 
-                // Skip hidden files, build outputs, dependencies, and other unnecessary directories
-                if Self::should_skip_directory(&path) {
-                    continue;
-                }
+    /// Handle key events in command mode
+    fn handle_command_mode_key(&mut self, key: KeyEvent) -> Result<()> {
+        let return_mode = if self.command_from_editor {
+            AppMode::Editing
+        } else {
+            AppMode::Normal
+        };
 
-                // Only include .hurl/.env files and directories that contain .hurl files
-                if path.is_dir() {
-                    // Only include directories that contain .hurl files (recursively)
-                    if Self::dir_contains_hurl(&path) {
-                        entries.push(FileEntry::new(path, depth));
-                    }
-                } else if path.extension().map_or(false, |e| e == "hurl" || e == "env") {
-                    entries.push(FileEntry::new(path, depth));
-                }
+        match key.code {
+            KeyCode::Esc => {
+                self.mode = return_mode;
+                self.command_from_editor = false;
+                self.command_input.clear();
+            }
+            KeyCode::Enter => {
+                self.mode = return_mode;
+                self.command_from_editor = false;
+                self.execute_command()?;
             }
+            KeyCode::Up => self.recall_command(true),
+            KeyCode::Down => self.recall_command(false),
+            KeyCode::Char('r') if key.modifiers.contains(KeyModifiers::CONTROL) => {
+                self.command_history_query.clear();
+                self.command_history_search_index = 0;
+                self.mode = AppMode::CommandHistorySearch;
+            }
+            KeyCode::Backspace => {
+                self.command_input.pop();
+            }
+            KeyCode::Char(c) => {
+                self.command_input.push(c);
+            }
+            _ => {}
         }
 
-        // Sort: directories first, then alphabetically
-        entries.sort_by(|a, b| match (a.is_dir, b.is_dir) {
-            (true, false) => std::cmp::Ordering::Less,
-            (false, true) => std::cmp::Ordering::Greater,
-            _ => a.name.to_lowercase().cmp(&b.name.to_lowercase()),
-        });
-
-        Ok(entries)
-    }
-
-    /// Open a hurl file
-    fn open_file(&mut self, path: &PathBuf) -> Result<()> {
-        self.open_file_internal(path, true)
-    }
-
-    /// Preview a hurl file (open without switching panel)
-    fn preview_file(&mut self, path: &PathBuf) -> Result<()> {
-        self.open_file_internal(path, false)
+        Ok(())
     }
 
-    /// Internal file opening logic
-    fn open_file_internal(&mut self, path: &PathBuf, switch_panel: bool) -> Result<()> {
-        let content = std::fs::read_to_string(path)?;
-        let is_hurl_file = path.extension().map_or(false, |e| e == "hurl");
-
-        if is_hurl_file {
-            let hurl_file = crate::parser::parse_hurl_file(&content)?;
-            self.current_file = Some(hurl_file);
-
-            // Restore execution state for this file if available
-            let relative_path = self.get_relative_path(path);
-            self.execution_result = self.file_execution_states.get(&relative_path).cloned();
+    /// Replace the command line with the previous (`older`) or next history entry
+    fn recall_command(&mut self, older: bool) {
+        let len = self.command_history.len();
+        let index = if older {
+            if self.command_history_index == 0 {
+                return;
+            }
+            self.command_history_index - 1
         } else {
-            // For non-hurl files (like .env), just show content without parsing
-            self.current_file = None;
-            self.execution_result = None;
-        }
-
-        self.current_file_path = Some(path.clone());
-        self.editor_content = content.lines().map(String::from).collect();
-        self.editor_cursor = (0, 0);
-        self.editor_scroll = 0;
-
-        self.response_scroll = 0;
-        self.assertions_scroll = 0;
-
-        // Generate preview content with variables resolved
-        self.update_preview_content();
+            if self.command_history_index >= len {
+                return;
+            }
+            self.command_history_index + 1
+        };
 
-        if switch_panel {
-            self.active_panel = ActivePanel::Editor;
+        if self.command_history_index == len {
+            self.command_history_draft = self.command_input.clone();
         }
+        self.command_history_index = index;
+        self.command_input = match self.command_history.get(index) {
+            Some(command) => command.clone(),
+            None => std::mem::take(&mut self.command_history_draft),
+        };
+    }
 
-        let file_name = path
-            .file_name()
-            .map(|n| n.to_string_lossy().to_string())
-            .unwrap_or_else(|| path.display().to_string());
-
-        // Show status with execution state info
-        if self.execution_result.is_some() {
-            self.set_status(
-                &format!("Preview: {} (with cached result)", file_name),
-                StatusLevel::Info,
-            );
-        } else {
-            self.set_status(&format!("Preview: {}", file_name), StatusLevel::Info);
+    /// Add an executed command to the history, keeping at most `general.max_history` entries
+    fn record_command(&mut self, cmd: &str) {
+        if cmd.is_empty() || self.command_history.last().is_some_and(|last| last == cmd) {
+            return;
         }
+        self.command_history.push(cmd.to_string());
+        let excess = self
+            .command_history
+            .len()
+            .saturating_sub(self.config.general.max_history);
+        self.command_history.drain(..excess);
+        self.save_state();
+    }
 
-        // Note: Don't save state here - it will be saved when user takes action
-        // This prevents overwriting env state during initialization
-
-        Ok(())
+    /// Commands matching the `Ctrl+r` query with their `!N` number, most recent first
+    pub fn command_history_matches(&self) -> Vec<(usize, &String)> {
+        self.command_history
+            .iter()
+            .rev()
+            .enumerate()
+            .map(|(i, command)| (i + 1, command))
+            .filter(|(_, command)| fuzzy_match(command, &self.command_history_query))
+            .collect()
     }
 
-    /// Auto-preview the currently selected file in the file browser
-    fn auto_preview_selected_file(&mut self) {
-        if let Some(entry) = self.get_selected_file_entry() {
-            if !entry.is_dir && entry.path.extension().map_or(false, |e| e == "hurl" || e == "env") {
-                let path = entry.path.clone();
-                let _ = self.preview_file(&path);
-                // Save state when user selects a file
-                self.save_state();
+    /// Handle key events in the `Ctrl+r` command history search; returns to command mode
+    fn handle_command_history_search_key(&mut self, key: KeyEvent) {
+        match key.code {
+            KeyCode::Esc => {
+                self.mode = AppMode::Command;
+            }
+            KeyCode::Enter => {
+                if let Some((_, command)) = self
+                    .command_history_matches()
+                    .get(self.command_history_search_index)
+                {
+                    self.command_input = command.to_string();
+                }
+                self.command_history_index = self.command_history.len();
+                self.mode = AppMode::Command;
+            }
+            KeyCode::Down => {
+                let last = self.command_history_matches().len().saturating_sub(1);
+                self.command_history_search_index =
+                    (self.command_history_search_index + 1).min(last);
+            }
+            KeyCode::Up => {
+                self.command_history_search_index =
+                    self.command_history_search_index.saturating_sub(1);
+            }
+            KeyCode::Backspace => {
+                self.command_history_query.pop();
+                self.command_history_search_index = 0;
+            }
+            KeyCode::Char(c) => {
+                self.command_history_query.push(c);
+                self.command_history_search_index = 0;
             }
+            _ => {}
         }
     }
 
-    /// Save the current file
-    pub fn save_current_file(&mut self) -> Result<()> {
-        if let Some(path) = &self.current_file_path {
-            let content = self.editor_content.join("\n");
-            std::fs::write(path, &content)?;
+    /// Open the `Ctrl+g` go-to-line popup
+    fn open_goto_line(&mut self, from_editor: bool) {
+        if !self.show_editor || self.editor_content.is_empty() {
+            self.set_status("No file loaded", StatusLevel::Warning);
+            return;
+        }
+        self.goto_line_input.clear();
+        self.goto_line_from_editor = from_editor;
+        self.mode = AppMode::GotoLine;
+    }
 
-            // Re-parse the file
-            if let Ok(hurl_file) = crate::parser::parse_hurl_file(&content) {
-                self.current_file = Some(hurl_file);
+    /// Handle key events while the editor is zoomed to one entry (navigation only)
+    fn handle_focused_entry_mode_key(&mut self, key: KeyEvent) -> Result<()> {
+        match key.code {
+            KeyCode::Esc | KeyCode::Char('q') => self.exit_focus_entry(),
+            KeyCode::Char(']') => self.cycle_focus_entry(true),
+            KeyCode::Char('[') => self.cycle_focus_entry(false),
+            KeyCode::Char('h' | 'j' | 'k' | 'l' | 'w' | 'b' | 'e' | '0' | '$' | '^')
+            | KeyCode::Left
+            | KeyCode::Down
+            | KeyCode::Up
+            | KeyCode::Right
+            | KeyCode::Home
+            | KeyCode::End => {
+                self.handle_vim_normal_mode(key)?;
+                // Keep the cursor inside the focused entry
+                if let Some((start, end)) = self.focus_bounds() {
+                    let line = self.editor_cursor.0.clamp(start, end);
+                    if line != self.editor_cursor.0 {
+                        let len = self.editor_content[line].chars().count();
+                        self.editor_cursor = (line, self.editor_cursor.1.min(len));
+                    }
+                }
+                self.ensure_cursor_visible();
             }
-
-            // Refresh preview content after save
-            self.update_preview_content();
-
-            self.set_status("File saved", StatusLevel::Success);
+            _ => {}
         }
 
         Ok(())
     }
 
-    /// Run the current request
-    pub async fn run_current_request(&mut self) -> Result<()> {
-        let Some(path) = self.current_file_path.clone() else {
-            self.set_status("No file selected", StatusLevel::Warning);
-            return Ok(());
+    /// Handle key events in the go-to-line popup
+    fn handle_goto_line_mode_key(&mut self, key: KeyEvent) -> Result<()> {
+        let return_mode = if self.goto_line_from_editor {
+            AppMode::Editing
+        } else {
+            AppMode::Normal
         };
 
-        self.is_running = true;
-        self.set_status("Running request...", StatusLevel::Info);
-        self.trigger_execution_start_effect();
-
-        // Run the request with variables file
-        let start = std::time::Instant::now();
-        let result = self.runner.run(&path, self.current_env_file.as_ref()).await;
-        let duration = start.elapsed();
-
-        self.is_running = false;
-
-        match result {
-            Ok(exec_result) => {
-                let success = exec_result.success;
-                let status_code = exec_result.response.as_ref().map(|r| r.status_code);
-
-                // Add to history
-                self.history.insert(
-                    0,
-                    HistoryEntry {
-                        id: uuid::Uuid::new_v4(),
-                        file_path: path.clone(),
-                        timestamp: chrono::Utc::now(),
-                        duration_ms: duration.as_millis() as u64,
-                        status_code,
-                        success,
-                    },
-                );
-
-                // Store execution result in the per-file cache
-                let relative_path = self.get_relative_path(&path);
-                self.file_execution_states
-                    .insert(relative_path, exec_result.clone());
-
-                self.execution_result = Some(exec_result);
-                self.response_scroll = 0;
-                self.assertions_scroll = 0;
-
-                // Persist state to disk
-                self.save_state();
-
-                if success {
-                    self.set_status(
-                        &format!("Request completed in {}ms", duration.as_millis()),
-                        StatusLevel::Success,
-                    );
-                    self.trigger_execution_complete_effect(true);
-                } else {
-                    self.set_status("Request completed with failures", StatusLevel::Warning);
-                    self.trigger_execution_complete_effect(false);
+        match key.code {
+            KeyCode::Esc => {
+                self.mode = return_mode;
+                self.goto_line_input.clear();
+            }
+            KeyCode::Enter => {
+                self.mode = return_mode;
+                let input = std::mem::take(&mut self.goto_line_input);
+                match parse_line_col(&input) {
+                    Some((line, col)) => self.goto_line(line, col),
+                    None => self.set_status("Expected line or line:col", StatusLevel::Error),
                 }
             }
-            Err(e) => {
-                self.set_status(&format!("Error: {e}"), StatusLevel::Error);
+            KeyCode::Backspace => {
+                self.goto_line_input.pop();
             }
+            KeyCode::Char(c) if c.is_ascii_digit() || c == ':' => {
+                self.goto_line_input.push(c);
+            }
+            _ => {}
         }
 
         Ok(())
     }
 
-    /// Recursively find all .env files in a directory
-    fn find_env_files(dir: &PathBuf) -> Vec<PathBuf> {
-        let mut env_files = Vec::new();
-
-        if let Ok(read_dir) = std::fs::read_dir(dir) {
-            for entry in read_dir.flatten() {
-                let path = entry.path();
-
-                // Skip hidden and ignored directories
-                if Self::should_skip_directory(&path) {
-                    continue;
-                }
-
-                if path.is_file() {
-                    if let Some(ext) = path.extension() {
-                        if ext == "env" {
-                            // Use absolute path
-                            if let Ok(abs_path) = path.canonicalize() {
-                                env_files.push(abs_path);
-                            } else {
-                                env_files.push(path);
-                            }
-                        }
-                    }
-                } else if path.is_dir() {
-                    // Recursively scan subdirectories
-                    env_files.extend(Self::find_env_files(&path));
+    /// Handle key events in filter mode
+    fn handle_filter_mode_key(&mut self, key: KeyEvent) -> Result<()> {
+        match key.code {
+            KeyCode::Esc => {
+                self.mode = AppMode::Normal;
+                // Keep the filter active, don't clear it
+            }
+            KeyCode::Enter => {
+                self.mode = AppMode::Normal;
+                if self.filter_query.is_empty() {
+                    self.set_status("Filter cleared", StatusLevel::Info);
+                } else {
+                    let count = self.get_visible_file_count();
+                    self.set_status(
+                        &format!("Filter: {} ({} files)", self.filter_query, count),
+                        StatusLevel::Info,
+                    );
                 }
             }
+            KeyCode::Char('x') if key.modifiers.contains(KeyModifiers::CONTROL) => {
+                self.filter_is_regex = !self.filter_is_regex;
+                self.update_filter_regex();
+                self.file_tree_index = 0;
+                self.file_tree_state.select(Some(0));
+                let mode = if self.filter_is_regex {
+                    "regex"
+                } else {
+                    "substring"
+                };
+                self.set_status(&format!("Filter mode: {}", mode), StatusLevel::Info);
+            }
+            KeyCode::Backspace => {
+                self.filter_query.pop();
+                self.update_filter_regex();
+                self.file_tree_index = 0;
+                self.file_tree_state.select(Some(0));
+            }
+            KeyCode::Char(c) => {
+                self.filter_query.push(c);
+                self.update_filter_regex();
+                self.file_tree_index = 0;
+                self.file_tree_state.select(Some(0));
+            }
+            _ => {}
         }
 
-        env_files
+        Ok(())
     }
 
-    /// Load environments by scanning for .env files recursively in the working directory
-    fn load_environments(&mut self) -> Result<()> {
-        self.environments.clear();
+    /// Recompile the filter regex after the query changed.
+    /// An invalid pattern is reported and the previous valid regex stays active.
+    fn update_filter_regex(&mut self) {
+        if !self.filter_is_regex || self.filter_query.is_empty() {
+            self.filter_regex = None;
+            return;
+        }
 
-        // Find all .env files recursively
-        let env_files = Self::find_env_files(&self.working_dir);
+        match regex::Regex::new(&self.filter_query) {
+            Ok(regex) => self.filter_regex = Some(regex),
+            Err(_) => {
+                self.set_status(
+                    &format!("Invalid regex: {}", self.filter_query),
+                    StatusLevel::Error,
+                );
+            }
+        }
+    }
 
-        for path in env_files {
-            if let Some(name) = path.file_stem() {
-                let name = name.to_string_lossy().to_string();
-                if !self.environments.contains(&name) {
-                    self.environments.push(name);
+    /// Handle key events in rename mode.
+    ///
+    /// Allows user to type a new filename for the selected file.
+    /// - Enter: Execute the rename operation
+    /// - Esc: Cancel and return to normal mode
+    /// - Backspace: Delete the character before the cursor
+    /// - Left/Right, Home/End: Move the cursor
+    /// - Any char: Insert at the cursor
+    fn handle_rename_mode_key(&mut self, key: KeyEvent) -> Result<()> {
+        match key.code {
+            KeyCode::Esc => {
+                // Cancel rename operation
+                self.mode = AppMode::Normal;
+                self.rename_input.clear();
+                self.rename_target = None;
+                if std::mem::take(&mut self.rename_is_new_dir) {
+                    self.set_status("New directory cancelled", StatusLevel::Info);
+                } else if self.new_file_dir.take().is_some() {
+                    self.set_status("New file cancelled", StatusLevel::Info);
+                } else {
+                    self.set_status("Rename cancelled", StatusLevel::Info);
+                }
+            }
+            KeyCode::Enter => {
+                // Execute the rename (or create the new file)
+                self.mode = AppMode::Normal;
+                if self.rename_is_new_dir {
+                    self.create_directory();
+                } else if self.new_file_dir.is_some() {
+                    self.execute_new_file();
+                } else {
+                    self.execute_rename();
+                }
+            }
+            KeyCode::Backspace => {
+                if let Some(c) = self.rename_input[..self.rename_cursor].chars().next_back() {
+                    self.rename_cursor -= c.len_utf8();
+                    self.rename_input.remove(self.rename_cursor);
+                }
+            }
+            KeyCode::Left => {
+                if let Some(c) = self.rename_input[..self.rename_cursor].chars().next_back() {
+                    self.rename_cursor -= c.len_utf8();
+                }
+            }
+            KeyCode::Right => {
+                if let Some(c) = self.rename_input[self.rename_cursor..].chars().next() {
+                    self.rename_cursor += c.len_utf8();
+                }
+            }
+            KeyCode::Home => self.rename_cursor = 0,
+            KeyCode::End => self.rename_cursor = self.rename_input.len(),
+            KeyCode::Char(c) => {
+                // Only allow valid filename characters ('/' nests new directories)
+                let allowed = if self.rename_is_new_dir {
+                    c.is_alphanumeric() || matches!(c, '-' | '_' | '/')
+                } else {
+                    c != '/' && c != '\\' && c != '\0'
+                };
+                if allowed {
+                    self.rename_input.insert(self.rename_cursor, c);
+                    self.rename_cursor += c.len_utf8();
                 }
             }
+            _ => {}
         }
 
-        // Sort environments alphabetically
-        self.environments.sort();
-
-        // Don't set default here - let restore_selected_environment() handle it
-        // or fall back to first one after restore attempt
-
         Ok(())
     }
 
-    /// Load variables for the current environment
-    fn load_current_environment_variables(&mut self) -> Result<()> {
-        self.variables.clear();
-        self.current_env_file = None;
-
-        if self.current_environment.is_empty() {
-            tracing::debug!("load_current_environment_variables: current_environment is empty");
-            return Ok(());
+    /// Handle key events while the delete confirmation is shown
+    fn handle_confirm_delete_mode_key(&mut self, key: KeyEvent) -> Result<()> {
+        self.mode = AppMode::Normal;
+        match key.code {
+            KeyCode::Char('y') | KeyCode::Char('Y') => self.execute_delete(),
+            _ => {
+                self.delete_target = None;
+                self.set_status("Delete cancelled", StatusLevel::Info);
+            }
         }
 
-        // Find the env file recursively
-        let env_files = Self::find_env_files(&self.working_dir);
-        tracing::debug!(
-            "load_current_environment_variables: looking for '{}' in {:?}",
-            self.current_environment,
-            env_files
-        );
-        
-        let env_file = env_files.into_iter().find(|p| {
-            p.file_stem()
-                .map(|s| s.to_string_lossy() == self.current_environment)
-                .unwrap_or(false)
-        });
+        Ok(())
+    }
 
-        let Some(env_file) = env_file else {
-            tracing::warn!(
-                "load_current_environment_variables: no file found for '{}'",
-                self.current_environment
-            );
+    /// Handle key events while asking whether to save before opening another file
+    fn handle_confirm_save_mode_key(&mut self, key: KeyEvent) -> Result<()> {
+        self.mode = AppMode::Normal;
+        let Some((path, switch_panel)) = self.pending_open.take() else {
             return Ok(());
         };
-        
-        tracing::debug!("load_current_environment_variables: found {:?}", env_file);
-
-        // Store the file path for --variables-file
-        self.current_env_file = Some(env_file.clone());
-
-        // Also parse variables for UI display
-        if let Ok(content) = std::fs::read_to_string(&env_file) {
-            for line in content.lines() {
-                let line = line.trim();
-                if line.is_empty() || line.starts_with('#') {
-                    continue;
-                }
-                if let Some((key, value)) = line.split_once('=') {
-                    self.variables.push(Variable {
-                        name: key.trim().to_string(),
-                        value: value.trim().to_string(),
-                        is_secret: key.to_lowercase().contains("secret")
-                            || key.to_lowercase().contains("password")
-                            || key.to_lowercase().contains("token"),
-                    });
+        match key.code {
+            KeyCode::Char('y') | KeyCode::Char('Y') => {
+                if self.current_file_path.is_none() {
+                    self.set_status("No file name: save with :w <path>", StatusLevel::Warning);
+                    return Ok(());
                 }
+                self.save_current_file()?;
+                self.open_file_internal(&path, switch_panel)?;
             }
+            KeyCode::Char('n') | KeyCode::Char('N') => {
+                self.editor_dirty = false;
+                self.open_file_internal(&path, switch_panel)?;
+            }
+            _ => self.set_status("Open cancelled", StatusLevel::Info),
         }
 
         Ok(())
     }
 
-    /// Cycle through environments
-    fn cycle_environment(&mut self) {
-        if self.environments.is_empty() {
-            self.set_status("No .env files found", StatusLevel::Warning);
+    /// Handle mouse events
+    ///
+    /// A left click focuses the panel under the cursor (and selects the clicked file in the
+    /// file browser, or the clicked response tab; a double-click opens a file). The wheel
+    /// scrolls the panel under the cursor, whichever panel has focus.
+    pub fn handle_mouse_event(&mut self, mouse: MouseEvent) {
+        if self.mode != AppMode::Normal || self.show_help {
             return;
         }
 
-        // Find current position, default to 0 if not found
-        let idx = self
-            .environments
+        let position = Position::new(mouse.column, mouse.row);
+        let Some((panel, area)) = self
+            .panel_areas
             .iter()
-            .position(|e| e == &self.current_environment)
-            .unwrap_or(0);
-
-        let next_idx = (idx + 1) % self.environments.len();
-        self.current_environment = self.environments[next_idx].clone();
-        let _ = self.load_current_environment_variables();
-        tracing::debug!(
-            "Cycled environment: {} -> {:?}",
-            self.current_environment,
-            self.current_env_file
-        );
-        // Refresh preview content with new environment variables
-        self.update_preview_content();
+            .copied()
+            .find(|(_, area)| area.contains(position))
+        else {
+            return;
+        };
 
-        self.set_status(
-            &format!("Environment: {}", self.current_environment),
-            StatusLevel::Info,
-        );
-        // Save state to persist selected environment
-        self.save_state();
+        match mouse.kind {
+            MouseEventKind::Down(MouseButton::Left) => {
+                let now = std::time::Instant::now();
+                let double_click = self.last_click.is_some_and(|(time, column, row)| {
+                    (column, row) == (mouse.column, mouse.row)
+                        && now.duration_since(time) < std::time::Duration::from_millis(400)
+                });
+                self.last_click = Some((now, mouse.column, mouse.row));
+
+                if self.active_panel != panel {
+                    self.active_panel = panel;
+                    self.trigger_panel_focus_effect();
+                }
+                match panel {
+                    ActivePanel::FileBrowser => {
+                        self.click_file_browser(area, mouse.column, mouse.row, double_click);
+                    }
+                    ActivePanel::Response => {
+                        let clicked = self
+                            .response_tab_areas
+                            .iter()
+                            .find(|(_, area)| area.contains(position))
+                            .map(|(tab, _)| *tab);
+                        if let Some(tab) = clicked {
+                            self.response_tab = tab;
+                            self.response_scroll = 0;
+                        }
+                    }
+                    _ => {}
+                }
+            }
+            MouseEventKind::ScrollDown | MouseEventKind::ScrollUp => {
+                // Scroll the panel under the cursor without moving focus
+                let focused = std::mem::replace(&mut self.active_panel, panel);
+                if mouse.kind == MouseEventKind::ScrollDown {
+                    self.navigate_down();
+                } else {
+                    self.navigate_up();
+                }
+                self.active_panel = focused;
+            }
+            _ => {}
+        }
     }
 
-    /// Resize sidebar by delta (positive = wider, negative = narrower)
-    fn resize_sidebar(&mut self, delta: i16) {
-        let new_width = (self.sidebar_width as i16 + delta).clamp(10, 50) as u16;
-        if new_width != self.sidebar_width {
-            self.sidebar_width = new_width;
-            self.save_state();
+    /// Select the file browser row at `row`; a double-click opens the file or toggles the folder
+    fn click_file_browser(&mut self, area: Rect, column: u16, row: u16, double_click: bool) {
+        // A breadcrumb segment in the title collapses the tree to that folder
+        if row == area.y {
+            let width = area.width.saturating_sub(2) as usize;
+            let column = column.saturating_sub(area.x + 1) as usize;
+            let dir = self
+                .get_selected_file_entry()
+                .and_then(|entry| breadcrumb_dir_at(entry, &self.working_dir, width, column));
+            if let Some(dir) = dir {
+                self.collapse_to_dir(&dir);
+            }
+            return;
         }
-    }
 
-    /// Toggle assertions panel visibility
-    fn toggle_assertions_panel(&mut self) {
-        self.show_assertions = !self.show_assertions;
+        // Skip the top and bottom borders
+        if row <= area.y || row + 1 >= area.bottom() {
+            return;
+        }
 
-        // If assertions panel was active and is now hidden, move to Response panel
-        if !self.show_assertions && self.active_panel == ActivePanel::Assertions {
-            self.active_panel = ActivePanel::Response;
+        // Pinned files come first, then bookmarks, then the tree
+        let row = (row - area.y - 1) as usize;
+        let pinned_rows = file_section_rows(self.pinned_files.len()) as usize;
+        if row < pinned_rows {
+            // The last row of a section is its divider
+            if row + 1 < pinned_rows {
+                let path = self.pinned_files[row].clone();
+                if let Err(e) = self.open_file(&path) {
+                    self.set_status(&format!("Failed to open file: {}", e), StatusLevel::Error);
+                }
+            }
+            return;
         }
+        let row = row - pinned_rows;
 
-        let status = if self.show_assertions {
-            "Assertions panel visible"
-        } else {
-            "Assertions panel hidden"
-        };
-        self.set_status(status, StatusLevel::Info);
-        self.save_state();
-    }
+        let bookmark_rows = file_section_rows(self.bookmarks.len()) as usize;
+        if row < bookmark_rows {
+            if row + 1 < bookmark_rows {
+                self.jump_to_bookmark(row);
+            }
+            return;
+        }
 
-    /// Toggle editor panel visibility
-    fn toggle_editor_panel(&mut self) {
-        // Don't allow hiding editor if response is also hidden (must have at least one main panel)
-        if self.show_editor && !self.show_response {
-            self.set_status(
-                "Cannot hide editor: response panel is also hidden",
-                StatusLevel::Warning,
-            );
+        let index = self.file_tree_state.offset() + row - bookmark_rows;
+        if index >= self.get_visible_file_count() {
             return;
         }
 
-        self.show_editor = !self.show_editor;
+        if index != self.file_tree_index {
+            self.file_tree_index = index;
+            self.file_tree_state.select(Some(index));
+            self.auto_preview_selected_file();
+        }
 
-        // If editor panel was active and is now hidden, move to Response panel
-        if !self.show_editor && self.active_panel == ActivePanel::Editor {
-            self.active_panel = ActivePanel::Response;
+        if double_click {
+            let selected = self.get_selected_file_entry().cloned();
+            if let Some(entry) = selected {
+                if entry.is_dir {
+                    self.toggle_expand();
+                } else if entry.path.extension().is_some_and(|e| e == "hurl") {
+                    if let Err(e) = self.open_file(&entry.path) {
+                        self.set_status(&format!("Failed to open file: {}", e), StatusLevel::Error);
+                    }
+                }
+            }
         }
+    }
 
-        let status = if self.show_editor {
-            "Editor panel visible"
-        } else {
-            "Editor panel hidden"
-        };
-        self.set_status(status, StatusLevel::Info);
-        self.save_state();
+    /// Handle terminal resize
+    pub fn handle_resize(&mut self, _width: u16, height: u16) {
+        // Rough estimate (status bar, tab bar and borders); the next render sets the exact value
+        self.set_editor_visible_height((height as usize).saturating_sub(4));
     }
 
-    /// Toggle response panel visibility
-    fn toggle_response_panel(&mut self) {
-        // Don't allow hiding response if editor is also hidden (must have at least one main panel)
-        if self.show_response && !self.show_editor {
+    /// Set the number of editor lines that fit on screen
+    pub fn set_editor_visible_height(&mut self, height: usize) {
+        self.editor_visible_height = height.max(1);
+    }
+
+    /// Reload a `.hurl` or `.env` file that was modified outside the TUI
+    pub fn handle_file_changed(&mut self, path: &Path) {
+        let same_file = |other: &PathBuf| {
+            other == path
+                || matches!(
+                    (std::fs::canonicalize(other), std::fs::canonicalize(path)),
+                    (Ok(a), Ok(b)) if a == b
+                )
+        };
+
+        if path.extension().is_some_and(|e| e == "env") {
+            let _ = self.load_environments();
+            if let Some(env_file) = self.current_env_file.clone().filter(|p| same_file(p)) {
+                let _ = self.load_environment_from_file(&env_file);
+                self.update_preview_content();
+                self.set_status("Environment reloaded from disk", StatusLevel::Info);
+            }
+        }
+
+        // Refresh stashed tabs without unsaved edits
+        let Ok(content) = std::fs::read_to_string(path) else {
+            return;
+        };
+        let lines: Vec<String> = content.lines().map(String::from).collect();
+        for (tab_path, tab) in self.tab_states.iter_mut() {
+            if !tab.dirty && same_file(tab_path) {
+                tab.content = lines.clone();
+            }
+        }
+
+        if !self.current_file_path.as_ref().is_some_and(same_file) || lines == self.editor_content {
+            return;
+        }
+        if self.editor_dirty {
             self.set_status(
-                "Cannot hide response: editor panel is also hidden",
+                "File changed on disk; keeping unsaved edits (:w overwrites)",
                 StatusLevel::Warning,
             );
             return;
         }
 
-        self.show_response = !self.show_response;
+        self.editor_content = lines;
+        self.editor_line_cache.clear();
+        self.editor_entry_cache = None;
+        let line = self
+            .editor_cursor
+            .0
+            .min(self.editor_content.len().saturating_sub(1));
+        let col = self
+            .editor_content
+            .get(line)
+            .map_or(0, |l| self.editor_cursor.1.min(l.len()));
+        self.editor_cursor = (line, col);
+        self.undo_stack.clear();
+        self.reparse_editor_content();
+        self.set_status("File reloaded from disk", StatusLevel::Info);
+    }
+
+    /// Navigate to next panel
+    fn next_panel(&mut self) {
+        let old_panel = self.active_panel;
+        self.active_panel = match self.active_panel {
+            ActivePanel::FileBrowser => {
+                if self.show_editor {
+                    ActivePanel::Editor
+                } else if self.show_response {
+                    ActivePanel::Response
+                } else {
+                    ActivePanel::Variables
+                }
+            }
+            ActivePanel::Editor => {
+                if self.show_response {
+                    ActivePanel::Response
+                } else {
+                    ActivePanel::Variables
+                }
+            }
+            ActivePanel::Response => {
+                if self.show_assertions {
+                    ActivePanel::Assertions
+                } else {
+                    ActivePanel::Variables
+                }
+            }
+            ActivePanel::Assertions => ActivePanel::Variables,
+            ActivePanel::Variables => ActivePanel::FileBrowser,
+        };
+        if old_panel != self.active_panel {
+            self.trigger_panel_focus_effect();
+        }
+    }
+
+    /// Navigate to previous panel
+    fn previous_panel(&mut self) {
+        let old_panel = self.active_panel;
+        self.active_panel = match self.active_panel {
+            ActivePanel::FileBrowser => ActivePanel::Variables,
+            ActivePanel::Editor => ActivePanel::FileBrowser,
+            ActivePanel::Response => {
+                if self.show_editor {
+                    ActivePanel::Editor
+                } else {
+                    ActivePanel::FileBrowser
+                }
+            }
+            ActivePanel::Assertions => ActivePanel::Response,
+            ActivePanel::Variables => {
+                if self.show_assertions {
+                    ActivePanel::Assertions
+                } else if self.show_response {
+                    ActivePanel::Response
+                } else if self.show_editor {
+                    ActivePanel::Editor
+                } else {
+                    ActivePanel::FileBrowser
+                }
+            }
+        };
+        if old_panel != self.active_panel {
+            self.trigger_panel_focus_effect();
+        }
+    }
+
+    /// Trigger panel focus effect - stored area will be updated during render
+    fn trigger_panel_focus_effect(&mut self) {
+        // The effect area will be set during render based on current layout
+        // For now we use a placeholder area - the UI module will update it
+        let effect = presets::panel_focus();
+        self.effect_manager.add_effect(
+            EffectId::PanelFocus(self.active_panel),
+            effect,
+            ratatui::layout::Rect::default(), // Will be updated during render
+        );
+    }
+
+    /// Trigger execution start effect (pulse on response panel)
+    fn trigger_execution_start_effect(&mut self) {
+        let effect = presets::execution_pulse();
+        self.effect_manager.add_effect(
+            EffectId::ExecutionStart,
+            effect,
+            ratatui::layout::Rect::default(), // Will be set to response panel during render
+        );
+    }
+
+    /// Trigger execution complete effect (success or error flash)
+    fn trigger_execution_complete_effect(&mut self, success: bool) {
+        let effect = if success {
+            presets::success_flash()
+        } else {
+            presets::error_flash()
+        };
+        self.effect_manager.add_effect(
+            EffectId::ExecutionComplete,
+            effect,
+            ratatui::layout::Rect::default(), // Will be set to response panel during render
+        );
+    }
+
+    /// Trigger help overlay animation
+    fn trigger_help_overlay_effect(&mut self) {
+        let effect = presets::dissolve_in();
+        self.effect_manager.add_effect(
+            EffectId::HelpOverlay,
+            effect,
+            ratatui::layout::Rect::default(), // Will be set to help area during render
+        );
+    }
+
+    /// Navigate down in current panel
+    fn navigate_down(&mut self) {
+        match self.active_panel {
+            ActivePanel::FileBrowser => {
+                let max = self.get_visible_file_count().saturating_sub(1);
+                if self.file_tree_index < max {
+                    self.file_tree_index += 1;
+                    self.file_tree_state.select(Some(self.file_tree_index));
+                    self.auto_preview_selected_file();
+                }
+            }
+            ActivePanel::Editor => {
+                match self.editor_tab {
+                    EditorTab::Hurl => {
+                        if self.editor_scroll < self.editor_content.len().saturating_sub(1) {
+                            self.editor_scroll += 1;
+                        }
+                    }
+                    EditorTab::Output => {
+                        self.output_scroll += 1;
+                    }
+                    EditorTab::Preview => {
+                        if self.preview_scroll < self.preview_content.len().saturating_sub(1) {
+                            self.preview_scroll += 1;
+                        }
+                    }
+                }
+            }
+            ActivePanel::Response => {
+                self.response_scroll += 1;
+            }
+            ActivePanel::Assertions => {
+                self.assertions_scroll += 1;
+            }
+            _ => {}
+        }
+    }
+
+    /// Navigate up in current panel
+    fn navigate_up(&mut self) {
+        match self.active_panel {
+            ActivePanel::FileBrowser => {
+                let old_index = self.file_tree_index;
+                self.file_tree_index = self.file_tree_index.saturating_sub(1);
+                self.file_tree_state.select(Some(self.file_tree_index));
+                if self.file_tree_index != old_index {
+                    self.auto_preview_selected_file();
+                }
+            }
+            ActivePanel::Editor => {
+                match self.editor_tab {
+                    EditorTab::Hurl => {
+                        self.editor_scroll = self.editor_scroll.saturating_sub(1);
+                    }
+                    EditorTab::Output => {
+                        self.output_scroll = self.output_scroll.saturating_sub(1);
+                    }
+                    EditorTab::Preview => {
+                        self.preview_scroll = self.preview_scroll.saturating_sub(1);
+                    }
+                }
+            }
+            ActivePanel::Response => {
+                self.response_scroll = self.response_scroll.saturating_sub(1);
+            }
+            ActivePanel::Assertions => {
+                self.assertions_scroll = self.assertions_scroll.saturating_sub(1);
+            }
+            _ => {}
+        }
+    }
+
+    /// Page down
+    fn page_down(&mut self) {
+        for _ in 0..10 {
+            self.navigate_down();
+        }
+    }
+
+    /// Page up
+    fn page_up(&mut self) {
+        for _ in 0..10 {
+            self.navigate_up();
+        }
+    }
+
+    /// Go to top
+    fn go_to_top(&mut self) {
+        match self.active_panel {
+            ActivePanel::FileBrowser => {
+                self.file_tree_index = 0;
+                self.file_tree_state.select(Some(0));
+            }
+            ActivePanel::Editor => {
+                match self.editor_tab {
+                    EditorTab::Hurl => self.editor_scroll = 0,
+                    EditorTab::Output => self.output_scroll = 0,
+                    EditorTab::Preview => self.preview_scroll = 0,
+                }
+            }
+            ActivePanel::Response => self.response_scroll = 0,
+            ActivePanel::Assertions => self.assertions_scroll = 0,
+            _ => {}
+        }
+    }
+
+    /// Go to bottom
+    fn go_to_bottom(&mut self) {
+        match self.active_panel {
+            ActivePanel::FileBrowser => {
+                self.file_tree_index = self.get_visible_file_count().saturating_sub(1);
+                self.file_tree_state.select(Some(self.file_tree_index));
+            }
+            ActivePanel::Editor => {
+                match self.editor_tab {
+                    EditorTab::Hurl => {
+                        self.editor_scroll = self.editor_content.len().saturating_sub(1);
+                    }
+                    EditorTab::Output => {
+                        // Output scroll doesn't have a known max, just set high
+                        self.output_scroll = usize::MAX / 2;
+                    }
+                    EditorTab::Preview => {
+                        self.preview_scroll = self.preview_content.len().saturating_sub(1);
+                    }
+                }
+            }
+            _ => {}
+        }
+    }
+
+    /// Handle enter key
+    async fn handle_enter(&mut self) -> Result<()> {
+        match self.active_panel {
+            ActivePanel::FileBrowser => {
+                let selected = self.get_selected_file_entry().cloned();
+                if let Some(entry) = selected {
+                    if entry.is_dir {
+                        self.toggle_expand();
+                    } else if entry.path.extension().map_or(false, |e| e == "hurl") {
+                        self.open_file(&entry.path)?;
+                    }
+                }
+            }
+            ActivePanel::Editor => {
+                // Run the current request
+                self.run_current_request()?;
+            }
+            ActivePanel::Assertions => {
+                // Jump to the source line of the selected (topmost) assertion
+                let line = self.execution_result.as_ref().and_then(|result| {
+                    result
+                        .assertions
+                        .get(self.assertions_scroll)
+                        .and_then(|a| a.source_line)
+                });
+                match line {
+                    Some(line) => self.goto_line(line + 1, None),
+                    None => {
+                        self.set_status("No source line for this assertion", StatusLevel::Warning)
+                    }
+                }
+            }
+            ActivePanel::Response if self.response_tab == ResponseTab::Body => {
+                self.toggle_response_collapse();
+            }
+            _ => {}
+        }
+
+        Ok(())
+    }
+
+    /// Show the active panel alone over the whole content area, or go back to the layout
+    fn toggle_fullscreen(&mut self) {
+        self.fullscreen_panel = match self.fullscreen_panel {
+            Some(_) => None,
+            None => Some(self.active_panel),
+        };
+    }
+
+    /// Toggle word wrap of the editor or response panel
+    fn toggle_word_wrap(&mut self) {
+        let wrap = match self.active_panel {
+            ActivePanel::Editor => {
+                self.editor_word_wrap = !self.editor_word_wrap;
+                self.editor_h_scroll = 0;
+                self.editor_word_wrap
+            }
+            ActivePanel::Response => {
+                self.response_word_wrap = !self.response_word_wrap;
+                self.response_h_scroll = 0;
+                self.response_word_wrap
+            }
+            _ => return,
+        };
+        let message = if wrap {
+            "Word wrap on"
+        } else {
+            "Word wrap off (Shift+Left/Right to scroll)"
+        };
+        self.set_status(message, StatusLevel::Info);
+    }
+
+    /// Whether the active panel scrolls horizontally (word wrap is off)
+    fn h_scroll_enabled(&self) -> bool {
+        match self.active_panel {
+            ActivePanel::Editor => !self.editor_word_wrap,
+            ActivePanel::Response => !self.response_word_wrap,
+            _ => false,
+        }
+    }
+
+    /// Scroll the active panel left or right by `delta` columns
+    fn scroll_horizontally(&mut self, delta: isize) {
+        if !self.h_scroll_enabled() {
+            return;
+        }
+        let h_scroll = match self.active_panel {
+            ActivePanel::Editor => &mut self.editor_h_scroll,
+            _ => &mut self.response_h_scroll,
+        };
+        *h_scroll = h_scroll.saturating_add_signed(delta);
+    }
+
+    /// Expand or collapse the first collapsible JSON array in view
+    fn toggle_response_collapse(&mut self) {
+        let Some((_, path)) = self.response_collapse_toggles.first().cloned() else {
+            self.set_status("No collapsible array in view", StatusLevel::Info);
+            return;
+        };
+        if !self.response_collapsed_paths.remove(&path) {
+            self.response_collapsed_paths.insert(path);
+        }
+        self.update_response_json_rows();
+    }
+
+    /// Collapse the large JSON arrays of a new response
+    fn reset_response_collapse(&mut self) {
+        self.response_collapsed_paths = self
+            .execution_result
+            .as_ref()
+            .and_then(|result| result.response.as_ref())
+            .map(|response| collapsible_json_paths(&response.body))
+            .unwrap_or_default();
+        self.update_response_json_rows();
+    }
+
+    /// Rebuild the pretty-printed JSON rows of the response body; search matches are found again
+    fn update_response_json_rows(&mut self) {
+        self.response_search_stale = true;
+        self.response_json_rows = self
+            .execution_result
+            .as_ref()
+            .and_then(|result| result.response.as_ref())
+            .filter(|_| self.response_pretty_print)
+            .and_then(|response| response_json_rows(response, &self.response_collapsed_paths));
+    }
+
+    /// Toggle directory expansion
+    fn toggle_expand(&mut self) {
+        let index = self.file_tree_index;
+        let ignore = self.ignore_patterns.clone();
+        if let Some(entry) = self.get_selected_file_entry_mut(index) {
+            if entry.is_dir {
+                entry.is_expanded = !entry.is_expanded;
+                if entry.is_expanded && entry.children.is_empty() {
+                    // Load children
+                    if let Ok(children) =
+                        Self::load_directory_children(&entry.path, entry.depth + 1, &ignore)
+                    {
+                        entry.children = children;
+                    }
+                }
+            }
+        }
+        // Save expanded state
+        self.save_state();
+    }
+
+    /// Get the count of visible files in the tree (respects filter)
+    fn get_visible_file_count(&self) -> usize {
+        self.get_visible_files().len()
+    }
+
+    /// Get selected file entry
+    pub fn get_selected_file_entry(&self) -> Option<&FileEntry> {
+        let target = self.file_tree_index;
+        let mut index = 0;
+        Self::find_entry(&self.file_tree, &mut index, target)
+    }
+
+    fn find_entry<'a>(
+        entries: &'a [FileEntry],
+        index: &mut usize,
+        target: usize,
+    ) -> Option<&'a FileEntry> {
+        for entry in entries {
+            if *index == target {
+                return Some(entry);
+            }
+            *index += 1;
+            if entry.is_expanded {
+                if let Some(found) = Self::find_entry(&entry.children, index, target) {
+                    return Some(found);
+                }
+            }
+        }
+        None
+    }
+
+    /// Get selected file entry (mutable)
+    fn get_selected_file_entry_mut(&mut self, target: usize) -> Option<&mut FileEntry> {
+        let mut index = 0;
+        Self::find_entry_mut(&mut self.file_tree, &mut index, target)
+    }
+
+    fn find_entry_mut<'a>(
+        entries: &'a mut [FileEntry],
+        index: &mut usize,
+        target: usize,
+    ) -> Option<&'a mut FileEntry> {
+        for entry in entries {
+            if *index == target {
+                return Some(entry);
+            }
+            *index += 1;
+            if entry.is_expanded {
+                if let Some(found) = Self::find_entry_mut(&mut entry.children, index, target) {
+                    return Some(found);
+                }
+            }
+        }
+        None
+    }
+
+    /// Refresh the file tree (preserves expanded state)
+    pub fn refresh_file_tree(&mut self) -> Result<()> {
+        // Collect currently expanded folders before refreshing
+        let expanded = self.collect_expanded_folders();
+
+        // Reload the file tree (the ignore file may have changed)
+        self.ignore_patterns = IgnorePatterns::load(&self.working_dir);
+        self.file_tree =
+            Self::load_directory_children(&self.working_dir, 0, &self.ignore_patterns)?;
+
+        // Restore expanded state
+        if !expanded.is_empty() {
+            self.restore_expanded_folders(&expanded);
+        } else {
+            // No folders were expanded, auto-expand directories with .hurl files
+            self.auto_expand_hurl_directories();
+        }
+
+        Ok(())
+    }
+
+    /// Auto-expand directories that contain .hurl files
+    fn auto_expand_hurl_directories(&mut self) {
+        Self::expand_entries_with_hurl(&mut self.file_tree, &self.ignore_patterns);
+    }
+
+    /// Recursively expand directory entries that contain .hurl files
+    fn expand_entries_with_hurl(entries: &mut [FileEntry], ignore: &IgnorePatterns) {
+        for entry in entries.iter_mut() {
+            if entry.is_dir {
+                // Load children if not already loaded
+                if entry.children.is_empty() {
+                    if let Ok(children) =
+                        App::load_directory_children(&entry.path, entry.depth + 1, ignore)
+                    {
+                        entry.children = children;
+                    }
+                }
+
+                // Check if this directory or its children contain .hurl files
+                let has_hurl = entry
+                    .children
+                    .iter()
+                    .any(|c| !c.is_dir || Self::dir_contains_hurl(&c.path, ignore));
+
+                if has_hurl {
+                    entry.is_expanded = true;
+                    // Recursively expand children
+                    Self::expand_entries_with_hurl(&mut entry.children, ignore);
+                }
+            }
+        }
+    }
+
+    /// Check if a directory should be skipped during scanning
+    /// Returns true for hidden directories and for names matching `ignore`
+    /// (common build output, dependency, and cache directories, and `.hurl-tui-ignore` patterns)
+    fn should_skip_directory(path: &Path, ignore: &IgnorePatterns) -> bool {
+        path.file_name()
+            .map(|name| {
+                let name = name.to_string_lossy();
+                // Skip hidden directories
+                name.starts_with('.') || ignore.matches(&name)
+            })
+            .unwrap_or(false)
+    }
+
+    /// Check if a directory contains any .hurl files (recursively)
+    fn dir_contains_hurl(path: &PathBuf, ignore: &IgnorePatterns) -> bool {
+        // Skip unnecessary directories
+        if Self::should_skip_directory(path, ignore) {
+            return false;
+        }
+
+        if let Ok(read_dir) = std::fs::read_dir(path) {
+            for entry in read_dir.flatten() {
+                let path = entry.path();
+                if path.is_file() && path.extension().map_or(false, |e| e == "hurl") {
+                    return true;
+                }
+                if path.is_dir() && Self::dir_contains_hurl(&path, ignore) {
+                    return true;
+                }
+            }
+        }
+        false
+    }
+
+    /// Load children of a directory
+    fn load_directory_children(
+        path: &PathBuf,
+        depth: usize,
+        ignore: &IgnorePatterns,
+    ) -> Result<Vec<FileEntry>> {
+        let mut entries = Vec::new();
+
+        if let Ok(read_dir) = std::fs::read_dir(path) {
+            for entry in read_dir.flatten() {
+                let path = entry.path();
+
+                // Skip hidden files, build outputs, dependencies, and other unnecessary directories
+                if Self::should_skip_directory(&path, ignore) {
+                    continue;
+                }
+
+                // Only include .hurl/.env files and directories that contain .hurl files
+                if path.is_dir() {
+                    // Only include directories that contain .hurl files (recursively)
+                    if Self::dir_contains_hurl(&path, ignore) {
+                        entries.push(FileEntry::new(path, depth));
+                    }
+                } else if path.extension().map_or(false, |e| e == "hurl" || e == "env") {
+                    entries.push(FileEntry::new(path, depth));
+                }
+            }
+        }
+
+        // Sort: directories first, then alphabetically
+        entries.sort_by(|a, b| match (a.is_dir, b.is_dir) {
+            (true, false) => std::cmp::Ordering::Less,
+            (false, true) => std::cmp::Ordering::Greater,
+            _ => a.name.to_lowercase().cmp(&b.name.to_lowercase()),
+        });
+
+        Ok(entries)
+    }
+
+    /// Open a hurl file as a tab
+    fn open_file(&mut self, path: &PathBuf) -> Result<()> {
+        if !self.open_tabs.contains(path) {
+            self.open_tabs.push(path.clone());
+        }
+        self.open_file_internal(path, true)
+    }
+
+    /// Preview a hurl file (open without switching panel)
+    fn preview_file(&mut self, path: &PathBuf) -> Result<()> {
+        self.open_file_internal(path, false)
+    }
+
+    /// Internal file opening logic
+    fn open_file_internal(&mut self, path: &PathBuf, switch_panel: bool) -> Result<()> {
+        // Unsaved edits of a file that isn't kept as a tab would be lost
+        let keeps_edits = self
+            .current_file_path
+            .as_ref()
+            .is_some_and(|p| p == path || self.open_tabs.contains(p));
+        if self.editor_dirty && !keeps_edits {
+            self.pending_open = Some((path.clone(), switch_panel));
+            self.mode = AppMode::ConfirmSave;
+            return Ok(());
+        }
+
+        let content = std::fs::read_to_string(path)?;
+        self.stash_active_tab();
+        let is_hurl_file = path.extension().map_or(false, |e| e == "hurl");
+
+        if is_hurl_file {
+            let hurl_file = crate::parser::parse_hurl_file(&content)?;
+            self.current_file = Some(hurl_file);
+
+            // Restore execution state for this file if available
+            let relative_path = self.get_relative_path(path);
+            self.execution_result = self.file_execution_states.get(&relative_path).cloned();
+        } else {
+            // For non-hurl files (like .env), just show content without parsing
+            self.current_file = None;
+            self.execution_result = None;
+        }
+        self.reset_response_collapse();
+        self.previous_execution_result = None;
+        self.update_body_diff();
+        if self.response_tab == ResponseTab::Diff {
+            self.response_tab = ResponseTab::Body;
+        }
+
+        self.current_file_path = Some(path.clone());
+        self.editor_content = content.lines().map(String::from).collect();
+        self.editor_cursor = (0, 0);
+        self.editor_scroll = 0;
+        self.selected_entry = 0;
+        self.editor_folds.clear();
+        self.editor_line_cache.clear();
+        self.editor_entry_cache = None;
+        self.editor_error_lines.clear();
+        self.undo_stack.clear();
+        self.editor_dirty = false;
+        self.clear_editor_search();
+
+        self.response_scroll = 0;
+        self.assertions_scroll = 0;
+
+        // Bring back unsaved edits of a tab that was switched away from
+        if let Some(tab) = self.tab_states.remove(path) {
+            self.editor_content = tab.content;
+            self.editor_cursor = tab.cursor;
+            self.editor_scroll = tab.scroll;
+            self.editor_dirty = tab.dirty;
+            if is_hurl_file {
+                let content = self.editor_content.join("\n");
+                if let Ok(hurl_file) = crate::parser::parse_hurl_file(&content) {
+                    self.current_file = Some(hurl_file);
+                }
+            }
+        }
+        if let Some(index) = self.open_tabs.iter().position(|p| p == path) {
+            self.active_tab = index;
+        }
+        self.cache_file_method(path);
+
+        // Generate preview content with variables resolved
+        self.update_preview_content();
+
+        if switch_panel {
+            self.active_panel = ActivePanel::Editor;
+        }
+
+        let file_name = path
+            .file_name()
+            .map(|n| n.to_string_lossy().to_string())
+            .unwrap_or_else(|| path.display().to_string());
+
+        // Show status with execution state info
+        if self.execution_result.is_some() {
+            self.set_status(
+                &format!("Preview: {} (with cached result)", file_name),
+                StatusLevel::Info,
+            );
+        } else {
+            self.set_status(&format!("Preview: {}", file_name), StatusLevel::Info);
+        }
+        self.warn_unknown_options();
+
+        // Note: Don't save state here - it will be saved when user takes action
+        // This prevents overwriting env state during initialization
+
+        Ok(())
+    }
+
+    /// Remember the method of the current file's first entry for its file browser badge
+    fn cache_file_method(&mut self, path: &PathBuf) {
+        match self.current_file.as_ref().and_then(|f| f.entries.first()) {
+            Some(entry) => {
+                self.file_method_cache
+                    .insert(path.clone(), entry.method.clone());
+            }
+            None => {
+                self.file_method_cache.remove(path);
+            }
+        }
+    }
+
+    /// Auto-preview the currently selected file in the file browser
+    fn auto_preview_selected_file(&mut self) {
+        if let Some(entry) = self.get_selected_file_entry() {
+            if !entry.is_dir && entry.path.extension().map_or(false, |e| e == "hurl" || e == "env") {
+                let path = entry.path.clone();
+                let _ = self.preview_file(&path);
+                // Save state when user selects a file
+                self.save_state();
+            }
+        }
+    }
+
+    /// Save the current file
+    pub fn save_current_file(&mut self) -> Result<()> {
+        if let Some(path) = self.current_file_path.clone() {
+            self.write_current_file(&path)?;
+
+            self.set_status("File saved", StatusLevel::Success);
+            self.warn_unknown_options();
+
+            if path.extension().map_or(false, |e| e == "hurl") {
+                self.check_syntax(path);
+            }
+        } else if !self.editor_content.is_empty() {
+            self.set_status("New file: save with :w <name>", StatusLevel::Warning);
+        }
+
+        Ok(())
+    }
+
+    /// Write the editor content to `path` and refresh what is derived from it
+    fn write_current_file(&mut self, path: &PathBuf) -> Result<()> {
+        let content = self.editor_content.join("\n");
+        std::fs::write(path, &content)?;
+        self.editor_dirty = false;
+
+        // Re-parse the file
+        if let Ok(hurl_file) = crate::parser::parse_hurl_file(&content) {
+            self.current_file = Some(hurl_file);
+        }
+        self.cache_file_method(path);
+
+        // Refresh preview content after save
+        self.update_preview_content();
+        Ok(())
+    }
+
+    /// Save unsaved edits every `editor.auto_save_interval_secs` (0 disables it)
+    ///
+    /// The previous version of the file is kept as `<file>.bak`. Only a failure
+    /// replaces the status message; success shows a brief muted "Auto-saved".
+    fn auto_save(&mut self) {
+        let interval = self.config.editor.auto_save_interval_secs;
+        if interval == 0 || !self.editor_dirty {
+            // The interval starts with the first unsaved edit
+            self.last_auto_save = None;
+            return;
+        }
+        let Some(path) = self.current_file_path.clone() else {
+            return;
+        };
+        let since = *self
+            .last_auto_save
+            .get_or_insert_with(std::time::Instant::now);
+        if since.elapsed() < std::time::Duration::from_secs(interval) {
+            return;
+        }
+        self.last_auto_save = Some(std::time::Instant::now());
+
+        let mut backup = path.clone().into_os_string();
+        backup.push(".bak");
+        let result = if path.exists() {
+            std::fs::copy(&path, &backup).map(|_| ())
+        } else {
+            Ok(())
+        };
+        match result
+            .map_err(anyhow::Error::from)
+            .and_then(|_| self.write_current_file(&path))
+        {
+            Ok(()) => {
+                self.set_status_for("Auto-saved", StatusLevel::Muted, AUTO_SAVE_STATUS_DURATION)
+            }
+            Err(e) => {
+                self.set_status(&format!("Auto-save failed: {}", e), StatusLevel::Error);
+            }
+        }
+    }
+
+    /// Suspend the TUI and edit the current file in `$EDITOR` (`vi` when unset).
+    ///
+    /// Unsaved edits are saved first; the file is reloaded from disk once the editor exits.
+    pub fn open_in_external_editor(&mut self) -> Result<()> {
+        use crossterm::event::{DisableMouseCapture, EnableMouseCapture};
+        use crossterm::execute;
+        use crossterm::terminal::{
+            disable_raw_mode, enable_raw_mode, EnterAlternateScreen, LeaveAlternateScreen,
+        };
+
+        let Some(path) = self.current_file_path.clone() else {
+            self.set_status("No file open", StatusLevel::Warning);
+            return Ok(());
+        };
+        if self.editor_dirty {
+            self.save_current_file()?;
+        }
+
+        // $EDITOR may include arguments, e.g. `code --wait`
+        let editor = std::env::var("EDITOR")
+            .ok()
+            .filter(|e| !e.trim().is_empty())
+            .unwrap_or_else(|| "vi".to_string());
+        let mut parts = editor.split_whitespace();
+        let program = parts.next().unwrap_or("vi");
+        let mut command = std::process::Command::new(program);
+        command.args(parts).arg(&path);
+
+        // The TUI draws on /dev/tty (stdout may be piped), so the editor uses it too
+        let mut tty = std::fs::OpenOptions::new()
+            .read(true)
+            .write(true)
+            .open(if cfg!(windows) { "CONOUT$" } else { "/dev/tty" })?;
+        if cfg!(unix) {
+            command.stdin(tty.try_clone()?).stdout(tty.try_clone()?);
+        }
+
+        if let Some(flag) = &self.input_pause {
+            flag.store(true, std::sync::atomic::Ordering::Relaxed);
+        }
+        disable_raw_mode()?;
+        execute!(tty, LeaveAlternateScreen, DisableMouseCapture)?;
+
+        let status = command.status();
+
+        enable_raw_mode()?;
+        execute!(tty, EnterAlternateScreen, EnableMouseCapture)?;
+        if let Some(flag) = &self.input_pause {
+            flag.store(false, std::sync::atomic::Ordering::Relaxed);
+        }
+        self.full_redraw = true;
+
+        let status = status.with_context(|| format!("failed to start {}", program))?;
+
+        // Picks up the edits ("File reloaded from disk" replaces the status when there are any)
+        self.set_status("No changes", StatusLevel::Info);
+        self.handle_file_changed(&path);
+        if !status.success() {
+            self.set_status(
+                &format!("{} exited with {}", program, status),
+                StatusLevel::Warning,
+            );
+        }
+        Ok(())
+    }
+
+    /// Run the current request
+    pub fn run_current_request(&mut self) -> Result<()> {
+        self.execute_request(None)
+    }
+
+    /// Run only the selected entry of the current file
+    pub fn run_selected_entry(&mut self) -> Result<()> {
+        let entry_count = self.current_file.as_ref().map_or(0, |f| f.entries.len());
+        if entry_count == 0 {
+            self.set_status("No entry to run", StatusLevel::Warning);
+            return Ok(());
+        }
+
+        let index = self.selected_entry.min(entry_count - 1);
+        self.execute_request(Some(index))
+    }
+
+    /// Run the whole file, or a single entry if `entry` is set.
+    /// The request runs in the background; the result is collected in `on_tick`.
+    fn execute_request(&mut self, entry: Option<usize>) -> Result<()> {
+        let Some(path) = self.current_file_path.clone() else {
+            self.set_status("No file selected", StatusLevel::Warning);
+            return Ok(());
+        };
+        if self.is_running {
+            self.set_status("A request is already running", StatusLevel::Warning);
+            return Ok(());
+        }
+
+        self.is_running = true;
+        match entry {
+            Some(index) => self.set_status(
+                &format!("Running entry {}...", index + 1),
+                StatusLevel::Info,
+            ),
+            None => self.set_status("Running request...", StatusLevel::Info),
+        }
+        self.trigger_execution_start_effect();
+
+        // Run the request with variables file, overrides and [Options] flags
+        let (retry_tx, retry_rx) = mpsc::unbounded_channel();
+        self.retry_rx = Some(retry_rx);
+        let runner = self.runner.clone().with_retry_progress(retry_tx);
+        let env_file = self.current_env_file.clone();
+        let options = self.current_file_options(entry);
+        let overrides = self.runtime_variable_overrides.clone();
+        let (tx, rx) = oneshot::channel();
+        let task = tokio::spawn(async move {
+            let start = std::time::Instant::now();
+            let result = match entry {
+                Some(index) => {
+                    runner
+                        .run_entry(&path, index, env_file.as_ref(), &options, &overrides)
+                        .await
+                }
+                None => {
+                    runner
+                        .run_with_variables(&path, env_file.as_ref(), &options, &overrides)
+                        .await
+                }
+            };
+            let _ = tx.send((path, result, start.elapsed()));
+        });
+
+        self.abort_handle = Some(task.abort_handle());
+        self.request_rx = Some(rx);
+        Ok(())
+    }
+
+    /// Abort the request that is running in the background
+    fn cancel_request(&mut self) {
+        if let Some(handle) = self.abort_handle.take() {
+            handle.abort();
+        }
+        self.request_rx = None;
+        self.retry_rx = None;
+        self.is_running = false;
+        self.set_status("Request cancelled", StatusLevel::Warning);
+    }
+
+    /// Collect the result of the background request once it has finished
+    fn poll_request(&mut self) {
+        if let Some(retry) = self
+            .retry_rx
+            .as_mut()
+            .and_then(|rx| std::iter::from_fn(|| rx.try_recv().ok()).last())
+        {
+            self.set_status(
+                &format!(
+                    "Connection failed, retrying ({}/{})...",
+                    retry,
+                    self.runner.retry_count()
+                ),
+                StatusLevel::Warning,
+            );
+        }
+
+        let Some(rx) = self.request_rx.as_mut() else {
+            return;
+        };
+        let finished = match rx.try_recv() {
+            Ok(finished) => Some(finished),
+            Err(oneshot::error::TryRecvError::Empty) => return,
+            // The task is gone without sending a result
+            Err(oneshot::error::TryRecvError::Closed) => None,
+        };
+        self.request_rx = None;
+        self.retry_rx = None;
+        self.abort_handle = None;
+        self.is_running = false;
+
+        let Some((path, result, duration)) = finished else {
+            return;
+        };
+
+        match result {
+            Ok(mut exec_result) => {
+                self.set_assertion_lines(&path, &mut exec_result);
+                let success = exec_result.success;
+                let status_code = exec_result.response.as_ref().map(|r| r.status_code);
+                let attempts = exec_result.attempts;
+
+                // Add to history
+                self.history.insert(
+                    0,
+                    HistoryEntry {
+                        id: uuid::Uuid::new_v4(),
+                        file_path: path.clone(),
+                        timestamp: chrono::Utc::now(),
+                        duration_ms: duration.as_millis() as u64,
+                        status_code,
+                        success,
+                    },
+                );
+
+                // Store execution result in the per-file cache
+                let relative_path = self.get_relative_path(&path);
+                self.file_execution_states
+                    .insert(relative_path, exec_result.clone());
+
+                // Another file may have been opened while the request ran; its result
+                // is then only cached and shown once that file is opened again
+                if self.current_file_path.as_ref() == Some(&path) {
+                    // Keep the previous result of a successful run for the Diff tab
+                    let previous = self.execution_result.replace(exec_result);
+                    self.previous_execution_result = if success { previous } else { None };
+                    self.update_body_diff();
+                    self.update_response_json_rows();
+                    if self.previous_execution_result.is_none()
+                        && self.response_tab == ResponseTab::Diff
+                    {
+                        self.response_tab = ResponseTab::Body;
+                    }
+                    self.response_scroll = 0;
+                    self.assertions_scroll = 0;
+                }
+
+                // Persist state to disk
+                self.save_state();
+
+                let retries = self.runner.retry_count();
+                if success {
+                    let mut message = format!("Request completed in {}ms", duration.as_millis());
+                    if attempts > 1 {
+                        message.push_str(&format!(
+                            " after retrying ({}/{})",
+                            attempts - 1,
+                            retries
+                        ));
+                    }
+                    self.set_status(&message, StatusLevel::Success);
+                    self.trigger_execution_complete_effect(true);
+                } else if attempts > 1 {
+                    self.set_status(
+                        &format!(
+                            "Connection failed after retrying ({}/{})",
+                            attempts - 1,
+                            retries
+                        ),
+                        StatusLevel::Error,
+                    );
+                    self.trigger_execution_complete_effect(false);
+                } else {
+                    self.set_status("Request completed with failures", StatusLevel::Warning);
+                    self.trigger_execution_complete_effect(false);
+                }
+            }
+            Err(e) => {
+                self.set_status(&format!("Error: {e}"), StatusLevel::Error);
+            }
+        }
+    }
+
+    /// Find the source line of assertions that don't have one by matching
+    /// their text against the assertions of the parsed file
+    fn set_assertion_lines(&self, path: &PathBuf, result: &mut ExecutionResult) {
+        let parsed;
+        let file = if self.current_file_path.as_ref() == Some(path) {
+            self.current_file.as_ref()
+        } else {
+            parsed = std::fs::read_to_string(path)
+                .ok()
+                .and_then(|content| crate::parser::parse_hurl_file(&content).ok());
+            parsed.as_ref()
+        };
+        let Some(file) = file else {
+            return;
+        };
+
+        let mut used: HashSet<usize> = result
+            .assertions
+            .iter()
+            .filter_map(|a| a.source_line)
+            .collect();
+        for assertion in result.assertions.iter_mut() {
+            if assertion.source_line.is_some() {
+                continue;
+            }
+            // The same assertion text can appear in several entries
+            assertion.source_line = file
+                .entries
+                .iter()
+                .flat_map(|entry| entry.asserts.iter())
+                .find(|a| a.text == assertion.text && !used.contains(&a.line))
+                .map(|a| a.line);
+            used.extend(assertion.source_line);
+        }
+    }
+
+    /// Run every visible .hurl file in the file browser in the background.
+    /// Progress is collected in `on_tick`.
+    pub fn run_all_files_in_directory(&mut self) -> Result<()> {
+        if self.collection_run.is_some() {
+            self.set_status("Collection run already in progress", StatusLevel::Warning);
+            return Ok(());
+        }
+
+        let files: Vec<PathBuf> = self
+            .get_visible_files()
+            .into_iter()
+            .filter(|e| !e.is_dir && e.path.extension().is_some_and(|ext| ext == "hurl"))
+            .map(|e| e.path.clone())
+            .collect();
+
+        if files.is_empty() {
+            self.set_status("No .hurl files to run", StatusLevel::Warning);
+            return Ok(());
+        }
+
+        let (tx, rx) = mpsc::unbounded_channel();
+        let semaphore = Arc::new(Semaphore::new(
+            self.config.general.concurrent_runners.max(1),
+        ));
+        let total = files.len();
+
+        for path in files {
+            // [Options] are read per file, as each file is run on its own
+            let options = std::fs::read_to_string(&path)
+                .ok()
+                .and_then(|content| crate::parser::parse_hurl_file(&content).ok())
+                .map(|file| file.run_options(None))
+                .unwrap_or_default();
+
+            let runner = self.runner.clone();
+            let env_file = self.current_env_file.clone();
+            let overrides = self.runtime_variable_overrides.clone();
+            let semaphore = semaphore.clone();
+            let tx = tx.clone();
+            tokio::spawn(async move {
+                let Ok(_permit) = semaphore.acquire().await else {
+                    return;
+                };
+                let result = runner
+                    .run_with_variables(&path, env_file.as_ref(), &options, &overrides)
+                    .await;
+                let _ = tx.send((path, result));
+            });
+        }
+
+        self.collection_run = Some(CollectionRun {
+            rx,
+            total,
+            done: 0,
+            passed: 0,
+        });
+        self.set_status(&format!("Running {} files...", total), StatusLevel::Info);
+        self.trigger_execution_start_effect();
+
+        Ok(())
+    }
+
+    /// Progress of the collection run as (finished, total)
+    pub fn collection_progress(&self) -> Option<(usize, usize)> {
+        self.collection_run
+            .as_ref()
+            .map(|run| (run.done, run.total))
+    }
+
+    /// Collect finished results of the collection run
+    fn poll_collection_run(&mut self) {
+        let Some(mut run) = self.collection_run.take() else {
+            return;
+        };
+
+        let disconnected = loop {
+            let (path, result) = match run.rx.try_recv() {
+                Ok(item) => item,
+                Err(e) => break e == mpsc::error::TryRecvError::Disconnected,
+            };
+            run.done += 1;
+
+            let Ok(mut exec_result) = result else {
+                continue;
+            };
+            self.set_assertion_lines(&path, &mut exec_result);
+            if exec_result.success {
+                run.passed += 1;
+            }
+
+            if self.current_file_path.as_ref() == Some(&path) {
+                self.execution_result = Some(exec_result.clone());
+                self.update_body_diff();
+                self.reset_response_collapse();
+            }
+            let relative_path = self.get_relative_path(&path);
+            self.file_execution_states
+                .insert(relative_path, exec_result);
+        };
+
+        // Keep waiting until every file has reported back (or all tasks are gone)
+        if run.done < run.total && !disconnected {
+            self.collection_run = Some(run);
+            return;
+        }
+
+        self.save_state();
+
+        let (passed, total) = (run.passed, run.total);
+        if passed == total {
+            self.set_status(
+                &format!("{}/{} passed", passed, total),
+                StatusLevel::Success,
+            );
+            self.trigger_execution_complete_effect(true);
+        } else {
+            self.set_status(
+                &format!("{}/{} passed ({} failed)", passed, total, total - passed),
+                StatusLevel::Warning,
+            );
+            self.trigger_execution_complete_effect(false);
+        }
+    }
+
+    /// Move the entry selection by `delta` and jump the editor cursor to that entry
+    fn select_entry(&mut self, delta: isize) {
+        let Some(file) = &self.current_file else {
+            return;
+        };
+        if file.entries.is_empty() {
+            return;
+        }
+
+        let last = file.entries.len() - 1;
+        let index = self.selected_entry.saturating_add_signed(delta).min(last);
+        let entry = &file.entries[index];
+        let message = format!(
+            "Entry {}/{}: {} {}",
+            index + 1,
+            last + 1,
+            entry.method,
+            entry.url
+        );
+        let line_start = entry.line_start;
+
+        self.selected_entry = index;
+        self.editor_cursor = (line_start, 0);
+        self.ensure_cursor_visible();
+        self.set_status(&message, StatusLevel::Info);
+    }
+
+    /// Move the editor cursor to the start of the next entry (`]]`)
+    fn next_entry_start(&mut self) {
+        let line = self.editor_cursor.0;
+        let Some(file) = &self.current_file else {
+            return;
+        };
+        let Some(index) = file.entries.iter().position(|e| e.line_start > line) else {
+            self.set_status("No next entry", StatusLevel::Info);
+            return;
+        };
+        self.selected_entry = index;
+        self.editor_cursor = (file.entries[index].line_start, 0);
+        self.ensure_cursor_visible();
+    }
+
+    /// Move the editor cursor to the start of the current entry, or the previous one
+    /// when already there (`[[`)
+    fn prev_entry_start(&mut self) {
+        let line = self.editor_cursor.0;
+        let Some(file) = &self.current_file else {
+            return;
+        };
+        let Some(index) = file.entries.iter().rposition(|e| e.line_start < line) else {
+            self.set_status("No previous entry", StatusLevel::Info);
+            return;
+        };
+        self.selected_entry = index;
+        self.editor_cursor = (file.entries[index].line_start, 0);
+        self.ensure_cursor_visible();
+    }
+
+    /// `[Options]` of the current file passed as hurl flags when running `entry`
+    fn current_file_options(&self, entry: Option<usize>) -> Vec<(String, String)> {
+        self.current_file
+            .as_ref()
+            .map(|file| file.run_options(entry))
+            .unwrap_or_default()
+    }
+
+    /// Warn in the status bar about `[Options]` keys that won't be passed to hurl
+    fn warn_unknown_options(&mut self) {
+        let Some(file) = self.current_file.as_ref() else {
+            return;
+        };
+        let mut unknown: Vec<String> = file
+            .entries
+            .iter()
+            .flat_map(|entry| entry.options.iter())
+            .map(|(name, _)| name.clone())
+            .filter(|name| !crate::runner::is_known_option(name))
+            .collect();
+        unknown.sort();
+        unknown.dedup();
+
+        if !unknown.is_empty() {
+            self.set_status(
+                &format!("Unknown options ignored: {}", unknown.join(", ")),
+                StatusLevel::Warning,
+            );
+        }
+    }
+
+    /// Recursively find all .env files in a directory
+    fn find_env_files(dir: &PathBuf, ignore: &IgnorePatterns) -> Vec<PathBuf> {
+        let mut env_files = Vec::new();
+
+        if let Ok(read_dir) = std::fs::read_dir(dir) {
+            for entry in read_dir.flatten() {
+                let path = entry.path();
+
+                // Skip hidden and ignored directories
+                if Self::should_skip_directory(&path, ignore) {
+                    continue;
+                }
+
+                if path.is_file() {
+                    if let Some(ext) = path.extension() {
+                        if ext == "env" {
+                            // Use absolute path
+                            if let Ok(abs_path) = path.canonicalize() {
+                                env_files.push(abs_path);
+                            } else {
+                                env_files.push(path);
+                            }
+                        }
+                    }
+                } else if path.is_dir() {
+                    // Recursively scan subdirectories
+                    env_files.extend(Self::find_env_files(&path, ignore));
+                }
+            }
+        }
+
+        env_files
+    }
+
+    /// Names of the environments (.env file stems) found in a directory, sorted alphabetically
+    pub fn environment_names(dir: &PathBuf, ignore: &IgnorePatterns) -> Vec<String> {
+        let mut names: Vec<String> = Vec::new();
+
+        // Find all .env files recursively
+        for path in Self::find_env_files(dir, ignore) {
+            if let Some(name) = path.file_stem() {
+                let name = name.to_string_lossy().to_string();
+                if !names.contains(&name) {
+                    names.push(name);
+                }
+            }
+        }
+
+        names.sort();
+        names
+    }
+
+    /// Load environments by scanning for .env files recursively in the working directory
+    fn load_environments(&mut self) -> Result<()> {
+        self.environments = Self::environment_names(&self.working_dir, &self.ignore_patterns);
+
+        // Don't set default here - let restore_selected_environment() handle it
+        // or fall back to first one after restore attempt
+
+        Ok(())
+    }
+
+    /// Load variables for the current environment
+    fn load_current_environment_variables(&mut self) -> Result<()> {
+        self.variables.clear();
+        self.current_env_file = None;
+
+        if self.current_environment.is_empty() {
+            tracing::debug!("load_current_environment_variables: current_environment is empty");
+            return Ok(());
+        }
+
+        // Find the env file recursively
+        let env_files = Self::find_env_files(&self.working_dir, &self.ignore_patterns);
+        tracing::debug!(
+            "load_current_environment_variables: looking for '{}' in {:?}",
+            self.current_environment,
+            env_files
+        );
+        
+        let env_file = env_files.into_iter().find(|p| {
+            p.file_stem()
+                .map(|s| s.to_string_lossy() == self.current_environment)
+                .unwrap_or(false)
+        });
+
+        let Some(env_file) = env_file else {
+            tracing::warn!(
+                "load_current_environment_variables: no file found for '{}'",
+                self.current_environment
+            );
+            return Ok(());
+        };
+        
+        tracing::debug!("load_current_environment_variables: found {:?}", env_file);
+
+        // Store the file path for --variables-file
+        self.current_env_file = Some(env_file.clone());
+
+        // Also parse variables for UI display
+        if let Ok(content) = std::fs::read_to_string(&env_file) {
+            for line in content.lines() {
+                let line = line.trim();
+                if line.is_empty() || line.starts_with('#') {
+                    continue;
+                }
+                if let Some((key, value)) = line.split_once('=') {
+                    self.variables.push(Variable {
+                        name: key.trim().to_string(),
+                        value: value.trim().to_string(),
+                        is_secret: is_secret_key(key),
+                    });
+                }
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Cycle through environments
+    fn cycle_environment(&mut self) {
+        if self.environments.is_empty() {
+            self.set_status("No .env files found", StatusLevel::Warning);
+            return;
+        }
+
+        // Find current position, default to 0 if not found
+        let idx = self
+            .environments
+            .iter()
+            .position(|e| e == &self.current_environment)
+            .unwrap_or(0);
+
+        let next_idx = (idx + 1) % self.environments.len();
+        self.current_environment = self.environments[next_idx].clone();
+        let _ = self.load_current_environment_variables();
+        tracing::debug!(
+            "Cycled environment: {} -> {:?}",
+            self.current_environment,
+            self.current_env_file
+        );
+        // Refresh preview content with new environment variables
+        self.update_preview_content();
+
+        self.set_status(
+            &format!("Environment: {}", self.current_environment),
+            StatusLevel::Info,
+        );
+        // Save state to persist selected environment
+        self.save_state();
+    }
+
+    /// Switch to the next (`delta` = 1) or previous (`delta` = -1) tab
+    fn switch_tab(&mut self, delta: isize) {
+        if self.open_tabs.is_empty() {
+            self.set_status("No open tabs", StatusLevel::Info);
+            return;
+        }
+
+        let count = self.open_tabs.len() as isize;
+        let index = (self.active_tab as isize + delta).rem_euclid(count) as usize;
+        let path = self.open_tabs[index].clone();
+        if let Err(e) = self.open_file_internal(&path, false) {
+            self.set_status(&format!("Failed to open tab: {}", e), StatusLevel::Error);
+        }
+    }
+
+    /// Close the active tab and show its neighbour (Ctrl+w)
+    fn close_active_tab(&mut self) {
+        if self.active_tab >= self.open_tabs.len() {
+            self.set_status("No open tabs", StatusLevel::Info);
+            return;
+        }
+
+        let path = self.open_tabs.remove(self.active_tab);
+        self.tab_states.remove(&path);
+        if self.current_file_path.as_ref() == Some(&path) {
+            self.close_editor_file();
+        }
+
+        if self.open_tabs.is_empty() {
+            self.active_tab = 0;
+        } else {
+            self.active_tab = self.active_tab.min(self.open_tabs.len() - 1);
+            let next = self.open_tabs[self.active_tab].clone();
+            let _ = self.open_file_internal(&next, false);
+        }
+
+        self.save_state();
+        let file_name = path
+            .file_name()
+            .map(|n| n.to_string_lossy().to_string())
+            .unwrap_or_default();
+        self.set_status(&format!("Closed tab: {}", file_name), StatusLevel::Info);
+    }
+
+    /// Keep the editor state of the shown tab so switching back restores it
+    fn stash_active_tab(&mut self) {
+        let Some(path) = self.current_file_path.clone() else {
+            return;
+        };
+        if !self.open_tabs.contains(&path) {
+            return;
+        }
+        self.tab_states.insert(
+            path,
+            TabState {
+                content: self.editor_content.clone(),
+                cursor: self.editor_cursor,
+                scroll: self.editor_scroll,
+                dirty: self.editor_dirty,
+            },
+        );
+    }
+
+    /// Clear the editor after its file was closed or deleted
+    fn close_editor_file(&mut self) {
+        self.current_file = None;
+        self.current_file_path = None;
+        self.editor_content.clear();
+        self.editor_line_cache.clear();
+        self.editor_entry_cache = None;
+        self.editor_cursor = (0, 0);
+        self.editor_scroll = 0;
+        self.undo_stack.clear();
+        self.editor_dirty = false;
+        self.execution_result = None;
+        self.previous_execution_result = None;
+        self.body_diff = None;
+        self.update_response_json_rows();
+        self.preview_content.clear();
+    }
+
+    /// Resize sidebar by delta (positive = wider, negative = narrower)
+    fn resize_sidebar(&mut self, delta: i16) {
+        let new_width = (self.sidebar_width as i16 + delta).clamp(10, 50) as u16;
+        if new_width != self.sidebar_width {
+            self.sidebar_width = new_width;
+            self.save_state();
+        }
+    }
+
+    /// Toggle assertions panel visibility
+    fn toggle_assertions_panel(&mut self) {
+        self.show_assertions = !self.show_assertions;
+
+        // If assertions panel was active and is now hidden, move to Response panel
+        if !self.show_assertions && self.active_panel == ActivePanel::Assertions {
+            self.active_panel = ActivePanel::Response;
+        }
+
+        let status = if self.show_assertions {
+            "Assertions panel visible"
+        } else {
+            "Assertions panel hidden"
+        };
+        self.set_status(status, StatusLevel::Info);
+        self.save_state();
+    }
+
+    /// Toggle editor panel visibility
+    fn toggle_editor_panel(&mut self) {
+        // Don't allow hiding editor if response is also hidden (must have at least one main panel)
+        if self.show_editor && !self.show_response {
+            self.set_status(
+                "Cannot hide editor: response panel is also hidden",
+                StatusLevel::Warning,
+            );
+            return;
+        }
+
+        self.show_editor = !self.show_editor;
+
+        // If editor panel was active and is now hidden, move to Response panel
+        if !self.show_editor && self.active_panel == ActivePanel::Editor {
+            self.active_panel = ActivePanel::Response;
+        }
+
+        let status = if self.show_editor {
+            "Editor panel visible"
+        } else {
+            "Editor panel hidden"
+        };
+        self.set_status(status, StatusLevel::Info);
+        self.save_state();
+    }
+
+    /// Toggle response panel visibility
+    fn toggle_response_panel(&mut self) {
+        // Don't allow hiding response if editor is also hidden (must have at least one main panel)
+        if self.show_response && !self.show_editor {
+            self.set_status(
+                "Cannot hide response: editor panel is also hidden",
+                StatusLevel::Warning,
+            );
+            return;
+        }
+
+        self.show_response = !self.show_response;
+
+        // If response panel was active and is now hidden, move to Editor panel
+        if !self.show_response && self.active_panel == ActivePanel::Response {
+            self.active_panel = ActivePanel::Editor;
+        }
+
+        // If assertions panel was active and response is now hidden, move to Editor panel
+        if !self.show_response && self.active_panel == ActivePanel::Assertions {
+            self.active_panel = ActivePanel::Editor;
+        }
+
+        // If response is hidden, also hide assertions (they share the results area)
+        if !self.show_response {
+            self.show_assertions = false;
+        }
+
+        let status = if self.show_response {
+            "Response panel visible"
+        } else {
+            "Response panel hidden"
+        };
+        self.set_status(status, StatusLevel::Info);
+        self.save_state();
+    }
+
+    /// Copy current file's relative path to clipboard
+    fn copy_current_file_path(&mut self) {
+        if let Some(path) = &self.current_file_path {
+            // Get relative path from working directory
+            let relative_path = path
+                .strip_prefix(&self.working_dir)
+                .unwrap_or(path)
+                .to_string_lossy()
+                .to_string();
+
+            match self.copy_to_clipboard(&relative_path) {
+                Ok(_) => {
+                    self.set_status(
+                        &format!("Copied path: {}", relative_path),
+                        StatusLevel::Success,
+                    );
+                }
+                Err(e) => {
+                    self.set_status(&format!("Copy failed: {}", e), StatusLevel::Error);
+                }
+            }
+        } else {
+            self.set_status("No file selected", StatusLevel::Warning);
+        }
+    }
+
+    /// Copy request content (hurl file content) to clipboard
+    fn copy_request_content(&mut self) {
+        if self.editor_content.is_empty() {
+            self.set_status("No request content to copy", StatusLevel::Warning);
+            return;
+        }
+
+        let content = self.editor_content.join("\n");
+        match self.copy_to_clipboard(&content) {
+            Ok(_) => {
+                let line_count = self.editor_content.len();
+                self.set_status(
+                    &format!("Copied request ({} lines)", line_count),
+                    StatusLevel::Success,
+                );
+            }
+            Err(e) => {
+                self.set_status(&format!("Copy failed: {}", e), StatusLevel::Error);
+            }
+        }
+    }
+
+    /// Copy response body to clipboard
+    fn copy_response(&mut self) {
+        if let Some(result) = &self.execution_result {
+            if let Some(response) = &result.response {
+                let body = if response.body.is_empty() {
+                    format!("HTTP {}\n(empty body)", response.status_code)
+                } else {
+                    // Try to pretty print JSON
+                    if let Ok(json) = serde_json::from_str::<serde_json::Value>(&response.body) {
+                        serde_json::to_string_pretty(&json).unwrap_or(response.body.clone())
+                    } else {
+                        response.body.clone()
+                    }
+                };
+
+                match self.copy_to_clipboard(&body) {
+                    Ok(_) => {
+                        let preview = if body.len() > 50 {
+                            format!("{}...", &body[..50])
+                        } else {
+                            body.clone()
+                        };
+                        self.set_status(
+                            &format!("Copied response ({} bytes)", body.len()),
+                            StatusLevel::Success,
+                        );
+                    }
+                    Err(e) => {
+                        self.set_status(&format!("Copy failed: {}", e), StatusLevel::Error);
+                    }
+                }
+            } else {
+                self.set_status("No response body available", StatusLevel::Warning);
+            }
+        } else {
+            self.set_status("No response yet. Run request first.", StatusLevel::Warning);
+        }
+    }
+
+    /// Run the current request and write output to a file.
+    ///
+    /// Uses hurl's native `--output` flag to write the response body directly to a file.
+    /// The output filename is derived from the hurl file name:
+    /// - `test.hurl` -> `test.output`
+    ///
+    /// The output directory can be configured via `config.general.output_dir`.
+    /// If not configured, the file is written to the same directory as the hurl file.
+    pub async fn run_and_write_output(&mut self) -> Result<()> {
+        let Some(path) = self.current_file_path.clone() else {
+            self.set_status("No file selected", StatusLevel::Warning);
+            return Ok(());
+        };
+
+        // Only for .hurl files
+        if !path.extension().map_or(false, |e| e == "hurl") {
+            self.set_status("Not a .hurl file", StatusLevel::Warning);
+            return Ok(());
+        }
+
+        // Compute output file path
+        let base_name = path
+            .file_stem()
+            .map(|s| s.to_string_lossy().to_string())
+            .unwrap_or_else(|| "output".to_string());
+
+        let output_dir = self
+            .config
+            .general
+            .output_dir
+            .clone()
+            .unwrap_or_else(|| {
+                path.parent()
+                    .map(|p| p.to_path_buf())
+                    .unwrap_or_else(|| self.working_dir.clone())
+            });
+
+        let output_path = output_dir.join(format!("{}.output", base_name));
+
+        self.is_running = true;
+        self.set_status("Running request with output...", StatusLevel::Info);
+        self.trigger_execution_start_effect();
+
+        // Run the request with output file
+        let options = self.current_file_options(None);
+        let start = std::time::Instant::now();
+        let result = self
+            .runner
+            .run_with_output(
+                &path,
+                self.current_env_file.as_ref(),
+                &options,
+                &self.runtime_variable_overrides,
+                Some(&output_path),
+            )
+            .await;
+        let duration = start.elapsed();
+
+        self.is_running = false;
+
+        match result {
+            Ok(mut exec_result) => {
+                self.set_assertion_lines(&path, &mut exec_result);
+                let success = exec_result.success;
+                let status_code = exec_result.response.as_ref().map(|r| r.status_code);
+
+                // Add to history
+                self.history.insert(
+                    0,
+                    HistoryEntry {
+                        id: uuid::Uuid::new_v4(),
+                        file_path: path.clone(),
+                        timestamp: chrono::Utc::now(),
+                        duration_ms: duration.as_millis() as u64,
+                        status_code,
+                        success,
+                    },
+                );
+
+                // Store execution result in the per-file cache
+                let relative_path = self.get_relative_path(&path);
+                self.file_execution_states
+                    .insert(relative_path, exec_result.clone());
+
+                self.execution_result = Some(exec_result);
+                self.update_body_diff();
+                self.reset_response_collapse();
+                self.response_scroll = 0;
+                self.assertions_scroll = 0;
+
+                // Persist state to disk
+                self.save_state();
+
+                // Get relative output path for display
+                let relative_output = output_path
+                    .strip_prefix(&self.working_dir)
+                    .unwrap_or(&output_path)
+                    .to_string_lossy();
+
+                if success {
+                    self.set_status(
+                        &format!("Output: {} ({}ms)", relative_output, duration.as_millis()),
+                        StatusLevel::Success,
+                    );
+                    self.trigger_execution_complete_effect(true);
+                } else {
+                    self.set_status(
+                        &format!("Output: {} (with failures)", relative_output),
+                        StatusLevel::Warning,
+                    );
+                    self.trigger_execution_complete_effect(false);
+                }
+            }
+            Err(e) => {
+                self.set_status(&format!("Error: {e}"), StatusLevel::Error);
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Copy text to system clipboard
+    fn copy_to_clipboard(&self, text: &str) -> Result<()> {
+        use arboard::Clipboard;
+        let mut clipboard = Clipboard::new()?;
+        clipboard.set_text(text)?;
+        Ok(())
+    }
+
+    /// Copy the hurl command to clipboard
+    fn copy_hurl_command(&mut self) {
+        let Some(file_path) = &self.current_file_path else {
+            self.set_status("No file selected", StatusLevel::Warning);
+            return;
+        };
+
+        // Only for .hurl files
+        if !file_path.extension().map_or(false, |e| e == "hurl") {
+            self.set_status("Not a .hurl file", StatusLevel::Warning);
+            return;
+        }
+
+        // Build the command
+        let mut cmd_parts = vec!["hurl".to_string()];
+
+        // Add variables file if available
+        if let Some(env_file) = &self.current_env_file {
+            cmd_parts.push("--variables-file".to_string());
+            cmd_parts.push(env_file.to_string_lossy().to_string());
+        }
+
+        // Add the hurl file path
+        cmd_parts.push(file_path.to_string_lossy().to_string());
+
+        let command = cmd_parts.join(" ");
+
+        match self.copy_to_clipboard(&command) {
+            Ok(_) => {
+                self.set_status(&format!("Copied: {}", command), StatusLevel::Success);
+            }
+            Err(e) => {
+                self.set_status(&format!("Copy failed: {}", e), StatusLevel::Error);
+            }
+        }
+    }
+
+    /// Copy the selected file to the internal clipboard for paste operation.
+    ///
+    /// This function stores the path of the currently selected file in `clipboard_file`.
+    /// Only works when the file browser panel is active and a file (not directory) is selected.
+    ///
+    /// # Behavior
+    /// - Only files can be copied, not directories
+    /// - The file path is stored in memory (not system clipboard)
+    /// - Shows status message indicating success or failure
+    ///
+    /// # Keyboard Shortcut
+    /// `p` - Copy selected file
+    fn copy_file_to_clipboard(&mut self) {
+        self.store_file_in_clipboard(false);
+    }
+
+    /// Cut the selected file to the internal clipboard.
+    ///
+    /// Works like `copy_file_to_clipboard`, but the next paste moves the file
+    /// instead of duplicating it.
+    ///
+    /// # Keyboard Shortcut
+    /// `x` - Cut selected file (in the file browser)
+    fn cut_file_to_clipboard(&mut self) {
+        self.store_file_in_clipboard(true);
+    }
+
+    /// Store the selected file in `clipboard_file` for a copy (`cut == false`) or a move
+    fn store_file_in_clipboard(&mut self, cut: bool) {
+        let verb = if cut { "Cut" } else { "Copy" };
+
+        // Ensure we're in the file browser panel
+        if self.active_panel != ActivePanel::FileBrowser {
+            self.set_status(
+                &format!("{} only works in file browser", verb),
+                StatusLevel::Warning,
+            );
+            return;
+        }
+
+        if let Some(entry) = self.get_selected_file_entry() {
+            // Directories cannot be copied (only files)
+            if entry.is_dir {
+                self.set_status(
+                    &format!("Cannot {} directories", verb.to_lowercase()),
+                    StatusLevel::Warning,
+                );
+                return;
+            }
+
+            // Store the file path in the clipboard
+            let path = entry.path.clone();
+            let file_name = entry.name.clone();
+            self.clipboard_file = Some(path);
+            self.clipboard_is_cut = cut;
+            let done = if cut { "Cut" } else { "Copied" };
+            self.set_status(&format!("{}: {}", done, file_name), StatusLevel::Success);
+        } else {
+            self.set_status("No file selected", StatusLevel::Warning);
+        }
+    }
+
+    /// Directory for new or pasted files, based on the file browser selection.
+    ///
+    /// A selected directory is used as-is, a selected file resolves to its parent,
+    /// and no selection falls back to the working directory.
+    fn selected_target_dir(&self) -> PathBuf {
+        if let Some(entry) = self.get_selected_file_entry() {
+            if entry.is_dir {
+                entry.path.clone()
+            } else {
+                entry
+                    .path
+                    .parent()
+                    .map(|p| p.to_path_buf())
+                    .unwrap_or_else(|| self.working_dir.clone())
+            }
+        } else {
+            self.working_dir.clone()
+        }
+    }
+
+    /// Paste the previously copied file to the current location.
+    ///
+    /// This function duplicates the file stored in `clipboard_file` to the target directory,
+    /// or moves it there when it was cut (`clipboard_is_cut`).
+    /// The target directory is determined by the current selection in the file browser.
+    ///
+    /// # Target Directory Logic
+    /// - If a directory is selected: paste into that directory
+    /// - If a file is selected: paste into the parent directory of that file
+    /// - If nothing is selected: paste into the working directory
+    ///
+    /// # Name Conflict Handling
+    /// If a file with the same name already exists, a suffix is appended:
+    /// - `file.hurl` -> `file_copy1.hurl`
+    /// - `file_copy1.hurl` -> `file_copy2.hurl`
+    ///
+    /// # Keyboard Shortcut
+    /// `P` (Shift+p) - Paste copied or cut file
+    fn paste_file_from_clipboard(&mut self) {
+        // Ensure we're in the file browser panel
+        if self.active_panel != ActivePanel::FileBrowser {
+            self.set_status("Paste only works in file browser", StatusLevel::Warning);
+            return;
+        }
+
+        // Check if there's a file in the clipboard
+        let Some(source_path) = self.clipboard_file.clone() else {
+            self.set_status(
+                "No file in clipboard. Use 'p' to copy first.",
+                StatusLevel::Warning,
+            );
+            return;
+        };
+
+        // Verify the source file still exists
+        if !source_path.exists() {
+            self.set_status("Source file no longer exists", StatusLevel::Error);
+            self.clipboard_file = None;
+            return;
+        }
+
+        // Determine the target directory based on current selection
+        let target_dir = self.selected_target_dir();
+        if self.clipboard_is_cut && source_path.parent() == Some(target_dir.as_path()) {
+            self.set_status("File is already in this directory", StatusLevel::Info);
+            return;
+        }
+
+        // Extract the source file name
+        let Some(file_name) = source_path.file_name() else {
+            self.set_status("Invalid source file", StatusLevel::Error);
+            return;
+        };
+
+        // Build the target path, handling name conflicts by appending _copyN suffix
+        let mut target_path = target_dir.join(file_name);
+        let mut counter = 1;
+
+        // Generate unique filename if target already exists
+        while target_path.exists() {
+            let stem = source_path
+                .file_stem()
+                .map(|s| s.to_string_lossy().to_string())
+                .unwrap_or_default();
+            let ext = source_path
+                .extension()
+                .map(|e| format!(".{}", e.to_string_lossy()))
+                .unwrap_or_default();
+            let new_name = format!("{}_copy{}{}", stem, counter, ext);
+            target_path = target_dir.join(new_name);
+            counter += 1;
+        }
+
+        // Perform the file copy (or move for a cut file)
+        let result = if self.clipboard_is_cut {
+            std::fs::rename(&source_path, &target_path)
+        } else {
+            std::fs::copy(&source_path, &target_path).map(|_| ())
+        };
+        match result {
+            Ok(_) => {
+                let target_name = target_path
+                    .file_name()
+                    .map(|n| n.to_string_lossy().to_string())
+                    .unwrap_or_default();
+                if self.clipboard_is_cut {
+                    self.update_moved_file_paths(&source_path, &target_path);
+                    self.clipboard_file = None;
+                    self.clipboard_is_cut = false;
+                    self.set_status(&format!("Moved: {}", target_name), StatusLevel::Success);
+                } else {
+                    self.set_status(&format!("Pasted: {}", target_name), StatusLevel::Success);
+                }
+
+                // Refresh file tree to show the newly pasted file
+                if let Err(e) = self.refresh_file_tree() {
+                    self.set_status(
+                        &format!("Pasted but refresh failed: {}", e),
+                        StatusLevel::Warning,
+                    );
+                }
+            }
+            Err(e) => {
+                self.set_status(&format!("Paste failed: {}", e), StatusLevel::Error);
+            }
+        }
+    }
+
+    /// Start the rename operation for the currently selected file.
+    ///
+    /// This function initiates rename mode and pre-fills the input with the current filename.
+    /// Only works when the file browser panel is active and a file or directory is selected.
+    ///
+    /// # Behavior
+    /// - Files and directories can be renamed
+    /// - The current name is pre-filled in the rename input (without a trailing slash)
+    /// - User can edit the name and press Enter to confirm or Esc to cancel
+    ///
+    /// # Keyboard Shortcut
+    /// `n` - Start rename for selected file
+    fn start_rename(&mut self) {
+        // Ensure we're in the file browser panel
+        if self.active_panel != ActivePanel::FileBrowser {
+            self.set_status("Rename only works in file browser", StatusLevel::Warning);
+            return;
+        }
+
+        // Get the selected entry info (clone to avoid borrow issues)
+        let entry_info = self
+            .get_selected_file_entry()
+            .map(|e| (e.path.clone(), e.name.trim_end_matches('/').to_string()));
+
+        if let Some((path, name)) = entry_info {
+            // Store the target path and pre-fill with current name
+            self.rename_target = Some(path);
+            self.rename_cursor = name.len();
+            self.rename_input = name;
+            self.mode = AppMode::Rename;
+            self.set_status(
+                "Enter new name (Enter to confirm, Esc to cancel)",
+                StatusLevel::Info,
+            );
+        } else {
+            self.set_status("No file selected", StatusLevel::Warning);
+        }
+    }
 
-        // If response panel was active and is now hidden, move to Editor panel
-        if !self.show_response && self.active_panel == ActivePanel::Response {
-            self.active_panel = ActivePanel::Editor;
+    /// Execute the rename operation with the current input.
+    ///
+    /// Renames the file stored in `rename_target` to the new name in `rename_input`.
+    /// Handles validation and error cases.
+    ///
+    /// # Validation
+    /// - New name cannot be empty
+    /// - New name cannot already exist in the same directory
+    /// - Must preserve .hurl extension for hurl files
+    fn execute_rename(&mut self) {
+        let Some(source_path) = self.rename_target.take() else {
+            self.set_status("No file to rename", StatusLevel::Error);
+            return;
+        };
+
+        let new_name = self.rename_input.trim().to_string();
+        self.rename_input.clear();
+
+        // Validate new name
+        if new_name.is_empty() {
+            self.set_status("Filename cannot be empty", StatusLevel::Error);
+            return;
         }
 
-        // If assertions panel was active and response is now hidden, move to Editor panel
-        if !self.show_response && self.active_panel == ActivePanel::Assertions {
-            self.active_panel = ActivePanel::Editor;
+        // Check for invalid characters (additional safety)
+        if new_name.contains('/') || new_name.contains('\\') {
+            self.set_status(
+                "Filename cannot contain path separators",
+                StatusLevel::Error,
+            );
+            return;
         }
 
-        // If response is hidden, also hide assertions (they share the results area)
-        if !self.show_response {
-            self.show_assertions = false;
+        // Ensure .hurl extension is preserved for hurl files
+        let new_name = if !source_path.is_dir()
+            && source_path.extension().map_or(false, |e| e == "hurl")
+            && !new_name.ends_with(".hurl")
+        {
+            format!("{}.hurl", new_name)
+        } else {
+            new_name
+        };
+
+        // Build target path in the same directory
+        let Some(parent_dir) = source_path.parent() else {
+            self.set_status("Cannot determine parent directory", StatusLevel::Error);
+            return;
+        };
+        let target_path = parent_dir.join(&new_name);
+
+        // Check if target already exists
+        if target_path.exists() && target_path != source_path {
+            self.set_status(
+                &format!("File '{}' already exists", new_name),
+                StatusLevel::Error,
+            );
+            return;
         }
 
-        let status = if self.show_response {
-            "Response panel visible"
+        // If name unchanged, just return
+        if target_path == source_path {
+            self.set_status("Name unchanged", StatusLevel::Info);
+            return;
+        }
+
+        // Perform the rename
+        match std::fs::rename(&source_path, &target_path) {
+            Ok(_) => {
+                let old_name = source_path
+                    .file_name()
+                    .map(|n| n.to_string_lossy().to_string())
+                    .unwrap_or_default();
+                self.set_status(
+                    &format!("Renamed: {} -> {}", old_name, new_name),
+                    StatusLevel::Success,
+                );
+
+                self.update_moved_file_paths(&source_path, &target_path);
+
+                // Point the clipboard at the renamed file (or the file inside a renamed directory)
+                if let Some(moved) = self
+                    .clipboard_file
+                    .as_ref()
+                    .and_then(|p| moved_path(p, &source_path, &target_path))
+                {
+                    self.clipboard_file = Some(moved);
+                }
+
+                // Keep the folders expanded under their new names
+                let expanded = self.expanded_folders_after_move(&source_path, &target_path);
+
+                // Refresh file tree to show the renamed file
+                if let Err(e) = self.refresh_file_tree() {
+                    self.set_status(
+                        &format!("Renamed but refresh failed: {}", e),
+                        StatusLevel::Warning,
+                    );
+                }
+                self.restore_expanded_folders(&expanded);
+                self.select_file_tree_path(&target_path);
+            }
+            Err(e) => {
+                self.set_status(&format!("Rename failed: {}", e), StatusLevel::Error);
+            }
+        }
+    }
+
+    /// Point the open file, editor tabs, bookmarks, pins and cached results at the new path of
+    /// a renamed or moved file, or of the files inside a renamed directory
+    fn update_moved_file_paths(&mut self, source_path: &PathBuf, target_path: &PathBuf) {
+        let moved = |path: &mut PathBuf| {
+            if let Some(new_path) = moved_path(path, source_path, target_path) {
+                *path = new_path;
+            }
+        };
+        if let Some(path) = self.current_file_path.as_mut() {
+            moved(path);
+        }
+        self.open_tabs.iter_mut().for_each(moved);
+        self.bookmarks.iter_mut().for_each(moved);
+        self.pinned_files.iter_mut().for_each(moved);
+        self.tab_states = std::mem::take(&mut self.tab_states)
+            .into_iter()
+            .map(|(mut path, tab)| {
+                moved(&mut path);
+                (path, tab)
+            })
+            .collect();
+        self.file_method_cache = std::mem::take(&mut self.file_method_cache)
+            .into_iter()
+            .map(|(mut path, method)| {
+                moved(&mut path);
+                (path, method)
+            })
+            .collect();
+
+        // Execution results are keyed by the path relative to the working directory
+        let (old_key, new_key) = (
+            PathBuf::from(self.get_relative_path(source_path)),
+            PathBuf::from(self.get_relative_path(target_path)),
+        );
+        self.file_execution_states = std::mem::take(&mut self.file_execution_states)
+            .into_iter()
+            .map(|(key, result)| {
+                let key = moved_path(Path::new(&key), &old_key, &new_key)
+                    .map_or(key, |moved| moved.to_string_lossy().to_string());
+                (key, result)
+            })
+            .collect();
+    }
+
+    /// Expanded folders, with those inside a moved directory under its new name
+    fn expanded_folders_after_move(&self, source_path: &Path, target_path: &Path) -> Vec<String> {
+        let relative = |p: &Path| p.strip_prefix(&self.working_dir).unwrap_or(p).to_path_buf();
+        let (old_dir, new_dir) = (relative(source_path), relative(target_path));
+        self.collect_expanded_folders()
+            .into_iter()
+            .map(|folder| {
+                moved_path(Path::new(&folder), &old_dir, &new_dir)
+                    .map_or(folder, |moved| moved.to_string_lossy().to_string())
+            })
+            .collect()
+    }
+
+    /// Start creating a new hurl file in the selected directory.
+    ///
+    /// Reuses rename mode for the name input; `execute_new_file` runs on Enter.
+    fn start_new_file(&mut self) {
+        self.new_file_dir = Some(self.selected_target_dir());
+        self.rename_target = None;
+        self.rename_input.clear();
+        self.rename_cursor = 0;
+        self.mode = AppMode::Rename;
+        self.set_status(
+            "Enter new file name (Enter to create, Esc to cancel)",
+            StatusLevel::Info,
+        );
+    }
+
+    /// Create the new hurl file from a minimal template and open it in the editor
+    fn execute_new_file(&mut self) {
+        let Some(target_dir) = self.new_file_dir.take() else {
+            return;
+        };
+
+        let name = self.rename_input.trim().to_string();
+        self.rename_input.clear();
+
+        if name.is_empty() {
+            self.set_status("Filename cannot be empty", StatusLevel::Error);
+            return;
+        }
+
+        let name = if name.ends_with(".hurl") {
+            name
         } else {
-            "Response panel hidden"
+            format!("{}.hurl", name)
         };
-        self.set_status(status, StatusLevel::Info);
+        let path = target_dir.join(&name);
+
+        // create_new fails instead of truncating a file that appeared since the prompt opened
+        let created = std::fs::OpenOptions::new()
+            .write(true)
+            .create_new(true)
+            .open(&path)
+            .and_then(|mut file| {
+                std::io::Write::write_all(&mut file, b"GET https://example.com\n\nHTTP 200\n")
+            });
+        match created {
+            Ok(()) => {}
+            Err(e) if e.kind() == std::io::ErrorKind::AlreadyExists => {
+                self.set_status(
+                    &format!("File '{}' already exists", name),
+                    StatusLevel::Error,
+                );
+                return;
+            }
+            Err(e) => {
+                self.set_status(&format!("Create failed: {}", e), StatusLevel::Error);
+                return;
+            }
+        }
+
+        if let Err(e) = self.refresh_file_tree() {
+            tracing::warn!("Failed to refresh file tree: {}", e);
+        }
+
+        if let Err(e) = self.open_file(&path) {
+            self.set_status(&format!("Failed to open file: {}", e), StatusLevel::Error);
+            return;
+        }
+
         self.save_state();
+        self.set_status(&format!("Created: {}", name), StatusLevel::Success);
     }
 
-    /// Copy current file's relative path to clipboard
-    fn copy_current_file_path(&mut self) {
-        if let Some(path) = &self.current_file_path {
-            // Get relative path from working directory
-            let relative_path = path
-                .strip_prefix(&self.working_dir)
-                .unwrap_or(path)
-                .to_string_lossy()
-                .to_string();
+    /// Start creating a new directory next to the selected entry.
+    ///
+    /// Reuses rename mode for the name input; `create_directory` runs on Enter.
+    fn start_create_directory(&mut self) {
+        self.rename_is_new_dir = true;
+        self.new_file_dir = None;
+        self.rename_target = None;
+        self.rename_input.clear();
+        self.rename_cursor = 0;
+        self.mode = AppMode::Rename;
+        self.set_status(
+            "Enter new directory name (Enter to create, Esc to cancel)",
+            StatusLevel::Info,
+        );
+    }
 
-            match self.copy_to_clipboard(&relative_path) {
-                Ok(_) => {
-                    self.set_status(
-                        &format!("Copied path: {}", relative_path),
-                        StatusLevel::Success,
-                    );
+    /// Create the directory named in `rename_input`, then expand and select it.
+    ///
+    /// The name is relative to the parent of the selected entry; `/` creates
+    /// nested directories.
+    pub fn create_directory(&mut self) {
+        self.rename_is_new_dir = false;
+        let name = self.rename_input.trim().trim_matches('/').to_string();
+        self.rename_input.clear();
+
+        if name.is_empty() {
+            self.set_status("Directory name cannot be empty", StatusLevel::Error);
+            return;
+        }
+        if !name
+            .chars()
+            .all(|c| c.is_alphanumeric() || matches!(c, '-' | '_' | '/'))
+            || name.contains("//")
+        {
+            self.set_status(
+                "Directory name may only contain letters, digits, '-', '_' and '/'",
+                StatusLevel::Error,
+            );
+            return;
+        }
+
+        let parent_dir = self
+            .get_selected_file_entry()
+            .and_then(|e| e.path.parent().map(|p| p.to_path_buf()))
+            .unwrap_or_else(|| self.working_dir.clone());
+        let path = parent_dir.join(&name);
+
+        if path.exists() {
+            self.set_status(
+                &format!("Directory '{}' already exists", name),
+                StatusLevel::Error,
+            );
+            return;
+        }
+
+        if let Err(e) = std::fs::create_dir_all(&path) {
+            self.set_status(&format!("Create failed: {}", e), StatusLevel::Error);
+            return;
+        }
+
+        if let Err(e) = self.refresh_file_tree() {
+            tracing::warn!("Failed to refresh file tree: {}", e);
+        }
+
+        // Expand every directory down to the new one so it is visible
+        let expanded: Vec<String> = path
+            .ancestors()
+            .take_while(|p| p.starts_with(&self.working_dir) && *p != self.working_dir)
+            .filter_map(|p| p.strip_prefix(&self.working_dir).ok())
+            .map(|p| p.to_string_lossy().to_string())
+            .collect();
+        self.restore_expanded_folders(&expanded);
+        self.select_file_tree_path(&path);
+
+        self.save_state();
+        self.set_status(&format!("Created: {}/", name), StatusLevel::Success);
+    }
+
+    /// Collapse the folder at `path` and select it
+    fn collapse_to_dir(&mut self, path: &Path) {
+        fn find_dir<'a>(entries: &'a mut [FileEntry], path: &Path) -> Option<&'a mut FileEntry> {
+            for entry in entries {
+                if entry.path == path {
+                    return Some(entry);
                 }
-                Err(e) => {
-                    self.set_status(&format!("Copy failed: {}", e), StatusLevel::Error);
+                if path.starts_with(&entry.path) {
+                    return find_dir(&mut entry.children, path);
                 }
             }
-        } else {
+            None
+        }
+
+        let Some(entry) = find_dir(&mut self.file_tree, path) else {
+            return;
+        };
+        entry.is_expanded = false;
+        self.select_file_tree_path(path);
+        self.save_state();
+    }
+
+    /// Move the file browser selection to the entry at `path`, if it is visible
+    fn select_file_tree_path(&mut self, path: &Path) {
+        fn find_path(entries: &[FileEntry], path: &Path, index: &mut usize) -> Option<usize> {
+            for entry in entries {
+                if entry.path == path {
+                    return Some(*index);
+                }
+                *index += 1;
+                if entry.is_expanded {
+                    if let Some(found) = find_path(&entry.children, path, index) {
+                        return Some(found);
+                    }
+                }
+            }
+            None
+        }
+
+        let mut index = 0;
+        if let Some(found_index) = find_path(&self.file_tree, path, &mut index) {
+            self.file_tree_index = found_index;
+            self.file_tree_state.select(Some(found_index));
+        }
+    }
+
+    /// Ask for confirmation to delete the selected file in the file browser.
+    ///
+    /// The file is only removed once the user answers `y` (see `execute_delete`).
+    fn delete_selected_file(&mut self) {
+        let entry_info = self
+            .get_selected_file_entry()
+            .map(|e| (e.path.clone(), e.is_dir));
+
+        let Some((path, is_dir)) = entry_info else {
             self.set_status("No file selected", StatusLevel::Warning);
+            return;
+        };
+
+        if is_dir {
+            self.set_status("Cannot delete directories", StatusLevel::Warning);
+            return;
+        }
+
+        self.delete_target = Some(path);
+        self.mode = AppMode::ConfirmDelete;
+    }
+
+    /// Delete the file stored in `delete_target` after confirmation
+    fn execute_delete(&mut self) {
+        let Some(path) = self.delete_target.take() else {
+            return;
+        };
+
+        let file_name = path
+            .file_name()
+            .map(|n| n.to_string_lossy().to_string())
+            .unwrap_or_default();
+
+        if let Err(e) = std::fs::remove_file(&path) {
+            self.set_status(&format!("Delete failed: {}", e), StatusLevel::Error);
+            return;
+        }
+
+        // Close the file if it was open in the editor
+        if self.current_file_path.as_ref() == Some(&path) {
+            self.close_editor_file();
+        }
+        if let Some(index) = self.open_tabs.iter().position(|p| p == &path) {
+            self.open_tabs.remove(index);
+            self.active_tab = self.active_tab.min(self.open_tabs.len().saturating_sub(1));
+        }
+        self.tab_states.remove(&path);
+
+        let relative_path = self.get_relative_path(&path);
+        self.file_execution_states.remove(&relative_path);
+
+        if self.clipboard_file.as_ref() == Some(&path) {
+            self.clipboard_file = None;
+        }
+
+        if let Err(e) = self.refresh_file_tree() {
+            self.set_status(
+                &format!("Deleted but refresh failed: {}", e),
+                StatusLevel::Warning,
+            );
+            return;
         }
+
+        // Keep the cursor on the nearest remaining entry
+        let count = self.get_visible_file_count();
+        self.file_tree_index = self.file_tree_index.min(count.saturating_sub(1));
+        self.file_tree_state.select(Some(self.file_tree_index));
+
+        self.save_state();
+        self.set_status(&format!("Deleted: {}", file_name), StatusLevel::Success);
     }
 
-    /// Copy request content (hurl file content) to clipboard
-    fn copy_request_content(&mut self) {
-        if self.editor_content.is_empty() {
-            self.set_status("No request content to copy", StatusLevel::Warning);
+    /// Copy full test context for AI (request + response + assertions + errors)
+    fn copy_ai_context(&mut self) {
+        let context = self.build_ai_context();
+
+        if context.is_empty() {
+            self.set_status("No context to copy", StatusLevel::Warning);
             return;
         }
 
-        let content = self.editor_content.join("\n");
-        match self.copy_to_clipboard(&content) {
+        match self.copy_to_clipboard(&context) {
             Ok(_) => {
-                let line_count = self.editor_content.len();
                 self.set_status(
-                    &format!("Copied request ({} lines)", line_count),
+                    &format!("Copied AI context ({} bytes)", context.len()),
                     StatusLevel::Success,
                 );
             }
@@ -2193,713 +6508,1295 @@ impl App {
         }
     }
 
-    /// Copy response body to clipboard
-    fn copy_response(&mut self) {
+    /// Build AI context string (shared between copy and output)
+    fn build_ai_context(&self) -> String {
+        let mut context = String::new();
+
+        // Add file path
+        if let Some(path) = &self.current_file_path {
+            let relative_path = path
+                .strip_prefix(&self.working_dir)
+                .unwrap_or(path)
+                .to_string_lossy();
+            context.push_str(&format!("## Hurl Test: {}\n\n", relative_path));
+        }
+
+        // Add request (hurl file content)
+        if !self.editor_content.is_empty() {
+            context.push_str("### Request (Hurl file)\n\n```hurl\n");
+            context.push_str(&self.editor_content.join("\n"));
+            context.push_str("\n```\n\n");
+        }
+
+        // Add environment file content (if selected)
+        if let Some(env_path) = &self.current_env_file {
+            if let Ok(env_content) = std::fs::read_to_string(env_path) {
+                let env_name = env_path
+                    .file_name()
+                    .map(|n| n.to_string_lossy().to_string())
+                    .unwrap_or_else(|| "env".to_string());
+                context.push_str(&format!("### Environment: {}\n\n```env\n", env_name));
+                context.push_str(&env_content);
+                if !env_content.ends_with('\n') {
+                    context.push_str("\n");
+                }
+                context.push_str("```\n\n");
+            }
+        }
+
+        // Add response
         if let Some(result) = &self.execution_result {
             if let Some(response) = &result.response {
-                let body = if response.body.is_empty() {
-                    format!("HTTP {}\n(empty body)", response.status_code)
-                } else {
-                    // Try to pretty print JSON
-                    if let Ok(json) = serde_json::from_str::<serde_json::Value>(&response.body) {
-                        serde_json::to_string_pretty(&json).unwrap_or(response.body.clone())
-                    } else {
-                        response.body.clone()
+                context.push_str(&format!(
+                    "### Response\n\n**Status:** {}\n**Duration:** {}ms\n\n",
+                    response.status_code, response.duration_ms
+                ));
+
+                // Headers
+                if !response.headers.is_empty() {
+                    context.push_str("**Headers:**\n```\n");
+                    for (name, value) in &response.headers {
+                        context.push_str(&format!("{}: {}\n", name, value));
                     }
-                };
+                    context.push_str("```\n\n");
+                }
 
-                match self.copy_to_clipboard(&body) {
-                    Ok(_) => {
-                        let preview = if body.len() > 50 {
-                            format!("{}...", &body[..50])
+                // Body
+                if !response.body.is_empty() {
+                    context.push_str("**Body:**\n```json\n");
+                    if let Ok(json) = serde_json::from_str::<serde_json::Value>(&response.body) {
+                        if let Ok(pretty) = serde_json::to_string_pretty(&json) {
+                            context.push_str(&pretty);
                         } else {
-                            body.clone()
-                        };
-                        self.set_status(
-                            &format!("Copied response ({} bytes)", body.len()),
-                            StatusLevel::Success,
-                        );
-                    }
-                    Err(e) => {
-                        self.set_status(&format!("Copy failed: {}", e), StatusLevel::Error);
+                            context.push_str(&response.body);
+                        }
+                    } else {
+                        context.push_str(&response.body);
                     }
+                    context.push_str("\n```\n\n");
                 }
-            } else {
-                self.set_status("No response body available", StatusLevel::Warning);
             }
-        } else {
-            self.set_status("No response yet. Run request first.", StatusLevel::Warning);
-        }
-    }
 
-    /// Run the current request and write output to a file.
-    ///
-    /// Uses hurl's native `--output` flag to write the response body directly to a file.
-    /// The output filename is derived from the hurl file name:
-    /// - `test.hurl` -> `test.output`
-    ///
-    /// The output directory can be configured via `config.general.output_dir`.
-    /// If not configured, the file is written to the same directory as the hurl file.
-    pub async fn run_and_write_output(&mut self) -> Result<()> {
-        let Some(path) = self.current_file_path.clone() else {
-            self.set_status("No file selected", StatusLevel::Warning);
-            return Ok(());
-        };
+            // Assertions
+            if !result.assertions.is_empty() {
+                context.push_str("### Assertion Results\n\n");
+                let passed = result.assertions.iter().filter(|a| a.success).count();
+                let total = result.assertions.len();
+                context.push_str(&format!("**Summary:** {}/{} passed\n\n", passed, total));
 
-        // Only for .hurl files
-        if !path.extension().map_or(false, |e| e == "hurl") {
-            self.set_status("Not a .hurl file", StatusLevel::Warning);
-            return Ok(());
-        }
+                context.push_str("| Status | Assertion |\n");
+                context.push_str("|--------|----------|\n");
+                for assertion in &result.assertions {
+                    let status = if assertion.success { "PASS" } else { "FAIL" };
+                    context.push_str(&format!("| {} | {} |\n", status, assertion.text));
+                }
+                context.push_str("\n");
 
-        // Compute output file path
-        let base_name = path
-            .file_stem()
-            .map(|s| s.to_string_lossy().to_string())
-            .unwrap_or_else(|| "output".to_string());
+                // Add failure details
+                let failures: Vec<_> = result.assertions.iter().filter(|a| !a.success).collect();
+                if !failures.is_empty() {
+                    context.push_str("**Failures:**\n\n");
+                    for failure in failures {
+                        context.push_str(&format!("- `{}`\n", failure.text));
+                        if let Some(expected) = &failure.expected {
+                            context.push_str(&format!("  - Expected: {}\n", expected));
+                        }
+                        if let Some(actual) = &failure.actual {
+                            context.push_str(&format!("  - Actual: {}\n", actual));
+                        }
+                        if let Some(message) = &failure.message {
+                            context.push_str(&format!("  - Error: {}\n", message));
+                        }
+                    }
+                    context.push_str("\n");
+                }
+            }
 
-        let output_dir = self
-            .config
-            .general
-            .output_dir
-            .clone()
-            .unwrap_or_else(|| {
-                path.parent()
-                    .map(|p| p.to_path_buf())
-                    .unwrap_or_else(|| self.working_dir.clone())
-            });
+            // Add stderr errors if present (e.g., undefined variables, parse errors)
+            if !result.stderr.is_empty() && !result.success {
+                context.push_str("### Errors\n\n```\n");
+                context.push_str(&result.stderr);
+                context.push_str("```\n\n");
+            }
 
-        let output_path = output_dir.join(format!("{}.output", base_name));
+            // Overall result
+            context.push_str(&format!(
+                "### Result: {}\n",
+                if result.success { "SUCCESS" } else { "FAILED" }
+            ));
+        } else {
+            context.push_str("### Response\n\n*No response yet - request not executed*\n");
+        }
 
-        self.is_running = true;
-        self.set_status("Running request with output...", StatusLevel::Info);
-        self.trigger_execution_start_effect();
+        context
+    }
 
-        // Run the request with output file
-        let start = std::time::Instant::now();
-        let result = self
-            .runner
-            .run_with_output(&path, self.current_env_file.as_ref(), Some(&output_path))
-            .await;
-        let duration = start.elapsed();
+    /// Output AI context to stdout and quit (for Helix/pipe integration)
+    fn output_ai_context_and_quit(&mut self) {
+        let context = self.build_ai_context();
 
-        self.is_running = false;
+        if context.is_empty() {
+            self.set_status("No context to output", StatusLevel::Warning);
+            return;
+        }
 
-        match result {
-            Ok(exec_result) => {
-                let success = exec_result.success;
-                let status_code = exec_result.response.as_ref().map(|r| r.status_code);
+        self.output = Some(context);
+        self.exit();
+    }
 
-                // Add to history
-                self.history.insert(
-                    0,
-                    HistoryEntry {
-                        id: uuid::Uuid::new_v4(),
-                        file_path: path.clone(),
-                        timestamp: chrono::Utc::now(),
-                        duration_ms: duration.as_millis() as u64,
-                        status_code,
-                        success,
-                    },
-                );
+    /// Execute search
+    fn execute_search(&mut self) {
+        // Simple search in file names
+        let query = self.search_query.to_lowercase();
 
-                // Store execution result in the per-file cache
-                let relative_path = self.get_relative_path(&path);
-                self.file_execution_states
-                    .insert(relative_path, exec_result.clone());
+        fn find_matching(entries: &[FileEntry], query: &str, index: &mut usize) -> Option<usize> {
+            for entry in entries {
+                if entry.name.to_lowercase().contains(query) {
+                    return Some(*index);
+                }
+                *index += 1;
+                if entry.is_expanded {
+                    if let Some(found) = find_matching(&entry.children, query, index) {
+                        return Some(found);
+                    }
+                }
+            }
+            None
+        }
 
-                self.execution_result = Some(exec_result);
-                self.response_scroll = 0;
-                self.assertions_scroll = 0;
+        let mut index = 0;
+        if let Some(found_index) = find_matching(&self.file_tree, &query, &mut index) {
+            self.file_tree_index = found_index;
+            self.file_tree_state.select(Some(found_index));
+            self.set_status(&format!("Found: {}", self.search_query), StatusLevel::Info);
+        } else {
+            self.set_status("No matches found", StatusLevel::Warning);
+        }
+    }
 
-                // Persist state to disk
-                self.save_state();
+    /// Execute command
+    fn execute_command(&mut self) -> Result<()> {
+        let cmd = self.command_input.trim().to_string();
+        let cmd_lower = cmd.to_lowercase();
 
-                // Get relative output path for display
-                let relative_output = output_path
-                    .strip_prefix(&self.working_dir)
-                    .unwrap_or(&output_path)
-                    .to_string_lossy();
+        // `!N` runs the Nth most recent command again
+        if let Some(n) = cmd.strip_prefix('!').and_then(|n| n.parse::<usize>().ok()) {
+            let previous = n
+                .checked_sub(1)
+                .and_then(|i| self.command_history.iter().rev().nth(i))
+                .cloned();
+            let Some(previous) = previous else {
+                self.set_status(&format!("No command !{}", n), StatusLevel::Error);
+                self.command_input.clear();
+                return Ok(());
+            };
+            self.command_input = previous;
+            return self.execute_command();
+        }
+        self.record_command(&cmd);
 
-                if success {
-                    self.set_status(
-                        &format!("Output: {} ({}ms)", relative_output, duration.as_millis()),
-                        StatusLevel::Success,
-                    );
-                    self.trigger_execution_complete_effect(true);
-                } else {
-                    self.set_status(
-                        &format!("Output: {} (with failures)", relative_output),
-                        StatusLevel::Warning,
-                    );
-                    self.trigger_execution_complete_effect(false);
-                }
+        match cmd_lower.as_str() {
+            "q" | "quit" => {
+                self.exit();
             }
-            Err(e) => {
-                self.set_status(&format!("Error: {e}"), StatusLevel::Error);
+            "w" | "write" | "save" => {
+                self.save_current_file()?;
+            }
+            "wq" => {
+                self.save_current_file()?;
+                self.exit();
+            }
+            "refresh" | "r" => {
+                self.scan_file_tree();
+            }
+            "help" | "h" => {
+                self.show_help = true;
+            }
+            _ if cmd.starts_with("s/") => {
+                self.substitute(&cmd);
+            }
+            _ if cmd_lower.starts_with("bind ") || cmd_lower.starts_with("bind! ") => {
+                self.bind_key(&cmd);
+            }
+            _ if cmd_lower == "curl" || cmd_lower.starts_with("curl ") => {
+                self.import_curl(&cmd);
+            }
+            "cookies" => {
+                self.open_cookie_jar();
+            }
+            "stats" => {
+                self.open_stats();
+            }
+            "compare" => {
+                self.compare_responses();
             }
+            _ if cmd_lower == "theme" || cmd_lower.starts_with("theme ") => {
+                self.switch_theme(cmd["theme".len()..].trim());
+            }
+            "insecure" => {
+                self.toggle_insecure();
+            }
+            _ if cmd_lower == "setvar" || cmd_lower.starts_with("setvar ") => {
+                self.set_variable_override(cmd["setvar".len()..].trim());
+            }
+            _ if cmd_lower == "proxy" || cmd_lower.starts_with("proxy ") => {
+                self.set_proxy_override(cmd["proxy".len()..].trim());
+            }
+            _ if cmd_lower.starts_with("export-har") => {
+                self.export_har_command(cmd["export-har".len()..].trim());
+            }
+            _ if cmd_lower.starts_with("export-env-json") => {
+                self.export_env_command(cmd["export-env-json".len()..].trim(), true);
+            }
+            _ if cmd_lower.starts_with("export-env") => {
+                self.export_env_command(cmd["export-env".len()..].trim(), false);
+            }
+            _ if cmd_lower.starts_with("export-junit") => {
+                self.export_junit_command(cmd["export-junit".len()..].trim());
+            }
+            _ if cmd_lower.starts_with("w ") => {
+                self.save_new_file(cmd[2..].trim())?;
+            }
+            _ => match parse_line_col(&cmd) {
+                Some((line, col)) => self.goto_line(line, col),
+                None => {
+                    self.set_status(&format!("Unknown command: {}", cmd), StatusLevel::Error);
+                }
+            },
         }
 
+        self.command_input.clear();
         Ok(())
     }
 
-    /// Copy text to system clipboard
-    fn copy_to_clipboard(&self, text: &str) -> Result<()> {
-        use arboard::Clipboard;
-        let mut clipboard = Clipboard::new()?;
-        clipboard.set_text(text)?;
-        Ok(())
-    }
+    /// Handle `:proxy <url>`: use a proxy for this session only.
+    /// Without a URL the proxy from the config (if any) is used again.
+    fn set_proxy_override(&mut self, proxy: &str) {
+        self.proxy_override = (!proxy.is_empty()).then(|| proxy.to_string());
+        self.runner.set_proxy(self.active_proxy().map(String::from));
 
-    /// Copy the hurl command to clipboard
-    fn copy_hurl_command(&mut self) {
-        let Some(file_path) = &self.current_file_path else {
-            self.set_status("No file selected", StatusLevel::Warning);
-            return;
-        };
+        match self.active_proxy().map(String::from) {
+            Some(proxy) => self.set_status(&format!("Proxy: {}", proxy), StatusLevel::Info),
+            None => self.set_status("Proxy disabled", StatusLevel::Info),
+        }
+    }
 
-        // Only for .hurl files
-        if !file_path.extension().map_or(false, |e| e == "hurl") {
-            self.set_status("Not a .hurl file", StatusLevel::Warning);
+    /// Handle `:setvar [KEY [VALUE]]`: override a variable for this session.
+    /// Without a value the override is removed; without arguments the overrides are listed.
+    fn set_variable_override(&mut self, args: &str) {
+        if args.is_empty() {
+            self.variable_override_index = 0;
+            self.mode = AppMode::VariableOverride;
             return;
         }
 
-        // Build the command
-        let mut cmd_parts = vec!["hurl".to_string()];
-
-        // Add variables file if available
-        if let Some(env_file) = &self.current_env_file {
-            cmd_parts.push("--variables-file".to_string());
-            cmd_parts.push(env_file.to_string_lossy().to_string());
+        let (name, value) = args
+            .split_once(char::is_whitespace)
+            .map_or((args, ""), |(name, value)| (name, value.trim()));
+        if value.is_empty() {
+            if self.runtime_variable_overrides.remove(name).is_some() {
+                let message = format!("Override removed: {}", name);
+                self.set_status(&message, StatusLevel::Success);
+            } else {
+                let message = format!("No override for {}", name);
+                self.set_status(&message, StatusLevel::Warning);
+            }
+            return;
         }
 
-        // Add the hurl file path
-        cmd_parts.push(file_path.to_string_lossy().to_string());
+        self.runtime_variable_overrides
+            .insert(name.to_string(), value.to_string());
+        self.set_status(
+            &format!("Override set: {} (:setvar to list)", name),
+            StatusLevel::Success,
+        );
+    }
 
-        let command = cmd_parts.join(" ");
+    /// Variable overrides sorted by name, as listed in the override popup
+    pub fn sorted_variable_overrides(&self) -> Vec<(&String, &String)> {
+        let mut overrides: Vec<_> = self.runtime_variable_overrides.iter().collect();
+        overrides.sort();
+        overrides
+    }
 
-        match self.copy_to_clipboard(&command) {
-            Ok(_) => {
-                self.set_status(&format!("Copied: {}", command), StatusLevel::Success);
+    /// Handle key events in the variable override popup
+    fn handle_variable_override_mode_key(&mut self, key: KeyEvent) -> Result<()> {
+        match key.code {
+            KeyCode::Esc | KeyCode::Char('q') => {
+                self.mode = AppMode::Normal;
             }
-            Err(e) => {
-                self.set_status(&format!("Copy failed: {}", e), StatusLevel::Error);
+            KeyCode::Char('j') | KeyCode::Down => {
+                let last = self.runtime_variable_overrides.len().saturating_sub(1);
+                self.variable_override_index = (self.variable_override_index + 1).min(last);
+            }
+            KeyCode::Char('k') | KeyCode::Up => {
+                self.variable_override_index = self.variable_override_index.saturating_sub(1);
+            }
+            KeyCode::Char('d') | KeyCode::Delete => {
+                let selected = self
+                    .sorted_variable_overrides()
+                    .get(self.variable_override_index)
+                    .map(|(name, _)| name.to_string());
+                if let Some(name) = selected {
+                    self.runtime_variable_overrides.remove(&name);
+                    self.variable_override_index = self
+                        .variable_override_index
+                        .min(self.runtime_variable_overrides.len().saturating_sub(1));
+                    let message = format!("Override removed: {}", name);
+                    self.set_status(&message, StatusLevel::Success);
+                }
             }
+            _ => {}
         }
+
+        Ok(())
     }
 
-    /// Copy the selected file to the internal clipboard for paste operation.
-    ///
-    /// This function stores the path of the currently selected file in `clipboard_file`.
-    /// Only works when the file browser panel is active and a file (not directory) is selected.
-    ///
-    /// # Behavior
-    /// - Only files can be copied, not directories
-    /// - The file path is stored in memory (not system clipboard)
-    /// - Shows status message indicating success or failure
-    ///
-    /// # Keyboard Shortcut
-    /// `p` - Copy selected file
-    fn copy_file_to_clipboard(&mut self) {
-        // Ensure we're in the file browser panel
-        if self.active_panel != ActivePanel::FileBrowser {
-            self.set_status("Copy only works in file browser", StatusLevel::Warning);
+    /// Handle `:theme <name>`: draw the UI with a built-in theme or a user theme file
+    fn switch_theme(&mut self, name: &str) {
+        if name.is_empty() {
+            self.set_status("Usage: :theme <name>", StatusLevel::Warning);
             return;
         }
 
-        if let Some(entry) = self.get_selected_file_entry() {
-            // Directories cannot be copied (only files)
-            if entry.is_dir {
-                self.set_status("Cannot copy directories", StatusLevel::Warning);
-                return;
+        match Theme::named(name) {
+            Ok(theme) => {
+                self.active_theme = theme;
+                // Highlighted editor lines hold the old colors
+                self.editor_line_cache.clear();
+                self.set_status(&format!("Theme: {}", name), StatusLevel::Success);
             }
-
-            // Store the file path in the clipboard
-            let path = entry.path.clone();
-            let file_name = entry.name.clone();
-            self.clipboard_file = Some(path);
-            self.set_status(&format!("Copied: {}", file_name), StatusLevel::Success);
-        } else {
-            self.set_status("No file selected", StatusLevel::Warning);
+            Err(e) => self.set_status(&format!("{:#}", e), StatusLevel::Error),
         }
     }
 
-    /// Paste the previously copied file to the current location.
-    ///
-    /// This function duplicates the file stored in `clipboard_file` to the target directory.
-    /// The target directory is determined by the current selection in the file browser.
-    ///
-    /// # Target Directory Logic
-    /// - If a directory is selected: paste into that directory
-    /// - If a file is selected: paste into the parent directory of that file
-    /// - If nothing is selected: paste into the working directory
-    ///
-    /// # Name Conflict Handling
-    /// If a file with the same name already exists, a suffix is appended:
-    /// - `file.hurl` -> `file_copy1.hurl`
-    /// - `file_copy1.hurl` -> `file_copy2.hurl`
-    ///
-    /// # Keyboard Shortcut
-    /// `P` (Shift+p) - Paste copied file
-    fn paste_file_from_clipboard(&mut self) {
-        // Ensure we're in the file browser panel
-        if self.active_panel != ActivePanel::FileBrowser {
-            self.set_status("Paste only works in file browser", StatusLevel::Warning);
+    /// Diff the previous and current response bodies for the Diff tab and `:compare`
+    fn update_body_diff(&mut self) {
+        self.body_diff = match (
+            response_body(&self.previous_execution_result),
+            response_body(&self.execution_result),
+        ) {
+            (Some(previous), Some(current)) => body_diff(previous, current),
+            _ => None,
+        };
+    }
+
+    /// Open the side-by-side diff of the previous and current response bodies
+    pub fn compare_responses(&mut self) {
+        if response_body(&self.previous_execution_result).is_none()
+            || response_body(&self.execution_result).is_none()
+        {
+            self.set_status("No previous run to compare", StatusLevel::Info);
             return;
         }
-
-        // Check if there's a file in the clipboard
-        let Some(source_path) = self.clipboard_file.clone() else {
-            self.set_status(
-                "No file in clipboard. Use 'p' to copy first.",
-                StatusLevel::Warning,
-            );
+        let Some(diff) = self.body_diff.clone() else {
+            self.set_status("Bodies are too large to diff", StatusLevel::Warning);
             return;
         };
 
-        // Verify the source file still exists
-        if !source_path.exists() {
-            self.set_status("Source file no longer exists", StatusLevel::Error);
-            self.clipboard_file = None;
-            return;
+        self.compare_rows = side_by_side(diff);
+        self.compare_scroll = (0, 0);
+        self.compare_right_focused = false;
+        self.pending_key = None;
+        self.mode = AppMode::Compare;
+        if !self.compare_rows.iter().any(DiffRow::is_changed) {
+            self.set_status("Body unchanged since the previous run", StatusLevel::Info);
         }
+    }
 
-        // Determine the target directory based on current selection
-        let target_dir = if let Some(entry) = self.get_selected_file_entry() {
-            if entry.is_dir {
-                // Selected item is a directory - paste into it
-                entry.path.clone()
-            } else {
-                // Selected item is a file - paste into its parent directory
-                entry
-                    .path
-                    .parent()
-                    .map(|p| p.to_path_buf())
-                    .unwrap_or_else(|| self.working_dir.clone())
+    /// Handle keys in the compare view: each side scrolls on its own, `]c` / `[c` jump
+    /// both sides to the next / previous hunk
+    fn handle_compare_mode_key(&mut self, key: KeyEvent) {
+        if let Some(pending) = self.pending_key.take() {
+            if key.code == KeyCode::Char('c') {
+                self.jump_to_compare_hunk(pending == ']');
+                return;
             }
+        }
+
+        let last = self.compare_rows.len().saturating_sub(1);
+        let scroll = if self.compare_right_focused {
+            &mut self.compare_scroll.1
         } else {
-            // No selection - paste into the working directory
-            self.working_dir.clone()
+            &mut self.compare_scroll.0
         };
+        match key.code {
+            KeyCode::Esc | KeyCode::Char('q') => {
+                self.mode = AppMode::Normal;
+            }
+            KeyCode::Char('j') | KeyCode::Down => *scroll = (*scroll + 1).min(last),
+            KeyCode::Char('k') | KeyCode::Up => *scroll = scroll.saturating_sub(1),
+            KeyCode::PageDown => *scroll = (*scroll + 10).min(last),
+            KeyCode::PageUp => *scroll = scroll.saturating_sub(10),
+            KeyCode::Char('g') | KeyCode::Home => *scroll = 0,
+            KeyCode::Char('G') | KeyCode::End => *scroll = last,
+            KeyCode::Tab | KeyCode::Char('h') | KeyCode::Char('l') => {
+                self.compare_right_focused = !self.compare_right_focused;
+            }
+            KeyCode::Char(c @ (']' | '[')) => self.pending_key = Some(c),
+            _ => {}
+        }
+    }
 
-        // Extract the source file name
-        let Some(file_name) = source_path.file_name() else {
-            self.set_status("Invalid source file", StatusLevel::Error);
-            return;
+    /// Scroll both compare panels to the next (or previous) hunk after the focused panel's position
+    fn jump_to_compare_hunk(&mut self, forward: bool) {
+        let position = if self.compare_right_focused {
+            self.compare_scroll.1
+        } else {
+            self.compare_scroll.0
+        };
+        let hunks = hunk_starts(&self.compare_rows);
+        let target = if forward {
+            hunks.into_iter().find(|&start| start > position)
+        } else {
+            hunks.into_iter().rev().find(|&start| start < position)
         };
 
-        // Build the target path, handling name conflicts by appending _copyN suffix
-        let mut target_path = target_dir.join(file_name);
-        let mut counter = 1;
+        match target {
+            Some(start) => self.compare_scroll = (start, start),
+            None => self.set_status("No more changes", StatusLevel::Info),
+        }
+    }
 
-        // Generate unique filename if target already exists
-        while target_path.exists() {
-            let stem = source_path
-                .file_stem()
-                .map(|s| s.to_string_lossy().to_string())
-                .unwrap_or_default();
-            let ext = source_path
-                .extension()
-                .map(|e| format!(".{}", e.to_string_lossy()))
-                .unwrap_or_default();
-            let new_name = format!("{}_copy{}{}", stem, counter, ext);
-            target_path = target_dir.join(new_name);
-            counter += 1;
+    /// Turn TLS certificate verification off or back on for this session
+    fn toggle_insecure(&mut self) {
+        self.insecure_override = !self.insecure_override;
+        self.runner.set_insecure(self.insecure_override);
+
+        if self.insecure_override {
+            self.set_status("TLS verification DISABLED", StatusLevel::Warning);
+        } else {
+            self.set_status("TLS verification enabled", StatusLevel::Info);
         }
+    }
 
-        // Perform the file copy operation
-        match std::fs::copy(&source_path, &target_path) {
-            Ok(_) => {
-                let target_name = target_path
-                    .file_name()
-                    .map(|n| n.to_string_lossy().to_string())
-                    .unwrap_or_default();
-                self.set_status(&format!("Pasted: {}", target_name), StatusLevel::Success);
+    /// Whether the open file is a `.env` file
+    pub fn is_env_file_open(&self) -> bool {
+        self.current_file_path.as_ref().is_some_and(|p| {
+            p.extension().is_some_and(|e| e == "env") || p.file_name().is_some_and(|n| n == ".env")
+        })
+    }
 
-                // Refresh file tree to show the newly pasted file
-                if let Err(e) = self.refresh_file_tree() {
-                    self.set_status(
-                        &format!("Pasted but refresh failed: {}", e),
-                        StatusLevel::Warning,
-                    );
-                }
-            }
-            Err(e) => {
-                self.set_status(&format!("Paste failed: {}", e), StatusLevel::Error);
-            }
+    /// Whether the secret value on `line` of the open .env file is currently shown
+    pub fn is_env_value_revealed(&self, line: usize) -> bool {
+        self.env_revealed.is_some_and(|(revealed, since)| {
+            revealed == line && since.elapsed() < ENV_REVEAL_DURATION
+        })
+    }
+
+    /// Show the masked value on the cursor line of a .env file for a few seconds
+    fn reveal_env_value(&mut self) {
+        if !self.is_env_file_open() {
+            return;
+        }
+        let line = self.editor_cursor.0;
+        let is_secret = self
+            .editor_content
+            .get(line)
+            .and_then(|l| l.split_once('='))
+            .is_some_and(|(key, _)| is_secret_key(key));
+        if is_secret {
+            self.env_revealed = Some((line, std::time::Instant::now()));
+            self.set_status("Value revealed for 3 seconds", StatusLevel::Info);
         }
     }
 
-    /// Start the rename operation for the currently selected file.
-    ///
-    /// This function initiates rename mode and pre-fills the input with the current filename.
-    /// Only works when the file browser panel is active and a file (not directory) is selected.
-    ///
-    /// # Behavior
-    /// - Only files can be renamed, not directories
-    /// - The current filename is pre-filled in the rename input
-    /// - User can edit the name and press Enter to confirm or Esc to cancel
-    ///
-    /// # Keyboard Shortcut
-    /// `n` - Start rename for selected file
-    fn start_rename(&mut self) {
-        // Ensure we're in the file browser panel
-        if self.active_panel != ActivePanel::FileBrowser {
-            self.set_status("Rename only works in file browser", StatusLevel::Warning);
-            return;
-        }
+    /// Proxy used for requests: the runtime override, then the config
+    pub fn active_proxy(&self) -> Option<&str> {
+        self.proxy_override
+            .as_deref()
+            .or(self.config.general.proxy.as_deref())
+    }
 
-        // Get the selected entry info (clone to avoid borrow issues)
-        let entry_info = self
-            .get_selected_file_entry()
-            .map(|e| (e.path.clone(), e.name.clone(), e.is_dir));
+    /// Handle `:curl <command>`: open the equivalent hurl file as a new unsaved buffer.
+    /// Without arguments the curl command is read from the system clipboard.
+    fn import_curl(&mut self, cmd: &str) {
+        let command = if cmd.trim().eq_ignore_ascii_case("curl") {
+            match arboard::Clipboard::new().and_then(|mut c| c.get_text()) {
+                Ok(text) => text,
+                Err(e) => {
+                    self.set_status(&format!("Clipboard error: {}", e), StatusLevel::Error);
+                    return;
+                }
+            }
+        } else {
+            cmd.to_string()
+        };
 
-        if let Some((path, name, is_dir)) = entry_info {
-            // Directories cannot be renamed (for now)
-            if is_dir {
-                self.set_status("Cannot rename directories", StatusLevel::Warning);
+        let content = match crate::parser::curl_to_hurl(command.trim()) {
+            Ok(content) => content,
+            Err(e) => {
+                self.set_status(&format!("Cannot import curl: {}", e), StatusLevel::Error);
                 return;
             }
+        };
 
-            // Store the target path and pre-fill with current name
-            self.rename_target = Some(path);
-            self.rename_input = name;
-            self.mode = AppMode::Rename;
+        self.current_file = crate::parser::parse_hurl_file(&content).ok();
+        self.current_file_path = None;
+        self.editor_content = content.lines().map(String::from).collect();
+        self.editor_cursor = (0, 0);
+        self.editor_scroll = 0;
+        self.selected_entry = 0;
+        self.editor_folds.clear();
+        self.editor_line_cache.clear();
+        self.editor_entry_cache = None;
+        self.editor_error_lines.clear();
+        self.undo_stack.clear();
+        self.editor_dirty = true;
+        self.clear_editor_search();
+        self.execution_result = None;
+        self.previous_execution_result = None;
+        self.body_diff = None;
+        self.update_response_json_rows();
+        self.editor_tab = EditorTab::Hurl;
+        self.active_panel = ActivePanel::Editor;
+        self.set_status(
+            "Imported curl command (save with :w <name>)",
+            StatusLevel::Success,
+        );
+    }
+
+    /// Handle `:w <name>`: save the editor content as a new file in the selected directory
+    fn save_new_file(&mut self, name: &str) -> Result<()> {
+        if name.is_empty() || name.contains('/') || name.contains('\\') {
+            self.set_status("Invalid file name", StatusLevel::Error);
+            return Ok(());
+        }
+        let name = if name.ends_with(".hurl") {
+            name.to_string()
+        } else {
+            format!("{}.hurl", name)
+        };
+
+        let path = self.selected_target_dir().join(&name);
+        if path.exists() && self.current_file_path.as_ref() != Some(&path) {
             self.set_status(
-                "Enter new name (Enter to confirm, Esc to cancel)",
-                StatusLevel::Info,
+                &format!("File '{}' already exists", name),
+                StatusLevel::Error,
             );
-        } else {
-            self.set_status("No file selected", StatusLevel::Warning);
+            return Ok(());
         }
+
+        self.current_file_path = Some(path);
+        self.save_current_file()?;
+        self.refresh_file_tree()?;
+        Ok(())
     }
 
-    /// Execute the rename operation with the current input.
-    ///
-    /// Renames the file stored in `rename_target` to the new name in `rename_input`.
-    /// Handles validation and error cases.
-    ///
-    /// # Validation
-    /// - New name cannot be empty
-    /// - New name cannot already exist in the same directory
-    /// - Must preserve .hurl extension for hurl files
-    fn execute_rename(&mut self) {
-        let Some(source_path) = self.rename_target.take() else {
-            self.set_status("No file to rename", StatusLevel::Error);
+    /// Handle `:export-junit <path>` (relative paths are resolved from the working directory)
+    fn export_junit_command(&mut self, path: &str) {
+        if path.is_empty() {
+            self.set_status("Usage: :export-junit <path>", StatusLevel::Error);
             return;
-        };
-
-        let new_name = self.rename_input.trim().to_string();
-        self.rename_input.clear();
-
-        // Validate new name
-        if new_name.is_empty() {
-            self.set_status("Filename cannot be empty", StatusLevel::Error);
+        }
+        if self.file_execution_states.is_empty() {
+            self.set_status("No results to export", StatusLevel::Warning);
             return;
         }
 
-        // Check for invalid characters (additional safety)
-        if new_name.contains('/') || new_name.contains('\\') {
+        let path = self.working_dir.join(path);
+        match self.export_results_as_junit(&path) {
+            Ok(()) => self.set_status(
+                &format!("Exported JUnit report: {}", path.display()),
+                StatusLevel::Success,
+            ),
+            Err(e) => self.set_status(&format!("Export failed: {:#}", e), StatusLevel::Error),
+        }
+    }
+
+    /// Handle `:export-env [--include-secrets] <path>` (and `:export-env-json`)
+    fn export_env_command(&mut self, args: &str, json: bool) {
+        let include_secrets = args.split_whitespace().any(|a| a == "--include-secrets");
+        let path = args
+            .split_whitespace()
+            .filter(|a| *a != "--include-secrets")
+            .collect::<Vec<_>>()
+            .join(" ");
+        if path.is_empty() {
+            let command = if json {
+                "export-env-json"
+            } else {
+                "export-env"
+            };
             self.set_status(
-                "Filename cannot contain path separators",
+                &format!("Usage: :{} [--include-secrets] <path>", command),
                 StatusLevel::Error,
             );
             return;
         }
+        if self.variables.is_empty() {
+            self.set_status("No variables to export", StatusLevel::Warning);
+            return;
+        }
 
-        // Ensure .hurl extension is preserved for hurl files
-        let new_name = if source_path.extension().map_or(false, |e| e == "hurl")
-            && !new_name.ends_with(".hurl")
-        {
-            format!("{}.hurl", new_name)
+        let path = self.working_dir.join(path);
+        // Say so when an existing file is replaced
+        let replaced = if path.exists() { " (replaced)" } else { "" };
+        let result = if json {
+            self.export_environment_json(&path, include_secrets)
         } else {
-            new_name
+            self.export_environment(&path, include_secrets)
         };
+        match result {
+            Ok(()) => self.set_status(
+                &format!(
+                    "Exported {} variables: {}{}",
+                    self.variables.len(),
+                    path.display(),
+                    replaced
+                ),
+                StatusLevel::Success,
+            ),
+            Err(e) => self.set_status(&format!("Export failed: {:#}", e), StatusLevel::Error),
+        }
+    }
 
-        // Build target path in the same directory
-        let Some(parent_dir) = source_path.parent() else {
-            self.set_status("Cannot determine parent directory", StatusLevel::Error);
-            return;
-        };
-        let target_path = parent_dir.join(&new_name);
+    /// The current variables with secret values replaced by `<REDACTED>` unless included
+    fn exported_variables(&self, include_secrets: bool) -> Vec<(&str, &str)> {
+        self.variables
+            .iter()
+            .map(|var| {
+                let value = if var.is_secret && !include_secrets {
+                    "<REDACTED>"
+                } else {
+                    var.value.as_str()
+                };
+                (var.name.as_str(), value)
+            })
+            .collect()
+    }
 
-        // Check if target already exists
-        if target_path.exists() && target_path != source_path {
-            self.set_status(
-                &format!("File '{}' already exists", new_name),
-                StatusLevel::Error,
-            );
-            return;
+    /// Write the current variables as a `.env` file (`KEY=VALUE` lines)
+    pub fn export_environment(&self, path: &PathBuf, include_secrets: bool) -> Result<()> {
+        let content: String = self
+            .exported_variables(include_secrets)
+            .iter()
+            .map(|(name, value)| format!("{}={}\n", name, value))
+            .collect();
+        std::fs::write(path, content).with_context(|| format!("Failed to write {}", path.display()))
+    }
+
+    /// Write the current variables as a JSON object (`{"KEY": "VALUE"}`)
+    pub fn export_environment_json(&self, path: &PathBuf, include_secrets: bool) -> Result<()> {
+        let object: serde_json::Map<String, serde_json::Value> = self
+            .exported_variables(include_secrets)
+            .into_iter()
+            .map(|(name, value)| (name.to_string(), value.into()))
+            .collect();
+        let content = serde_json::to_string_pretty(&object)? + "\n";
+        std::fs::write(path, content).with_context(|| format!("Failed to write {}", path.display()))
+    }
+
+    /// Write the cached execution results as a JUnit XML report.
+    ///
+    /// Each hurl file becomes a `<testsuite>` and each assertion a `<testcase>`;
+    /// files without assertions get a single test case for the whole run.
+    pub fn export_results_as_junit(&self, path: &PathBuf) -> Result<()> {
+        use std::fmt::Write;
+
+        let mut files: Vec<(&String, &ExecutionResult)> =
+            self.file_execution_states.iter().collect();
+        files.sort_by(|a, b| a.0.cmp(b.0));
+
+        let mut xml = String::from("<?xml version=\"1.0\" encoding=\"UTF-8\"?>\n<testsuites>\n");
+        for (file, result) in files {
+            let time = result
+                .response
+                .as_ref()
+                .map_or(0.0, |r| r.duration_ms as f64 / 1000.0);
+            let cases: Vec<(String, Option<String>)> = if result.assertions.is_empty() {
+                let failure = (!result.success).then(|| {
+                    format!(
+                        "hurl exited with code {}\n{}",
+                        result.exit_code, result.stderr
+                    )
+                });
+                vec![(file.clone(), failure)]
+            } else {
+                result
+                    .assertions
+                    .iter()
+                    .map(|a| {
+                        let failure = (!a.success)
+                            .then(|| a.message.clone().unwrap_or_else(|| a.text.clone()));
+                        (a.text.clone(), failure)
+                    })
+                    .collect()
+            };
+            let failures = cases.iter().filter(|(_, f)| f.is_some()).count();
+
+            writeln!(
+                xml,
+                "  <testsuite name=\"{}\" tests=\"{}\" failures=\"{}\" errors=\"0\" time=\"{:.3}\">",
+                xml_escape(file),
+                cases.len(),
+                failures,
+                time
+            )?;
+            for (name, failure) in cases {
+                let name = xml_escape(&name);
+                let classname = xml_escape(file);
+                match failure {
+                    Some(message) => {
+                        writeln!(
+                            xml,
+                            "    <testcase name=\"{}\" classname=\"{}\">",
+                            name, classname
+                        )?;
+                        writeln!(
+                            xml,
+                            "      <failure message=\"{}\">{}</failure>",
+                            name,
+                            xml_escape(&message)
+                        )?;
+                        writeln!(xml, "    </testcase>")?;
+                    }
+                    None => {
+                        writeln!(
+                            xml,
+                            "    <testcase name=\"{}\" classname=\"{}\"/>",
+                            name, classname
+                        )?;
+                    }
+                }
+            }
+            writeln!(xml, "  </testsuite>")?;
         }
+        xml.push_str("</testsuites>\n");
 
-        // If name unchanged, just return
-        if target_path == source_path {
-            self.set_status("Name unchanged", StatusLevel::Info);
+        let context = || format!("Failed to write {}", path.display());
+        if let Some(parent) = path.parent() {
+            std::fs::create_dir_all(parent).with_context(context)?;
+        }
+        std::fs::write(path, xml).with_context(context)
+    }
+
+    /// Handle `:export-har <path>` (relative paths are resolved from the working directory)
+    fn export_har_command(&mut self, path: &str) {
+        if path.is_empty() {
+            self.set_status("Usage: :export-har <path>", StatusLevel::Error);
             return;
         }
 
-        // Perform the rename
-        match std::fs::rename(&source_path, &target_path) {
-            Ok(_) => {
-                let old_name = source_path
-                    .file_name()
-                    .map(|n| n.to_string_lossy().to_string())
-                    .unwrap_or_default();
-                self.set_status(
-                    &format!("Renamed: {} -> {}", old_name, new_name),
-                    StatusLevel::Success,
-                );
+        let path = self.working_dir.join(path);
+        match self.export_har(&path) {
+            Ok(()) => self.set_status(
+                &format!("Exported HAR: {}", path.display()),
+                StatusLevel::Success,
+            ),
+            Err(e) => self.set_status(&format!("Export failed: {:#}", e), StatusLevel::Error),
+        }
+    }
 
-                // Update current_file_path if the renamed file was open
-                if self.current_file_path.as_ref() == Some(&source_path) {
-                    self.current_file_path = Some(target_path.clone());
-                }
+    /// Write the current execution result as a HAR 1.2 archive.
+    ///
+    /// The request is taken from the last entry of the current file with variables
+    /// resolved; headers and body that hurl reported as sent are preferred when available.
+    pub fn export_har(&self, path: &PathBuf) -> Result<()> {
+        use serde_json::json;
 
-                // Clear clipboard if it referenced the renamed file
-                if self.clipboard_file.as_ref() == Some(&source_path) {
-                    self.clipboard_file = Some(target_path);
-                }
+        let Some(response) = self
+            .execution_result
+            .as_ref()
+            .and_then(|r| r.response.as_ref())
+        else {
+            anyhow::bail!("No response to export");
+        };
+        let Some(entry) = self.current_file.as_ref().and_then(|f| f.entries.last()) else {
+            anyhow::bail!("No request in the current file");
+        };
 
-                // Refresh file tree to show the renamed file
-                if let Err(e) = self.refresh_file_tree() {
-                    self.set_status(
-                        &format!("Renamed but refresh failed: {}", e),
-                        StatusLevel::Warning,
-                    );
-                }
-            }
-            Err(e) => {
-                self.set_status(&format!("Rename failed: {}", e), StatusLevel::Error);
+        let resolve = |text: &str| {
+            self.resolve_variables(&[text.to_string()])
+                .pop()
+                .unwrap_or_default()
+        };
+        let url = resolve(&entry.url);
+        let sent = self
+            .execution_result
+            .as_ref()
+            .and_then(|r| r.request.as_ref());
+        let request_headers: Vec<(String, String)> = match sent {
+            Some(request) if !request.headers.is_empty() => request.headers.clone(),
+            _ => entry
+                .headers
+                .iter()
+                .map(|h| (h.name.clone(), resolve(&h.value)))
+                .collect(),
+        };
+        let request_body = sent
+            .and_then(|request| request.body.clone())
+            .or_else(|| entry.body.as_deref().map(resolve));
+
+        let query: Vec<(String, String)> = url
+            .split_once('?')
+            .map(|(_, query)| {
+                query
+                    .split('&')
+                    .filter(|pair| !pair.is_empty())
+                    .map(|pair| {
+                        let (name, value) = pair.split_once('=').unwrap_or((pair, ""));
+                        (name.to_string(), value.to_string())
+                    })
+                    .collect()
+            })
+            .unwrap_or_default();
+
+        let mut request = json!({
+            "method": entry.method,
+            "url": url,
+            "httpVersion": "HTTP/1.1",
+            "cookies": [],
+            "headers": har_pairs(&request_headers),
+            "queryString": har_pairs(&query),
+            "headersSize": -1,
+            "bodySize": request_body.as_ref().map_or(0, |b| b.len()),
+        });
+        if let Some(body) = &request_body {
+            request["postData"] = json!({
+                "mimeType": header_value(&request_headers, "content-type"),
+                "text": body,
+            });
+        }
+
+        // HAR timings are durations of each phase, hurl reports them from the start of the call
+        let timings = match &response.timings {
+            Some(t) => {
+                let connected = t.appconnect.max(t.connect);
+                let ssl = if t.appconnect > 0.0 {
+                    (t.appconnect - t.connect).max(0.0)
+                } else {
+                    -1.0
+                };
+                json!({
+                    "blocked": -1,
+                    "dns": t.namelookup,
+                    "connect": (connected - t.namelookup).max(0.0),
+                    "ssl": ssl,
+                    "send": (t.pretransfer - connected).max(0.0),
+                    "wait": (t.starttransfer - t.pretransfer).max(0.0),
+                    "receive": (t.total - t.starttransfer).max(0.0),
+                })
+            }
+            None => json!({
+                "blocked": -1,
+                "dns": -1,
+                "connect": -1,
+                "send": 0,
+                "wait": response.duration_ms,
+                "receive": 0,
+            }),
+        };
+
+        let cookies: Vec<serde_json::Value> = response
+            .cookies
+            .iter()
+            .map(|c| {
+                json!({
+                    "name": c.name,
+                    "value": c.value,
+                    "path": c.path,
+                    "domain": c.domain,
+                    "httpOnly": c.http_only,
+                    "secure": c.secure,
+                })
+            })
+            .collect();
+
+        let started =
+            chrono::Utc::now() - chrono::Duration::milliseconds(response.duration_ms as i64);
+        let har = json!({
+            "log": {
+                "version": "1.2",
+                "creator": { "name": "hurl-tui", "version": env!("CARGO_PKG_VERSION") },
+                "entries": [{
+                    "startedDateTime": started.to_rfc3339(),
+                    "time": response.duration_ms,
+                    "request": request,
+                    "response": {
+                        "status": response.status_code,
+                        "statusText": "",
+                        "httpVersion": "HTTP/1.1",
+                        "cookies": cookies,
+                        "headers": har_pairs(&response.headers),
+                        "content": {
+                            "size": response.body.len(),
+                            "mimeType": header_value(&response.headers, "content-type"),
+                            "text": response.body,
+                        },
+                        "redirectURL": header_value(&response.headers, "location"),
+                        "headersSize": -1,
+                        "bodySize": response.body.len(),
+                    },
+                    "cache": {},
+                    "timings": timings,
+                }],
             }
+        });
+
+        let context = || format!("Failed to write {}", path.display());
+        if let Some(parent) = path.parent() {
+            std::fs::create_dir_all(parent).with_context(context)?;
         }
+        std::fs::write(path, serde_json::to_string_pretty(&har)?).with_context(context)
     }
 
-    /// Copy full test context for AI (request + response + assertions + errors)
-    fn copy_ai_context(&mut self) {
-        let context = self.build_ai_context();
+    /// Handle `:bind <key> <action>`; `:bind!` also writes the binding to the user config
+    fn bind_key(&mut self, cmd: &str) {
+        let persist = cmd.starts_with("bind!");
+        let args: Vec<&str> = cmd.split_whitespace().skip(1).collect();
+        let [key, action] = args[..] else {
+            self.set_status("Usage: :bind <key> <action>", StatusLevel::Error);
+            return;
+        };
 
-        if context.is_empty() {
-            self.set_status("No context to copy", StatusLevel::Warning);
+        let Some(action) = Action::from_name(&action.to_lowercase()) else {
+            let names: Vec<&str> = Action::ALL.iter().map(|a| a.name()).collect();
+            self.set_status(
+                &format!("Unknown action: {} (expected {})", action, names.join(", ")),
+                StatusLevel::Error,
+            );
             return;
-        }
+        };
 
-        match self.copy_to_clipboard(&context) {
-            Ok(_) => {
-                self.set_status(
-                    &format!("Copied AI context ({} bytes)", context.len()),
-                    StatusLevel::Success,
-                );
-            }
+        let binding = match self.keymap.bind(action, key, &mut self.config.keys) {
+            Ok(binding) => binding,
             Err(e) => {
-                self.set_status(&format!("Copy failed: {}", e), StatusLevel::Error);
+                self.set_status(&e.to_string(), StatusLevel::Error);
+                return;
+            }
+        };
+
+        if persist {
+            let saved =
+                Config::user_config_path().map(|path| Config::save_key(&path, action.name(), key));
+            if !matches!(saved, Some(Ok(()))) {
+                self.set_status("Failed to save config", StatusLevel::Error);
+                return;
             }
         }
-    }
 
-    /// Build AI context string (shared between copy and output)
-    fn build_ai_context(&self) -> String {
-        let mut context = String::new();
+        self.set_status(
+            &format!("Bound {} to {}", binding, action.name()),
+            StatusLevel::Success,
+        );
+    }
 
-        // Add file path
-        if let Some(path) = &self.current_file_path {
-            let relative_path = path
-                .strip_prefix(&self.working_dir)
-                .unwrap_or(path)
-                .to_string_lossy();
-            context.push_str(&format!("## Hurl Test: {}\n\n", relative_path));
+    /// Move the editor cursor to a 1-based line (and optional column), clamped to the content
+    fn goto_line(&mut self, line: usize, col: Option<usize>) {
+        if self.editor_content.is_empty() {
+            self.set_status("No file loaded", StatusLevel::Warning);
+            return;
         }
 
-        // Add request (hurl file content)
-        if !self.editor_content.is_empty() {
-            context.push_str("### Request (Hurl file)\n\n```hurl\n");
-            context.push_str(&self.editor_content.join("\n"));
-            context.push_str("\n```\n\n");
-        }
+        let line = line.saturating_sub(1).min(self.editor_content.len() - 1);
+        let col = col
+            .map(|c| c.saturating_sub(1).min(self.editor_content[line].len()))
+            .unwrap_or(0);
+        self.editor_cursor = (line, col);
+        self.ensure_cursor_visible();
+        self.active_panel = ActivePanel::Editor;
+        self.editor_highlight_line = Some((line, std::time::Instant::now()));
 
-        // Add environment file content (if selected)
-        if let Some(env_path) = &self.current_env_file {
-            if let Ok(env_content) = std::fs::read_to_string(env_path) {
-                let env_name = env_path
-                    .file_name()
-                    .map(|n| n.to_string_lossy().to_string())
-                    .unwrap_or_else(|| "env".to_string());
-                context.push_str(&format!("### Environment: {}\n\n```env\n", env_name));
-                context.push_str(&env_content);
-                if !env_content.ends_with('\n') {
-                    context.push_str("\n");
-                }
-                context.push_str("```\n\n");
+        self.set_status(&format!("Jumped to line {}", line + 1), StatusLevel::Info);
+    }
+
+    /// Run a `:s/pattern/replacement/[g]` substitution on the editor content.
+    /// Without `g` only the first match on the current line is replaced;
+    /// with `g` every match in the buffer is replaced.
+    fn substitute(&mut self, cmd: &str) {
+        let Some((pattern, replacement, global)) = parse_substitute_command(cmd) else {
+            self.set_status("Usage: :s/pattern/replacement/[g]", StatusLevel::Error);
+            return;
+        };
+
+        let regex = match regex::Regex::new(&pattern) {
+            Ok(regex) => regex,
+            Err(e) => {
+                self.set_status(&format!("Invalid pattern: {}", e), StatusLevel::Error);
+                return;
+            }
+        };
+
+        let line = self.editor_cursor.0;
+        let count = if global {
+            self.editor_content
+                .iter()
+                .map(|l| regex.find_iter(l).count())
+                .sum()
+        } else {
+            self.editor_content
+                .get(line)
+                .map_or(0, |l| usize::from(regex.is_match(l)))
+        };
+
+        if count == 0 {
+            self.set_status(
+                &format!("Pattern not found: {}", pattern),
+                StatusLevel::Warning,
+            );
+            return;
+        }
+
+        self.record_undo();
+        if global {
+            for content in self.editor_content.iter_mut() {
+                *content = regex
+                    .replace_all(content, replacement.as_str())
+                    .into_owned();
             }
+        } else if let Some(content) = self.editor_content.get_mut(line) {
+            *content = regex.replace(content, replacement.as_str()).into_owned();
         }
 
-        // Add response
-        if let Some(result) = &self.execution_result {
-            if let Some(response) = &result.response {
-                context.push_str(&format!(
-                    "### Response\n\n**Status:** {}\n**Duration:** {}ms\n\n",
-                    response.status_code, response.duration_ms
-                ));
+        let col = self.editor_content.get(line).map_or(0, |l| l.len());
+        self.editor_cursor.1 = self.editor_cursor.1.min(col);
+        self.reparse_editor_content();
 
-                // Headers
-                if !response.headers.is_empty() {
-                    context.push_str("**Headers:**\n```\n");
-                    for (name, value) in &response.headers {
-                        context.push_str(&format!("{}: {}\n", name, value));
-                    }
-                    context.push_str("```\n\n");
-                }
+        self.set_status(
+            &format!("{} substitution(s) made", count),
+            StatusLevel::Success,
+        );
+    }
 
-                // Body
-                if !response.body.is_empty() {
-                    context.push_str("**Body:**\n```json\n");
-                    if let Ok(json) = serde_json::from_str::<serde_json::Value>(&response.body) {
-                        if let Ok(pretty) = serde_json::to_string_pretty(&json) {
-                            context.push_str(&pretty);
-                        } else {
-                            context.push_str(&response.body);
-                        }
-                    } else {
-                        context.push_str(&response.body);
-                    }
-                    context.push_str("\n```\n\n");
-                }
+    /// Re-parse the editor content after an in-memory change
+    fn reparse_editor_content(&mut self) {
+        let is_hurl_file = self
+            .current_file_path
+            .as_ref()
+            .is_some_and(|p| p.extension().is_some_and(|e| e == "hurl"));
+
+        if is_hurl_file {
+            let content = self.editor_content.join("\n");
+            if let Ok(hurl_file) = crate::parser::parse_hurl_file(&content) {
+                self.current_file = Some(hurl_file);
             }
+        }
 
-            // Assertions
-            if !result.assertions.is_empty() {
-                context.push_str("### Assertion Results\n\n");
-                let passed = result.assertions.iter().filter(|a| a.success).count();
-                let total = result.assertions.len();
-                context.push_str(&format!("**Summary:** {}/{} passed\n\n", passed, total));
+        self.update_preview_content();
+    }
 
-                context.push_str("| Status | Assertion |\n");
-                context.push_str("|--------|----------|\n");
-                for assertion in &result.assertions {
-                    let status = if assertion.success { "PASS" } else { "FAIL" };
-                    context.push_str(&format!("| {} | {} |\n", status, assertion.text));
-                }
-                context.push_str("\n");
+    /// Set status message
+    pub fn set_status(&mut self, message: &str, level: StatusLevel) {
+        self.status_message = Some((message.to_string(), level));
+        self.status_message_timestamp = Some(std::time::Instant::now());
+        self.status_message_duration = None;
+    }
 
-                // Add failure details
-                let failures: Vec<_> = result.assertions.iter().filter(|a| !a.success).collect();
-                if !failures.is_empty() {
-                    context.push_str("**Failures:**\n\n");
-                    for failure in failures {
-                        context.push_str(&format!("- `{}`\n", failure.text));
-                        if let Some(expected) = &failure.expected {
-                            context.push_str(&format!("  - Expected: {}\n", expected));
-                        }
-                        if let Some(actual) = &failure.actual {
-                            context.push_str(&format!("  - Actual: {}\n", actual));
-                        }
-                        if let Some(message) = &failure.message {
-                            context.push_str(&format!("  - Error: {}\n", message));
-                        }
-                    }
-                    context.push_str("\n");
-                }
-            }
+    /// Set a status message that clears after `duration`
+    pub fn set_status_for(
+        &mut self,
+        message: &str,
+        level: StatusLevel,
+        duration: std::time::Duration,
+    ) {
+        self.set_status(message, level);
+        self.status_message_duration = Some(duration);
+    }
 
-            // Add stderr errors if present (e.g., undefined variables, parse errors)
-            if !result.stderr.is_empty() && !result.success {
-                context.push_str("### Errors\n\n```\n");
-                context.push_str(&result.stderr);
-                context.push_str("```\n\n");
+    /// Show a message in the status bar that does not expire (e.g. a config error)
+    pub fn set_status_notice(&mut self, message: &str, level: StatusLevel) {
+        self.status_notice = Some((message.to_string(), level));
+    }
+
+    /// Clear the status message once it has been shown long enough (errors stay longer)
+    fn expire_status_message(&mut self) {
+        let timeout = self.config.ui.status_message_timeout_secs;
+        let (Some((_, level)), Some(since)) = (&self.status_message, self.status_message_timestamp)
+        else {
+            return;
+        };
+        let timeout = match self.status_message_duration {
+            Some(duration) => duration,
+            // 0 keeps messages until they are replaced
+            None if timeout == 0 => return,
+            None if *level == StatusLevel::Error => {
+                std::time::Duration::from_secs(timeout.max(ERROR_STATUS_TIMEOUT_SECS))
+            }
+            None => std::time::Duration::from_secs(timeout),
+        };
+        if since.elapsed() >= timeout {
+            self.status_message = None;
+            self.status_message_timestamp = None;
+        }
+    }
+
+    // Editor operations
+
+    /// Snapshot the editor content before a mutation so it can be undone
+    fn record_undo(&mut self) {
+        self.undo_stack
+            .record(&self.editor_content, self.editor_cursor);
+        self.editor_line_cache.clear();
+        self.editor_entry_cache = None;
+        self.editor_dirty = true;
+    }
+
+    fn editor_undo(&mut self) {
+        match self
+            .undo_stack
+            .undo(&mut self.editor_content, self.editor_cursor)
+        {
+            Some(cursor) => {
+                self.restore_editor_cursor(cursor);
+                self.editor_line_cache.clear();
+                self.editor_entry_cache = None;
+                self.editor_dirty = true;
             }
+            None => self.set_status("Already at oldest change", StatusLevel::Info),
+        }
+    }
 
-            // Overall result
-            context.push_str(&format!(
-                "### Result: {}\n",
-                if result.success { "SUCCESS" } else { "FAILED" }
-            ));
-        } else {
-            context.push_str("### Response\n\n*No response yet - request not executed*\n");
+    fn editor_redo(&mut self) {
+        match self
+            .undo_stack
+            .redo(&mut self.editor_content, self.editor_cursor)
+        {
+            Some(cursor) => {
+                self.restore_editor_cursor(cursor);
+                self.editor_line_cache.clear();
+                self.editor_entry_cache = None;
+                self.editor_dirty = true;
+            }
+            None => self.set_status("Already at newest change", StatusLevel::Info),
         }
+    }
 
-        context
+    /// Selected line range (start, end inclusive) while in visual mode
+    pub fn visual_selection(&self) -> Option<(usize, usize)> {
+        if self.vim_mode != VimMode::Visual {
+            return None;
+        }
+        let anchor = self.visual_anchor?.0;
+        let cursor = self.editor_cursor.0;
+        Some((anchor.min(cursor), anchor.max(cursor)))
     }
 
-    /// Output AI context to stdout and quit (for Helix/pipe integration)
-    fn output_ai_context_and_quit(&mut self) {
-        let context = self.build_ai_context();
+    fn editor_yank_selection(&mut self) {
+        let Some((start, end)) = self.visual_selection() else {
+            return;
+        };
+        let end = end.min(self.editor_content.len().saturating_sub(1));
+        let Some(lines) = self.editor_content.get(start..=end) else {
+            return;
+        };
 
-        if context.is_empty() {
-            self.set_status("No context to output", StatusLevel::Warning);
+        self.editor_yank_buffer = lines.to_vec();
+        let count = end - start + 1;
+        self.set_status(&format!("{} line(s) yanked", count), StatusLevel::Info);
+    }
+
+    fn editor_delete_selection(&mut self) {
+        let Some((start, end)) = self.visual_selection() else {
+            return;
+        };
+        if start >= self.editor_content.len() {
             return;
         }
+        self.record_undo();
 
-        self.output = Some(context);
-        self.quit = true;
+        let end = end.min(self.editor_content.len() - 1);
+        self.editor_yank_buffer = self.editor_content.drain(start..=end).collect();
+        if self.editor_content.is_empty() {
+            self.editor_content.push(String::new());
+        }
+
+        self.editor_cursor = (start.min(self.editor_content.len() - 1), 0);
+        self.ensure_cursor_visible();
     }
 
-    /// Execute search
-    fn execute_search(&mut self) {
-        // Simple search in file names
-        let query = self.search_query.to_lowercase();
+    /// Replace the selected lines with the yank buffer
+    fn editor_put_over_selection(&mut self) {
+        if self.editor_yank_buffer.is_empty() {
+            self.set_status("Nothing to paste", StatusLevel::Warning);
+            return;
+        }
+        let Some((start, end)) = self.visual_selection() else {
+            return;
+        };
+        self.record_undo();
 
-        fn find_matching(entries: &[FileEntry], query: &str, index: &mut usize) -> Option<usize> {
-            for entry in entries {
-                if entry.name.to_lowercase().contains(query) {
-                    return Some(*index);
-                }
-                *index += 1;
-                if entry.is_expanded {
-                    if let Some(found) = find_matching(&entry.children, query, index) {
-                        return Some(found);
-                    }
-                }
+        let end = (end + 1).min(self.editor_content.len());
+        let start = start.min(end);
+        let yank = self.editor_yank_buffer.clone();
+        self.editor_content.splice(start..end, yank);
+
+        self.editor_cursor = (start, 0);
+        self.ensure_cursor_visible();
+    }
+
+    fn collapse_cursor_to_selection_start(&mut self) {
+        if let Some((start, _)) = self.visual_selection() {
+            if start < self.editor_cursor.0 {
+                self.editor_cursor = (start, 0);
+                self.ensure_cursor_visible();
             }
-            None
         }
+    }
 
-        let mut index = 0;
-        if let Some(found_index) = find_matching(&self.file_tree, &query, &mut index) {
-            self.file_tree_index = found_index;
-            self.file_tree_state.select(Some(found_index));
-            self.set_status(&format!("Found: {}", self.search_query), StatusLevel::Info);
-        } else {
-            self.set_status("No matches found", StatusLevel::Warning);
+    /// Recompute the positions of `editor_search_query` in the editor content
+    fn update_editor_search_matches(&mut self) {
+        self.editor_search_matches.clear();
+        if self.editor_search_query.is_empty() {
+            return;
+        }
+        for (line_num, line) in self.editor_content.iter().enumerate() {
+            for (col, _) in line.match_indices(self.editor_search_query.as_str()) {
+                self.editor_search_matches.push((line_num, col));
+            }
         }
     }
 
-    /// Execute command
-    fn execute_command(&mut self) -> Result<()> {
-        let cmd = self.command_input.trim();
-        let cmd_lower = cmd.to_lowercase();
+    /// Move the cursor to the next (or previous) search match, wrapping around
+    fn editor_search_jump(&mut self, forward: bool) {
+        if self.editor_search_query.is_empty() {
+            return;
+        }
+        let cursor = self.editor_cursor;
+        let target = if forward {
+            self.editor_search_matches
+                .iter()
+                .find(|&&pos| pos > cursor)
+                .or(self.editor_search_matches.first())
+        } else {
+            self.editor_search_matches
+                .iter()
+                .rev()
+                .find(|&&pos| pos < cursor)
+                .or(self.editor_search_matches.last())
+        };
 
-        match cmd_lower.as_str() {
-            "q" | "quit" => {
-                self.quit = true;
-            }
-            "w" | "write" | "save" => {
-                self.save_current_file()?;
-            }
-            "wq" => {
-                self.save_current_file()?;
-                self.quit = true;
-            }
-            "refresh" | "r" => {
-                self.refresh_file_tree()?;
-            }
-            "help" | "h" => {
-                self.show_help = true;
-            }
-            _ => {
-                self.set_status(&format!("Unknown command: {}", cmd), StatusLevel::Error);
+        match target.copied() {
+            Some(pos) => {
+                self.editor_cursor = pos;
+                self.ensure_cursor_visible();
             }
+            None => self.set_status(
+                &format!("Pattern not found: {}", self.editor_search_query),
+                StatusLevel::Warning,
+            ),
         }
+    }
 
-        self.command_input.clear();
-        Ok(())
+    fn clear_editor_search(&mut self) {
+        self.editor_search_query.clear();
+        self.editor_search_matches.clear();
+        self.editor_search_input = false;
     }
 
-    /// Set status message
-    pub fn set_status(&mut self, message: &str, level: StatusLevel) {
-        self.status_message = Some((message.to_string(), level));
+    /// Move the cursor to a restored position, clamped to the current content
+    fn restore_editor_cursor(&mut self, (line, col): (usize, usize)) {
+        if self.editor_content.is_empty() {
+            self.editor_content.push(String::new());
+        }
+        let line = line.min(self.editor_content.len() - 1);
+        let col = col.min(self.editor_content[line].len());
+        self.editor_cursor = (line, col);
+        self.ensure_cursor_visible();
     }
 
-    // Editor operations
     fn editor_insert_char(&mut self, c: char) {
+        self.record_undo();
         if self.editor_content.is_empty() {
             self.editor_content.push(String::new());
         }
@@ -2912,7 +7809,35 @@ impl App {
         }
     }
 
+    /// Insert (possibly multi-line) text at the cursor, leaving the cursor after it
+    fn editor_insert_text(&mut self, text: &str) {
+        self.record_undo();
+        if self.editor_content.is_empty() {
+            self.editor_content.push(String::new());
+        }
+
+        let (line, col) = self.editor_cursor;
+        let line = line.min(self.editor_content.len() - 1);
+        let col = col.min(self.editor_content[line].len());
+        let after = self.editor_content[line].split_off(col);
+
+        let mut inserted = text.split('\n');
+        let first = inserted.next().unwrap_or_default();
+        self.editor_content[line].push_str(first);
+        let mut last_line = line;
+        for text_line in inserted {
+            last_line += 1;
+            self.editor_content.insert(last_line, text_line.to_string());
+        }
+
+        let end_col = self.editor_content[last_line].len();
+        self.editor_content[last_line].push_str(&after);
+        self.editor_cursor = (last_line, end_col);
+        self.ensure_cursor_visible();
+    }
+
     fn editor_insert_newline(&mut self) {
+        self.record_undo();
         let (line, col) = self.editor_cursor;
         if let Some(content) = self.editor_content.get_mut(line) {
             let new_line = content.split_off(col.min(content.len()));
@@ -2927,6 +7852,7 @@ impl App {
     }
 
     fn editor_backspace(&mut self) {
+        self.record_undo();
         let (line, col) = self.editor_cursor;
 
         if col > 0 {
@@ -2950,6 +7876,7 @@ impl App {
     }
 
     fn editor_delete(&mut self) {
+        self.record_undo();
         let (line, col) = self.editor_cursor;
 
         let content_len = self.editor_content.get(line).map(|c| c.len()).unwrap_or(0);
@@ -2996,9 +7923,80 @@ impl App {
         }
     }
 
+    /// Start line and request body lines of each entry in the editor, parsed once per change
+    pub fn editor_entries(&mut self) -> Vec<EntryLines> {
+        self.editor_entry_cache
+            .get_or_insert_with(|| {
+                crate::parser::parse_hurl_file(&self.editor_content.join("\n"))
+                    .map(|file| {
+                        file.entries
+                            .iter()
+                            .map(|entry| (entry.line_start, entry.body_lines))
+                            .collect()
+                    })
+                    .unwrap_or_default()
+            })
+            .clone()
+    }
+
+    /// Line ranges of the request bodies that are currently folded
+    pub fn folded_ranges(&mut self) -> Vec<(usize, usize)> {
+        self.editor_entries()
+            .into_iter()
+            .enumerate()
+            .filter(|(index, (line_start, _))| {
+                self.editor_folds.get(index) == self.editor_content.get(*line_start)
+            })
+            .filter_map(|(_, (_, body_lines))| body_lines)
+            .collect()
+    }
+
+    /// Fold or unfold the request body of the entry under the cursor
+    fn toggle_body_fold(&mut self) {
+        let line = self.editor_cursor.0;
+        let Ok(file) = crate::parser::parse_hurl_file(&self.editor_content.join("\n")) else {
+            return;
+        };
+        let Some(entry) = file.entry_at_line(line) else {
+            return;
+        };
+        let Some((start, _)) = file.entries[entry].body_lines else {
+            self.set_status("No request body to fold", StatusLevel::Warning);
+            return;
+        };
+
+        // Forget the folds of entries that were edited, moved or removed
+        let content = &self.editor_content;
+        self.editor_folds.retain(|index, request| {
+            file.entries
+                .get(*index)
+                .is_some_and(|e| content[e.line_start] == *request)
+        });
+
+        if self.editor_folds.remove(&entry).is_some() {
+            self.set_status("Body unfolded", StatusLevel::Info);
+        } else {
+            let request = self.editor_content[file.entries[entry].line_start].clone();
+            self.editor_folds.insert(entry, request);
+            // Keep the cursor on the summary line instead of a hidden one
+            if line > start {
+                self.editor_cursor = (start, 0);
+            }
+            self.set_status("Body folded", StatusLevel::Info);
+        }
+        self.ensure_cursor_visible();
+    }
+
     fn editor_move_cursor_up(&mut self) {
-        if self.editor_cursor.0 > 0 {
-            self.editor_cursor.0 -= 1;
+        // Step over folded bodies
+        let prev = self.editor_cursor.0.checked_sub(1).map(|prev| {
+            self.folded_ranges()
+                .into_iter()
+                .find(|&(start, end)| prev > start && prev <= end)
+                .map_or(prev, |(start, _)| start)
+        });
+        if let Some(prev) = prev {
+            self.editor_cursor.0 = prev;
             let line_len = self
                 .editor_content
                 .get(self.editor_cursor.0)
@@ -3009,8 +8007,14 @@ impl App {
     }
 
     fn editor_move_cursor_down(&mut self) {
-        if self.editor_cursor.0 + 1 < self.editor_content.len() {
-            self.editor_cursor.0 += 1;
+        // Step over folded bodies
+        let next = self
+            .folded_ranges()
+            .into_iter()
+            .find(|&(start, end)| self.editor_cursor.0 >= start && self.editor_cursor.0 < end)
+            .map_or(self.editor_cursor.0 + 1, |(_, end)| end + 1);
+        if next < self.editor_content.len() {
+            self.editor_cursor.0 = next;
             let line_len = self
                 .editor_content
                 .get(self.editor_cursor.0)
@@ -3021,11 +8025,13 @@ impl App {
     }
 
     /// Ensure the cursor is visible by adjusting editor_scroll.
-    /// Uses a default visible height of 20 lines (approximate terminal height minus borders).
     fn ensure_cursor_visible(&mut self) {
-        // Use a reasonable default for visible height
-        // This will be approximately correct for most terminal sizes
-        let visible_height = 20_usize;
+        // The subtitle and a focused entry's header take lines; nothing above a focused entry is shown
+        let visible_height = self
+            .editor_visible_height
+            .saturating_sub(self.editor_header_rows())
+            .max(1);
+        let min_scroll = self.focus_bounds().map_or(0, |(start, _)| start);
 
         // If cursor is above the visible area, scroll up
         if self.editor_cursor.0 < self.editor_scroll {
@@ -3036,6 +8042,7 @@ impl App {
         if self.editor_cursor.0 >= self.editor_scroll + visible_height {
             self.editor_scroll = self.editor_cursor.0 - visible_height + 1;
         }
+        self.editor_scroll = self.editor_scroll.max(min_scroll);
     }
 
     // Vim-style editor navigation methods
@@ -3067,7 +8074,7 @@ impl App {
             self.editor_cursor.0 = self.editor_content.len() - 1;
             self.editor_cursor.1 = 0;
             // Adjust scroll to show last line
-            let visible_height = 20; // approximate
+            let visible_height = self.editor_visible_height;
             if self.editor_cursor.0 >= visible_height {
                 self.editor_scroll = self.editor_cursor.0 - visible_height + 1;
             }
@@ -3163,6 +8170,7 @@ impl App {
     }
 
     fn editor_insert_line_below(&mut self) {
+        self.record_undo();
         let line = self.editor_cursor.0;
         self.editor_content.insert(line + 1, String::new());
         self.editor_cursor = (line + 1, 0);
@@ -3170,13 +8178,45 @@ impl App {
     }
 
     fn editor_insert_line_above(&mut self) {
+        self.record_undo();
         let line = self.editor_cursor.0;
         self.editor_content.insert(line, String::new());
         self.editor_cursor = (line, 0);
         self.ensure_cursor_visible();
     }
 
+    /// Yank the current line (vim `yy`)
+    fn editor_yank_line(&mut self) {
+        let Some(line) = self.editor_content.get(self.editor_cursor.0) else {
+            return;
+        };
+        self.editor_yank_buffer = vec![line.clone()];
+        self.set_status("1 line(s) yanked", StatusLevel::Info);
+    }
+
+    /// Put the yank buffer below (vim `p`) or above (vim `P`) the current line
+    fn editor_put_lines(&mut self, below: bool) {
+        if self.editor_yank_buffer.is_empty() {
+            self.set_status("Nothing to paste", StatusLevel::Warning);
+            return;
+        }
+        self.record_undo();
+
+        let line = self.editor_cursor.0;
+        let at = if below {
+            (line + 1).min(self.editor_content.len())
+        } else {
+            line
+        };
+        let yank = self.editor_yank_buffer.clone();
+        self.editor_content.splice(at..at, yank);
+        self.editor_cursor = (at, 0);
+        self.editor_move_to_first_non_whitespace();
+        self.ensure_cursor_visible();
+    }
+
     fn editor_delete_char(&mut self) {
+        self.record_undo();
         // Delete character under cursor (vim 'x')
         let (line, col) = self.editor_cursor;
         if let Some(content) = self.editor_content.get_mut(line) {
@@ -3191,10 +8231,11 @@ impl App {
     }
 
     fn editor_delete_line(&mut self) {
+        self.record_undo();
         // Delete entire line (vim 'dd')
         if !self.editor_content.is_empty() {
             let line = self.editor_cursor.0;
-            self.editor_content.remove(line);
+            self.editor_yank_buffer = vec![self.editor_content.remove(line)];
 
             // Ensure at least one empty line
             if self.editor_content.is_empty() {
@@ -3209,7 +8250,168 @@ impl App {
         }
     }
 
+    /// Zoom the editor to the entry under the cursor (`zf`)
+    fn focus_entry(&mut self) {
+        let line = self.editor_cursor.0;
+        let Some(index) = self
+            .current_file
+            .as_ref()
+            .and_then(|file| file.entry_at_line(line))
+        else {
+            self.set_status("Cursor is not inside an entry", StatusLevel::Warning);
+            return;
+        };
+        self.set_focus_entry(index);
+        self.mode = AppMode::FocusedEntry;
+    }
+
+    /// Focus the next or previous entry, wrapping around
+    fn cycle_focus_entry(&mut self, forward: bool) {
+        let (Some(current), Some(file)) = (self.editor_focus_entry, &self.current_file) else {
+            return;
+        };
+        let count = file.entries.len();
+        if count == 0 {
+            return;
+        }
+        let index = if forward {
+            (current + 1) % count
+        } else {
+            (current + count - 1) % count
+        };
+        self.set_focus_entry(index);
+    }
+
+    /// Focus an entry and move the cursor to its first line
+    fn set_focus_entry(&mut self, index: usize) {
+        let Some(file) = &self.current_file else {
+            return;
+        };
+        let count = file.entries.len();
+        let Some(start) = file.entries.get(index).map(|entry| entry.line_start) else {
+            return;
+        };
+        self.editor_focus_entry = Some(index);
+        self.selected_entry = index;
+        self.editor_cursor = (start, 0);
+        self.editor_scroll = start;
+        self.ensure_cursor_visible();
+        self.set_status(&format!("Entry {}/{}", index + 1, count), StatusLevel::Info);
+    }
+
+    /// Leave focus mode and show the whole file again
+    fn exit_focus_entry(&mut self) {
+        self.editor_focus_entry = None;
+        self.mode = AppMode::Editing;
+        self.ensure_cursor_visible();
+    }
+
+    /// First and last line of the focused entry
+    pub fn focus_bounds(&self) -> Option<(usize, usize)> {
+        let entry = self
+            .current_file
+            .as_ref()?
+            .entries
+            .get(self.editor_focus_entry?)?;
+        let last = self.editor_content.len().checked_sub(1)?;
+        Some((entry.line_start.min(last), entry.line_end.min(last)))
+    }
+
+    /// The entry whose lines contain the editor cursor
+    pub fn entry_under_cursor(&self) -> Option<&HurlEntry> {
+        let file = self.current_file.as_ref()?;
+        file.entries.get(file.entry_at_line(self.editor_cursor.0)?)
+    }
+
+    /// Rows above the editor text: the request subtitle and the focused entry's header
+    pub fn editor_header_rows(&self) -> usize {
+        let subtitle = self.current_file.is_some() && !self.is_env_file_open();
+        usize::from(subtitle) + usize::from(self.editor_focus_entry.is_some())
+    }
+
+    /// Comment out the current line (or the visual selection), or uncomment it if it already is
+    ///
+    /// Lines of a selection are all commented unless every non-blank one already is.
+    pub fn toggle_comment(&mut self) {
+        let last = self.editor_content.len().saturating_sub(1);
+        let (start, end) = self
+            .visual_selection()
+            .unwrap_or((self.editor_cursor.0, self.editor_cursor.0));
+        let end = end.min(last);
+        if self.editor_content.is_empty() || start > end {
+            return;
+        }
+
+        let uncomment = self.editor_content[start..=end]
+            .iter()
+            .filter(|line| !line.trim().is_empty())
+            .all(|line| line.trim_start().starts_with('#'));
+
+        self.record_undo();
+        for line in &mut self.editor_content[start..=end] {
+            let indent = line.len() - line.trim_start().len();
+            if uncomment {
+                if line[indent..].starts_with('#') {
+                    let rest = &line[indent + 1..];
+                    let rest = rest.strip_prefix(' ').unwrap_or(rest);
+                    *line = format!("{}{}", &line[..indent], rest);
+                }
+            } else if !line.trim().is_empty() || start == end {
+                line.insert_str(indent, "# ");
+            }
+        }
+
+        let line_len = self.editor_content[self.editor_cursor.0].chars().count();
+        self.editor_cursor.1 = self.editor_cursor.1.min(line_len);
+    }
+
+    /// Duplicate the entry under the cursor, inserting the copy after it
+    pub fn duplicate_hurl_entry(&mut self) {
+        // Line ranges are only current right after a parse
+        self.reparse_editor_content();
+        let line = self.editor_cursor.0;
+        let Some(file) = self.current_file.as_ref() else {
+            self.set_status("No entries to duplicate", StatusLevel::Warning);
+            return;
+        };
+        let Some(index) = file.entry_at_line(line) else {
+            self.set_status("Cursor is not inside an entry", StatusLevel::Warning);
+            return;
+        };
+
+        // The entry range includes trailing blank lines; copy only its content
+        let entry = &file.entries[index];
+        let line_end = entry
+            .line_end
+            .min(self.editor_content.len().saturating_sub(1));
+        let content_end = (entry.line_start..=line_end)
+            .rev()
+            .find(|&i| !self.editor_content[i].trim().is_empty())
+            .unwrap_or(entry.line_start);
+        let copied = self.editor_content[entry.line_start..=content_end].to_vec();
+
+        // Keep a blank line on both sides of the duplicate
+        let insert_at = line_end + 1;
+        let mut block = Vec::new();
+        if !self.editor_content[line_end].trim().is_empty() {
+            block.push(String::new());
+        }
+        let new_start = insert_at + block.len();
+        block.extend(copied);
+        if insert_at < self.editor_content.len() {
+            block.push(String::new());
+        }
+
+        self.record_undo();
+        self.editor_content.splice(insert_at..insert_at, block);
+        self.reparse_editor_content();
+        self.editor_cursor = (new_start, 0);
+        self.ensure_cursor_visible();
+        self.set_status("Entry duplicated", StatusLevel::Success);
+    }
+
     fn editor_delete_to_end(&mut self) {
+        self.record_undo();
         // Delete from cursor to end of line (vim 'D')
         let (line, col) = self.editor_cursor;
         if let Some(content) = self.editor_content.get_mut(line) {
@@ -3222,7 +8424,7 @@ impl App {
     }
 
     fn editor_page_up(&mut self) {
-        let page_size = 20; // Could be calculated from visible area
+        let page_size = self.editor_visible_height;
         self.editor_cursor.0 = self.editor_cursor.0.saturating_sub(page_size);
         self.editor_scroll = self.editor_scroll.saturating_sub(page_size);
         // Adjust column to line length
@@ -3234,7 +8436,7 @@ impl App {
     }
 
     fn editor_page_down(&mut self) {
-        let page_size = 20; // Could be calculated from visible area
+        let page_size = self.editor_visible_height;
         let max_line = self.editor_content.len().saturating_sub(1);
         self.editor_cursor.0 = (self.editor_cursor.0 + page_size).min(max_line);
         self.editor_scroll = (self.editor_scroll + page_size).min(max_line);
@@ -3251,23 +8453,23 @@ impl App {
         fn collect_visible<'a>(
             entries: &'a [FileEntry],
             result: &mut Vec<&'a FileEntry>,
-            filter: &str,
+            filter: Option<&dyn Fn(&str) -> bool>,
         ) {
-            let filter_lower = filter.to_lowercase();
             for entry in entries {
                 // Check if this entry matches the filter
-                let matches_filter =
-                    filter.is_empty() || entry.name.to_lowercase().contains(&filter_lower);
+                let matches_filter = filter.map_or(true, |matches| matches(&entry.name));
 
                 // For directories, also check if any children match
                 let has_matching_children = entry.is_dir
                     && entry.is_expanded
-                    && App::has_matching_descendants(&entry.children, &filter_lower);
+                    && filter.is_some_and(|matches| {
+                        App::has_matching_descendants(&entry.children, matches)
+                    });
 
                 if matches_filter || has_matching_children || entry.is_dir {
                     // Include directories to maintain tree structure, but only if
                     // they match or have matching descendants (when filter is active)
-                    if filter.is_empty() || matches_filter || has_matching_children {
+                    if filter.is_none() || matches_filter || has_matching_children {
                         result.push(entry);
                     }
                 }
@@ -3278,21 +8480,983 @@ impl App {
             }
         }
 
+        // Case-insensitive; space-separated words must all appear in the name (`auth login`)
+        let tokens: Vec<String> = self
+            .filter_query
+            .split_whitespace()
+            .map(str::to_lowercase)
+            .collect();
+        let contains = |name: &str| {
+            let name = name.to_lowercase();
+            tokens.iter().all(|token| name.contains(token.as_str()))
+        };
+        let regex_match = |name: &str| self.filter_regex.as_ref().is_some_and(|r| r.is_match(name));
+        let filter: Option<&dyn Fn(&str) -> bool> = if self.filter_is_regex {
+            self.filter_regex.as_ref().map(|_| &regex_match as _)
+        } else {
+            (!tokens.is_empty()).then_some(&contains as _)
+        };
+
         let mut result = Vec::new();
-        collect_visible(&self.file_tree, &mut result, &self.filter_query);
+        collect_visible(&self.file_tree, &mut result, filter);
         result
     }
 
     /// Check if any descendants match the filter
-    fn has_matching_descendants(entries: &[FileEntry], filter_lower: &str) -> bool {
+    fn has_matching_descendants(entries: &[FileEntry], matches: &dyn Fn(&str) -> bool) -> bool {
         for entry in entries {
-            if entry.name.to_lowercase().contains(filter_lower) {
+            if matches(&entry.name) {
                 return true;
             }
-            if entry.is_expanded && Self::has_matching_descendants(&entry.children, filter_lower) {
+            if entry.is_expanded && Self::has_matching_descendants(&entry.children, matches) {
                 return true;
             }
         }
         false
     }
 }
+
+/// Escape text for use in XML attributes and elements
+fn xml_escape(text: &str) -> String {
+    let mut escaped = String::with_capacity(text.len());
+    for c in text.chars() {
+        match c {
+            '&' => escaped.push_str("&amp;"),
+            '<' => escaped.push_str("&lt;"),
+            '>' => escaped.push_str("&gt;"),
+            '"' => escaped.push_str("&quot;"),
+            '\'' => escaped.push_str("&apos;"),
+            // Control characters (e.g. ANSI escapes in hurl output) are not allowed in XML
+            c if c.is_control() && !matches!(c, '\n' | '\r' | '\t') => {}
+            c => escaped.push(c),
+        }
+    }
+    escaped
+}
+
+/// Name/value pairs as HAR `{ "name", "value" }` objects
+fn har_pairs(pairs: &[(String, String)]) -> Vec<serde_json::Value> {
+    pairs
+        .iter()
+        .map(|(name, value)| serde_json::json!({ "name": name, "value": value }))
+        .collect()
+}
+
+/// Value of the first header with the given name (case-insensitive), empty when missing
+fn header_value(headers: &[(String, String)], name: &str) -> String {
+    headers
+        .iter()
+        .find(|(n, _)| n.eq_ignore_ascii_case(name))
+        .map(|(_, v)| v.clone())
+        .unwrap_or_default()
+}
+
+/// Whether an environment variable name looks like it holds a secret
+pub fn is_secret_key(key: &str) -> bool {
+    let key = key.to_lowercase();
+    key.contains("secret") || key.contains("password") || key.contains("token")
+}
+
+/// Rewrite .env file content with the given variables.
+/// Comments and blank lines are kept, removed variables are dropped and new ones appended.
+fn update_env_content(content: &str, vars: &[(String, String)]) -> String {
+    let mut written = vec![false; vars.len()];
+    let mut lines: Vec<String> = Vec::new();
+
+    for line in content.lines() {
+        let trimmed = line.trim();
+        let variable = trimmed
+            .split_once('=')
+            .filter(|_| !trimmed.starts_with('#'));
+        let Some((key, _)) = variable else {
+            lines.push(line.to_string());
+            continue;
+        };
+        if let Some(index) = vars.iter().position(|(name, _)| name == key.trim()) {
+            if !written[index] {
+                written[index] = true;
+                lines.push(format!("{}={}", vars[index].0, vars[index].1));
+            }
+        }
+    }
+
+    for (index, (name, value)) in vars.iter().enumerate() {
+        if !written[index] {
+            lines.push(format!("{}={}", name, value));
+        }
+    }
+
+    let mut content = lines.join("\n");
+    content.push('\n');
+    content
+}
+
+/// Variable names completing the `{{` left open at the end of `input` (the text before the cursor).
+/// Only offered on request lines (`METHOD url`); names are sorted and deduplicated.
+pub fn url_completion_candidates(input: &str, variables: &[Variable]) -> Vec<String> {
+    let first_word = input.split_whitespace().next().unwrap_or("");
+    if !crate::parser::METHODS.contains(&first_word) {
+        return Vec::new();
+    }
+    let Some(start) = input.rfind("{{") else {
+        return Vec::new();
+    };
+    let prefix = input[start + 2..].trim_start();
+    if prefix.contains("}}") || prefix.contains(char::is_whitespace) {
+        return Vec::new();
+    }
+
+    let mut names: Vec<String> = variables
+        .iter()
+        .map(|var| var.name.clone())
+        .filter(|name| name.starts_with(prefix) && name != prefix)
+        .collect();
+    names.sort();
+    names.dedup();
+    names
+}
+
+/// Position of the bracket matching the one at `line`/`col` (byte column), if any.
+/// Openers are matched by scanning forward, closers by scanning backward.
+pub fn find_matching_bracket(lines: &[String], line: usize, col: usize) -> Option<(usize, usize)> {
+    let bracket = *lines.get(line)?.as_bytes().get(col)?;
+    let (open, close, forward) = match bracket {
+        b'{' => (b'{', b'}', true),
+        b'[' => (b'[', b']', true),
+        b'(' => (b'(', b')', true),
+        b'}' => (b'{', b'}', false),
+        b']' => (b'[', b']', false),
+        b')' => (b'(', b')', false),
+        _ => return None,
+    };
+
+    let mut depth = 0usize;
+    let mut visit = |l: usize, c: usize, byte: u8| -> Option<(usize, usize)> {
+        if byte == open || byte == close {
+            let towards = if forward { byte == close } else { byte == open };
+            if !towards {
+                depth += 1;
+            } else if depth == 1 {
+                return Some((l, c));
+            } else {
+                depth -= 1;
+            }
+        }
+        None
+    };
+
+    if forward {
+        for (l, text) in lines.iter().enumerate().skip(line) {
+            let start = if l == line { col } else { 0 };
+            for (c, &byte) in text.as_bytes().iter().enumerate().skip(start) {
+                if let Some(found) = visit(l, c, byte) {
+                    return Some(found);
+                }
+            }
+        }
+    } else {
+        for l in (0..=line).rev() {
+            let bytes = lines[l].as_bytes();
+            let end = if l == line { col + 1 } else { bytes.len() };
+            for c in (0..end).rev() {
+                if let Some(found) = visit(l, c, bytes[c]) {
+                    return Some(found);
+                }
+            }
+        }
+    }
+    None
+}
+
+/// Name of the `{{variable}}` whose braces enclose byte column `col` of `content`
+pub fn find_variable_at_cursor(content: &str, col: usize) -> Option<String> {
+    let mut offset = 0;
+    while let Some(start) = content[offset..].find("{{").map(|i| offset + i) {
+        let end = content[start + 2..].find("}}").map(|i| start + 2 + i + 2)?;
+        if (start..end).contains(&col) {
+            let name = content[start + 2..end - 2].trim();
+            return (!name.is_empty()).then(|| name.to_string());
+        }
+        offset = end;
+    }
+    None
+}
+
+/// Every .hurl file under `dir` (skipping hidden and ignored directories), in path order
+pub fn collect_hurl_files(dir: &Path, ignore: &IgnorePatterns) -> Vec<PathBuf> {
+    fn collect_files(dir: &Path, ignore: &IgnorePatterns, files: &mut Vec<PathBuf>) {
+        let Ok(read_dir) = std::fs::read_dir(dir) else {
+            return;
+        };
+        for entry in read_dir.flatten() {
+            let path = entry.path();
+            if path.is_dir() {
+                if !App::should_skip_directory(&path, ignore) {
+                    collect_files(&path, ignore, files);
+                }
+            } else if path.extension().is_some_and(|e| e == "hurl") {
+                files.push(path);
+            }
+        }
+    }
+
+    let mut files = Vec::new();
+    collect_files(dir, ignore, &mut files);
+    files.sort();
+    files
+}
+
+/// Search `query` in every .hurl file under `dir`, in path order.
+/// `open_file` is searched in the given content instead of its content on disk.
+fn search_hurl_files(
+    dir: &Path,
+    ignore: &IgnorePatterns,
+    query: &str,
+    open_file: Option<(PathBuf, Vec<String>)>,
+) -> Vec<GlobalSearchResult> {
+    let mut results = Vec::new();
+    for path in collect_hurl_files(dir, ignore) {
+        let lines = match &open_file {
+            Some((open_path, content)) if *open_path == path => content.clone(),
+            _ => match std::fs::read_to_string(&path) {
+                Ok(content) => content.lines().map(String::from).collect(),
+                Err(_) => continue,
+            },
+        };
+
+        for (line, text) in lines.iter().enumerate() {
+            if let Some(col) = text.find(query) {
+                results.push(GlobalSearchResult {
+                    path: path.clone(),
+                    line,
+                    col,
+                    text: text.trim().to_string(),
+                });
+                if results.len() >= GLOBAL_SEARCH_LIMIT {
+                    return results;
+                }
+            }
+        }
+    }
+
+    results
+}
+
+/// Response body of a run result, if it got a response
+fn response_body(result: &Option<ExecutionResult>) -> Option<&str> {
+    result
+        .as_ref()
+        .and_then(|r| r.response.as_ref())
+        .map(|r| r.body.as_str())
+}
+
+/// Whether a command line may be written to the state file.
+/// `:setvar` values and `:export-env --include-secrets` stay in this session's history only.
+fn is_persisted_command(cmd: &str) -> bool {
+    let mut words = cmd.split_whitespace();
+    match words.next().map(str::to_lowercase).as_deref() {
+        Some("setvar") => words.next().is_none(),
+        Some("export-env" | "export-env-json") => !words.any(|w| w == "--include-secrets"),
+        _ => true,
+    }
+}
+
+/// Whether all characters of `query` appear in `text` in order (case-insensitive)
+fn fuzzy_match(text: &str, query: &str) -> bool {
+    let mut chars = text.chars().flat_map(char::to_lowercase);
+    query
+        .chars()
+        .flat_map(char::to_lowercase)
+        .all(|q| chars.any(|c| c == q))
+}
+
+/// Parse a go-to target, `N`, `N:M` or `NcolM`, into (line, column), both 1-based.
+/// Used by the go-to-line popup and the `:N` command.
+fn parse_line_col(input: &str) -> Option<(usize, Option<usize>)> {
+    let input = input.trim();
+    let (line, col) = match input.split_once(':').or_else(|| input.split_once("col")) {
+        Some((line, col)) => (line, Some(col)),
+        None => (input, None),
+    };
+    let number = |s: &str| {
+        s.chars()
+            .all(|c| c.is_ascii_digit())
+            .then(|| s.parse().ok())
+            .flatten()
+    };
+    let col = match col {
+        Some(col) => Some(number(col)?),
+        None => None,
+    };
+    Some((number(line)?, col))
+}
+
+/// Parse a `s/pattern/replacement/[g]` command into (pattern, replacement, global).
+/// A `/` inside the pattern or replacement can be escaped as `\/`.
+fn parse_substitute_command(cmd: &str) -> Option<(String, String, bool)> {
+    let rest = cmd.strip_prefix("s/")?;
+
+    let mut parts = vec![String::new()];
+    let mut chars = rest.chars().peekable();
+    while let Some(c) = chars.next() {
+        match c {
+            '\\' if chars.peek() == Some(&'/') => {
+                parts.last_mut()?.push('/');
+                chars.next();
+            }
+            '/' => parts.push(String::new()),
+            _ => parts.last_mut()?.push(c),
+        }
+    }
+
+    match parts.as_slice() {
+        [pattern, replacement] => Some((pattern.clone(), replacement.clone(), false)),
+        [pattern, replacement, flags] if flags.is_empty() || flags == "g" => {
+            Some((pattern.clone(), replacement.clone(), flags == "g"))
+        }
+        _ => None,
+    }
+    .filter(|(pattern, _, _)| !pattern.is_empty())
+}
+
+/// New location of `path` after `source` was moved to `target`, if it is `source` or inside it
+fn moved_path(path: &Path, source: &Path, target: &Path) -> Option<PathBuf> {
+    let relative = path.strip_prefix(source).ok()?;
+    if relative.as_os_str().is_empty() {
+        Some(target.to_path_buf())
+    } else {
+        Some(target.join(relative))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn test_app(dir: &Path) -> App {
+        App::new(Config::default(), dir.to_path_buf(), None).unwrap()
+    }
+
+    #[test]
+    fn test_read_ignore_file() {
+        let dir = tempfile::tempdir().unwrap();
+        assert!(read_ignore_file(dir.path()).is_empty());
+
+        std::fs::write(
+            dir.path().join(IGNORE_FILE),
+            "# generated\nfixtures/\n\n  legacy-*  # old suites\ntmp\n",
+        )
+        .unwrap();
+        assert_eq!(
+            read_ignore_file(dir.path()),
+            vec!["fixtures", "legacy-*", "tmp"]
+        );
+    }
+
+    #[test]
+    fn test_should_skip_directory() {
+        let ignore = IgnorePatterns::new(&["legacy-*".to_string(), "[".to_string()]);
+        let skips = |name: &str| {
+            App::should_skip_directory(Path::new("/project").join(name).as_path(), &ignore)
+        };
+
+        assert!(skips(".git"));
+        assert!(skips("node_modules"));
+        assert!(skips("Node_Modules"));
+        assert!(skips("legacy-v1"));
+        assert!(skips("LEGACY-v2"));
+        assert!(!skips("legacy"));
+        assert!(!skips("api"));
+
+        // Without an ignore file only the built-in names are skipped
+        let default = IgnorePatterns::default();
+        assert!(App::should_skip_directory(Path::new("target"), &default));
+        assert!(!App::should_skip_directory(
+            Path::new("legacy-v1"),
+            &default
+        ));
+    }
+
+    #[test]
+    fn test_search_hurl_files() {
+        let dir = tempfile::tempdir().unwrap();
+        let api = dir.path().join("api.hurl");
+        std::fs::write(&api, "GET {{host}}/users\nHTTP 200\n").unwrap();
+        std::fs::create_dir(dir.path().join("node_modules")).unwrap();
+        std::fs::write(dir.path().join("node_modules/dep.hurl"), "GET /users\n").unwrap();
+        std::fs::write(dir.path().join("notes.txt"), "GET /users\n").unwrap();
+
+        let ignore = IgnorePatterns::default();
+        let results = search_hurl_files(dir.path(), &ignore, "/users", None);
+        assert_eq!(results.len(), 1);
+        assert_eq!((results[0].line, results[0].col), (0, 12));
+        assert_eq!(results[0].text, "GET {{host}}/users");
+
+        // Matching is case-sensitive
+        assert!(search_hurl_files(dir.path(), &ignore, "/USERS", None).is_empty());
+
+        // The open file is searched in its unsaved content
+        let open_file = Some((api, vec!["POST /users/1".to_string()]));
+        let results = search_hurl_files(dir.path(), &ignore, "/users", open_file);
+        assert_eq!((results[0].line, results[0].col), (0, 5));
+    }
+
+    #[test]
+    fn test_fold_nested_body() {
+        let dir = tempfile::tempdir().unwrap();
+        let mut app = test_app(dir.path());
+        app.editor_content = "POST https://api.example.com/users\n{\n  \"address\": {\n    \"city\": \"Paris\"\n  }\n}\n\nPOST https://api.example.com/orders\n{\n  \"id\": 1\n}"
+            .lines()
+            .map(String::from)
+            .collect();
+
+        app.editor_cursor = (2, 0);
+        app.toggle_body_fold();
+        assert_eq!(app.folded_ranges(), vec![(1, 5)]);
+        assert_eq!(app.editor_cursor, (1, 0));
+
+        // Removing the first entry must not fold the body that moves into its place
+        app.record_undo();
+        app.editor_content.drain(0..7);
+        assert!(app.folded_ranges().is_empty());
+        app.editor_cursor = (0, 0);
+        app.toggle_body_fold();
+        assert_eq!(app.folded_ranges(), vec![(1, 3)]);
+        assert_eq!(app.editor_folds.len(), 1);
+    }
+
+    #[test]
+    fn test_load_directory_children() {
+        let dir = tempfile::tempdir().unwrap();
+        let root = dir.path();
+        for sub in ["users/admin", "empty", "node_modules", "Orders"] {
+            std::fs::create_dir_all(root.join(sub)).unwrap();
+        }
+        for file in [
+            "users/admin/list.hurl",
+            "node_modules/dep.hurl",
+            "Orders/create.hurl",
+            "b.hurl",
+            "a.env",
+            "notes.txt",
+        ] {
+            std::fs::write(root.join(file), "").unwrap();
+        }
+
+        let ignore = IgnorePatterns::default();
+        let entries = App::load_directory_children(&root.to_path_buf(), 1, &ignore).unwrap();
+        let names: Vec<&str> = entries.iter().map(|e| e.name.as_str()).collect();
+        assert_eq!(names, vec!["Orders", "users", "a.env", "b.hurl"]);
+        assert!(entries.iter().all(|e| e.depth == 1));
+
+        let ignore = IgnorePatterns::new(&["users".to_string()]);
+        let entries = App::load_directory_children(&root.to_path_buf(), 0, &ignore).unwrap();
+        assert_eq!(entries.len(), 3);
+    }
+
+    #[test]
+    fn test_migrate_state() {
+        // Written before versioning
+        let state = migrate_state(serde_json::json!({
+            "last_opened_file": "api.hurl",
+            "sidebar_width": 30,
+        }));
+        assert_eq!(state.schema_version, STATE_SCHEMA_VERSION);
+        assert_eq!(state.last_opened_file.as_deref(), Some("api.hurl"));
+        assert_eq!(state.sidebar_width, 30);
+        assert!(state.show_editor);
+
+        // An unreadable field is dropped, the others are kept
+        let state = migrate_state(serde_json::json!({
+            "schema_version": 1,
+            "sidebar_width": "wide",
+            "open_tabs": ["a.hurl"],
+        }));
+        assert_eq!(state.sidebar_width, default_sidebar_width());
+        assert_eq!(state.open_tabs, vec!["a.hurl"]);
+
+        let state = migrate_state(serde_json::json!(["a.hurl"]));
+        assert_eq!(state.schema_version, 0);
+        assert!(state.open_tabs.is_empty());
+
+        // A newer layout keeps its version
+        let state = migrate_state(serde_json::json!({
+            "schema_version": STATE_SCHEMA_VERSION + 1,
+            "open_tabs": ["b.hurl"],
+        }));
+        assert_eq!(state.schema_version, STATE_SCHEMA_VERSION + 1);
+        assert_eq!(state.open_tabs, vec!["b.hurl"]);
+    }
+
+    #[test]
+    fn test_parse_line_col() {
+        assert_eq!(parse_line_col("42"), Some((42, None)));
+        assert_eq!(parse_line_col(" 42:5 "), Some((42, Some(5))));
+        assert_eq!(parse_line_col("42col5"), Some((42, Some(5))));
+        assert_eq!(parse_line_col("42:"), None);
+        assert_eq!(parse_line_col("col5"), None);
+        assert_eq!(parse_line_col("+4"), None);
+        assert_eq!(parse_line_col("4:-1"), None);
+        assert_eq!(parse_line_col("w"), None);
+        assert_eq!(parse_line_col(""), None);
+    }
+
+    #[test]
+    fn test_url_completion_candidates() {
+        let variables: Vec<Variable> = ["host", "host_v2", "token", "host"]
+            .into_iter()
+            .map(|name| Variable {
+                name: name.to_string(),
+                value: String::new(),
+                is_secret: false,
+            })
+            .collect();
+        let candidates = |input: &str| url_completion_candidates(input, &variables);
+
+        assert_eq!(candidates("GET {{"), vec!["host", "host_v2", "token"]);
+        assert_eq!(candidates("TRACE https://{{ ho"), vec!["host", "host_v2"]);
+        // A complete name still offers the longer ones
+        assert_eq!(candidates("POST {{host"), vec!["host_v2"]);
+        assert!(candidates("GET {{host}}/").is_empty());
+        assert!(candidates("GET {{host }}").is_empty());
+        assert!(candidates("GET /users").is_empty());
+        assert!(candidates("Authorization: {{").is_empty());
+    }
+
+    #[test]
+    fn test_file_section_rows() {
+        assert_eq!(file_section_rows(0), 0);
+        assert_eq!(file_section_rows(3), 4);
+        assert_eq!(
+            file_section_rows(FILE_SECTION_MAX_ENTRIES + 4),
+            FILE_SECTION_MAX_ENTRIES as u16 + 1
+        );
+    }
+
+    #[test]
+    fn test_execute_new_file() {
+        let dir = tempfile::tempdir().unwrap();
+        let mut app = test_app(dir.path());
+
+        app.new_file_dir = Some(dir.path().to_path_buf());
+        app.rename_input = "users".to_string();
+        app.execute_new_file();
+        let path = dir.path().join("users.hurl");
+        assert_eq!(
+            std::fs::read_to_string(&path).unwrap(),
+            "GET https://example.com\n\nHTTP 200\n"
+        );
+
+        // An existing file is left alone
+        std::fs::write(&path, "POST https://example.com/users\n").unwrap();
+        app.new_file_dir = Some(dir.path().to_path_buf());
+        app.rename_input = "users.hurl".to_string();
+        app.execute_new_file();
+        assert_eq!(
+            std::fs::read_to_string(&path).unwrap(),
+            "POST https://example.com/users\n"
+        );
+        assert_eq!(app.status_message.as_ref().unwrap().1, StatusLevel::Error);
+    }
+
+    #[test]
+    fn test_unknown_env_argument() {
+        let dir = tempfile::tempdir().unwrap();
+        let app = App::new(
+            Config::default(),
+            dir.path().to_path_buf(),
+            Some("nope".to_string()),
+        )
+        .unwrap();
+
+        let (message, level) = app.status_message.unwrap();
+        assert_eq!(
+            message,
+            "Environment 'nope' not found, using the first available one"
+        );
+        assert_eq!(level, StatusLevel::Warning);
+    }
+
+    #[test]
+    fn test_export_env_command() {
+        let dir = tempfile::tempdir().unwrap();
+        let mut app = test_app(dir.path());
+        app.variables = vec![
+            Variable {
+                name: "HOST".to_string(),
+                value: "localhost".to_string(),
+                is_secret: false,
+            },
+            Variable {
+                name: "TOKEN".to_string(),
+                value: "abc".to_string(),
+                is_secret: true,
+            },
+        ];
+
+        app.export_env_command("out.env", false);
+        assert_eq!(
+            std::fs::read_to_string(dir.path().join("out.env")).unwrap(),
+            "HOST=localhost\nTOKEN=<REDACTED>\n"
+        );
+
+        // Replacing a file is reported
+        app.export_env_command("--include-secrets out.env", false);
+        assert!(app
+            .status_message
+            .as_ref()
+            .unwrap()
+            .0
+            .ends_with("(replaced)"));
+        assert_eq!(
+            std::fs::read_to_string(dir.path().join("out.env")).unwrap(),
+            "HOST=localhost\nTOKEN=abc\n"
+        );
+
+        // A failed write names the file
+        app.export_env_command("missing/out.json", true);
+        let (message, level) = app.status_message.clone().unwrap();
+        assert_eq!(level, StatusLevel::Error);
+        assert!(message.starts_with(&format!(
+            "Export failed: Failed to write {}",
+            dir.path().join("missing/out.json").display()
+        )));
+    }
+
+    #[test]
+    fn test_moved_path() {
+        let (source, target) = (Path::new("/work/api"), Path::new("/work/v2"));
+        assert_eq!(
+            moved_path(source, source, target),
+            Some(target.to_path_buf())
+        );
+        assert_eq!(
+            moved_path(Path::new("/work/api/auth/login.hurl"), source, target),
+            Some(PathBuf::from("/work/v2/auth/login.hurl"))
+        );
+        // Only whole components match
+        assert_eq!(
+            moved_path(Path::new("/work/api2/users.hurl"), source, target),
+            None
+        );
+        assert_eq!(
+            moved_path(Path::new("/work/users.hurl"), source, target),
+            None
+        );
+    }
+
+    #[test]
+    fn test_update_moved_file_paths() {
+        let dir = tempfile::tempdir().unwrap();
+        let mut app = test_app(dir.path());
+        let result = ExecutionResult {
+            success: true,
+            request: None,
+            response: None,
+            assertions: Vec::new(),
+            captures: Vec::new(),
+            stdout: String::new(),
+            stderr: String::new(),
+            exit_code: 0,
+            attempts: 1,
+        };
+
+        let login = dir.path().join("api/login.hurl");
+        let other = dir.path().join("api2/login.hurl");
+        for path in [&login, &other] {
+            app.file_method_cache
+                .insert(path.clone(), "POST".to_string());
+            app.file_execution_states
+                .insert(app.get_relative_path(path), result.clone());
+        }
+
+        app.update_moved_file_paths(&dir.path().join("api"), &dir.path().join("v2"));
+
+        let moved = dir.path().join("v2/login.hurl");
+        assert_eq!(
+            app.file_method_cache.keys().collect::<HashSet<_>>(),
+            HashSet::from([&moved, &other])
+        );
+        assert_eq!(
+            app.file_execution_states.keys().collect::<HashSet<_>>(),
+            HashSet::from([
+                &app.get_relative_path(&moved),
+                &app.get_relative_path(&other)
+            ])
+        );
+    }
+
+    #[test]
+    fn test_parse_substitute_command() {
+        assert_eq!(
+            parse_substitute_command("s/foo/bar"),
+            Some(("foo".to_string(), "bar".to_string(), false))
+        );
+        assert_eq!(
+            parse_substitute_command("s/foo/bar/g"),
+            Some(("foo".to_string(), "bar".to_string(), true))
+        );
+        assert_eq!(
+            parse_substitute_command("s/foo//"),
+            Some(("foo".to_string(), String::new(), false))
+        );
+        assert_eq!(
+            parse_substitute_command(r"s/api\/v1/api\/v2/g"),
+            Some(("api/v1".to_string(), "api/v2".to_string(), true))
+        );
+        assert_eq!(parse_substitute_command("s//bar/"), None);
+        assert_eq!(parse_substitute_command("s/foo/bar/x"), None);
+        assert_eq!(parse_substitute_command("s/foo"), None);
+        assert_eq!(parse_substitute_command("set foo"), None);
+    }
+
+    #[test]
+    fn test_find_variable_at_cursor() {
+        let line = "GET {{host}}/users/{{ id }}";
+        assert_eq!(find_variable_at_cursor(line, 4), Some("host".to_string()));
+        assert_eq!(find_variable_at_cursor(line, 11), Some("host".to_string()));
+        assert_eq!(find_variable_at_cursor(line, 12), None);
+        assert_eq!(find_variable_at_cursor(line, 22), Some("id".to_string()));
+        assert_eq!(find_variable_at_cursor("{{}} {{host", 1), None);
+        assert_eq!(find_variable_at_cursor("{{}} {{host", 7), None);
+    }
+
+    #[test]
+    fn test_find_matching_bracket() {
+        let lines: Vec<String> = ["{", "  \"ids\": [1, (2)],", "}"]
+            .iter()
+            .map(|s| s.to_string())
+            .collect();
+        assert_eq!(find_matching_bracket(&lines, 0, 0), Some((2, 0)));
+        assert_eq!(find_matching_bracket(&lines, 2, 0), Some((0, 0)));
+        assert_eq!(find_matching_bracket(&lines, 1, 9), Some((1, 16)));
+        assert_eq!(find_matching_bracket(&lines, 1, 15), Some((1, 13)));
+        assert_eq!(find_matching_bracket(&lines, 1, 2), None);
+
+        let unbalanced = vec!["[1, 2".to_string()];
+        assert_eq!(find_matching_bracket(&unbalanced, 0, 0), None);
+    }
+
+    #[test]
+    fn test_xml_escape() {
+        assert_eq!(
+            xml_escape(r#"<a href="x">Tom & 'Jerry'</a>"#),
+            "&lt;a href=&quot;x&quot;&gt;Tom &amp; &apos;Jerry&apos;&lt;/a&gt;"
+        );
+        // ANSI escapes are dropped, line breaks and tabs kept
+        assert_eq!(
+            xml_escape("\u{1b}[31merror\u{1b}[0m\n\tat"),
+            "[31merror[0m\n\tat"
+        );
+    }
+
+    #[test]
+    fn test_update_env_content() {
+        let content = "# API\nHOST=old\n\nTOKEN=abc\nHOST=dup\nREMOVED=1\n";
+        let vars = vec![
+            ("HOST".to_string(), "new".to_string()),
+            ("TOKEN".to_string(), "abc".to_string()),
+            ("ADDED".to_string(), "2".to_string()),
+        ];
+        assert_eq!(
+            update_env_content(content, &vars),
+            "# API\nHOST=new\n\nTOKEN=abc\nADDED=2\n"
+        );
+        assert_eq!(update_env_content("", &[]), "\n");
+    }
+
+    #[test]
+    fn test_har_pairs() {
+        let headers = vec![
+            ("Content-Type".to_string(), "application/json".to_string()),
+            ("X-Id".to_string(), "1".to_string()),
+        ];
+        assert_eq!(
+            har_pairs(&headers),
+            vec![
+                serde_json::json!({ "name": "Content-Type", "value": "application/json" }),
+                serde_json::json!({ "name": "X-Id", "value": "1" }),
+            ]
+        );
+        assert_eq!(header_value(&headers, "content-type"), "application/json");
+        assert_eq!(header_value(&headers, "Accept"), "");
+    }
+
+    #[test]
+    fn test_auto_save() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("users.hurl");
+        std::fs::write(&path, "GET https://example.com\n").unwrap();
+        let mut app = test_app(dir.path());
+        app.config.editor.auto_save_interval_secs = 1;
+        app.current_file_path = Some(path.clone());
+        app.editor_content = vec!["GET https://example.com/users".to_string()];
+
+        // Nothing to save until there is an unsaved edit
+        app.auto_save();
+        assert!(app.last_auto_save.is_none());
+
+        // The interval starts with the first auto-save check after an edit
+        app.editor_dirty = true;
+        app.auto_save();
+        assert!(app.last_auto_save.is_some());
+        assert_eq!(
+            std::fs::read_to_string(&path).unwrap(),
+            "GET https://example.com\n"
+        );
+
+        app.last_auto_save =
+            std::time::Instant::now().checked_sub(std::time::Duration::from_secs(2));
+        app.auto_save();
+        assert!(!app.editor_dirty);
+        assert_eq!(
+            std::fs::read_to_string(&path).unwrap(),
+            "GET https://example.com/users"
+        );
+        assert_eq!(
+            std::fs::read_to_string(dir.path().join("users.hurl.bak")).unwrap(),
+            "GET https://example.com\n"
+        );
+    }
+
+    #[test]
+    fn test_response_search_survives_redraws() {
+        let dir = tempfile::tempdir().unwrap();
+        let mut app = test_app(dir.path());
+        app.execution_result = Some(ExecutionResult {
+            success: true,
+            request: None,
+            response: Some(crate::runner::Response {
+                status_code: 200,
+                headers: Vec::new(),
+                body: "alpha\nbeta\nalpha beta".to_string(),
+                duration_ms: 1,
+                cookies: Vec::new(),
+                timings: None,
+                body_bytes: None,
+                body_truncated: false,
+                body_original_size: 0,
+            }),
+            assertions: Vec::new(),
+            captures: Vec::new(),
+            stdout: String::new(),
+            stderr: String::new(),
+            exit_code: 0,
+            attempts: 1,
+        });
+        app.response_search_query = "beta".to_string();
+        app.response_search_stale = true;
+
+        let mut terminal =
+            ratatui::Terminal::new(ratatui::backend::TestBackend::new(80, 20)).unwrap();
+        for _ in 0..2 {
+            terminal
+                .draw(|frame| crate::ui::render_response(frame, &mut app, frame.area()))
+                .unwrap();
+        }
+        assert_eq!(app.response_search_matches, vec![(1, 1), (2, 7)]);
+
+        app.response_search_jump(true);
+        assert_eq!(
+            app.status_message,
+            Some(("Match 2/2".to_string(), StatusLevel::Info))
+        );
+    }
+
+    #[test]
+    fn test_export_junit_error_names_file() {
+        let dir = tempfile::tempdir().unwrap();
+        let app = test_app(dir.path());
+        // A file where the report's directory should be
+        std::fs::write(dir.path().join("reports"), "").unwrap();
+
+        let path = dir.path().join("reports/junit.xml");
+        let err = app.export_results_as_junit(&path).unwrap_err();
+        assert!(format!("{:#}", err).starts_with(&format!("Failed to write {}", path.display())));
+    }
+
+    #[test]
+    fn test_recall_command() {
+        let dir = tempfile::tempdir().unwrap();
+        let mut app = test_app(dir.path());
+        for cmd in ["help", "refresh", "help"] {
+            app.command_input = cmd.to_string();
+            app.execute_command().unwrap();
+        }
+        assert_eq!(app.command_history, vec!["help", "refresh", "help"]);
+
+        app.command_history_index = app.command_history.len();
+        app.command_input = "draft".to_string();
+        app.recall_command(true);
+        assert_eq!(app.command_input, "help");
+        app.recall_command(true);
+        app.recall_command(true);
+        assert_eq!(app.command_input, "help");
+        // Stops at the oldest entry
+        app.recall_command(true);
+        assert_eq!(app.command_input, "help");
+        assert_eq!(app.command_history_index, 0);
+
+        app.recall_command(false);
+        assert_eq!(app.command_input, "refresh");
+        app.recall_command(false);
+        app.recall_command(false);
+        assert_eq!(app.command_input, "draft");
+        app.recall_command(false);
+        assert_eq!(app.command_input, "draft");
+    }
+
+    #[test]
+    fn test_rerun_command_by_number() {
+        let dir = tempfile::tempdir().unwrap();
+        let mut app = test_app(dir.path());
+        for cmd in ["setvar host localhost", "setvar port 8080"] {
+            app.command_input = cmd.to_string();
+            app.execute_command().unwrap();
+        }
+        app.runtime_variable_overrides.clear();
+
+        app.command_input = "!2".to_string();
+        app.execute_command().unwrap();
+        assert_eq!(
+            app.runtime_variable_overrides
+                .get("host")
+                .map(String::as_str),
+            Some("localhost")
+        );
+        assert!(!app.runtime_variable_overrides.contains_key("port"));
+        assert_eq!(app.command_history.last().unwrap(), "setvar host localhost");
+
+        app.command_input = "!9".to_string();
+        app.execute_command().unwrap();
+        assert_eq!(app.status_message.as_ref().unwrap().0, "No command !9");
+    }
+
+    #[test]
+    fn test_persisted_commands() {
+        let dir = tempfile::tempdir().unwrap();
+        let mut app = test_app(dir.path());
+        for cmd in [
+            "setvar token abc",
+            "setvar",
+            "export-env --include-secrets out.env",
+            "export-env out.env",
+        ] {
+            app.command_input = cmd.to_string();
+            app.execute_command().unwrap();
+        }
+
+        let state = std::fs::read_to_string(dir.path().join(".hurl-tui-state.json")).unwrap();
+        let state = App::parse_state(&state).unwrap();
+        assert_eq!(state.command_history, vec!["setvar", "export-env out.env"]);
+    }
+
+    #[test]
+    fn test_fuzzy_match() {
+        assert!(fuzzy_match("export-env prod.env", "exp"));
+        assert!(fuzzy_match("export-env prod.env", "EPE"));
+        assert!(fuzzy_match("export-env prod.env", ""));
+        assert!(!fuzzy_match("export-env prod.env", "vne"));
+        assert!(!fuzzy_match("w", "ww"));
+    }
+}
@@ -0,0 +1,256 @@
+//! Key bindings
+//!
+//! Parses the key strings in `KeyConfig` (e.g. `"r"`, `"ctrl+s"`) into key codes
+//! that can be matched against terminal key events.
+
+use anyhow::{bail, Result};
+use crossterm::event::{KeyCode, KeyEvent, KeyModifiers};
+use std::collections::HashMap;
+
+use super::KeyConfig;
+
+/// Actions that can be bound to a key
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Action {
+    Quit,
+    Run,
+    Edit,
+    Save,
+    Help,
+}
+
+impl Action {
+    pub const ALL: [Action; 5] = [
+        Action::Quit,
+        Action::Run,
+        Action::Edit,
+        Action::Save,
+        Action::Help,
+    ];
+
+    /// Name used in the config file and in `:bind`
+    pub fn name(self) -> &'static str {
+        match self {
+            Action::Quit => "quit",
+            Action::Run => "run",
+            Action::Edit => "edit",
+            Action::Save => "save",
+            Action::Help => "help",
+        }
+    }
+
+    pub fn from_name(name: &str) -> Option<Self> {
+        Self::ALL.into_iter().find(|a| a.name() == name)
+    }
+
+    /// The key configured for this action
+    fn config_key(self, keys: &KeyConfig) -> &str {
+        match self {
+            Action::Quit => &keys.quit,
+            Action::Run => &keys.run,
+            Action::Edit => &keys.edit,
+            Action::Save => &keys.save,
+            Action::Help => &keys.help,
+        }
+    }
+
+    /// The `KeyConfig` field holding this action's key
+    fn config_key_mut(self, keys: &mut KeyConfig) -> &mut String {
+        match self {
+            Action::Quit => &mut keys.quit,
+            Action::Run => &mut keys.run,
+            Action::Edit => &mut keys.edit,
+            Action::Save => &mut keys.save,
+            Action::Help => &mut keys.help,
+        }
+    }
+}
+
+/// A parsed key binding
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct KeyBinding {
+    pub modifiers: KeyModifiers,
+    pub code: KeyCode,
+}
+
+impl KeyBinding {
+    /// Parse a key string such as `r`, `R`, `ctrl+s`, `alt+x`, `shift+x` or `f5`
+    pub fn parse(s: &str) -> Option<Self> {
+        let mut modifiers = KeyModifiers::NONE;
+        let mut parts: Vec<&str> = s.trim().split('+').collect();
+        let key = parts.pop()?;
+
+        for part in parts {
+            match part.to_lowercase().as_str() {
+                "ctrl" | "control" => modifiers |= KeyModifiers::CONTROL,
+                "alt" => modifiers |= KeyModifiers::ALT,
+                "shift" => modifiers |= KeyModifiers::SHIFT,
+                _ => return None,
+            }
+        }
+
+        let code = match key.to_lowercase().as_str() {
+            "enter" => KeyCode::Enter,
+            "esc" => KeyCode::Esc,
+            "tab" => KeyCode::Tab,
+            "space" => KeyCode::Char(' '),
+            "backspace" => KeyCode::Backspace,
+            "delete" | "del" => KeyCode::Delete,
+            "up" => KeyCode::Up,
+            "down" => KeyCode::Down,
+            "left" => KeyCode::Left,
+            "right" => KeyCode::Right,
+            name if name.len() > 1 && name.starts_with('f') => KeyCode::F(name[1..].parse().ok()?),
+            _ => {
+                let mut chars = key.chars();
+                let c = chars.next()?;
+                if chars.next().is_some() {
+                    return None;
+                }
+                KeyCode::Char(c)
+            }
+        };
+
+        Some(Self::normalized(modifiers, code))
+    }
+
+    /// Binding for a key event
+    pub fn from_event(key: &KeyEvent) -> Self {
+        Self::normalized(key.modifiers, key.code)
+    }
+
+    /// Fold Shift into the character so `shift+x`, `X` and a Shift+x key event all match
+    fn normalized(mut modifiers: KeyModifiers, code: KeyCode) -> Self {
+        let code = match code {
+            KeyCode::Char(c) if modifiers.contains(KeyModifiers::SHIFT) || c.is_uppercase() => {
+                modifiers.remove(KeyModifiers::SHIFT);
+                KeyCode::Char(c.to_ascii_uppercase())
+            }
+            code => code,
+        };
+        Self { modifiers, code }
+    }
+}
+
+impl std::fmt::Display for KeyBinding {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        if self.modifiers.contains(KeyModifiers::CONTROL) {
+            write!(f, "Ctrl+")?;
+        }
+        if self.modifiers.contains(KeyModifiers::ALT) {
+            write!(f, "Alt+")?;
+        }
+        match self.code {
+            KeyCode::Char(' ') => write!(f, "Space"),
+            KeyCode::Char(c) => write!(f, "{}", c),
+            KeyCode::F(n) => write!(f, "F{}", n),
+            code => write!(f, "{:?}", code),
+        }
+    }
+}
+
+/// Runtime key bindings for the configurable actions
+#[derive(Debug, Clone)]
+pub struct KeyMap {
+    bindings: HashMap<Action, KeyBinding>,
+}
+
+impl KeyMap {
+    /// Build the key map from the config, falling back to the default for invalid keys
+    pub fn from_config(keys: &KeyConfig) -> Self {
+        let defaults = KeyConfig::default();
+        let bindings = Action::ALL
+            .into_iter()
+            .filter_map(|action| {
+                let binding = KeyBinding::parse(action.config_key(keys))
+                    .or_else(|| KeyBinding::parse(action.config_key(&defaults)))?;
+                Some((action, binding))
+            })
+            .collect();
+
+        Self { bindings }
+    }
+
+    /// The action bound to a key event, if any
+    pub fn action(&self, key: &KeyEvent) -> Option<Action> {
+        let pressed = KeyBinding::from_event(key);
+        self.bindings
+            .iter()
+            .find(|(_, binding)| **binding == pressed)
+            .map(|(action, _)| *action)
+    }
+
+    /// The key bound to an action
+    pub fn binding(&self, action: Action) -> Option<KeyBinding> {
+        self.bindings.get(&action).copied()
+    }
+
+    /// Bind `key` to `action`, updating `keys` so the binding can be saved.
+    /// Fails for invalid keys and for keys already bound to another action.
+    pub fn bind(&mut self, action: Action, key: &str, keys: &mut KeyConfig) -> Result<KeyBinding> {
+        let Some(binding) = KeyBinding::parse(key) else {
+            bail!("Invalid key: {}", key);
+        };
+        if let Some((other, _)) = self
+            .bindings
+            .iter()
+            .find(|(other, bound)| **other != action && **bound == binding)
+        {
+            bail!("{} is already bound to {}", binding, other.name());
+        }
+        self.bindings.insert(action, binding);
+        *action.config_key_mut(keys) = key.to_string();
+        Ok(binding)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_key_binding() {
+        let ctrl_s = KeyBinding::parse("ctrl+s").unwrap();
+        assert_eq!(ctrl_s.modifiers, KeyModifiers::CONTROL);
+        assert_eq!(ctrl_s.code, KeyCode::Char('s'));
+
+        assert_eq!(KeyBinding::parse("shift+x"), KeyBinding::parse("X"));
+        assert_eq!(KeyBinding::parse("f5").unwrap().code, KeyCode::F(5));
+        assert!(KeyBinding::parse("hyper+x").is_none());
+        assert!(KeyBinding::parse("xy").is_none());
+
+        let mut keys = KeyConfig::default();
+        let mut keymap = KeyMap::from_config(&keys);
+        let r = KeyEvent::new(KeyCode::Char('r'), KeyModifiers::NONE);
+        let shift_r = KeyEvent::new(KeyCode::Char('R'), KeyModifiers::SHIFT);
+        assert_eq!(keymap.action(&r), Some(Action::Run));
+
+        keymap.bind(Action::Run, "shift+r", &mut keys).unwrap();
+        assert_eq!(keymap.action(&r), None);
+        assert_eq!(keymap.action(&shift_r), Some(Action::Run));
+        assert_eq!(keys.run, "shift+r");
+    }
+
+    #[test]
+    fn test_bind_conflict() {
+        let mut keys = KeyConfig::default();
+        let mut keymap = KeyMap::from_config(&keys);
+        let help = keymap.binding(Action::Help).unwrap();
+
+        let err = keymap
+            .bind(Action::Run, &keys.help.clone(), &mut keys)
+            .unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            format!("{} is already bound to help", help)
+        );
+        assert_eq!(keymap.binding(Action::Help), Some(help));
+        assert_eq!(keys.run, KeyConfig::default().run);
+
+        // Rebinding an action to its own key is fine
+        assert!(keymap
+            .bind(Action::Help, &keys.help.clone(), &mut keys)
+            .is_ok());
+        assert!(keymap.bind(Action::Run, "hyper+r", &mut keys).is_err());
+    }
+}
@@ -2,12 +2,17 @@
 //!
 //! This module handles application configuration loading and management.
 
-use anyhow::Result;
+mod keymap;
+
+pub use keymap::{Action, KeyMap};
+
+use anyhow::{anyhow, bail, Result};
 use serde::{Deserialize, Serialize};
-use std::path::PathBuf;
+use std::path::{Path, PathBuf};
 
 /// Application configuration
 #[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
 pub struct Config {
     /// General settings
     #[serde(default)]
@@ -24,6 +29,10 @@ pub struct Config {
     /// Keybindings
     #[serde(default)]
     pub keys: KeyConfig,
+
+    /// Request snippets inserted from the editor (`Ctrl+s` in insert mode)
+    #[serde(default = "default_snippets")]
+    pub snippets: Vec<Snippet>,
 }
 
 impl Default for Config {
@@ -33,12 +42,14 @@ impl Default for Config {
             ui: UiConfig::default(),
             editor: EditorConfig::default(),
             keys: KeyConfig::default(),
+            snippets: default_snippets(),
         }
     }
 }
 
 /// General application settings
 #[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
 pub struct GeneralConfig {
     /// Default working directory
     pub default_directory: Option<PathBuf>,
@@ -56,6 +67,39 @@ pub struct GeneralConfig {
 
     /// Output directory for saving response files (default: same directory as hurl file)
     pub output_dir: Option<PathBuf>,
+
+    /// Number of retries when hurl fails to connect
+    #[serde(default)]
+    pub retry_count: u32,
+
+    /// Delay between retries in milliseconds
+    #[serde(default = "default_retry_delay_ms")]
+    pub retry_delay_ms: u64,
+
+    /// Number of files run in parallel by the collection runner
+    #[serde(default = "default_concurrent_runners")]
+    pub concurrent_runners: usize,
+
+    /// Keep cookies between runs in `.hurl-tui-cookies.txt` (`--cookie-jar`)
+    #[serde(default)]
+    pub use_cookie_jar: bool,
+
+    /// Proxy for all requests (`--proxy`), e.g. `http://localhost:8080`
+    pub proxy: Option<String>,
+
+    /// Comma-separated hosts that bypass the proxy (`--no-proxy`)
+    pub no_proxy: Option<String>,
+
+    /// Skip TLS certificate verification (`--insecure`)
+    #[serde(default)]
+    pub insecure: bool,
+
+    /// Directory of Lua assertion scripts, relative to the working directory (`lua-scripts` feature)
+    pub script_dir: Option<PathBuf>,
+
+    /// Response bodies larger than this many bytes are truncated for display
+    #[serde(default = "default_max_body_display_bytes")]
+    pub max_body_display_bytes: usize,
 }
 
 impl Default for GeneralConfig {
@@ -66,6 +110,15 @@ impl Default for GeneralConfig {
             timeout: default_timeout(),
             max_history: default_max_history(),
             output_dir: None,
+            retry_count: 0,
+            retry_delay_ms: default_retry_delay_ms(),
+            concurrent_runners: default_concurrent_runners(),
+            use_cookie_jar: false,
+            proxy: None,
+            no_proxy: None,
+            insecure: false,
+            script_dir: None,
+            max_body_display_bytes: default_max_body_display_bytes(),
         }
     }
 }
@@ -78,8 +131,21 @@ fn default_max_history() -> usize {
     100
 }
 
+fn default_retry_delay_ms() -> u64 {
+    1000
+}
+
+fn default_concurrent_runners() -> usize {
+    1
+}
+
+fn default_max_body_display_bytes() -> usize {
+    512 * 1024
+}
+
 /// UI settings
 #[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
 pub struct UiConfig {
     /// Show line numbers in editor
     #[serde(default = "default_true")]
@@ -100,6 +166,26 @@ pub struct UiConfig {
     /// File browser width percentage
     #[serde(default = "default_file_browser_width")]
     pub file_browser_width: u16,
+
+    /// Play panel/status animations
+    #[serde(default = "default_true")]
+    pub animations_enabled: bool,
+
+    /// Animation duration multiplier (0.5 = twice as fast, 2.0 = twice as slow)
+    #[serde(default = "default_animation_speed_multiplier")]
+    pub animation_speed_multiplier: f32,
+
+    /// Show `[BasicAuth]` passwords in the editor as `••••••`
+    #[serde(default = "default_true")]
+    pub mask_basic_auth: bool,
+
+    /// Seconds a status message stays visible (errors at least 10; 0 keeps messages)
+    #[serde(default = "default_status_message_timeout_secs")]
+    pub status_message_timeout_secs: u64,
+
+    /// Show the size and age of `.hurl` files in the file browser
+    #[serde(default)]
+    pub show_file_metadata: bool,
 }
 
 impl Default for UiConfig {
@@ -110,6 +196,11 @@ impl Default for UiConfig {
             theme: default_theme(),
             border_style: default_border_style(),
             file_browser_width: default_file_browser_width(),
+            animations_enabled: true,
+            animation_speed_multiplier: default_animation_speed_multiplier(),
+            mask_basic_auth: true,
+            status_message_timeout_secs: default_status_message_timeout_secs(),
+            show_file_metadata: false,
         }
     }
 }
@@ -130,8 +221,17 @@ fn default_file_browser_width() -> u16 {
     20
 }
 
+fn default_animation_speed_multiplier() -> f32 {
+    1.0
+}
+
+fn default_status_message_timeout_secs() -> u64 {
+    5
+}
+
 /// Editor settings
 #[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
 pub struct EditorConfig {
     /// Tab size
     #[serde(default = "default_tab_size")]
@@ -145,9 +245,17 @@ pub struct EditorConfig {
     #[serde(default)]
     pub auto_save: bool,
 
+    /// Save unsaved edits every this many seconds (0 disables it)
+    #[serde(default)]
+    pub auto_save_interval_secs: u64,
+
     /// Syntax highlighting enabled
     #[serde(default = "default_true")]
     pub syntax_highlighting: bool,
+
+    /// Maximum number of undo steps kept for the editor
+    #[serde(default = "default_editor_history_limit")]
+    pub editor_history_limit: usize,
 }
 
 impl Default for EditorConfig {
@@ -156,7 +264,9 @@ impl Default for EditorConfig {
             tab_size: default_tab_size(),
             use_spaces: true,
             auto_save: false,
+            auto_save_interval_secs: 0,
             syntax_highlighting: true,
+            editor_history_limit: default_editor_history_limit(),
         }
     }
 }
@@ -165,8 +275,13 @@ fn default_tab_size() -> usize {
     2
 }
 
+fn default_editor_history_limit() -> usize {
+    100
+}
+
 /// Keybinding configuration
 #[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
 pub struct KeyConfig {
     /// Quit key
     #[serde(default = "default_quit_key")]
@@ -221,23 +336,71 @@ fn default_help_key() -> String {
     "?".to_string()
 }
 
+/// A named piece of hurl content (`[[snippets]]` in the config)
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct Snippet {
+    pub name: String,
+    pub content: String,
+}
+
+fn default_snippets() -> Vec<Snippet> {
+    let snippet = |name: &str, content: &str| Snippet {
+        name: name.to_string(),
+        content: content.to_string(),
+    };
+
+    vec![
+        snippet(
+            "GET-json",
+            "GET {{base_url}}/\nAccept: application/json\n\nHTTP 200\n",
+        ),
+        snippet(
+            "POST-json",
+            "POST {{base_url}}/\nContent-Type: application/json\n{\n  \"name\": \"value\"\n}\n\nHTTP 201\n",
+        ),
+        snippet("auth-bearer", "Authorization: Bearer {{token}}"),
+        snippet(
+            "assert-jsonpath",
+            "[Asserts]\njsonpath \"$.id\" exists\njsonpath \"$.name\" == \"value\"\njsonpath \"$.items\" count > 0\n",
+        ),
+    ]
+}
+
 impl Config {
+    /// Path of the user config file (`~/.config/hurl-tui/config.toml`)
+    pub fn user_config_path() -> Option<PathBuf> {
+        dirs::config_dir().map(|p| p.join("hurl-tui").join("config.toml"))
+    }
+
+    /// Directory of the user theme files (`~/.config/hurl-tui/themes`)
+    pub fn themes_dir() -> Option<PathBuf> {
+        dirs::config_dir().map(|p| p.join("hurl-tui").join("themes"))
+    }
+
     /// Load configuration from file or use defaults
+    ///
+    /// The first config file found is used; if it cannot be read or parsed the
+    /// error names the file and the offending line.
     pub fn load() -> Result<Self> {
         // Try to load from standard config locations
         let config_paths = vec![
-            dirs::config_dir().map(|p| p.join("hurl-tui").join("config.toml")),
+            Self::user_config_path(),
             Some(PathBuf::from(".hurl-tui.toml")),
             Some(PathBuf::from("hurl-tui.toml")),
         ];
 
         for path in config_paths.into_iter().flatten() {
             if path.exists() {
-                if let Ok(content) = std::fs::read_to_string(&path) {
-                    if let Ok(config) = toml::from_str(&content) {
-                        return Ok(config);
-                    }
-                }
+                let content = std::fs::read_to_string(&path)
+                    .map_err(|e| anyhow!("Config error in {}: {}", display_path(&path), e))?;
+                return toml::from_str(&content).map_err(|e| {
+                    anyhow!(
+                        "Config error in {}: {}",
+                        display_path(&path),
+                        describe_toml_error(&e, &content)
+                    )
+                });
             }
         }
 
@@ -245,10 +408,90 @@ impl Config {
         Ok(Config::default())
     }
 
-    /// Save configuration to file
-    pub fn save(&self, path: &PathBuf) -> Result<()> {
-        let content = toml::to_string_pretty(self)?;
-        std::fs::write(path, content)?;
+    /// Set the key of an action (`[keys] <action> = "<key>"`) in the config file at `path`.
+    /// The rest of the file is kept; settings it doesn't have are not written.
+    pub fn save_key(path: &Path, action: &str, key: &str) -> Result<()> {
+        let mut table: toml::Table = match std::fs::read_to_string(path) {
+            Ok(content) => toml::from_str(&content)?,
+            Err(e) if e.kind() == std::io::ErrorKind::NotFound => toml::Table::new(),
+            Err(e) => return Err(e.into()),
+        };
+        let keys = table
+            .entry("keys")
+            .or_insert_with(|| toml::Value::Table(toml::Table::new()));
+        let Some(keys) = keys.as_table_mut() else {
+            bail!("`keys` is not a table in {}", display_path(path));
+        };
+        keys.insert(action.to_string(), toml::Value::String(key.to_string()));
+
+        if let Some(parent) = path.parent() {
+            std::fs::create_dir_all(parent)?;
+        }
+        std::fs::write(path, toml::to_string_pretty(&table)?)?;
         Ok(())
     }
 }
+
+/// Show a path with the home directory abbreviated to `~`
+fn display_path(path: &Path) -> String {
+    match dirs::home_dir().and_then(|home| path.strip_prefix(home).ok().map(Path::to_path_buf)) {
+        Some(relative) => format!("~/{}", relative.display()),
+        None => path.display().to_string(),
+    }
+}
+
+/// Describe a TOML error on one line: the message and the line it points at
+fn describe_toml_error(error: &toml::de::Error, content: &str) -> String {
+    let message = match error.message().strip_prefix("unknown field") {
+        // Drop the long list of expected keys
+        Some(rest) => format!(
+            "unknown key{}",
+            rest.split(", expected").next().unwrap_or(rest)
+        ),
+        None => error.message().to_string(),
+    };
+    match error.span() {
+        Some(span) => {
+            let line = content[..span.start.min(content.len())]
+                .matches('\n')
+                .count()
+                + 1;
+            format!("{} at line {}", message, line)
+        }
+        None => message,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_save_key() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("hurl-tui").join("config.toml");
+
+        Config::save_key(&path, "run", "ctrl+r").unwrap();
+        assert_eq!(
+            std::fs::read_to_string(&path).unwrap(),
+            "[keys]\nrun = \"ctrl+r\"\n"
+        );
+
+        // Other settings of the file are kept, unset ones are not added
+        std::fs::write(
+            &path,
+            "[general]\ntimeout = 5\n\n[keys]\nquit = \"x\"\nrun = \"ctrl+r\"\n",
+        )
+        .unwrap();
+        Config::save_key(&path, "run", "f5").unwrap();
+        let table: toml::Table = toml::from_str(&std::fs::read_to_string(&path).unwrap()).unwrap();
+        assert_eq!(table["general"]["timeout"].as_integer(), Some(5));
+        assert_eq!(table["keys"]["quit"].as_str(), Some("x"));
+        assert_eq!(table["keys"]["run"].as_str(), Some("f5"));
+        assert!(table["keys"].get("help").is_none());
+        assert!(table.get("ui").is_none());
+
+        std::fs::write(&path, "keys = 1\n").unwrap();
+        assert!(Config::save_key(&path, "run", "f5").is_err());
+    }
+}
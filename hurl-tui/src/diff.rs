@@ -0,0 +1,266 @@
+//! Line-based diff
+//!
+//! A plain Myers diff, used to compare response bodies between two runs.
+
+/// A line of a diff
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum DiffLine {
+    Unchanged(String),
+    Added(String),
+    Removed(String),
+}
+
+/// Bodies with more lines than this on either side are not diffed
+pub const MAX_DIFF_LINES: usize = 10_000;
+
+/// Diffs needing more line edits than this are given up (the trace grows with its square)
+const MAX_EDIT_DISTANCE: usize = 2_000;
+
+/// Diff `old` against `new`, returning lines in display order
+///
+/// Returns `None` when the input is too large to diff: more than `MAX_DIFF_LINES`
+/// lines on a side, or more than `MAX_EDIT_DISTANCE` added and removed lines.
+pub fn diff_lines(old: &[String], new: &[String]) -> Option<Vec<DiffLine>> {
+    if old.len() > MAX_DIFF_LINES || new.len() > MAX_DIFF_LINES {
+        return None;
+    }
+    let n = old.len() as isize;
+    let m = new.len() as isize;
+    let max = (n + m) as usize;
+    let offset = max as isize + 1;
+
+    // Forward pass: keep the furthest-reaching x per diagonal for every edit distance.
+    // Backtracking round `d` only reads diagonals `-d - 1..=d + 1`, so only that window is kept.
+    let mut v = vec![0isize; 2 * max + 3];
+    let mut trace: Vec<Vec<isize>> = Vec::new();
+    let mut found = false;
+    'search: for d in 0..=max.min(MAX_EDIT_DISTANCE) as isize {
+        trace.push(v[(offset - d - 1) as usize..=(offset + d + 1) as usize].to_vec());
+        for k in (-d..=d).step_by(2) {
+            let idx = (k + offset) as usize;
+            let mut x = if k == -d || (k != d && v[idx - 1] < v[idx + 1]) {
+                v[idx + 1]
+            } else {
+                v[idx - 1] + 1
+            };
+            let mut y = x - k;
+            while x < n && y < m && old[x as usize] == new[y as usize] {
+                x += 1;
+                y += 1;
+            }
+            v[idx] = x;
+            if x >= n && y >= m {
+                found = true;
+                break 'search;
+            }
+        }
+    }
+    if !found {
+        return None;
+    }
+
+    // Backtrack from the end, collecting lines in reverse
+    let mut lines = Vec::new();
+    let (mut x, mut y) = (n, m);
+    for (d, window) in trace.iter().enumerate().rev() {
+        let d = d as isize;
+        let v = |k: isize| window[(k + d + 1) as usize];
+        let k = x - y;
+        let prev_k = if k == -d || (k != d && v(k - 1) < v(k + 1)) {
+            k + 1
+        } else {
+            k - 1
+        };
+        let prev_x = v(prev_k);
+        let prev_y = prev_x - prev_k;
+
+        while x > prev_x && y > prev_y {
+            x -= 1;
+            y -= 1;
+            lines.push(DiffLine::Unchanged(old[x as usize].clone()));
+        }
+        if d > 0 {
+            if x == prev_x {
+                lines.push(DiffLine::Added(new[prev_y as usize].clone()));
+            } else {
+                lines.push(DiffLine::Removed(old[prev_x as usize].clone()));
+            }
+        }
+        x = prev_x;
+        y = prev_y;
+    }
+
+    lines.reverse();
+    Some(lines)
+}
+
+/// A row of a side-by-side diff; a side is `None` where only the other side has a line
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DiffRow {
+    pub old: Option<String>,
+    pub new: Option<String>,
+}
+
+impl DiffRow {
+    /// Whether the row differs between the two sides
+    pub fn is_changed(&self) -> bool {
+        self.old != self.new
+    }
+}
+
+/// Lay out a diff as side-by-side rows, pairing removed lines with the added lines that follow
+pub fn side_by_side(diff: Vec<DiffLine>) -> Vec<DiffRow> {
+    let mut rows = Vec::new();
+    let mut removed: Vec<String> = Vec::new();
+    let mut added: Vec<String> = Vec::new();
+
+    let flush = |rows: &mut Vec<DiffRow>, removed: &mut Vec<String>, added: &mut Vec<String>| {
+        let count = removed.len().max(added.len());
+        let mut removed = std::mem::take(removed).into_iter();
+        let mut added = std::mem::take(added).into_iter();
+        for _ in 0..count {
+            rows.push(DiffRow {
+                old: removed.next(),
+                new: added.next(),
+            });
+        }
+    };
+
+    for line in diff {
+        match line {
+            DiffLine::Removed(text) => removed.push(text),
+            DiffLine::Added(text) => added.push(text),
+            DiffLine::Unchanged(text) => {
+                flush(&mut rows, &mut removed, &mut added);
+                rows.push(DiffRow {
+                    old: Some(text.clone()),
+                    new: Some(text),
+                });
+            }
+        }
+    }
+    flush(&mut rows, &mut removed, &mut added);
+    rows
+}
+
+/// Diff two response bodies (pretty-printed when JSON); `None` when they are too large to diff
+pub fn body_diff(previous_body: &str, current_body: &str) -> Option<Vec<DiffLine>> {
+    diff_lines(&format_body(previous_body), &format_body(current_body))
+}
+
+/// Format the response body for display (pretty-printed when JSON)
+pub fn format_body(body: &str) -> Vec<String> {
+    // Try to parse and pretty-print JSON
+    if let Ok(json) = serde_json::from_str::<serde_json::Value>(body) {
+        if let Ok(pretty) = serde_json::to_string_pretty(&json) {
+            return pretty.lines().map(String::from).collect();
+        }
+    }
+
+    // Fall back to raw body
+    body.lines().map(String::from).collect()
+}
+
+/// Indices of the rows where a run of changed rows (a hunk) starts
+pub fn hunk_starts(rows: &[DiffRow]) -> Vec<usize> {
+    (0..rows.len())
+        .filter(|&i| rows[i].is_changed() && (i == 0 || !rows[i - 1].is_changed()))
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn lines(text: &[&str]) -> Vec<String> {
+        text.iter().map(|s| s.to_string()).collect()
+    }
+
+    #[test]
+    fn test_diff_lines() {
+        let old = lines(&["{", "  \"id\": 1,", "  \"name\": \"a\"", "}"]);
+        let new = lines(&[
+            "{",
+            "  \"id\": 2,",
+            "  \"name\": \"a\"",
+            "  \"tag\": null",
+            "}",
+        ]);
+
+        assert_eq!(
+            diff_lines(&old, &new).unwrap(),
+            vec![
+                DiffLine::Unchanged("{".to_string()),
+                DiffLine::Removed("  \"id\": 1,".to_string()),
+                DiffLine::Added("  \"id\": 2,".to_string()),
+                DiffLine::Unchanged("  \"name\": \"a\"".to_string()),
+                DiffLine::Added("  \"tag\": null".to_string()),
+                DiffLine::Unchanged("}".to_string()),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_diff_lines_empty() {
+        let new = lines(&["a", "b"]);
+
+        assert_eq!(
+            diff_lines(&[], &new).unwrap(),
+            vec![
+                DiffLine::Added("a".to_string()),
+                DiffLine::Added("b".to_string()),
+            ]
+        );
+        assert!(diff_lines(&new, &new)
+            .unwrap()
+            .iter()
+            .all(|l| matches!(l, DiffLine::Unchanged(_))));
+    }
+
+    #[test]
+    fn test_diff_lines_too_large() {
+        let long: Vec<String> = (0..=MAX_DIFF_LINES).map(|i| i.to_string()).collect();
+        assert!(diff_lines(&long, &[]).is_none());
+
+        // Within the line limit, but every line differs
+        let old: Vec<String> = (0..MAX_EDIT_DISTANCE).map(|i| format!("a{}", i)).collect();
+        let new: Vec<String> = (0..MAX_EDIT_DISTANCE).map(|i| format!("b{}", i)).collect();
+        assert!(diff_lines(&old, &new).is_none());
+
+        // Few edits in long inputs are still diffed
+        let mut changed = long[1..].to_vec();
+        changed[100] = "x".to_string();
+        assert_eq!(
+            diff_lines(&long[1..], &changed)
+                .unwrap()
+                .iter()
+                .filter(|l| !matches!(l, DiffLine::Unchanged(_)))
+                .count(),
+            2
+        );
+    }
+
+    #[test]
+    fn test_side_by_side() {
+        let old = lines(&["a", "b", "c", "d", "e"]);
+        let new = lines(&["a", "B", "c", "d", "x", "y"]);
+        let rows = side_by_side(diff_lines(&old, &new).unwrap());
+
+        let row = |old: Option<&str>, new: Option<&str>| DiffRow {
+            old: old.map(String::from),
+            new: new.map(String::from),
+        };
+        assert_eq!(
+            rows,
+            vec![
+                row(Some("a"), Some("a")),
+                row(Some("b"), Some("B")),
+                row(Some("c"), Some("c")),
+                row(Some("d"), Some("d")),
+                row(Some("e"), Some("x")),
+                row(None, Some("y")),
+            ]
+        );
+        assert_eq!(hunk_starts(&rows), vec![1, 4]);
+    }
+}
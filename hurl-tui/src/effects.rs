@@ -37,6 +37,10 @@ pub struct EffectManager {
     last_frame: Instant,
     /// Cached delta from last tick
     last_delta: StdDuration,
+    /// Whether new effects are started at all
+    enabled: bool,
+    /// Scales animation durations (0.5 = twice as fast)
+    speed_multiplier: f32,
 }
 
 impl EffectManager {
@@ -46,14 +50,39 @@ impl EffectManager {
             effects: HashMap::new(),
             last_frame: Instant::now(),
             last_delta: StdDuration::ZERO,
+            enabled: true,
+            speed_multiplier: 1.0,
+        }
+    }
+
+    /// Set whether animations run and how fast they play
+    pub fn with_animations(mut self, enabled: bool, speed_multiplier: f32) -> Self {
+        self.enabled = enabled;
+        self.speed_multiplier = speed_multiplier.max(0.01);
+        self
+    }
+
+    /// Whether animations are enabled
+    pub fn is_enabled(&self) -> bool {
+        self.enabled
+    }
+
+    /// Enable or disable animations; disabling drops the running effects
+    pub fn set_enabled(&mut self, enabled: bool) {
+        self.enabled = enabled;
+        if !enabled {
+            self.effects.clear();
         }
     }
 
     /// Calculate time since last frame and update timestamp
-    /// Returns the duration since last call
+    /// Returns the duration since last call, divided by the speed multiplier so that
+    /// every preset duration is effectively scaled by it
     pub fn tick(&mut self) -> StdDuration {
         let now = Instant::now();
-        let delta = now.duration_since(self.last_frame);
+        let delta = now
+            .duration_since(self.last_frame)
+            .div_f32(self.speed_multiplier);
         self.last_frame = now;
         self.last_delta = delta;
         delta
@@ -64,8 +93,11 @@ impl EffectManager {
         self.last_delta
     }
 
-    /// Add or replace an effect
+    /// Add or replace an effect (ignored while animations are disabled)
     pub fn add_effect(&mut self, id: EffectId, effect: Effect, area: Rect) {
+        if !self.enabled {
+            return;
+        }
         self.effects.insert(id, (effect, area));
     }
 
@@ -136,7 +168,8 @@ impl Default for EffectManager {
 pub mod presets {
     use super::*;
 
-    /// Duration constants (in milliseconds for tachyonfx)
+    /// Duration constants (in milliseconds for tachyonfx), scaled at playback by
+    /// `animation_speed_multiplier`
     pub const FAST: u32 = 150;
     pub const NORMAL: u32 = 300;
     pub const SLOW: u32 = 500;
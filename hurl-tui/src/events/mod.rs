@@ -4,7 +4,13 @@
 
 use anyhow::Result;
 use crossterm::event::{self, KeyEvent, MouseEvent};
+use notify::{EventKind, RecommendedWatcher, RecursiveMode, Watcher};
+use std::path::{Path, PathBuf};
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::Arc;
 use std::time::Duration;
+
+use crate::app::FileEntry;
 use tokio::sync::mpsc;
 use tokio::time::interval;
 
@@ -19,12 +25,22 @@ pub enum Event {
     Mouse(MouseEvent),
     /// Terminal resize event
     Resize(u16, u16),
+    /// A `.hurl` or `.env` file was modified on disk
+    FileChanged(PathBuf),
+    /// The background scan of the working directory finished
+    FileTreeLoaded(Vec<FileEntry>),
 }
 
 /// Event handler that polls for terminal events
 pub struct EventHandler {
     /// Event receiver
     rx: mpsc::UnboundedReceiver<Event>,
+    /// Event sender (shared with the file watcher)
+    tx: mpsc::UnboundedSender<Event>,
+    /// File system watcher, kept alive while watching
+    watcher: Option<RecommendedWatcher>,
+    /// Set while another program owns the terminal; input is not read meanwhile
+    input_paused: Arc<AtomicBool>,
     /// Tick rate in milliseconds
     _tick_rate: u64,
 }
@@ -48,8 +64,18 @@ impl EventHandler {
         });
 
         // Spawn event polling task - handles input events
+        let input_tx = tx.clone();
+        let input_paused = Arc::new(AtomicBool::new(false));
+        let paused = input_paused.clone();
         tokio::spawn(async move {
+            let tx = input_tx;
             loop {
+                // Leave the input to an external program (e.g. $EDITOR) while paused
+                if paused.load(Ordering::Relaxed) {
+                    tokio::time::sleep(Duration::from_millis(10)).await;
+                    continue;
+                }
+
                 // Poll for events with a short timeout to remain responsive
                 if event::poll(Duration::from_millis(1)).unwrap_or(false) {
                     match event::read() {
@@ -80,10 +106,45 @@ impl EventHandler {
 
         Self {
             rx,
+            tx,
+            watcher: None,
+            input_paused,
             _tick_rate: tick_rate,
         }
     }
 
+    /// Watch `dir` recursively and emit `Event::FileChanged` for modified `.hurl` and `.env` files
+    pub fn watch(&mut self, dir: &Path) -> Result<()> {
+        let tx = self.tx.clone();
+        let mut watcher =
+            notify::recommended_watcher(move |res: notify::Result<notify::Event>| {
+                let Ok(event) = res else {
+                    return;
+                };
+                if !matches!(event.kind, EventKind::Modify(_) | EventKind::Create(_)) {
+                    return;
+                }
+                for path in event.paths {
+                    if path.extension().is_some_and(|e| e == "hurl" || e == "env") {
+                        let _ = tx.send(Event::FileChanged(path));
+                    }
+                }
+            })?;
+        watcher.watch(dir, RecursiveMode::Recursive)?;
+        self.watcher = Some(watcher);
+        Ok(())
+    }
+
+    /// Sender for events produced outside the event handler (e.g. background tasks)
+    pub fn sender(&self) -> mpsc::UnboundedSender<Event> {
+        self.tx.clone()
+    }
+
+    /// Flag that stops reading terminal input while set
+    pub fn input_pause(&self) -> Arc<AtomicBool> {
+        self.input_paused.clone()
+    }
+
     /// Get the next event
     pub async fn next(&mut self) -> Result<Event> {
         self.rx
@@ -5,13 +5,17 @@
 
 mod app;
 mod config;
+mod diff;
 mod effects;
 mod events;
 mod parser;
+mod report;
 mod runner;
 mod ui;
+mod undo;
+mod util;
 
-use anyhow::Result;
+use anyhow::{bail, Context, Result};
 use crossterm::{
     event::{DisableMouseCapture, EnableMouseCapture},
     execute,
@@ -21,7 +25,7 @@ use ratatui::{backend::CrosstermBackend, Terminal};
 use std::{fs::File, io, path::PathBuf};
 use tracing_subscriber::{layer::SubscriberExt, util::SubscriberInitExt, EnvFilter};
 
-use crate::app::App;
+use crate::app::{App, IgnorePatterns, StatusLevel};
 use crate::config::Config;
 use crate::events::EventHandler;
 
@@ -31,14 +35,47 @@ async fn main() -> Result<()> {
     // Initialize logging
     init_logging()?;
 
-    // Load configuration
-    let config = Config::load()?;
+    let args = Args::parse(std::env::args().skip(1))?;
 
-    // Get the working directory (current dir or from args)
-    let working_dir = std::env::args()
-        .nth(1)
-        .map(PathBuf::from)
-        .unwrap_or_else(|| std::env::current_dir().unwrap_or_default());
+    // Load configuration (a broken config file is fatal unless --config-ignore-errors is given)
+    let (config, config_error) = match Config::load() {
+        Ok(config) => (config, None),
+        Err(e) if args.config_ignore_errors => {
+            eprintln!("Warning: {e}; using the default configuration");
+            (Config::default(), Some(e.to_string()))
+        }
+        Err(e) => {
+            eprintln!("{e}");
+            eprintln!("Fix the file or pass --config-ignore-errors to use the defaults");
+            std::process::exit(1);
+        }
+    };
+    let working_dir = args.working_dir.clone();
+
+    if args.list_envs {
+        for name in App::environment_names(&working_dir, &IgnorePatterns::load(&working_dir)) {
+            println!("{}", name);
+        }
+        return Ok(());
+    }
+
+    // Validate every file without sending requests
+    if args.dry_run {
+        let valid = report::dry_run(&config, &working_dir).await?;
+        std::process::exit(if valid { 0 } else { 1 });
+    }
+
+    // Headless mode: run everything, print a report and exit
+    if args.run_all {
+        let passed = report::run_all(&config, &working_dir).await?;
+        std::process::exit(if passed { 0 } else { 1 });
+    }
+
+    // Create application state (before the terminal is set up so --env warnings stay visible)
+    let mut app = App::new(config, working_dir, args.env.clone())?;
+    if let Some(error) = config_error {
+        app.set_status_notice(&format!("{error} (using defaults)"), StatusLevel::Warning);
+    }
 
     // Open /dev/tty for interactive terminal (works even when stdout is piped)
     let tty = open_tty()?;
@@ -46,12 +83,20 @@ async fn main() -> Result<()> {
     // Setup terminal using tty
     let mut terminal = setup_terminal(tty)?;
 
-    // Create application state
-    let mut app = App::new(config, working_dir)?;
-
     // Create event handler
     // Use 16ms tick rate (~60 FPS) for smooth animations
-    let event_handler = EventHandler::new(16);
+    let mut event_handler = EventHandler::new(16);
+
+    // Watch for .hurl/.env changes made outside the TUI
+    if let Err(e) = event_handler.watch(&app.working_dir) {
+        tracing::warn!("Failed to watch {:?}: {}", app.working_dir, e);
+    }
+
+    // Scan the file tree in the background so large directories don't block startup
+    app.set_event_sender(event_handler.sender());
+    app.set_input_pause(event_handler.input_pause());
+    app.scan_file_tree();
+    app.check_hurl_version();
 
     // Run the application
     let result = run_app(&mut terminal, &mut app, event_handler).await;
@@ -68,14 +113,99 @@ async fn main() -> Result<()> {
         std::process::exit(1);
     }
 
-    // Print output to stdout (for piping to editors like Helix)
+    // Write output to the --output file, or print it to stdout (for piping to editors like Helix)
     if let Some(output) = output {
-        print!("{}", output);
+        match args.output {
+            Some(path) => write_output(&path, &output, args.append)?,
+            None => print!("{}", output),
+        }
     }
 
     Ok(())
 }
 
+/// Command line arguments
+struct Args {
+    /// Directory to browse (current dir if not given)
+    working_dir: PathBuf,
+    /// Run all .hurl files and print a report instead of opening the TUI
+    run_all: bool,
+    /// Validate all .hurl files (parser and `hurl --dry-run`) without running them
+    dry_run: bool,
+    /// Environment to start in (`--env <name>`)
+    env: Option<String>,
+    /// Print the detected environment names and exit
+    list_envs: bool,
+    /// File to write the output to instead of stdout (`--output <path>`)
+    output: Option<PathBuf>,
+    /// Append to the output file instead of overwriting it
+    append: bool,
+    /// Fall back to the default configuration when the config file is invalid
+    config_ignore_errors: bool,
+}
+
+impl Args {
+    /// Parse the arguments (without the program name); unknown options are an error
+    fn parse(args: impl IntoIterator<Item = String>) -> Result<Self> {
+        let mut working_dir = None;
+        let mut run_all = false;
+        let mut dry_run = false;
+        let mut env = None;
+        let mut list_envs = false;
+        let mut output = None;
+        let mut append = false;
+        let mut config_ignore_errors = false;
+
+        let mut args = args.into_iter();
+        while let Some(arg) = args.next() {
+            match arg.as_str() {
+                "--run-all" => run_all = true,
+                "--dry-run" => dry_run = true,
+                "--env" => match args.next() {
+                    Some(name) => env = Some(name),
+                    None => bail!("--env needs an environment name"),
+                },
+                "--list-envs" => list_envs = true,
+                "--output" => match args.next() {
+                    Some(path) => output = Some(PathBuf::from(path)),
+                    None => bail!("--output needs a file path"),
+                },
+                "--append" => append = true,
+                "--config-ignore-errors" => config_ignore_errors = true,
+                _ if arg.starts_with('-') => bail!("Unknown option '{}'", arg),
+                _ => working_dir = Some(PathBuf::from(arg)),
+            }
+        }
+
+        Ok(Self {
+            working_dir: working_dir.unwrap_or_else(|| std::env::current_dir().unwrap_or_default()),
+            run_all,
+            dry_run,
+            env,
+            list_envs,
+            output,
+            append,
+            config_ignore_errors,
+        })
+    }
+}
+
+/// Write the output to a file, appending to it when `append` is set
+fn write_output(path: &PathBuf, output: &str, append: bool) -> Result<()> {
+    use std::io::Write;
+
+    let mut file = std::fs::OpenOptions::new()
+        .create(true)
+        .write(true)
+        .append(append)
+        .truncate(!append)
+        .open(path)
+        .with_context(|| format!("Failed to write {}", path.display()))?;
+    file.write_all(output.as_bytes())
+        .with_context(|| format!("Failed to write {}", path.display()))?;
+    Ok(())
+}
+
 /// Open /dev/tty for interactive terminal access
 #[cfg(unix)]
 fn open_tty() -> Result<File> {
@@ -142,6 +272,11 @@ async fn run_app(
     mut event_handler: EventHandler,
 ) -> Result<()> {
     loop {
+        // Redraw everything after another program used the terminal
+        if app.take_full_redraw() {
+            terminal.clear()?;
+        }
+
         // Draw the UI (app is mutable for effect processing)
         terminal.draw(|frame| ui::draw(frame, app))?;
 
@@ -159,6 +294,12 @@ async fn run_app(
             events::Event::Resize(width, height) => {
                 app.handle_resize(width, height);
             }
+            events::Event::FileChanged(path) => {
+                app.handle_file_changed(&path);
+            }
+            events::Event::FileTreeLoaded(entries) => {
+                app.handle_file_tree_loaded(entries);
+            }
         }
 
         // Check if we should quit
@@ -169,3 +310,26 @@ async fn run_app(
 
     Ok(())
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn parse(args: &[&str]) -> Result<Args> {
+        Args::parse(args.iter().map(|arg| arg.to_string()))
+    }
+
+    #[test]
+    fn test_parse_args() {
+        let args = parse(&["--env", "staging", "--run-all", "api"]).unwrap();
+        assert_eq!(args.env.as_deref(), Some("staging"));
+        assert!(args.run_all);
+        assert_eq!(args.working_dir, PathBuf::from("api"));
+
+        // A mistyped flag is not taken for the directory to browse
+        let err = parse(&["--run_all"]).err().unwrap();
+        assert_eq!(err.to_string(), "Unknown option '--run_all'");
+        assert!(parse(&["-v"]).is_err());
+        assert!(parse(&["--output"]).is_err());
+    }
+}
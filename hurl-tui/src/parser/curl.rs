@@ -0,0 +1,396 @@
+//! cURL import
+//!
+//! Converts a `curl` command line into the content of an equivalent Hurl file.
+
+use anyhow::{anyhow, bail, Result};
+
+/// curl flags that take a value, so the value is never mistaken for the URL
+const VALUE_FLAGS: &[&str] = &[
+    "-A",
+    "-b",
+    "-c",
+    "-D",
+    "-d",
+    "-e",
+    "-E",
+    "-F",
+    "-H",
+    "-K",
+    "-m",
+    "-o",
+    "-r",
+    "-T",
+    "-u",
+    "-U",
+    "-w",
+    "-x",
+    "-X",
+    "--cacert",
+    "--cert",
+    "--config",
+    "--connect-timeout",
+    "--connect-to",
+    "--cookie",
+    "--cookie-jar",
+    "--data",
+    "--data-ascii",
+    "--data-binary",
+    "--data-raw",
+    "--data-urlencode",
+    "--dump-header",
+    "--form",
+    "--header",
+    "--interface",
+    "--key",
+    "--limit-rate",
+    "--max-redirs",
+    "--max-time",
+    "--output",
+    "--proxy",
+    "--proxy-user",
+    "--range",
+    "--referer",
+    "--request",
+    "--resolve",
+    "--retry",
+    "--retry-delay",
+    "--stderr",
+    "--trace",
+    "--trace-ascii",
+    "--upload-file",
+    "--url",
+    "--user",
+    "--user-agent",
+    "--write-out",
+];
+
+/// Convert a curl command (e.g. pasted from the browser dev tools) into Hurl file content
+pub fn curl_to_hurl(command: &str) -> Result<String> {
+    let args = split_args(command)?;
+    let mut args = args.into_iter();
+    if args.next().as_deref() != Some("curl") {
+        bail!("Not a curl command");
+    }
+
+    let mut method = None;
+    let mut url = None;
+    let mut headers = Vec::new();
+    let mut data: Vec<String> = Vec::new();
+    let mut form_params = Vec::new();
+    let mut multipart = Vec::new();
+    let mut basic_auth = None;
+    let mut cookies = Vec::new();
+    let mut options = Vec::new();
+
+    while let Some(arg) = args.next() {
+        let (flag, inline_value) = split_flag(&arg);
+        let value = match inline_value {
+            Some(value) => value.to_string(),
+            None if VALUE_FLAGS.contains(&flag) => args
+                .next()
+                .ok_or_else(|| anyhow!("Missing value for {}", flag))?,
+            None => String::new(),
+        };
+        match flag {
+            "-X" | "--request" => method = Some(value.to_uppercase()),
+            "-H" | "--header" => headers.push(value),
+            "-A" | "--user-agent" => headers.push(format!("User-Agent: {}", value)),
+            "-e" | "--referer" => headers.push(format!("Referer: {}", value)),
+            "-r" | "--range" => headers.push(format!("Range: bytes={}", value)),
+            "-d" | "--data" | "--data-raw" | "--data-binary" | "--data-ascii" => data.push(value),
+            // Hurl encodes [FormParams] itself
+            "--data-urlencode" => match value.split_once('=') {
+                Some((name, value)) if !name.is_empty() => {
+                    form_params.push(format!("{}: {}", name, value))
+                }
+                _ => data.push(value),
+            },
+            "-F" | "--form" => {
+                let (name, value) = value.split_once('=').unwrap_or((&value, ""));
+                match value.strip_prefix('@') {
+                    Some(path) => multipart.push(format!("{}: file,{};", name, path)),
+                    None => multipart.push(format!("{}: {}", name, value)),
+                }
+            }
+            "-u" | "--user" => basic_auth = Some(value),
+            "-b" | "--cookie" => cookies.push(value),
+            "--url" => url = Some(value),
+            "-k" | "--insecure" => options.push("insecure: true".to_string()),
+            "-L" | "--location" => options.push("location: true".to_string()),
+            "-o" | "--output" => options.push(format!("output: {}", value)),
+            "-x" | "--proxy" => options.push(format!("proxy: {}", value)),
+            "-m" | "--max-time" => options.push(format!("max-time: {}", duration(&value))),
+            "--connect-timeout" => options.push(format!("connect-timeout: {}", duration(&value))),
+            "--retry" => options.push(format!("retry: {}", value)),
+            "--max-redirs" => options.push(format!("max-redirs: {}", value)),
+            "--limit-rate" => options.push(format!("limit-rate: {}", value)),
+            "--cacert" | "--cert" | "--key" | "--resolve" | "--connect-to" => {
+                options.push(format!("{}: {}", &flag[2..], value))
+            }
+            // Other flags don't change the request (their values were consumed above)
+            _ if flag.starts_with('-') => {}
+            _ => url = Some(arg),
+        }
+    }
+
+    let url = url.ok_or_else(|| anyhow!("No URL in curl command"))?;
+    let has_body = !data.is_empty() || !form_params.is_empty() || !multipart.is_empty();
+    let method = method.unwrap_or_else(|| if has_body { "POST" } else { "GET" }.to_string());
+
+    let mut lines = vec![format!("{} {}", method, url)];
+    lines.extend(headers);
+
+    if let Some(credentials) = basic_auth {
+        let (user, password) = credentials.split_once(':').unwrap_or((&credentials, ""));
+        lines.push("[BasicAuth]".to_string());
+        lines.push(format!("{}: {}", user, password));
+    }
+
+    let cookies: Vec<String> = cookies
+        .iter()
+        .flat_map(|c| c.split(';'))
+        .filter_map(|c| c.trim().split_once('='))
+        .map(|(name, value)| format!("{}: {}", name.trim(), value.trim()))
+        .collect();
+    if !cookies.is_empty() {
+        lines.push("[Cookies]".to_string());
+        lines.extend(cookies);
+    }
+
+    if !form_params.is_empty() {
+        lines.push("[FormParams]".to_string());
+        lines.extend(form_params);
+    }
+
+    if !multipart.is_empty() {
+        lines.push("[MultipartFormData]".to_string());
+        lines.extend(multipart);
+    }
+
+    if !options.is_empty() {
+        lines.push("[Options]".to_string());
+        lines.extend(options);
+    }
+
+    // JSON bodies are written as-is (pretty-printed), anything else as a multiline string
+    if !data.is_empty() {
+        let body = data.join("&");
+        match serde_json::from_str::<serde_json::Value>(&body) {
+            Ok(json) if json.is_object() || json.is_array() => {
+                lines.push(serde_json::to_string_pretty(&json)?);
+            }
+            _ => {
+                lines.push("```".to_string());
+                lines.push(body);
+                lines.push("```".to_string());
+            }
+        }
+    }
+
+    lines.push(String::new());
+    lines.push("HTTP *".to_string());
+    lines.push(String::new());
+    Ok(lines.join("\n"))
+}
+
+/// Split `--name=value` and `-Xvalue` into the flag and its inline value
+fn split_flag(arg: &str) -> (&str, Option<&str>) {
+    if arg.starts_with("--") {
+        return match arg.split_once('=') {
+            Some((flag, value)) => (flag, Some(value)),
+            None => (arg, None),
+        };
+    }
+    match (arg.get(..2), arg.get(2..)) {
+        (Some(flag), Some(value)) if !value.is_empty() && VALUE_FLAGS.contains(&flag) => {
+            (flag, Some(value))
+        }
+        _ => (arg, None),
+    }
+}
+
+/// curl durations are (possibly fractional) seconds
+fn duration(seconds: &str) -> String {
+    match seconds.parse::<f64>() {
+        Ok(s) if s.fract() == 0.0 => format!("{}s", s),
+        Ok(s) => format!("{}ms", (s * 1000.0).round()),
+        Err(_) => seconds.to_string(),
+    }
+}
+
+/// Split a shell command into arguments, handling quotes and `\` line continuations
+fn split_args(command: &str) -> Result<Vec<String>> {
+    let mut args = Vec::new();
+    let mut current = String::new();
+    let mut in_arg = false;
+    let mut chars = command.chars();
+
+    while let Some(c) = chars.next() {
+        match c {
+            '\'' => {
+                in_arg = true;
+                loop {
+                    match chars.next() {
+                        Some('\'') => break,
+                        Some(c) => current.push(c),
+                        None => bail!("Unterminated ' quote"),
+                    }
+                }
+            }
+            '"' => {
+                in_arg = true;
+                loop {
+                    match chars.next() {
+                        Some('"') => break,
+                        Some('\\') => match chars.next() {
+                            Some(c @ ('"' | '\\' | '$' | '`')) => current.push(c),
+                            Some(c) => {
+                                current.push('\\');
+                                current.push(c);
+                            }
+                            None => bail!("Unterminated \" quote"),
+                        },
+                        Some(c) => current.push(c),
+                        None => bail!("Unterminated \" quote"),
+                    }
+                }
+            }
+            '\\' => match chars.next() {
+                // Line continuation
+                Some('\n') | Some('\r') | None => {}
+                Some(c) => {
+                    in_arg = true;
+                    current.push(c);
+                }
+            },
+            c if c.is_whitespace() => {
+                if in_arg {
+                    args.push(std::mem::take(&mut current));
+                    in_arg = false;
+                }
+            }
+            c => {
+                in_arg = true;
+                current.push(c);
+            }
+        }
+    }
+    if in_arg {
+        args.push(current);
+    }
+
+    Ok(args)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_curl_to_hurl() {
+        let command = r#"curl -X PUT 'https://api.example.com/users/1' \
+  -H 'Content-Type: application/json' \
+  -u admin:secret -b 'session=abc; theme=dark' \
+  --data-raw '{"name":"bob"}' --compressed"#;
+
+        let hurl = curl_to_hurl(command).unwrap();
+        assert_eq!(
+            hurl,
+            "PUT https://api.example.com/users/1\n\
+             Content-Type: application/json\n\
+             [BasicAuth]\n\
+             admin: secret\n\
+             [Cookies]\n\
+             session: abc\n\
+             theme: dark\n\
+             {\n  \"name\": \"bob\"\n}\n\
+             \n\
+             HTTP *\n"
+        );
+
+        let post = curl_to_hurl("curl https://example.com -d \"a=1\"").unwrap();
+        assert!(post.starts_with("POST https://example.com\n```\na=1\n```\n"));
+        assert!(curl_to_hurl("wget https://example.com").is_err());
+    }
+
+    #[test]
+    fn test_curl_value_flags() {
+        // Values of flags that don't map to Hurl are not taken for the URL
+        let hurl = curl_to_hurl(
+            "curl -w '%{http_code}' -c jar.txt --trace-ascii out.txt https://example.com/a",
+        )
+        .unwrap();
+        assert!(hurl.starts_with("GET https://example.com/a\n"));
+
+        let hurl = curl_to_hurl(
+            "curl https://example.com -A 'agent/1.0' -e https://ref.example.com -r 0-99 \\
+             -o out.json -x http://proxy:8080 -m 2.5 --connect-timeout 10 --retry 3",
+        )
+        .unwrap();
+        assert_eq!(
+            hurl,
+            "GET https://example.com\n\
+             User-Agent: agent/1.0\n\
+             Referer: https://ref.example.com\n\
+             Range: bytes=0-99\n\
+             [Options]\n\
+             output: out.json\n\
+             proxy: http://proxy:8080\n\
+             max-time: 2500ms\n\
+             connect-timeout: 10s\n\
+             retry: 3\n\
+             \n\
+             HTTP *\n"
+        );
+
+        assert!(curl_to_hurl("curl https://example.com -o").is_err());
+    }
+
+    #[test]
+    fn test_curl_inline_values() {
+        let hurl = curl_to_hurl(
+            "curl --url=https://example.com/b --request=PATCH --header='Accept: text/plain' \\
+             --data='x=1' -XDELETE -HX-Id:7",
+        )
+        .unwrap();
+        assert_eq!(
+            hurl,
+            "DELETE https://example.com/b\n\
+             Accept: text/plain\n\
+             X-Id:7\n\
+             ```\n\
+             x=1\n\
+             ```\n\
+             \n\
+             HTTP *\n"
+        );
+    }
+
+    #[test]
+    fn test_curl_forms() {
+        let hurl = curl_to_hurl(
+            "curl https://example.com --data-urlencode 'q=hello world' --data-urlencode=lang=en",
+        )
+        .unwrap();
+        assert_eq!(
+            hurl,
+            "POST https://example.com\n\
+             [FormParams]\n\
+             q: hello world\n\
+             lang: en\n\
+             \n\
+             HTTP *\n"
+        );
+
+        let hurl = curl_to_hurl("curl https://example.com -F name=bob -F avatar=@me.png").unwrap();
+        assert_eq!(
+            hurl,
+            "POST https://example.com\n\
+             [MultipartFormData]\n\
+             name: bob\n\
+             avatar: file,me.png;\n\
+             \n\
+             HTTP *\n"
+        );
+    }
+}
@@ -2,6 +2,10 @@
 //!
 //! This module provides parsing functionality for Hurl files.
 
+mod curl;
+
+pub use curl::curl_to_hurl;
+
 use anyhow::Result;
 use regex::Regex;
 
@@ -12,6 +16,39 @@ pub struct HurlFile {
     pub entries: Vec<HurlEntry>,
     /// Raw content of the file
     pub content: String,
+    /// Problems found in request lines (the entries are still parsed)
+    pub parse_errors: Vec<ParseError>,
+}
+
+/// Problem found while parsing a Hurl file
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ParseError {
+    /// Line number (0-based)
+    pub line: usize,
+    /// Description of the problem
+    pub message: String,
+}
+
+impl HurlFile {
+    /// Index of the entry whose lines contain `line` (0-based)
+    pub fn entry_at_line(&self, line: usize) -> Option<usize> {
+        self.entries
+            .iter()
+            .position(|e| (e.line_start..=e.line_end).contains(&line))
+    }
+
+    /// `[Options]` to pass as hurl flags when running `entry` (`None` = the whole file)
+    ///
+    /// Flags apply to every entry of a run, so only the options of an entry that
+    /// runs on its own are returned; hurl reads the others per entry itself.
+    pub fn run_options(&self, entry: Option<usize>) -> Vec<(String, String)> {
+        let entry = match entry {
+            Some(index) => self.entries.get(index),
+            None if self.entries.len() == 1 => self.entries.first(),
+            None => None,
+        };
+        entry.map(|e| e.options.clone()).unwrap_or_default()
+    }
 }
 
 /// Represents a single entry (request/response pair) in a Hurl file
@@ -25,12 +62,30 @@ pub struct HurlEntry {
     pub headers: Vec<Header>,
     /// Request body (if any)
     pub body: Option<String>,
+    /// First and last line of the request body in the file
+    pub body_lines: Option<(usize, usize)>,
     /// Expected response status
     pub expected_status: Option<u16>,
     /// Assertions
     pub asserts: Vec<Assert>,
     /// Captures
     pub captures: Vec<Capture>,
+    /// Options from the `[Options]` section as key-value pairs (e.g. `retry: 3`)
+    pub options: Vec<(String, String)>,
+    /// Form parameters from the `[FormParams]` section
+    pub form_params: Vec<(String, String)>,
+    /// Query parameters from the `[QueryStringParams]` section
+    pub query_params: Vec<(String, String)>,
+    /// Request cookies from the `[Cookies]` section
+    pub cookies: Vec<(String, String)>,
+    /// Parts from the `[MultipartFormData]` section
+    pub multipart: Vec<MultipartPart>,
+    /// User and password from the `[BasicAuth]` section
+    pub basic_auth: Option<(String, String)>,
+    /// Query of a GraphQL body (`[GraphQL]` section or ```` ```graphql ```` block)
+    pub graphql_query: Option<String>,
+    /// `variables` block of a GraphQL body
+    pub graphql_variables: Option<String>,
     /// Line number where this entry starts
     pub line_start: usize,
     /// Line number where this entry ends
@@ -44,6 +99,19 @@ pub struct Header {
     pub value: String,
 }
 
+/// Part of a `[MultipartFormData]` section
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum MultipartPart {
+    /// Inline value (`name: value`)
+    Field { name: String, value: String },
+    /// File upload (`name: file,path/to/file.png; image/png`)
+    FileUpload {
+        name: String,
+        path: String,
+        content_type: Option<String>,
+    },
+}
+
 /// Assertion in Hurl file
 #[derive(Debug, Clone)]
 pub struct Assert {
@@ -73,7 +141,10 @@ pub struct Capture {
 /// Parse a Hurl file content into structured representation
 pub fn parse_hurl_file(content: &str) -> Result<HurlFile> {
     let mut entries = Vec::new();
+    let mut parse_errors = Vec::new();
     let lines: Vec<&str> = content.lines().collect();
+    let method_regex =
+        Regex::new(r"^(GET|POST|PUT|DELETE|PATCH|HEAD|OPTIONS|CONNECT|TRACE)\s+(.+)$")?;
 
     let mut i = 0;
     while i < lines.len() {
@@ -85,9 +156,22 @@ pub fn parse_hurl_file(content: &str) -> Result<HurlFile> {
         }
 
         // Try to parse a request
-        if let Some(entry) = parse_entry(&lines, &mut i) {
+        if let Some(entry) = parse_entry(&lines, &mut i, &method_regex) {
+            if let Some(message) = validate_url(&entry.url) {
+                parse_errors.push(ParseError {
+                    line: entry.line_start,
+                    message,
+                });
+            }
             entries.push(entry);
         } else {
+            // A method keyword on its own is a request line without a URL
+            if METHODS.contains(&line) {
+                parse_errors.push(ParseError {
+                    line: i,
+                    message: format!("{} request has no URL", line),
+                });
+            }
             i += 1;
         }
     }
@@ -95,17 +179,70 @@ pub fn parse_hurl_file(content: &str) -> Result<HurlFile> {
     Ok(HurlFile {
         entries,
         content: content.to_string(),
+        parse_errors,
     })
 }
 
+/// HTTP methods that start a request line
+pub const METHODS: [&str; 9] = [
+    "GET", "POST", "PUT", "DELETE", "PATCH", "HEAD", "OPTIONS", "CONNECT", "TRACE",
+];
+
+/// Check the URL of a request line, returning a message describing the problem
+///
+/// A URL must have an http(s) scheme unless it starts with a template variable.
+fn validate_url(url: &str) -> Option<String> {
+    if url.starts_with("http://") || url.starts_with("https://") || url.starts_with("{{") {
+        return None;
+    }
+    if let Some((scheme, _)) = url.split_once("://") {
+        return Some(format!("Unsupported URL scheme `{}` in `{}`", scheme, url));
+    }
+    Some(format!(
+        "URL `{}` is missing a scheme (http:// or https://)",
+        url
+    ))
+}
+
+/// Section of an entry whose lines are currently being parsed
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Section {
+    None,
+    Asserts,
+    Captures,
+    Options,
+    FormParams,
+    QueryParams,
+    Cookies,
+    Multipart,
+    BasicAuth,
+    GraphQl,
+}
+
+impl Section {
+    /// Match a section marker line (e.g. `[Asserts]`), including short aliases
+    fn from_marker(line: &str) -> Option<Self> {
+        match line {
+            "[Asserts]" => Some(Self::Asserts),
+            "[Captures]" => Some(Self::Captures),
+            "[Options]" => Some(Self::Options),
+            "[FormParams]" | "[Form]" => Some(Self::FormParams),
+            "[QueryStringParams]" | "[Query]" => Some(Self::QueryParams),
+            "[Cookies]" => Some(Self::Cookies),
+            "[MultipartFormData]" | "[Multipart]" => Some(Self::Multipart),
+            "[BasicAuth]" => Some(Self::BasicAuth),
+            "[GraphQL]" => Some(Self::GraphQl),
+            _ => None,
+        }
+    }
+}
+
 /// Parse a single entry starting at the given index
-fn parse_entry(lines: &[&str], index: &mut usize) -> Option<HurlEntry> {
+fn parse_entry(lines: &[&str], index: &mut usize, method_regex: &Regex) -> Option<HurlEntry> {
     let line_start = *index;
     let line = lines[*index].trim();
 
     // Parse method and URL
-    let method_regex =
-        Regex::new(r"^(GET|POST|PUT|DELETE|PATCH|HEAD|OPTIONS|CONNECT|TRACE)\s+(.+)$").ok()?;
     let caps = method_regex.captures(line)?;
 
     let method = caps.get(1)?.as_str().to_string();
@@ -118,10 +255,19 @@ fn parse_entry(lines: &[&str], index: &mut usize) -> Option<HurlEntry> {
     let mut expected_status = None;
     let mut asserts = Vec::new();
     let mut captures = Vec::new();
-    let mut in_asserts_section = false;
-    let mut in_captures_section = false;
+    let mut options = Vec::new();
+    let mut form_params = Vec::new();
+    let mut query_params = Vec::new();
+    let mut cookies = Vec::new();
+    let mut multipart = Vec::new();
+    let mut basic_auth = None;
+    let mut graphql_lines: Vec<&str> = Vec::new();
+    let mut section = Section::None;
     let mut body_lines: Vec<String> = Vec::new();
     let mut in_body = false;
+    let mut body_start = 0;
+    let mut body_range = None;
+    let mut json_depth = 0;
 
     // Parse headers, body, response, asserts
     while *index < lines.len() {
@@ -137,8 +283,8 @@ fn parse_entry(lines: &[&str], index: &mut usize) -> Option<HurlEntry> {
             continue;
         }
 
-        // Check if this is a new request (start of next entry)
-        if method_regex.is_match(trimmed) {
+        // Check if this is a new request (start of next entry), even one missing its URL
+        if method_regex.is_match(trimmed) || METHODS.contains(&trimmed) {
             break;
         }
 
@@ -150,64 +296,57 @@ fn parse_entry(lines: &[&str], index: &mut usize) -> Option<HurlEntry> {
                     expected_status = Some(status);
                 }
             }
-            in_asserts_section = false;
-            in_captures_section = false;
+            section = Section::None;
             *index += 1;
             continue;
         }
 
         // Section markers
-        if trimmed == "[Asserts]" {
-            in_asserts_section = true;
-            in_captures_section = false;
+        if let Some(marker) = Section::from_marker(trimmed) {
+            section = marker;
             in_body = false;
             *index += 1;
             continue;
         }
 
-        if trimmed == "[Captures]" {
-            in_captures_section = true;
-            in_asserts_section = false;
-            in_body = false;
+        // GraphQL query and variables (may contain `{` lines, so before the body markers)
+        if section == Section::GraphQl {
+            graphql_lines.push(line.trim_end());
             *index += 1;
             continue;
         }
 
-        if trimmed == "[Options]"
-            || trimmed == "[QueryStringParams]"
-            || trimmed == "[FormParams]"
-            || trimmed == "[MultipartFormData]"
-            || trimmed == "[Cookies]"
-            || trimmed == "[BasicAuth]"
-        {
-            in_asserts_section = false;
-            in_captures_section = false;
-            in_body = false;
-            *index += 1;
-            continue;
-        }
-
-        // Body markers
-        if trimmed.starts_with("```") || trimmed.starts_with("{") || trimmed.starts_with("[") {
+        // Body markers: a ``` body ends at the next ``` line, a JSON body once its brackets close
+        let opens_body = !in_body
+            && (trimmed.starts_with("```") || trimmed.starts_with("{") || trimmed.starts_with("["));
+        if opens_body {
             in_body = true;
-            body_lines.push(trimmed.to_string());
-            *index += 1;
-            continue;
+            body_start = *index;
+            json_depth = 0;
         }
 
         if in_body {
             body_lines.push(trimmed.to_string());
-            if trimmed.starts_with("```") || trimmed == "}" || trimmed == "]" {
+            let closed = if body_lines[0].starts_with("```") {
+                !opens_body && trimmed.starts_with("```")
+            } else {
+                json_depth += json_depth_change(trimmed);
+                json_depth <= 0
+            };
+            if closed {
                 in_body = false;
                 body = Some(body_lines.join("\n"));
                 body_lines.clear();
+                if expected_status.is_none() {
+                    body_range = Some((body_start, *index));
+                }
             }
             *index += 1;
             continue;
         }
 
         // Parse assertions
-        if in_asserts_section {
+        if section == Section::Asserts {
             if let Some(assert) = parse_assert(trimmed, *index) {
                 asserts.push(assert);
             }
@@ -216,7 +355,7 @@ fn parse_entry(lines: &[&str], index: &mut usize) -> Option<HurlEntry> {
         }
 
         // Parse captures
-        if in_captures_section {
+        if section == Section::Captures {
             if let Some(capture) = parse_capture(trimmed, *index) {
                 captures.push(capture);
             }
@@ -224,6 +363,40 @@ fn parse_entry(lines: &[&str], index: &mut usize) -> Option<HurlEntry> {
             continue;
         }
 
+        // Parse multipart fields and file uploads
+        if section == Section::Multipart {
+            if let Some(part) = parse_multipart_part(trimmed) {
+                multipart.push(part);
+            }
+            *index += 1;
+            continue;
+        }
+
+        // Parse basic auth credentials (`user: password`)
+        if section == Section::BasicAuth {
+            if let Some(credentials) = parse_key_value(trimmed) {
+                basic_auth = Some(credentials);
+            }
+            *index += 1;
+            continue;
+        }
+
+        // Parse key-value sections
+        let pairs = match section {
+            Section::Options => Some(&mut options),
+            Section::FormParams => Some(&mut form_params),
+            Section::QueryParams => Some(&mut query_params),
+            Section::Cookies => Some(&mut cookies),
+            _ => None,
+        };
+        if let Some(pairs) = pairs {
+            if let Some(pair) = parse_key_value(trimmed) {
+                pairs.push(pair);
+            }
+            *index += 1;
+            continue;
+        }
+
         // Parse headers (before response)
         if expected_status.is_none() && trimmed.contains(':') && !trimmed.starts_with('#') {
             if let Some((name, value)) = trimmed.split_once(':') {
@@ -239,19 +412,118 @@ fn parse_entry(lines: &[&str], index: &mut usize) -> Option<HurlEntry> {
 
     let line_end = index.saturating_sub(1);
 
+    // A ```graphql body holds the same query/variables as a [GraphQL] section
+    if graphql_lines.is_empty() {
+        if let Some(inner) = body
+            .as_deref()
+            .and_then(|body| body.strip_prefix("```graphql\n"))
+            .and_then(|body| body.strip_suffix("```"))
+        {
+            graphql_lines = inner.lines().collect();
+        }
+    }
+    let (graphql_query, graphql_variables) = split_graphql(&graphql_lines);
+
     Some(HurlEntry {
         method,
         url,
         headers,
         body,
+        body_lines: body_range,
         expected_status,
         asserts,
         captures,
+        options,
+        form_params,
+        query_params,
+        cookies,
+        multipart,
+        basic_auth,
+        graphql_query,
+        graphql_variables,
         line_start,
         line_end,
     })
 }
 
+/// Change in `{` / `[` nesting over a JSON body line, ignoring brackets inside strings
+fn json_depth_change(line: &str) -> i32 {
+    let mut depth = 0;
+    let mut in_string = false;
+    let mut escaped = false;
+    for c in line.chars() {
+        if escaped {
+            escaped = false;
+            continue;
+        }
+        match c {
+            '\\' if in_string => escaped = true,
+            '"' => in_string = !in_string,
+            '{' | '[' if !in_string => depth += 1,
+            '}' | ']' if !in_string => depth -= 1,
+            _ => {}
+        }
+    }
+    depth
+}
+
+/// Split GraphQL body lines into the query and the `variables { ... }` block (without the keyword)
+fn split_graphql(lines: &[&str]) -> (Option<String>, Option<String>) {
+    if lines.is_empty() {
+        return (None, None);
+    }
+
+    let variables_start = lines
+        .iter()
+        .position(|line| line.trim_start().starts_with("variables"));
+    let (query, variables) = lines.split_at(variables_start.unwrap_or(lines.len()));
+
+    let query = query.join("\n").trim().to_string();
+    let variables = variables_start.map(|_| {
+        variables
+            .join("\n")
+            .trim()
+            .trim_start_matches("variables")
+            .trim()
+            .to_string()
+    });
+
+    ((!query.is_empty()).then_some(query), variables)
+}
+
+/// Parse a `key: value` section line (e.g. `retry: 3`) into a key-value pair
+fn parse_key_value(line: &str) -> Option<(String, String)> {
+    let trimmed = line.trim();
+    if trimmed.is_empty() || trimmed.starts_with('#') {
+        return None;
+    }
+
+    let (key, value) = trimmed.split_once(':')?;
+    let key = key.trim();
+    if key.is_empty() {
+        return None;
+    }
+
+    Some((key.to_string(), value.trim().to_string()))
+}
+
+/// Parse a `[MultipartFormData]` line, either `name: value` or `name: file,path; content-type`
+fn parse_multipart_part(line: &str) -> Option<MultipartPart> {
+    let (name, value) = parse_key_value(line)?;
+
+    let Some(file) = value.strip_prefix("file,") else {
+        return Some(MultipartPart::Field { name, value });
+    };
+
+    let (path, content_type) = file.split_once(';').unwrap_or((file, ""));
+    let content_type = content_type.trim();
+    Some(MultipartPart::FileUpload {
+        name,
+        path: path.trim().to_string(),
+        content_type: (!content_type.is_empty()).then(|| content_type.to_string()),
+    })
+}
+
 /// Parse an assertion line
 fn parse_assert(line: &str, line_num: usize) -> Option<Assert> {
     let trimmed = line.trim();
@@ -367,6 +639,32 @@ HTTP 200
         assert_eq!(hurl_file.entries[0].expected_status, Some(200));
     }
 
+    #[test]
+    fn test_run_options_only_for_a_single_entry() {
+        let content = r#"
+GET https://api.example.com/a
+[Options]
+insecure: true
+
+GET https://api.example.com/b
+[Options]
+retry: 3
+"#;
+        let hurl_file = parse_hurl_file(content).unwrap();
+        assert!(hurl_file.run_options(None).is_empty());
+        assert_eq!(
+            hurl_file.run_options(Some(1)),
+            vec![("retry".to_string(), "3".to_string())]
+        );
+        assert!(hurl_file.run_options(Some(5)).is_empty());
+
+        let single = parse_hurl_file("GET https://a.com\n[Options]\nretry: 2\n").unwrap();
+        assert_eq!(
+            single.run_options(None),
+            vec![("retry".to_string(), "2".to_string())]
+        );
+    }
+
     #[test]
     fn test_parse_request_with_asserts() {
         let content = r#"
@@ -382,4 +680,214 @@ jsonpath "$.users[0].id" exists
         assert_eq!(hurl_file.entries.len(), 1);
         assert_eq!(hurl_file.entries[0].asserts.len(), 2);
     }
+
+    #[test]
+    fn test_parse_request_with_options() {
+        let content = r#"
+GET https://api.example.com/users
+Accept: application/json
+[Options]
+retry: 3
+insecure: true
+# comment
+max-redirs: 5
+
+HTTP 200
+"#;
+        let hurl_file = parse_hurl_file(content).unwrap();
+        let entry = &hurl_file.entries[0];
+        assert_eq!(entry.headers.len(), 1);
+        assert_eq!(
+            entry.options,
+            vec![
+                ("retry".to_string(), "3".to_string()),
+                ("insecure".to_string(), "true".to_string()),
+                ("max-redirs".to_string(), "5".to_string()),
+            ]
+        );
+        assert_eq!(entry.expected_status, Some(200));
+    }
+
+    #[test]
+    fn test_parse_request_with_params_and_cookies() {
+        let content = r#"
+POST https://api.example.com/login
+[QueryStringParams]
+lang: en
+[FormParams]
+user: bob
+password: {{password}}
+[Cookies]
+session: abc123
+[BasicAuth]
+admin: s3cr3t:pass
+
+HTTP 200
+"#;
+        let hurl_file = parse_hurl_file(content).unwrap();
+        let entry = &hurl_file.entries[0];
+        assert_eq!(
+            entry.query_params,
+            vec![("lang".to_string(), "en".to_string())]
+        );
+        assert_eq!(entry.form_params.len(), 2);
+        assert_eq!(entry.form_params[1].1, "{{password}}");
+        assert_eq!(
+            entry.cookies,
+            vec![("session".to_string(), "abc123".to_string())]
+        );
+        assert_eq!(
+            entry.basic_auth,
+            Some(("admin".to_string(), "s3cr3t:pass".to_string()))
+        );
+        assert!(entry.headers.is_empty());
+    }
+
+    #[test]
+    fn test_parse_multipart_form_data() {
+        let content = r#"
+POST https://api.example.com/upload
+[MultipartFormData]
+field1: value1
+file1: file,data/example.txt;
+file2: file,image.png; image/png
+
+HTTP 200
+"#;
+        let hurl_file = parse_hurl_file(content).unwrap();
+        let entry = &hurl_file.entries[0];
+        assert_eq!(
+            entry.multipart,
+            vec![
+                MultipartPart::Field {
+                    name: "field1".to_string(),
+                    value: "value1".to_string(),
+                },
+                MultipartPart::FileUpload {
+                    name: "file1".to_string(),
+                    path: "data/example.txt".to_string(),
+                    content_type: None,
+                },
+                MultipartPart::FileUpload {
+                    name: "file2".to_string(),
+                    path: "image.png".to_string(),
+                    content_type: Some("image/png".to_string()),
+                },
+            ]
+        );
+        assert!(entry.headers.is_empty());
+    }
+
+    #[test]
+    fn test_parse_graphql() {
+        let content = r#"
+POST https://api.example.com/graphql
+[GraphQL]
+{
+  user(id: $id) {
+    name
+  }
+}
+variables {
+  "id": 1
+}
+
+HTTP 200
+
+POST https://api.example.com/graphql
+```graphql
+{ me { id } }
+```
+"#;
+        let hurl_file = parse_hurl_file(content).unwrap();
+        let entry = &hurl_file.entries[0];
+        assert_eq!(
+            entry.graphql_query.as_deref(),
+            Some("{\n  user(id: $id) {\n    name\n  }\n}")
+        );
+        assert_eq!(
+            entry.graphql_variables.as_deref(),
+            Some("{\n  \"id\": 1\n}")
+        );
+        assert!(entry.body.is_none());
+        assert_eq!(entry.expected_status, Some(200));
+
+        let entry = &hurl_file.entries[1];
+        assert_eq!(entry.graphql_query.as_deref(), Some("{ me { id } }"));
+        assert!(entry.graphql_variables.is_none());
+    }
+
+    #[test]
+    fn test_parse_body_lines() {
+        let content = r#"POST https://api.example.com/users
+{
+  "name": "{{name}}",
+  "tags": ["a", "b]"],
+  "address": {
+    "city": "Paris"
+  }
+}
+HTTP 201
+
+PUT https://api.example.com/users/1
+{"name": "bob"}
+X-Trace: 1
+
+POST https://api.example.com/upload
+```
+raw
+```
+
+GET https://api.example.com/users
+HTTP 200
+{
+  "users": []
+}
+"#;
+        let hurl_file = parse_hurl_file(content).unwrap();
+        let ranges: Vec<_> = hurl_file.entries.iter().map(|e| e.body_lines).collect();
+        assert_eq!(
+            ranges,
+            vec![Some((1, 7)), Some((11, 11)), Some((15, 17)), None]
+        );
+        assert!(hurl_file.entries[0]
+            .body
+            .as_deref()
+            .unwrap()
+            .ends_with("\"city\": \"Paris\"\n}\n}"));
+        assert_eq!(hurl_file.entries[1].headers.len(), 1);
+    }
+
+    #[test]
+    fn test_json_depth_change() {
+        assert_eq!(json_depth_change("{"), 1);
+        assert_eq!(json_depth_change(r#""a": [1, {"b": 2}],"#), 0);
+        assert_eq!(json_depth_change(r#""a": "}\"]","#), 0);
+        assert_eq!(json_depth_change("}]"), -2);
+    }
+
+    #[test]
+    fn test_entry_at_line() {
+        let content = "# users\nGET https://api.example.com/users\nHTTP 200\n\nPOST https://api.example.com/users\nHTTP 201\n";
+        let hurl_file = parse_hurl_file(content).unwrap();
+        assert_eq!(hurl_file.entry_at_line(1), Some(0));
+        assert_eq!(hurl_file.entry_at_line(2), Some(0));
+        assert_eq!(hurl_file.entry_at_line(4), Some(1));
+        assert_eq!(hurl_file.entry_at_line(5), Some(1));
+        assert_eq!(hurl_file.entry_at_line(0), None);
+    }
+
+    #[test]
+    fn test_parse_errors_for_invalid_urls() {
+        let content = "GET api.example.com/users\n\nGET {{base_url}}/users\n\nPOST\n\nGET ftp://example.com\n";
+        let hurl_file = parse_hurl_file(content).unwrap();
+        // Entries with a bad URL are still parsed
+        assert_eq!(hurl_file.entries.len(), 3);
+        let lines: Vec<usize> = hurl_file.parse_errors.iter().map(|e| e.line).collect();
+        assert_eq!(lines, vec![0, 4, 6]);
+        assert!(hurl_file.parse_errors[0]
+            .message
+            .contains("missing a scheme"));
+        assert_eq!(hurl_file.parse_errors[1].message, "POST request has no URL");
+    }
 }
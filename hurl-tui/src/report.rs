@@ -0,0 +1,206 @@
+//! Run-all report
+//!
+//! Runs every .hurl file under the working directory without the TUI and prints
+//! a summary table, so hurl-tui can be used as a test runner in CI (`--run-all`).
+//! `--dry-run` only validates the files.
+
+use anyhow::Result;
+use crossterm::style::Stylize;
+use std::collections::HashMap;
+use std::path::{Path, PathBuf};
+
+use crate::app::{collect_hurl_files, IgnorePatterns, COOKIE_JAR_FILE};
+use crate::config::Config;
+use crate::runner::Runner;
+
+/// Run all .hurl files in `working_dir` and print the results.
+/// Returns whether every file passed.
+pub async fn run_all(config: &Config, working_dir: &Path) -> Result<bool> {
+    let runner = configured_runner(config, working_dir).await;
+
+    let files = collect_hurl_files(working_dir, &IgnorePatterns::load(working_dir));
+    if files.is_empty() {
+        println!("No .hurl files found in {}", working_dir.display());
+        return Ok(true);
+    }
+
+    let (names, width) = display_names(&files, working_dir);
+
+    println!(
+        "{}",
+        format!(
+            "{:<width$}  {:>6}  {:>9}  RESULT",
+            "FILE", "STATUS", "DURATION"
+        )
+        .bold()
+    );
+
+    let mut passed = 0;
+    for (path, name) in files.iter().zip(&names) {
+        // [Options] are read per file, as in a collection run from the TUI; a file with
+        // several entries keeps them per request instead of applying one entry's to all
+        let options = std::fs::read_to_string(path)
+            .ok()
+            .and_then(|content| crate::parser::parse_hurl_file(&content).ok())
+            .map(|file| file.run_options(None))
+            .unwrap_or_default();
+
+        let result = runner.run(path, None, &options).await;
+        let (status, duration, success) = match &result {
+            Ok(result) => match &result.response {
+                Some(response) => (
+                    response.status_code.to_string(),
+                    format!("{}ms", response.duration_ms),
+                    result.success,
+                ),
+                None => ("---".to_string(), "---".to_string(), result.success),
+            },
+            Err(_) => ("error".to_string(), "---".to_string(), false),
+        };
+        if success {
+            passed += 1;
+        }
+
+        let status = format!("{:>6}", status);
+        let status = match status.trim().parse::<u16>() {
+            Ok(200..=299) => status.green(),
+            Ok(300..=399) => status.cyan(),
+            Ok(400..=499) => status.yellow(),
+            Ok(_) => status.red(),
+            Err(_) => status.dark_grey(),
+        };
+        let verdict = if success {
+            "PASS".green()
+        } else {
+            "FAIL".red()
+        };
+        println!(
+            "{:<width$}  {}  {:>9}  {}",
+            name,
+            status,
+            duration,
+            verdict.bold()
+        );
+
+        if let Err(e) = result {
+            println!("  {}", e.to_string().red());
+        }
+    }
+
+    let total = files.len();
+    let summary = format!("{}/{} passed", passed, total);
+    println!();
+    if passed == total {
+        println!("{}", summary.green().bold());
+    } else {
+        println!("{}", summary.red().bold());
+    }
+
+    Ok(passed == total)
+}
+
+/// Parse and `hurl --dry-run` every .hurl file in `working_dir` without sending requests.
+/// Returns whether every file is valid.
+pub async fn dry_run(config: &Config, working_dir: &Path) -> Result<bool> {
+    let runner = configured_runner(config, working_dir).await;
+
+    let files = collect_hurl_files(working_dir, &IgnorePatterns::load(working_dir));
+    if files.is_empty() {
+        println!("No .hurl files found in {}", working_dir.display());
+        return Ok(true);
+    }
+
+    let (names, width) = display_names(&files, working_dir);
+    println!("{}", format!("{:<width$}  RESULT", "FILE").bold());
+
+    let mut valid = 0;
+    for (path, name) in files.iter().zip(&names) {
+        // Our own parser first, then hurl's
+        let error = match std::fs::read_to_string(path) {
+            Ok(content) => match crate::parser::parse_hurl_file(&content) {
+                Ok(_) => match runner.check_syntax(path, None, &HashMap::new()).await {
+                    Ok(None) => None,
+                    Ok(Some(error)) if error.lines.is_empty() => {
+                        Some(format!("hurl: {}", error.message))
+                    }
+                    Ok(Some(error)) => Some(format!(
+                        "hurl: {} (line {})",
+                        error.message,
+                        error
+                            .lines
+                            .iter()
+                            .map(|line| (line + 1).to_string())
+                            .collect::<Vec<_>>()
+                            .join(", ")
+                    )),
+                    Err(e) => Some(format!("hurl: {}", e)),
+                },
+                Err(e) => Some(format!("parse: {}", e)),
+            },
+            Err(e) => Some(format!("read: {}", e)),
+        };
+
+        match error {
+            None => {
+                valid += 1;
+                println!("{:<width$}  {}", name, "OK".green().bold());
+            }
+            Some(error) => println!("{:<width$}  {}", name, error.red()),
+        }
+    }
+
+    let total = files.len();
+    let summary = format!("{}/{} valid", valid, total);
+    println!();
+    if valid == total {
+        println!("{}", summary.green().bold());
+    } else {
+        println!("{}", summary.red().bold());
+    }
+
+    Ok(valid == total)
+}
+
+/// Runner with the settings of `config` for the hurl version found in PATH
+async fn configured_runner(config: &Config, working_dir: &Path) -> Runner {
+    let mut runner = Runner::new()
+        .with_retry(config.general.retry_count, config.general.retry_delay_ms)
+        .with_proxy(
+            config.general.proxy.clone(),
+            config.general.no_proxy.clone(),
+        )
+        .with_script_dir(
+            config
+                .general
+                .script_dir
+                .as_ref()
+                .map(|dir| working_dir.join(dir)),
+        )
+        .with_max_body_bytes(config.general.max_body_display_bytes)
+        .with_insecure(config.general.insecure);
+    if config.general.use_cookie_jar {
+        runner = runner.with_cookie_jar(working_dir.join(COOKIE_JAR_FILE));
+    }
+    let hurl_version = runner.check_hurl_version().await.ok();
+    runner.with_hurl_version(hurl_version)
+}
+
+/// File paths relative to `working_dir`, and the width of the widest one (at least 4)
+fn display_names(files: &[PathBuf], working_dir: &Path) -> (Vec<String>, usize) {
+    let names: Vec<String> = files
+        .iter()
+        .map(|path| {
+            path.strip_prefix(working_dir)
+                .unwrap_or(path)
+                .display()
+                .to_string()
+        })
+        .collect();
+    let width = names
+        .iter()
+        .map(|name| name.chars().count())
+        .max()
+        .unwrap_or(0)
+        .max(4);
+    (names, width)
+}
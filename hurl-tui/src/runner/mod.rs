@@ -2,26 +2,138 @@
 //!
 //! This module handles executing Hurl files and capturing results.
 
+#[cfg(feature = "lua-scripts")]
+mod scripts;
+
 use anyhow::{Context, Result};
 use serde::{Deserialize, Serialize};
-use std::path::PathBuf;
+use std::collections::HashMap;
+use std::path::{Path, PathBuf};
 use std::process::Stdio;
 use tokio::process::Command;
 
+/// `[Options]` keys that map to boolean hurl flags (e.g. `insecure: true` -> `--insecure`)
+const BOOLEAN_OPTIONS: &[&str] = &[
+    "compressed",
+    "http1.0",
+    "http1.1",
+    "http2",
+    "http3",
+    "insecure",
+    "ipv4",
+    "ipv6",
+    "location",
+    "location-trusted",
+    "netrc",
+    "netrc-optional",
+    "path-as-is",
+    "skip",
+    "verbose",
+    "very-verbose",
+];
+
+/// `[Options]` keys that map to hurl flags taking a value (e.g. `retry: 3` -> `--retry 3`)
+const VALUE_OPTIONS: &[&str] = &[
+    "aws-sigv4",
+    "cacert",
+    "cert",
+    "connect-timeout",
+    "connect-to",
+    "delay",
+    "key",
+    "limit-rate",
+    "max-redirs",
+    "netrc-file",
+    "proxy",
+    "repeat",
+    "resolve",
+    "retry",
+    "retry-interval",
+    "unix-socket",
+    "user",
+    "variable",
+];
+
+/// Check whether an `[Options]` key can be translated into a hurl CLI flag
+pub fn is_known_option(name: &str) -> bool {
+    BOOLEAN_OPTIONS.contains(&name) || VALUE_OPTIONS.contains(&name)
+}
+
+/// Translate an `[Options]` key-value pair into hurl CLI arguments.
+///
+/// Returns `None` for unknown keys, which must not be passed to hurl.
+/// Boolean options set to anything other than `true` produce no arguments.
+pub fn option_args(name: &str, value: &str) -> Option<Vec<String>> {
+    if BOOLEAN_OPTIONS.contains(&name) {
+        if value == "true" {
+            Some(vec![format!("--{}", name)])
+        } else {
+            Some(Vec::new())
+        }
+    } else if VALUE_OPTIONS.contains(&name) {
+        Some(vec![format!("--{}", name), value.to_string()])
+    } else {
+        None
+    }
+}
+
 /// Hurl runner that executes .hurl files
+#[derive(Debug, Clone)]
 pub struct Runner {
     /// Path to hurl binary (None = use PATH)
     hurl_path: Option<PathBuf>,
     /// Default timeout in seconds
     timeout: u64,
+    /// Number of retries after a connection-level error
+    retry_count: u32,
+    /// Delay between retries in milliseconds
+    retry_delay_ms: u64,
+    /// Cookie jar file read before and written after every run
+    cookie_jar: Option<PathBuf>,
+    /// Proxy passed as `--proxy`
+    proxy: Option<String>,
+    /// Hosts that bypass the proxy, passed as `--no-proxy`
+    no_proxy: Option<String>,
+    /// Skip TLS certificate verification (`--insecure`)
+    insecure: bool,
+    /// Directory of Lua assertion scripts run after each file (`lua-scripts` feature)
+    script_dir: Option<PathBuf>,
+    /// Response bodies longer than this are cut to this many bytes
+    max_body_bytes: usize,
+    /// Version of the hurl binary (None = unknown, every flag is tried)
+    hurl_version: Option<HurlVersion>,
+    /// Receives the number of each retry before it starts
+    retry_tx: Option<tokio::sync::mpsc::UnboundedSender<u32>>,
 }
 
+/// Hurl version as (major, minor, patch)
+pub type HurlVersion = (u32, u32, u32);
+
+/// First hurl version with `--json`
+const JSON_MIN_VERSION: HurlVersion = (3, 0, 0);
+
+/// First hurl version with `--to-entry`
+const TO_ENTRY_MIN_VERSION: HurlVersion = (1, 7, 0);
+
+/// First hurl version with `--from-entry`
+const FROM_ENTRY_MIN_VERSION: HurlVersion = (4, 1, 0);
+
 impl Runner {
     /// Create a new runner instance
     pub fn new() -> Self {
         Self {
             hurl_path: None,
             timeout: 30,
+            retry_count: 0,
+            retry_delay_ms: 1000,
+            cookie_jar: None,
+            proxy: None,
+            no_proxy: None,
+            insecure: false,
+            script_dir: None,
+            max_body_bytes: 512 * 1024,
+            hurl_version: None,
+            retry_tx: None,
         }
     }
 
@@ -37,75 +149,523 @@ impl Runner {
         self
     }
 
+    /// Retry up to `count` times, `delay_ms` apart, when hurl fails to connect
+    pub fn with_retry(mut self, count: u32, delay_ms: u64) -> Self {
+        self.retry_count = count;
+        self.retry_delay_ms = delay_ms;
+        self
+    }
+
+    /// Report each retry (1, 2, ...) through `tx` before it starts
+    pub fn with_retry_progress(mut self, tx: tokio::sync::mpsc::UnboundedSender<u32>) -> Self {
+        self.retry_tx = Some(tx);
+        self
+    }
+
+    /// Share cookies between runs through a Netscape cookie jar file
+    pub fn with_cookie_jar(mut self, path: PathBuf) -> Self {
+        self.cookie_jar = Some(path);
+        self
+    }
+
+    /// Send requests through a proxy, except for the `no_proxy` hosts
+    pub fn with_proxy(mut self, proxy: Option<String>, no_proxy: Option<String>) -> Self {
+        self.proxy = proxy;
+        self.no_proxy = no_proxy;
+        self
+    }
+
+    /// Change the proxy used by later runs
+    pub fn set_proxy(&mut self, proxy: Option<String>) {
+        self.proxy = proxy;
+    }
+
+    /// Skip TLS certificate verification (for self-signed certificates)
+    pub fn with_insecure(mut self, insecure: bool) -> Self {
+        self.insecure = insecure;
+        self
+    }
+
+    /// Turn TLS certificate verification off or on for later runs
+    pub fn set_insecure(&mut self, insecure: bool) {
+        self.insecure = insecure;
+    }
+
+    /// Run the Lua scripts in `dir` that match the hurl file name after each run
+    pub fn with_script_dir(mut self, dir: Option<PathBuf>) -> Self {
+        self.script_dir = dir;
+        self
+    }
+
+    /// Cut response bodies longer than `bytes` (the original size is kept in the response)
+    pub fn with_max_body_bytes(mut self, bytes: usize) -> Self {
+        self.max_body_bytes = bytes;
+        self
+    }
+
+    /// Only pass the flags supported by this hurl version
+    pub fn with_hurl_version(mut self, version: Option<HurlVersion>) -> Self {
+        self.hurl_version = version;
+        self
+    }
+
+    /// Run `hurl --version` and parse the version it reports (e.g. `hurl 4.3.0`)
+    pub async fn check_hurl_version(&self) -> Result<HurlVersion> {
+        let output = Command::new(self.hurl_program())
+            .arg("--version")
+            .stdin(Stdio::null())
+            .output()
+            .await
+            .context("hurl not found in PATH")?;
+        let stdout = String::from_utf8_lossy(&output.stdout);
+        parse_hurl_version(&stdout)
+            .with_context(|| format!("Unexpected hurl --version output: {}", stdout.trim()))
+    }
+
+    /// Whether the hurl binary is at least `version` (assumed when unknown)
+    fn supports(&self, version: HurlVersion) -> bool {
+        self.hurl_version.is_none_or(|found| found >= version)
+    }
+
+    /// Maximum number of retries after a connection-level error
+    pub fn retry_count(&self) -> u32 {
+        self.retry_count
+    }
+
     /// Run a hurl file and return the execution result
+    ///
+    /// `options` are the `[Options]` key-value pairs from the file; known keys are
+    /// translated into hurl CLI flags, unknown keys are ignored.
     pub async fn run(
         &self,
         file_path: &PathBuf,
         variables_file: Option<&PathBuf>,
+        options: &[(String, String)],
+    ) -> Result<ExecutionResult> {
+        self.run_with_variables(file_path, variables_file, options, &HashMap::new())
+            .await
+    }
+
+    /// Run a hurl file with variables set on the command line (`--variable key=value`)
+    ///
+    /// Inline variables take precedence over the ones from `variables_file`.
+    pub async fn run_with_variables(
+        &self,
+        file_path: &PathBuf,
+        variables_file: Option<&PathBuf>,
+        options: &[(String, String)],
+        inline_vars: &HashMap<String, String>,
     ) -> Result<ExecutionResult> {
-        self.run_with_output(file_path, variables_file, None).await
+        self.run_with_output(file_path, variables_file, options, inline_vars, None)
+            .await
     }
 
     /// Run a hurl file with optional output file and return the execution result
     ///
     /// If `output_file` is provided, hurl will write the response body to that file
-    /// using the `--output` flag.
+    /// using the `--output` flag. `inline_vars` are passed like in `run_with_variables`.
     pub async fn run_with_output(
         &self,
         file_path: &PathBuf,
         variables_file: Option<&PathBuf>,
+        options: &[(String, String)],
+        inline_vars: &HashMap<String, String>,
         output_file: Option<&PathBuf>,
     ) -> Result<ExecutionResult> {
-        let hurl_cmd = self
-            .hurl_path
-            .as_ref()
-            .map(|p| p.to_string_lossy().to_string())
-            .unwrap_or_else(|| "hurl".to_string());
+        // Translate [Options] into CLI flags (unknown keys are skipped)
+        let mut args: Vec<String> = options
+            .iter()
+            .filter_map(|(name, value)| option_args(name, value))
+            .flatten()
+            .collect();
+        args.extend(variable_args(inline_vars));
 
-        // First run: with --very-verbose to get body and details
-        let mut cmd = Command::new(&hurl_cmd);
-        cmd.arg(file_path);
-        cmd.arg("--very-verbose");
-        cmd.arg("--max-time");
-        cmd.arg(self.timeout.to_string());
+        // Add --output flag if output file is specified
+        if let Some(out_file) = output_file {
+            args.push("--output".to_string());
+            args.push(out_file.to_string_lossy().to_string());
+        }
 
-        if let Some(vars_file) = variables_file {
-            cmd.arg("--variables-file");
-            cmd.arg(vars_file);
+        self.execute(file_path, variables_file, &args).await
+    }
+
+    /// Run a single entry of a hurl file (`entry_index` is 0-based)
+    ///
+    /// `options` are the `[Options]` of that entry, passed as flags like in `run`.
+    pub async fn run_entry(
+        &self,
+        file_path: &PathBuf,
+        entry_index: usize,
+        vars: Option<&PathBuf>,
+        options: &[(String, String)],
+        inline_vars: &HashMap<String, String>,
+    ) -> Result<ExecutionResult> {
+        if !self.supports(TO_ENTRY_MIN_VERSION) {
+            anyhow::bail!("Running a single entry needs hurl 1.7 or later");
+        }
+        let entry = (entry_index + 1).to_string();
+        let mut args: Vec<String> = options
+            .iter()
+            .filter_map(|(name, value)| option_args(name, value))
+            .flatten()
+            .collect();
+        // Without --from-entry the entries before this one run too
+        if self.supports(FROM_ENTRY_MIN_VERSION) {
+            args.push("--from-entry".to_string());
+            args.push(entry.clone());
         }
+        args.push("--to-entry".to_string());
+        args.push(entry);
+        args.extend(variable_args(inline_vars));
 
-        // Add --output flag if output file is specified
-        if let Some(out_file) = output_file {
-            cmd.arg("--output");
-            cmd.arg(out_file);
+        self.execute(file_path, vars, &args).await
+    }
+
+    /// Execute hurl with extra CLI arguments, retrying on connection errors
+    ///
+    /// Assertion failures are never retried. When all attempts fail, the stderr of
+    /// every attempt is kept in the result.
+    async fn execute(
+        &self,
+        file_path: &PathBuf,
+        variables_file: Option<&PathBuf>,
+        extra_args: &[String],
+    ) -> Result<ExecutionResult> {
+        let mut stderrs = Vec::new();
+        let mut attempt = 1;
+
+        let mut result = loop {
+            let mut result = self
+                .execute_once(file_path, variables_file, extra_args)
+                .await?;
+            result.attempts = attempt;
+
+            let connection_failed = !result.success && Self::is_connection_error(&result.stderr);
+            if connection_failed {
+                stderrs.push(format!("--- attempt {} ---\n{}", attempt, result.stderr));
+            }
+            if !self.should_retry(&result, attempt) {
+                if connection_failed && attempt > 1 {
+                    result.stderr = stderrs.join("\n");
+                }
+                break result;
+            }
+
+            tracing::debug!(
+                "Connection error, retrying ({}/{})",
+                attempt,
+                self.retry_count
+            );
+            if let Some(tx) = &self.retry_tx {
+                let _ = tx.send(attempt);
+            }
+            attempt += 1;
+            tokio::time::sleep(std::time::Duration::from_millis(self.retry_delay_ms)).await;
+        };
+
+        self.apply_scripts(file_path, &mut result);
+        if let Some(response) = result.response.as_mut() {
+            truncate_body(response, self.max_body_bytes);
+        }
+        Ok(result)
+    }
+
+    /// Add the assertions of the matching Lua scripts; any failure fails the run
+    #[cfg(feature = "lua-scripts")]
+    fn apply_scripts(&self, file_path: &Path, result: &mut ExecutionResult) {
+        let Some(dir) = &self.script_dir else {
+            return;
+        };
+        let assertions = scripts::run_scripts(dir, file_path, result);
+        if assertions.iter().any(|a| !a.success) {
+            result.success = false;
         }
+        result.assertions.extend(assertions);
+    }
 
-        cmd.stdout(Stdio::piped());
-        cmd.stderr(Stdio::piped());
+    #[cfg(not(feature = "lua-scripts"))]
+    fn apply_scripts(&self, _file_path: &Path, _result: &mut ExecutionResult) {
+        if self.script_dir.is_some() {
+            tracing::warn!(
+                "script_dir is set but hurl-tui was built without the lua-scripts feature"
+            );
+        }
+    }
 
-        let output = cmd
+    /// Whether to run hurl again after `attempt` (1-based) produced `result`
+    fn should_retry(&self, result: &ExecutionResult, attempt: u32) -> bool {
+        !result.success && Self::is_connection_error(&result.stderr) && attempt <= self.retry_count
+    }
+
+    /// Check whether hurl failed before getting a response (connection refused, DNS, ...)
+    fn is_connection_error(stderr: &str) -> bool {
+        stderr.contains("error: connect")
+            || stderr.contains("error: HTTP connection")
+            || stderr.contains("Failed to connect")
+    }
+
+    /// Run a single hurl invocation and collect the result
+    async fn execute_once(
+        &self,
+        file_path: &PathBuf,
+        variables_file: Option<&PathBuf>,
+        extra_args: &[String],
+    ) -> Result<ExecutionResult> {
+        // Run with --json for a structured report; --very-verbose keeps the body in stderr
+        let mut output = self
+            .build_command(
+                file_path,
+                variables_file,
+                extra_args,
+                self.supports(JSON_MIN_VERSION),
+            )
             .output()
             .await
             .context("Failed to execute hurl command")?;
 
+        let json_report = Self::parse_json_report(&String::from_utf8_lossy(&output.stdout));
+
+        // Older hurl versions reject --json before running anything, so re-running is safe
+        if json_report.is_none()
+            && Self::is_json_flag_unsupported(&String::from_utf8_lossy(&output.stderr))
+        {
+            output = self
+                .build_command(file_path, variables_file, extra_args, false)
+                .output()
+                .await
+                .context("Failed to execute hurl command")?;
+        }
+
         let stdout = String::from_utf8_lossy(&output.stdout).to_string();
         let stderr = String::from_utf8_lossy(&output.stderr).to_string();
         let success = output.status.success();
 
-        // Parse response from very-verbose output (includes body)
-        let response = self.parse_response_from_very_verbose(&stderr, &stdout);
-        let asserts = self.parse_asserts(&stderr);
+        let (mut response, asserts) = match &json_report {
+            Some(report) => {
+                let source = std::fs::read_to_string(file_path).unwrap_or_default();
+                (
+                    self.parse_response_from_json(report, &stderr),
+                    self.parse_asserts_from_json(report, &source),
+                )
+            }
+            None => (
+                // Parse response from very-verbose output (includes body)
+                self.parse_response_from_very_verbose(&stderr, &stdout),
+                self.parse_asserts(&stderr),
+            ),
+        };
+
+        // Without --json, stdout is the response body; keep it as-is when it isn't text
+        if json_report.is_none() && std::str::from_utf8(&output.stdout).is_err() {
+            if let Some(response) = response.as_mut() {
+                response.body_bytes = Some(output.stdout.clone());
+            }
+        }
 
         Ok(ExecutionResult {
             success,
+            request: parse_request_from_very_verbose(&stderr),
             response,
             assertions: asserts,
+            captures: parse_captures(&stderr),
             stdout,
             stderr,
             exit_code: output.status.code().unwrap_or(-1),
+            attempts: 1,
+        })
+    }
+
+    /// Check a hurl file for syntax errors without sending any request (`--dry-run`)
+    ///
+    /// Variables are passed like in `run_with_variables`, so templates that need them resolve.
+    /// Returns `None` when the file is valid. Fails only when hurl can't be run.
+    pub async fn check_syntax(
+        &self,
+        file_path: &PathBuf,
+        variables_file: Option<&PathBuf>,
+        inline_vars: &HashMap<String, String>,
+    ) -> Result<Option<SyntaxError>> {
+        let mut cmd = self.hurl_command();
+        cmd.arg("--dry-run").arg(file_path);
+        if let Some(vars_file) = variables_file {
+            cmd.arg("--variables-file").arg(vars_file);
+        }
+        let output = cmd
+            .args(variable_args(inline_vars))
+            .stdin(Stdio::null())
+            .kill_on_drop(true)
+            .output()
+            .await
+            .context("Failed to execute hurl command")?;
+        if output.status.success() {
+            return Ok(None);
+        }
+
+        let stderr = String::from_utf8_lossy(&output.stderr);
+        let message = stderr.lines().find(|l| !l.trim().is_empty()).unwrap_or("");
+        Ok(Some(SyntaxError {
+            lines: parse_error_lines(&stderr),
+            message: message.trim().to_string(),
+        }))
+    }
+
+    /// The hurl executable (`hurl_path` or `hurl` from PATH)
+    fn hurl_program(&self) -> String {
+        self.hurl_path
+            .as_ref()
+            .map(|p| p.to_string_lossy().to_string())
+            .unwrap_or_else(|| "hurl".to_string())
+    }
+
+    /// Command running the hurl executable
+    fn hurl_command(&self) -> Command {
+        Command::new(self.hurl_program())
+    }
+
+    /// Build the hurl command for a file
+    ///
+    /// With `json` set, hurl writes a JSON report to stdout instead of the response body.
+    fn build_command(
+        &self,
+        file_path: &PathBuf,
+        variables_file: Option<&PathBuf>,
+        extra_args: &[String],
+        json: bool,
+    ) -> Command {
+        let mut cmd = self.hurl_command();
+        cmd.arg(file_path);
+        if json {
+            cmd.arg("--json");
+        }
+        cmd.arg("--very-verbose");
+        cmd.arg("--max-time");
+        cmd.arg(self.timeout.to_string());
+
+        if let Some(vars_file) = variables_file {
+            cmd.arg("--variables-file");
+            cmd.arg(vars_file);
+        }
+
+        // Send the cookies of earlier runs and store the new ones
+        if let Some(jar) = &self.cookie_jar {
+            if jar.exists() {
+                cmd.arg("--cookie");
+                cmd.arg(jar);
+            }
+            cmd.arg("--cookie-jar");
+            cmd.arg(jar);
+        }
+
+        if let Some(proxy) = &self.proxy {
+            cmd.arg("--proxy");
+            cmd.arg(proxy);
+        }
+        if let Some(no_proxy) = &self.no_proxy {
+            cmd.arg("--no-proxy");
+            cmd.arg(no_proxy);
+        }
+        if self.insecure {
+            cmd.arg("--insecure");
+        }
+
+        cmd.args(extra_args);
+
+        cmd.stdout(Stdio::piped());
+        cmd.stderr(Stdio::piped());
+        // Kill hurl when a running request is cancelled
+        cmd.kill_on_drop(true);
+        cmd
+    }
+
+    /// Parse the JSON report written to stdout by `hurl --json`
+    fn parse_json_report(stdout: &str) -> Option<HurlJsonOutput> {
+        // One JSON line is written per file; we only run a single file
+        let line = stdout.lines().rev().find(|l| !l.trim().is_empty())?;
+        serde_json::from_str(line.trim()).ok()
+    }
+
+    /// Check whether hurl rejected the `--json` flag (versions before 3.0)
+    fn is_json_flag_unsupported(stderr: &str) -> bool {
+        stderr.contains("--json")
+            && (stderr.contains("unexpected argument")
+                || stderr.contains("unrecognized")
+                || stderr.contains("wasn't expected"))
+    }
+
+    /// Build the response from the JSON report (last call of the last entry)
+    ///
+    /// The JSON report doesn't contain the response body, so it is taken from the
+    /// `--very-verbose` output in stderr.
+    fn parse_response_from_json(&self, report: &HurlJsonOutput, stderr: &str) -> Option<Response> {
+        let entry = report.entries.last()?;
+        let call = entry.calls.last()?;
+
+        let duration_ms = call
+            .timings
+            .as_ref()
+            .map(|t| t.total / 1000)
+            .unwrap_or(entry.time);
+
+        let verbose = self.parse_response_from_very_verbose(stderr, "");
+        let timings = call
+            .timings
+            .as_ref()
+            .map(HurlJsonTimings::to_timings)
+            .or_else(|| verbose.as_ref().and_then(|r| r.timings.clone()));
+        let body = verbose.map(|r| r.body).unwrap_or_default();
+
+        let headers: Vec<(String, String)> = call
+            .response
+            .headers
+            .iter()
+            .map(|h| (h.name.clone(), h.value.clone()))
+            .collect();
+
+        Some(Response {
+            status_code: call.response.status,
+            cookies: parse_set_cookies(&headers),
+            headers,
+            body,
+            duration_ms,
+            timings,
+            body_bytes: None,
+            body_truncated: false,
+            body_original_size: 0,
         })
     }
 
+    /// Build assertion results from the JSON report
+    ///
+    /// The report only references assertions by line number, so the text is
+    /// looked up in the hurl file source.
+    fn parse_asserts_from_json(
+        &self,
+        report: &HurlJsonOutput,
+        source: &str,
+    ) -> Vec<AssertionResult> {
+        let lines: Vec<&str> = source.lines().collect();
+
+        report
+            .entries
+            .iter()
+            .flat_map(|entry| entry.asserts.iter())
+            .map(|assert| {
+                let text = lines
+                    .get(assert.line.saturating_sub(1))
+                    .map(|l| l.trim().to_string())
+                    .unwrap_or_else(|| format!("line {}", assert.line));
+                AssertionResult {
+                    success: assert.success,
+                    text,
+                    expected: None,
+                    actual: None,
+                    message: assert.message.clone(),
+                    source_line: assert.line.checked_sub(1),
+                }
+            })
+            .collect()
+    }
+
     /// Parse response from --very-verbose output
     /// The body is output to stdout, metadata is in stderr
     fn parse_response_from_very_verbose(&self, stderr: &str, stdout: &str) -> Option<Response> {
@@ -115,8 +675,23 @@ impl Runner {
         let mut in_response_headers = false;
         let mut body_lines: Vec<String> = Vec::new();
         let mut in_response_body = false;
+        let mut timings = Timings::default();
+        let mut has_timings = false;
+        // The request body section is followed by "* " lines that aren't the response body
+        let mut in_request_body = false;
 
         for line in stderr.lines() {
+            if line.starts_with("* Request body:") {
+                in_request_body = true;
+                continue;
+            }
+            if in_request_body {
+                if line.starts_with("* ") {
+                    continue;
+                }
+                in_request_body = false;
+            }
+
             // Look for response status line: "< HTTP/1.1 200 OK" or "< HTTP/2 200"
             if line.starts_with("< HTTP/") {
                 let parts: Vec<&str> = line.split_whitespace().collect();
@@ -181,6 +756,11 @@ impl Runner {
                 continue;
             }
 
+            // Collect the timing breakdown ("* time_connect: 0.001 s")
+            if let Some((name, ms)) = parse_timing_line(line) {
+                has_timings |= timings.set(name, ms);
+            }
+
             // Look for timing information
             if line.contains("time_total:") {
                 // Format: "* time_total: 0.123456 s"
@@ -203,9 +783,14 @@ impl Runner {
         if status_code > 0 {
             Some(Response {
                 status_code,
+                cookies: parse_set_cookies(&headers),
                 headers,
                 body,
                 duration_ms,
+                timings: has_timings.then_some(timings),
+                body_bytes: None,
+                body_truncated: false,
+                body_original_size: 0,
             })
         } else {
             None
@@ -230,6 +815,7 @@ impl Runner {
                     expected: None,
                     actual: None,
                     message: Some(line.to_string()),
+                    source_line: None,
                 });
             } else if line.trim().starts_with("*") && line.contains("assert") {
                 // Verbose output shows assertions with *
@@ -240,6 +826,7 @@ impl Runner {
                     expected: None,
                     actual: None,
                     message: None,
+                    source_line: None,
                 });
             }
         }
@@ -259,16 +846,42 @@ impl Default for Runner {
 pub struct ExecutionResult {
     /// Whether execution was successful (all assertions passed)
     pub success: bool,
+    /// The request that was sent (if hurl reported it)
+    #[serde(default)]
+    pub request: Option<Request>,
     /// Response details (if available)
     pub response: Option<Response>,
     /// Assertion results
     pub assertions: Vec<AssertionResult>,
+    /// Values captured by `[Captures]` sections
+    #[serde(default)]
+    pub captures: Vec<CaptureResult>,
     /// Raw stdout output
     pub stdout: String,
     /// Raw stderr output
     pub stderr: String,
     /// Exit code
     pub exit_code: i32,
+    /// Number of hurl invocations (more than 1 when retried)
+    #[serde(default = "default_attempts")]
+    pub attempts: u32,
+}
+
+fn default_attempts() -> u32 {
+    1
+}
+
+/// HTTP request that was actually sent (variables resolved)
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Request {
+    /// HTTP method
+    pub method: String,
+    /// Full URL when hurl reported it, otherwise the target of the request line
+    pub url: String,
+    /// Request headers
+    pub headers: Vec<(String, String)>,
+    /// Request body
+    pub body: Option<String>,
 }
 
 /// HTTP response details
@@ -282,6 +895,350 @@ pub struct Response {
     pub body: String,
     /// Response duration in milliseconds
     pub duration_ms: u64,
+    /// Cookies set by the response (parsed from `Set-Cookie` headers)
+    #[serde(default)]
+    pub cookies: Vec<Cookie>,
+    /// Timing breakdown of the call
+    #[serde(default)]
+    pub timings: Option<Timings>,
+    /// Raw body bytes, kept when the body isn't valid UTF-8
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub body_bytes: Option<Vec<u8>>,
+    /// Whether the body was cut to the configured display limit
+    #[serde(default)]
+    pub body_truncated: bool,
+    /// Size of the body in bytes before truncation
+    #[serde(default)]
+    pub body_original_size: usize,
+}
+
+/// Cut the body (and raw bytes) of `response` to at most `max_bytes`
+fn truncate_body(response: &mut Response, max_bytes: usize) {
+    let size = response
+        .body_bytes
+        .as_ref()
+        .map_or(response.body.len(), Vec::len);
+    response.body_original_size = size;
+    if size <= max_bytes {
+        return;
+    }
+
+    let mut end = max_bytes.min(response.body.len());
+    while !response.body.is_char_boundary(end) {
+        end -= 1;
+    }
+    response.body.truncate(end);
+    if let Some(bytes) = response.body_bytes.as_mut() {
+        bytes.truncate(max_bytes);
+    }
+    response.body_truncated = true;
+}
+
+/// Timing breakdown of a call in milliseconds, each measured from the start of the call
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct Timings {
+    pub namelookup: f64,
+    pub connect: f64,
+    pub appconnect: f64,
+    pub pretransfer: f64,
+    pub starttransfer: f64,
+    pub total: f64,
+}
+
+impl Timings {
+    /// Set a timing from its hurl/curl name (with or without the `time_` prefix)
+    fn set(&mut self, name: &str, ms: f64) -> bool {
+        let field = match name.trim_start_matches("time_").replace('_', "").as_str() {
+            "namelookup" => &mut self.namelookup,
+            "connect" => &mut self.connect,
+            "appconnect" => &mut self.appconnect,
+            "pretransfer" => &mut self.pretransfer,
+            "starttransfer" => &mut self.starttransfer,
+            "total" => &mut self.total,
+            _ => return false,
+        };
+        *field = ms;
+        true
+    }
+}
+
+/// Parse a `* time_total: 0.123456 s` (or `* total: 5146 µs`) line into (name, milliseconds)
+fn parse_timing_line(line: &str) -> Option<(&str, f64)> {
+    let (name, value) = line.strip_prefix("* ")?.split_once(':')?;
+    let value = value.trim();
+    let (number, scale) = if let Some(v) = value.strip_suffix("µs").or(value.strip_suffix("us")) {
+        (v, 0.001)
+    } else if let Some(v) = value.strip_suffix("ms") {
+        (v, 1.0)
+    } else if let Some(v) = value.strip_suffix('s') {
+        (v, 1000.0)
+    } else {
+        return None;
+    };
+    Some((name.trim(), number.trim().parse::<f64>().ok()? * scale))
+}
+
+/// A cookie parsed from a `Set-Cookie` response header
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Cookie {
+    pub name: String,
+    pub value: String,
+    pub domain: Option<String>,
+    pub path: Option<String>,
+    /// Expiry date, `Max-Age` or "Session"
+    pub expires: Option<String>,
+    pub secure: bool,
+    pub http_only: bool,
+}
+
+/// Parse all `Set-Cookie` headers, skipping malformed ones
+pub fn parse_set_cookies(headers: &[(String, String)]) -> Vec<Cookie> {
+    headers
+        .iter()
+        .filter(|(name, _)| name.eq_ignore_ascii_case("set-cookie"))
+        .filter_map(|(_, value)| cookie::Cookie::parse(value.as_str()).ok())
+        .map(|c| {
+            let expires = match (c.expires(), c.max_age()) {
+                (_, Some(max_age)) => Some(format!("Max-Age {}s", max_age.whole_seconds())),
+                (Some(cookie::Expiration::DateTime(date)), None) => date
+                    .format(&cookie::time::format_description::well_known::Rfc2822)
+                    .ok(),
+                (Some(cookie::Expiration::Session), None) => Some("Session".to_string()),
+                (None, None) => None,
+            };
+            Cookie {
+                name: c.name().to_string(),
+                value: c.value().to_string(),
+                domain: c.domain().map(String::from),
+                path: c.path().map(String::from),
+                expires,
+                secure: c.secure().unwrap_or(false),
+                http_only: c.http_only().unwrap_or(false),
+            }
+        })
+        .collect()
+}
+
+/// Parse a Netscape cookie jar (as written by `hurl --cookie-jar`).
+///
+/// Each cookie is returned with the index of its line so it can be removed from the file.
+/// Lines are `domain  include-subdomains  path  secure  expires  name  value` separated by
+/// tabs; HttpOnly cookies have their domain prefixed with `#HttpOnly_`.
+pub fn parse_cookie_jar(content: &str) -> Vec<(usize, Cookie)> {
+    content
+        .lines()
+        .enumerate()
+        .filter_map(|(index, line)| {
+            let (line, http_only) = match line.strip_prefix("#HttpOnly_") {
+                Some(rest) => (rest, true),
+                None if line.starts_with('#') => return None,
+                None => (line, false),
+            };
+            let fields: Vec<&str> = line.split('\t').collect();
+            let [domain, _, path, secure, expires, name, ..] = fields[..] else {
+                return None;
+            };
+
+            let expires = match expires.parse::<i64>() {
+                Ok(0) => "Session".to_string(),
+                Ok(timestamp) => chrono::DateTime::from_timestamp(timestamp, 0)
+                    .map(|date| date.format("%Y-%m-%d %H:%M").to_string())
+                    .unwrap_or_else(|| expires.to_string()),
+                Err(_) => expires.to_string(),
+            };
+
+            Some((
+                index,
+                Cookie {
+                    name: name.to_string(),
+                    value: fields.get(6).copied().unwrap_or_default().to_string(),
+                    domain: Some(domain.to_string()),
+                    path: Some(path.to_string()),
+                    expires: Some(expires),
+                    secure: secure == "TRUE",
+                    http_only,
+                },
+            ))
+        })
+        .collect()
+}
+
+/// `--variable name=value` arguments, sorted by name so runs are reproducible
+pub fn variable_args(vars: &HashMap<String, String>) -> Vec<String> {
+    let mut names: Vec<&String> = vars.keys().collect();
+    names.sort();
+    names
+        .into_iter()
+        .flat_map(|name| ["--variable".to_string(), format!("{}={}", name, vars[name])])
+        .collect()
+}
+
+/// Parse the version from the first line of `hurl --version` (e.g. `hurl 4.3.0 (x86_64...)`)
+///
+/// Pre-release suffixes such as `5.0.0-SNAPSHOT` are ignored.
+pub fn parse_hurl_version(output: &str) -> Option<HurlVersion> {
+    let version = output.lines().next()?.split_whitespace().nth(1)?;
+    let mut parts = version.trim_start_matches('v').splitn(3, '.').map(|part| {
+        let digits: String = part.chars().take_while(|c| c.is_ascii_digit()).collect();
+        digits.parse::<u32>().ok()
+    });
+    Some((parts.next()??, parts.next()??, parts.next()??))
+}
+
+/// Errors reported by `hurl --dry-run`
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SyntaxError {
+    /// 0-based lines hurl pointed at (empty when it gave no location)
+    pub lines: Vec<usize>,
+    /// First line of hurl's message (e.g. `error: Parsing method`)
+    pub message: String,
+}
+
+/// Parse the 0-based lines of hurl errors from their `--> file:line:column` locations
+pub fn parse_error_lines(stderr: &str) -> Vec<usize> {
+    let mut lines: Vec<usize> = stderr
+        .lines()
+        .filter_map(|line| {
+            let location = line.trim().strip_prefix("-->")?.trim();
+            let mut parts = location.rsplitn(3, ':');
+            let _column = parts.next()?;
+            let line: usize = parts.next()?.parse().ok()?;
+            line.checked_sub(1)
+        })
+        .collect();
+    lines.sort_unstable();
+    lines.dedup();
+    lines
+}
+
+/// A value captured during a run
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct CaptureResult {
+    pub name: String,
+    pub value: String,
+}
+
+/// Parse the request that was sent from `--very-verbose` stderr
+///
+/// The request line and headers are the `> ` lines up to the blank `>` line; the body is
+/// either the `> ` lines after it or a `* Request body:` section of `* ` lines.
+/// With several calls (retries, redirects) the last one is kept.
+pub fn parse_request_from_very_verbose(stderr: &str) -> Option<Request> {
+    let mut request: Option<Request> = None;
+    let mut body_lines: Vec<String> = Vec::new();
+    // Full URL from the "* Request:" section, which precedes the request line
+    let mut reported_url = None;
+    let mut in_request_info = false;
+    let mut in_headers = false;
+    let mut in_body_section = false;
+
+    for line in stderr.lines() {
+        if let Some(text) = line.strip_prefix('>') {
+            in_body_section = false;
+            let text = text.strip_prefix(' ').unwrap_or(text);
+            if let Some((method, target)) = parse_request_line(text) {
+                request = Some(Request {
+                    method,
+                    url: reported_url.take().unwrap_or(target),
+                    ..Request::default()
+                });
+                body_lines.clear();
+                in_headers = true;
+            } else if in_headers {
+                // A blank line ends the headers
+                in_headers = !text.trim().is_empty();
+                if let (Some(request), Some((name, value))) = (request.as_mut(), text.split_once(':')) {
+                    request.headers.push((name.trim().to_string(), value.trim().to_string()));
+                }
+            } else if request.is_some() {
+                body_lines.push(text.to_string());
+            }
+            continue;
+        }
+
+        if std::mem::take(&mut in_request_info) {
+            // "* GET http://localhost:8000/users"
+            reported_url = line
+                .strip_prefix("* ")
+                .and_then(|text| text.split_once(' '))
+                .map(|(_, url)| url.trim().to_string());
+        }
+        if line.starts_with("* Request:") {
+            in_request_info = true;
+        } else if line.starts_with("* Request body:") {
+            in_body_section = true;
+        } else if in_body_section {
+            match line.strip_prefix("* ") {
+                Some(body_line) => body_lines.push(body_line.to_string()),
+                None => in_body_section = false,
+            }
+        }
+    }
+
+    while body_lines.last().is_some_and(|line| line.trim().is_empty()) {
+        body_lines.pop();
+    }
+    let mut request = request?;
+    request.body = (!body_lines.is_empty()).then(|| body_lines.join("\n"));
+    Some(request)
+}
+
+/// Split a request line (`POST /login HTTP/1.1`) into method and target
+fn parse_request_line(line: &str) -> Option<(String, String)> {
+    let mut parts = line.split_whitespace();
+    let (method, target, version) = (parts.next()?, parts.next()?, parts.next()?);
+    (version.starts_with("HTTP/") && parts.next().is_none())
+        .then(|| (method.to_string(), target.to_string()))
+}
+
+/// Parse captured values from `--very-verbose` stderr
+///
+/// Hurl prints either a `* Captures:` header followed by `* name: value` lines,
+/// or a single `* Captures  name: "value"` line. Surrounding quotes are dropped.
+pub fn parse_captures(stderr: &str) -> Vec<CaptureResult> {
+    let mut captures = Vec::new();
+    let mut in_captures = false;
+
+    for line in stderr.lines() {
+        let Some(text) = line.trim().strip_prefix('*').map(str::trim) else {
+            in_captures = false;
+            continue;
+        };
+
+        let capture = if let Some(rest) = text.strip_prefix("Captures") {
+            let rest = rest.trim_start_matches(':').trim();
+            if rest.is_empty() {
+                in_captures = true;
+                continue;
+            }
+            rest
+        } else if in_captures {
+            text
+        } else {
+            continue;
+        };
+
+        let Some((name, value)) = capture.split_once(':') else {
+            in_captures = false;
+            continue;
+        };
+        let (name, value) = (name.trim(), value.trim());
+        if name.is_empty() || name.contains(char::is_whitespace) || value.is_empty() {
+            in_captures = false;
+            continue;
+        }
+
+        let value = value
+            .strip_prefix('"')
+            .and_then(|v| v.strip_suffix('"'))
+            .unwrap_or(value);
+        captures.push(CaptureResult {
+            name: name.to_string(),
+            value: value.to_string(),
+        });
+    }
+
+    captures
 }
 
 /// Result of a single assertion
@@ -297,9 +1254,12 @@ pub struct AssertionResult {
     pub actual: Option<String>,
     /// Error message (if failed)
     pub message: Option<String>,
+    /// Line of the assertion in the hurl file (0-based)
+    #[serde(default)]
+    pub source_line: Option<usize>,
 }
 
-// Structures for parsing Hurl JSON output
+// Structures for parsing Hurl JSON output (`hurl --json`)
 
 #[derive(Debug, Deserialize)]
 struct HurlJsonOutput {
@@ -308,23 +1268,26 @@ struct HurlJsonOutput {
 
 #[derive(Debug, Deserialize)]
 struct HurlJsonEntry {
-    request: Option<HurlJsonRequest>,
-    response: Option<HurlJsonResponse>,
-    #[serde(rename = "timings")]
-    time_in_ms: Option<u64>,
+    #[serde(default)]
+    asserts: Vec<HurlJsonAssert>,
+    #[serde(default)]
+    calls: Vec<HurlJsonCall>,
+    /// Entry duration in milliseconds
+    #[serde(default)]
+    time: u64,
 }
 
 #[derive(Debug, Deserialize)]
-struct HurlJsonRequest {
-    method: String,
-    url: String,
+struct HurlJsonCall {
+    response: HurlJsonResponse,
+    timings: Option<HurlJsonTimings>,
 }
 
 #[derive(Debug, Deserialize)]
 struct HurlJsonResponse {
     status: u16,
+    #[serde(default)]
     headers: Vec<HurlJsonHeader>,
-    body: Option<String>,
 }
 
 #[derive(Debug, Deserialize)]
@@ -333,6 +1296,43 @@ struct HurlJsonHeader {
     value: String,
 }
 
+#[derive(Debug, Deserialize)]
+struct HurlJsonTimings {
+    /// Total call time in microseconds
+    total: u64,
+    #[serde(default)]
+    name_lookup: u64,
+    #[serde(default)]
+    connect: u64,
+    #[serde(default)]
+    app_connect: u64,
+    #[serde(default)]
+    pre_transfer: u64,
+    #[serde(default)]
+    start_transfer: u64,
+}
+
+impl HurlJsonTimings {
+    fn to_timings(&self) -> Timings {
+        let ms = |us: u64| us as f64 / 1000.0;
+        Timings {
+            namelookup: ms(self.name_lookup),
+            connect: ms(self.connect),
+            appconnect: ms(self.app_connect),
+            pretransfer: ms(self.pre_transfer),
+            starttransfer: ms(self.start_transfer),
+            total: ms(self.total),
+        }
+    }
+}
+
+#[derive(Debug, Deserialize)]
+struct HurlJsonAssert {
+    line: usize,
+    success: bool,
+    message: Option<String>,
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -344,6 +1344,34 @@ mod tests {
         assert_eq!(runner.timeout, 30);
     }
 
+    #[test]
+    fn test_should_retry() {
+        let runner = Runner::new().with_retry(2, 0);
+        let mut result = ExecutionResult {
+            success: false,
+            request: None,
+            response: None,
+            assertions: Vec::new(),
+            captures: Vec::new(),
+            stdout: String::new(),
+            stderr: "error: HTTP connection\n  Connection refused".to_string(),
+            exit_code: 3,
+            attempts: 1,
+        };
+
+        assert!(runner.should_retry(&result, 1));
+        assert!(runner.should_retry(&result, 2));
+        assert!(!runner.should_retry(&result, 3));
+        assert!(!Runner::new().should_retry(&result, 1));
+
+        result.stderr = "error: Assert status code".to_string();
+        assert!(!runner.should_retry(&result, 1));
+
+        result.success = true;
+        result.stderr = "Failed to connect".to_string();
+        assert!(!runner.should_retry(&result, 1));
+    }
+
     #[test]
     fn test_runner_with_options() {
         let runner = Runner::new()
@@ -353,4 +1381,241 @@ mod tests {
         assert_eq!(runner.hurl_path, Some(PathBuf::from("/usr/bin/hurl")));
         assert_eq!(runner.timeout, 60);
     }
+
+    #[test]
+    fn test_option_args() {
+        assert_eq!(
+            option_args("retry", "3"),
+            Some(vec!["--retry".to_string(), "3".to_string()])
+        );
+        assert_eq!(
+            option_args("insecure", "true"),
+            Some(vec!["--insecure".to_string()])
+        );
+        assert_eq!(option_args("insecure", "false"), Some(Vec::new()));
+        assert_eq!(option_args("unknown-option", "1"), None);
+        assert!(is_known_option("max-redirs"));
+        assert!(!is_known_option("unknown-option"));
+    }
+
+    #[test]
+    fn test_parse_json_report() {
+        let stdout = r#"{"entries":[{"asserts":[{"line":3,"success":true},{"line":6,"success":false,"message":"assert failure"}],"calls":[{"request":{"method":"GET","url":"http://localhost/users"},"response":{"status":200,"headers":[{"name":"Content-Type","value":"application/json"}]},"timings":{"total":42000}}],"time":45}],"success":false}"#;
+        let source = "GET http://localhost/users\n\nHTTP 200\n[Asserts]\nstatus == 200\njsonpath \"$.id\" exists\n";
+
+        let runner = Runner::new();
+        let report = Runner::parse_json_report(stdout).unwrap();
+        let response = runner.parse_response_from_json(&report, "").unwrap();
+        assert_eq!(response.status_code, 200);
+        assert_eq!(response.duration_ms, 42);
+        assert_eq!(response.headers.len(), 1);
+
+        let asserts = runner.parse_asserts_from_json(&report, source);
+        assert_eq!(asserts.len(), 2);
+        assert_eq!(asserts[0].text, "HTTP 200");
+        assert!(!asserts[1].success);
+        assert_eq!(asserts[1].text, "jsonpath \"$.id\" exists");
+    }
+
+    #[test]
+    fn test_parse_set_cookies() {
+        let headers = vec![
+            ("Content-Type".to_string(), "text/html".to_string()),
+            (
+                "Set-Cookie".to_string(),
+                "session_id=abc123; Domain=example.com; Path=/; Max-Age=3600; Secure; HttpOnly"
+                    .to_string(),
+            ),
+            ("set-cookie".to_string(), "theme=dark".to_string()),
+        ];
+
+        let cookies = parse_set_cookies(&headers);
+        assert_eq!(cookies.len(), 2);
+        assert_eq!(cookies[0].name, "session_id");
+        assert_eq!(cookies[0].value, "abc123");
+        assert_eq!(cookies[0].domain.as_deref(), Some("example.com"));
+        assert_eq!(cookies[0].path.as_deref(), Some("/"));
+        assert_eq!(cookies[0].expires.as_deref(), Some("Max-Age 3600s"));
+        assert!(cookies[0].secure && cookies[0].http_only);
+        assert_eq!(cookies[1].name, "theme");
+        assert!(cookies[1].expires.is_none());
+        assert!(!cookies[1].secure);
+    }
+
+    #[test]
+    fn test_parse_cookie_jar() {
+        let content = "# Netscape HTTP Cookie File\n\nlocalhost\tFALSE\t/\tFALSE\t0\ttheme\tdark\n#HttpOnly_example.com\tTRUE\t/api\tTRUE\t1700000000\tsession\tabc\n";
+
+        let cookies = parse_cookie_jar(content);
+        assert_eq!(cookies.len(), 2);
+        assert_eq!(cookies[0].0, 2);
+        assert_eq!(cookies[0].1.name, "theme");
+        assert_eq!(cookies[0].1.expires.as_deref(), Some("Session"));
+        assert!(!cookies[0].1.http_only);
+        let (index, session) = &cookies[1];
+        assert_eq!(*index, 3);
+        assert_eq!(session.domain.as_deref(), Some("example.com"));
+        assert_eq!(session.path.as_deref(), Some("/api"));
+        assert_eq!(session.expires.as_deref(), Some("2023-11-14 22:13"));
+        assert!(session.secure && session.http_only);
+    }
+
+    #[test]
+    fn test_parse_error_lines() {
+        let stderr = "error: Parsing method\n  --> C:\\api\\users.hurl:3:1\n   |\n 3 | GETT http://localhost\n   | ^ the HTTP method is not valid\n";
+
+        assert_eq!(parse_error_lines(stderr), vec![2]);
+        assert!(parse_error_lines("error: unexpected argument '--dry-run'").is_empty());
+    }
+
+    #[test]
+    fn test_parse_timings_from_very_verbose() {
+        let stderr = "> POST /login HTTP/1.1\n> Host: localhost:8000\n> Authorization: Bearer abc\n>\n* Request body:\n* {\"user\": \"bob\"}\n*\n< HTTP/1.1 200 OK\n< Content-Type: text/plain\n<\n* time_namelookup: 0.001500 s\n* time_connect: 0.002 s\n* time_starttransfer: 0.010 s\n* time_total: 0.012 s\n";
+
+        let response = Runner::new()
+            .parse_response_from_very_verbose(stderr, "ok")
+            .unwrap();
+        let timings = response.timings.unwrap();
+        assert_eq!(response.duration_ms, 12);
+        assert_eq!(timings.namelookup, 1.5);
+        assert_eq!(timings.connect, 2.0);
+        assert_eq!(timings.appconnect, 0.0);
+        assert_eq!(timings.starttransfer, 10.0);
+        assert_eq!(timings.total, 12.0);
+        assert_eq!(response.body, "ok");
+        assert_eq!(parse_timing_line("* total: 5146 µs"), Some(("total", 5.146)));
+
+        let request = parse_request_from_very_verbose(stderr).unwrap();
+        assert_eq!(request.method, "POST");
+        assert_eq!(request.url, "/login");
+        assert_eq!(
+            request.headers,
+            vec![
+                ("Host".to_string(), "localhost:8000".to_string()),
+                ("Authorization".to_string(), "Bearer abc".to_string()),
+            ]
+        );
+        assert_eq!(request.body.as_deref(), Some("{\"user\": \"bob\"}"));
+    }
+
+    #[test]
+    fn test_parse_request_from_very_verbose() {
+        let stderr = "* Request:\n* PUT http://localhost:8000/users/1\n> PUT /users/1 HTTP/1.1\n> Content-Type: application/json\n>\n> {\n>   \"name\": \"bob\"\n> }\n>\n< HTTP/1.1 204 No Content\n";
+
+        let request = parse_request_from_very_verbose(stderr).unwrap();
+        assert_eq!(request.method, "PUT");
+        assert_eq!(request.url, "http://localhost:8000/users/1");
+        assert_eq!(
+            request.headers,
+            vec![("Content-Type".to_string(), "application/json".to_string())]
+        );
+        assert_eq!(request.body.as_deref(), Some("{\n  \"name\": \"bob\"\n}"));
+        assert!(parse_request_from_very_verbose("< HTTP/1.1 200 OK\n").is_none());
+    }
+
+    #[test]
+    fn test_parse_captures() {
+        let stderr = "* Captures:\n* token: abc123\n* user_id: 42\n*\n* Request:\n* GET http://localhost\n* Captures  csrf: \"x-1\"\n";
+
+        let captures = parse_captures(stderr);
+        let pairs: Vec<(&str, &str)> = captures
+            .iter()
+            .map(|c| (c.name.as_str(), c.value.as_str()))
+            .collect();
+        assert_eq!(
+            pairs,
+            vec![("token", "abc123"), ("user_id", "42"), ("csrf", "x-1")]
+        );
+    }
+
+    #[test]
+    fn test_variable_args() {
+        let vars = HashMap::from([
+            ("token".to_string(), "abc=1".to_string()),
+            ("host".to_string(), "localhost".to_string()),
+        ]);
+        assert_eq!(
+            variable_args(&vars),
+            vec!["--variable", "host=localhost", "--variable", "token=abc=1"]
+        );
+        assert!(variable_args(&HashMap::new()).is_empty());
+    }
+
+    #[test]
+    fn test_parse_hurl_version() {
+        assert_eq!(
+            parse_hurl_version("hurl 4.3.0 (x86_64-pc-linux-gnu) libcurl/8.4.0\nFeatures: HTTP2\n"),
+            Some((4, 3, 0))
+        );
+        assert_eq!(parse_hurl_version("hurl 5.0.0-SNAPSHOT"), Some((5, 0, 0)));
+        assert_eq!(parse_hurl_version("hurl"), None);
+        assert_eq!(parse_hurl_version("hurl 4.3"), None);
+    }
+
+    #[test]
+    fn test_supports_json() {
+        let runner = Runner::new().with_hurl_version(Some((2, 99, 99)));
+        assert!(!runner.supports(JSON_MIN_VERSION));
+        let runner = runner.with_hurl_version(Some((3, 0, 0)));
+        assert!(runner.supports(JSON_MIN_VERSION));
+        // An unknown version is assumed to support everything
+        assert!(Runner::new().supports(JSON_MIN_VERSION));
+    }
+
+    #[test]
+    fn test_truncate_body() {
+        let mut response: Response = serde_json::from_str(
+            r#"{"status_code": 200, "headers": [], "body": "abcdé", "duration_ms": 1}"#,
+        )
+        .unwrap();
+
+        // The cut never splits a character
+        truncate_body(&mut response, 5);
+        assert_eq!(response.body, "abcd");
+        assert!(response.body_truncated);
+        assert_eq!(response.body_original_size, 6);
+
+        let mut response = Response {
+            body: "short".to_string(),
+            body_truncated: false,
+            ..response
+        };
+        truncate_body(&mut response, 5);
+        assert_eq!(response.body, "short");
+        assert!(!response.body_truncated);
+    }
+
+    #[cfg(feature = "lua-scripts")]
+    #[test]
+    fn test_lua_scripts() {
+        let dir = tempfile::tempdir().unwrap();
+        std::fs::write(
+            dir.path().join("users.lua"),
+            "return { { success = result.exit_code == 0, text = \"exit code\" } }",
+        )
+        .unwrap();
+        std::fs::write(dir.path().join("users.broken.lua"), "return {").unwrap();
+        std::fs::write(dir.path().join("orders.lua"), "error('not run')").unwrap();
+
+        let result = ExecutionResult {
+            success: true,
+            request: None,
+            response: None,
+            assertions: Vec::new(),
+            captures: Vec::new(),
+            stdout: String::new(),
+            stderr: String::new(),
+            exit_code: 0,
+            attempts: 1,
+        };
+        let assertions = scripts::run_scripts(dir.path(), Path::new("users.hurl"), &result);
+        let summary: Vec<(bool, &str)> = assertions
+            .iter()
+            .map(|a| (a.success, a.text.as_str()))
+            .collect();
+        assert_eq!(
+            summary,
+            vec![(false, "script users.broken.lua"), (true, "exit code")]
+        );
+    }
 }
@@ -0,0 +1,86 @@
+//! Lua assertion scripts (`lua-scripts` feature)
+//!
+//! After a run, every `<name>.lua` or `<name>.*.lua` file in the script directory
+//! is executed for `<name>.hurl`. The script sees the execution result as the
+//! global `result` table and returns a list of assertions, e.g.
+//!
+//! ```lua
+//! local ok = result.response ~= nil and result.response.status_code == 200
+//! return { { success = ok, text = "status is 200", message = "unexpected status" } }
+//! ```
+
+use mlua::{Lua, LuaSerdeExt, SerializeOptions, Value};
+use std::path::Path;
+
+use super::{AssertionResult, ExecutionResult};
+
+/// Run the scripts matching a hurl file and collect the assertions they return
+///
+/// A script that fails to load or run is reported as a failed assertion.
+pub fn run_scripts(
+    script_dir: &Path,
+    file_path: &Path,
+    result: &ExecutionResult,
+) -> Vec<AssertionResult> {
+    let Some(stem) = file_path.file_stem().and_then(|s| s.to_str()) else {
+        return Vec::new();
+    };
+    let Ok(read_dir) = std::fs::read_dir(script_dir) else {
+        return Vec::new();
+    };
+
+    let mut scripts: Vec<_> = read_dir
+        .flatten()
+        .map(|entry| entry.path())
+        .filter(|path| {
+            let Some(name) = path.file_name().and_then(|n| n.to_str()) else {
+                return false;
+            };
+            name.ends_with(".lua")
+                && (name == format!("{}.lua", stem) || name.starts_with(&format!("{}.", stem)))
+        })
+        .collect();
+    scripts.sort();
+
+    scripts
+        .iter()
+        .flat_map(|script| {
+            let name = script
+                .file_name()
+                .map(|n| n.to_string_lossy().to_string())
+                .unwrap_or_default();
+            run_script(script, result).unwrap_or_else(|e| {
+                vec![AssertionResult {
+                    success: false,
+                    text: format!("script {}", name),
+                    expected: None,
+                    actual: None,
+                    message: Some(e.to_string()),
+                    source_line: None,
+                }]
+            })
+        })
+        .collect()
+}
+
+/// Execute one script with the result as its `result` global
+fn run_script(script: &Path, result: &ExecutionResult) -> anyhow::Result<Vec<AssertionResult>> {
+    let source = std::fs::read_to_string(script)?;
+    let lua = Lua::new();
+
+    // Missing values are nil rather than a null placeholder
+    let options = SerializeOptions::new()
+        .serialize_none_to_null(false)
+        .serialize_unit_to_null(false);
+    lua.globals()
+        .set("result", lua.to_value_with(result, options)?)?;
+
+    let returned: Value = lua
+        .load(&source)
+        .set_name(script.to_string_lossy())
+        .eval()?;
+    if returned.is_nil() {
+        return Ok(Vec::new());
+    }
+    Ok(lua.from_value(returned)?)
+}
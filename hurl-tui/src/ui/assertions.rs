@@ -10,17 +10,19 @@ use ratatui::{
     Frame,
 };
 
-use super::theme::{BoxChars, HackerTheme};
+use super::scrollbar::render_scrollbar;
+use super::theme::BoxChars;
 use crate::app::{ActivePanel, App};
 
 /// Render the assertions panel
 pub fn render_assertions(frame: &mut Frame, app: &App, area: Rect) {
+    let theme = &app.active_theme;
     let is_active = app.active_panel == ActivePanel::Assertions;
 
     let border_color = if is_active {
-        HackerTheme::MATRIX_GREEN
+        theme.matrix_green
     } else {
-        HackerTheme::BORDER_DIM
+        theme.border_dim
     };
 
     let block = Block::default()
@@ -32,18 +34,20 @@ pub fn render_assertions(frame: &mut Frame, app: &App, area: Rect) {
         )
         .borders(Borders::ALL)
         .border_style(Style::default().fg(border_color))
-        .style(Style::default().bg(HackerTheme::VOID_BLACK));
+        .style(Style::default().bg(theme.void_black));
+
+    // Parse errors of the current file come first
+    let mut lines = parse_error_lines(app);
+    let error_rows = lines.len() as u16;
 
     // Check if we have execution results
     let Some(result) = &app.execution_result else {
-        let placeholder = Paragraph::new(vec![
-            Line::from(""),
-            Line::from(Span::styled(
-                format!("  {} No test results", BoxChars::DOT),
-                Style::default().fg(HackerTheme::TEXT_MUTED),
-            )),
-        ])
-        .block(block);
+        lines.push(Line::from(""));
+        lines.push(Line::from(Span::styled(
+            format!("  {} No test results", BoxChars::DOT),
+            Style::default().fg(theme.text_muted),
+        )));
+        let placeholder = Paragraph::new(lines).block(block);
         frame.render_widget(placeholder, area);
         return;
     };
@@ -56,8 +60,6 @@ pub fn render_assertions(frame: &mut Frame, app: &App, area: Rect) {
             .collect::<Vec<_>>()
     });
 
-    let mut lines: Vec<Line> = Vec::new();
-
     // Show assertion results from execution
     if !result.assertions.is_empty() {
         let passed = result.assertions.iter().filter(|a| a.success).count();
@@ -65,11 +67,11 @@ pub fn render_assertions(frame: &mut Frame, app: &App, area: Rect) {
 
         // Summary line with hacker styling
         let (summary_color, summary_icon) = if passed == total {
-            (HackerTheme::ASSERT_PASS, BoxChars::CHECK)
+            (theme.assert_pass, BoxChars::CHECK)
         } else if passed > 0 {
-            (HackerTheme::AMBER_WARNING, BoxChars::DIAMOND)
+            (theme.amber_warning, BoxChars::DIAMOND)
         } else {
-            (HackerTheme::ASSERT_FAIL, BoxChars::CROSS)
+            (theme.assert_fail, BoxChars::CROSS)
         };
 
         lines.push(Line::from(vec![
@@ -88,48 +90,69 @@ pub fn render_assertions(frame: &mut Frame, app: &App, area: Rect) {
 
         // Individual assertions
         let scroll = app.assertions_scroll;
-        let visible_height = area.height.saturating_sub(5) as usize;
+        let visible_height = area.height.saturating_sub(5 + error_rows) as usize;
 
-        for assertion in result.assertions.iter().skip(scroll).take(visible_height) {
+        for (index, assertion) in result
+            .assertions
+            .iter()
+            .enumerate()
+            .skip(scroll)
+            .take(visible_height)
+        {
             let (icon, color) = if assertion.success {
-                (BoxChars::CHECK, HackerTheme::ASSERT_PASS)
+                (BoxChars::CHECK, theme.assert_pass)
+            } else {
+                (BoxChars::CROSS, theme.assert_fail)
+            };
+            let line_number = assertion
+                .source_line
+                .map(|line| format!("{:>4} ", line + 1))
+                .unwrap_or_else(|| "     ".to_string());
+
+            // The topmost assertion is the one Enter jumps to
+            let row_style = if is_active && index == scroll {
+                Style::default().bg(theme.selected_bg)
             } else {
-                (BoxChars::CROSS, HackerTheme::ASSERT_FAIL)
+                Style::default()
             };
 
-            lines.push(Line::from(vec![
-                Span::styled(
-                    format!("  {} ", icon),
-                    Style::default().fg(color).add_modifier(Modifier::BOLD),
-                ),
-                Span::styled(
-                    assertion.text.clone(),
-                    Style::default().fg(if assertion.success {
-                        HackerTheme::TEXT_PRIMARY
-                    } else {
-                        HackerTheme::ASSERT_FAIL
-                    }),
-                ),
-            ]));
+            lines.push(
+                Line::from(vec![
+                    Span::styled(
+                        format!("  {} ", icon),
+                        Style::default().fg(color).add_modifier(Modifier::BOLD),
+                    ),
+                    Span::styled(line_number, Style::default().fg(theme.text_muted)),
+                    Span::styled(
+                        assertion.text.clone(),
+                        Style::default().fg(if assertion.success {
+                            theme.text_primary
+                        } else {
+                            theme.assert_fail
+                        }),
+                    ),
+                ])
+                .style(row_style),
+            );
 
             // Show expected/actual on failure
             if !assertion.success {
                 if let Some(expected) = &assertion.expected {
                     lines.push(Line::from(Span::styled(
                         format!("      {} expected: {}", BoxChars::DOT, expected),
-                        Style::default().fg(HackerTheme::TEXT_MUTED),
+                        Style::default().fg(theme.text_muted),
                     )));
                 }
                 if let Some(actual) = &assertion.actual {
                     lines.push(Line::from(Span::styled(
                         format!("      {} actual:   {}", BoxChars::DOT, actual),
-                        Style::default().fg(HackerTheme::TEXT_MUTED),
+                        Style::default().fg(theme.text_muted),
                     )));
                 }
                 if let Some(message) = &assertion.message {
                     lines.push(Line::from(Span::styled(
                         format!("      {} {}", BoxChars::CROSS, message),
-                        Style::default().fg(HackerTheme::NEON_RED),
+                        Style::default().fg(theme.neon_red),
                     )));
                 }
             }
@@ -139,12 +162,12 @@ pub fn render_assertions(frame: &mut Frame, app: &App, area: Rect) {
         if asserts.is_empty() {
             lines.push(Line::from(Span::styled(
                 format!("  {} No assertions defined", BoxChars::DOT),
-                Style::default().fg(HackerTheme::TEXT_MUTED),
+                Style::default().fg(theme.text_muted),
             )));
         } else {
             lines.push(Line::from(Span::styled(
                 format!("  {} {} tests pending", BoxChars::BULLET, asserts.len()),
-                Style::default().fg(HackerTheme::ASSERT_PENDING),
+                Style::default().fg(theme.assert_pending),
             )));
             lines.push(Line::from(""));
 
@@ -152,11 +175,11 @@ pub fn render_assertions(frame: &mut Frame, app: &App, area: Rect) {
                 lines.push(Line::from(vec![
                     Span::styled(
                         format!("  {} ", BoxChars::DOT),
-                        Style::default().fg(HackerTheme::TEXT_MUTED),
+                        Style::default().fg(theme.text_muted),
                     ),
                     Span::styled(
                         assert.text.clone(),
-                        Style::default().fg(HackerTheme::TEXT_SECONDARY),
+                        Style::default().fg(theme.text_secondary),
                     ),
                 ]));
             }
@@ -164,24 +187,67 @@ pub fn render_assertions(frame: &mut Frame, app: &App, area: Rect) {
             if asserts.len() > 10 {
                 lines.push(Line::from(Span::styled(
                     format!("    {} +{} more...", BoxChars::DOT, asserts.len() - 10),
-                    Style::default().fg(HackerTheme::TEXT_MUTED),
+                    Style::default().fg(theme.text_muted),
                 )));
             }
 
             lines.push(Line::from(""));
             lines.push(Line::from(Span::styled(
                 format!("  {} Press [r] to execute", BoxChars::TERMINAL_PROMPT),
-                Style::default().fg(HackerTheme::MATRIX_GREEN_DIM),
+                Style::default().fg(theme.matrix_green_dim),
             )));
         }
     } else {
         lines.push(Line::from(Span::styled(
             format!("  {} Load a .hurl file", BoxChars::DOT),
-            Style::default().fg(HackerTheme::TEXT_MUTED),
+            Style::default().fg(theme.text_muted),
         )));
     }
 
     let paragraph = Paragraph::new(lines).block(block).wrap(Wrap { trim: true });
 
     frame.render_widget(paragraph, area);
+
+    // Results start below the summary line and the blank line after it
+    render_scrollbar(
+        frame,
+        Rect::new(
+            area.x + 1,
+            area.y + 3 + error_rows,
+            area.width.saturating_sub(2),
+            area.height.saturating_sub(5 + error_rows),
+        ),
+        app.assertions_scroll,
+        result.assertions.len(),
+        theme,
+    );
+}
+
+/// Warning rows for the parse errors of the current file
+fn parse_error_lines(app: &App) -> Vec<Line<'static>> {
+    let theme = &app.active_theme;
+    let Some(file) = &app.current_file else {
+        return Vec::new();
+    };
+    file.parse_errors
+        .iter()
+        .map(|error| {
+            Line::from(vec![
+                Span::styled(
+                    format!("  {} ", BoxChars::WARNING),
+                    Style::default()
+                        .fg(theme.amber_warning)
+                        .add_modifier(Modifier::BOLD),
+                ),
+                Span::styled(
+                    format!("{:>4} ", error.line + 1),
+                    Style::default().fg(theme.text_muted),
+                ),
+                Span::styled(
+                    error.message.clone(),
+                    Style::default().fg(theme.amber_warning),
+                ),
+            ])
+        })
+        .collect()
 }
@@ -0,0 +1,58 @@
+//! Bookmark manager popup
+//!
+//! Lists the bookmarked files in slot order for reordering and removal.
+
+use ratatui::{
+    style::Style,
+    text::{Line, Span},
+    widgets::{Clear, Paragraph},
+    Frame,
+};
+
+use super::layout::centered_rect;
+use super::popup::{popup_block, popup_list, popup_placeholder};
+use crate::app::App;
+
+/// Render the bookmark manager popup
+pub fn render_bookmarks(frame: &mut Frame, app: &App) {
+    let theme = &app.active_theme;
+    let area = centered_rect(60, 50, frame.area());
+
+    // Clear the background
+    frame.render_widget(Clear, area);
+
+    let block = popup_block(format!(" Bookmarks ({}) ", app.bookmarks.len()), theme);
+
+    let lines = if app.bookmarks.is_empty() {
+        popup_placeholder("No bookmarks (m on a .hurl file adds one)", theme)
+    } else {
+        popup_list(
+            area,
+            app.bookmarks.len(),
+            app.bookmark_index,
+            theme,
+            |index| {
+                let path = &app.bookmarks[index];
+                let slot = if index < 9 {
+                    format!("'{} ", index + 1)
+                } else {
+                    "   ".to_string()
+                };
+                let file = path
+                    .strip_prefix(&app.working_dir)
+                    .unwrap_or(path)
+                    .display()
+                    .to_string();
+                Line::from(vec![
+                    Span::styled("  ★ ", Style::default().fg(theme.cyber_cyan)),
+                    Span::styled(slot, Style::default().fg(theme.text_muted)),
+                    Span::styled(file, Style::default().fg(theme.syntax_url)),
+                ])
+            },
+        )
+    };
+
+    let paragraph = Paragraph::new(lines).block(block);
+
+    frame.render_widget(paragraph, area);
+}
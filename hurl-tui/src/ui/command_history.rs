@@ -0,0 +1,59 @@
+//! Command history search popup
+//!
+//! Opened with `Ctrl+r` in command mode; filters the executed commands as the query is typed.
+
+use ratatui::{
+    style::Style,
+    text::{Line, Span},
+    widgets::{Clear, Paragraph},
+    Frame,
+};
+
+use super::layout::centered_rect;
+use super::popup::{popup_block, popup_list, popup_placeholder};
+use crate::app::App;
+
+/// Render the command history search popup
+pub fn render_command_history(frame: &mut Frame, app: &App) {
+    let theme = &app.active_theme;
+    let area = centered_rect(60, 50, frame.area());
+
+    // Clear the background
+    frame.render_widget(Clear, area);
+
+    let block = popup_block(format!(" History: {}_ ", app.command_history_query), theme);
+
+    let matches = app.command_history_matches();
+    let lines = if matches.is_empty() {
+        let message = if app.command_history.is_empty() {
+            "No commands run yet"
+        } else {
+            "No matches"
+        };
+        popup_placeholder(message, theme)
+    } else {
+        popup_list(
+            area,
+            matches.len(),
+            app.command_history_search_index,
+            theme,
+            |index| {
+                let (number, command) = &matches[index];
+                Line::from(vec![
+                    Span::styled(
+                        format!(" !{:<3} ", number),
+                        Style::default().fg(theme.text_muted),
+                    ),
+                    Span::styled(
+                        format!(":{}", command),
+                        Style::default().fg(theme.text_primary),
+                    ),
+                ])
+            },
+        )
+    };
+
+    let paragraph = Paragraph::new(lines).block(block);
+
+    frame.render_widget(paragraph, area);
+}
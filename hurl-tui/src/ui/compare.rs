@@ -0,0 +1,106 @@
+//! Response compare view
+//!
+//! Replaces the normal layout with the previous and current response bodies side by side.
+//! Changed lines are highlighted and each side scrolls on its own.
+
+use ratatui::{
+    layout::{Constraint, Direction, Layout, Rect},
+    style::{Modifier, Style},
+    text::{Line, Span},
+    widgets::{Block, Borders, Clear, Paragraph},
+    Frame,
+};
+
+use super::scrollbar::render_scrollbar;
+use crate::app::App;
+use crate::runner::ExecutionResult;
+
+/// Render the compare view over `area`
+pub fn render_compare(frame: &mut Frame, app: &App, area: Rect) {
+    frame.render_widget(Clear, area);
+
+    let chunks = Layout::default()
+        .direction(Direction::Horizontal)
+        .constraints([Constraint::Percentage(50), Constraint::Percentage(50)])
+        .split(area);
+
+    render_side(
+        frame,
+        app,
+        chunks[0],
+        "Previous",
+        app.previous_execution_result.as_ref(),
+        false,
+    );
+    render_side(
+        frame,
+        app,
+        chunks[1],
+        "Current",
+        app.execution_result.as_ref(),
+        true,
+    );
+}
+
+/// Render one side of the compare view
+fn render_side(
+    frame: &mut Frame,
+    app: &App,
+    area: Rect,
+    label: &str,
+    result: Option<&ExecutionResult>,
+    right: bool,
+) {
+    let theme = &app.active_theme;
+    let focused = app.compare_right_focused == right;
+    let border_color = if focused {
+        theme.cyber_cyan
+    } else {
+        theme.border_dim
+    };
+    let title = match result.and_then(|r| r.response.as_ref()) {
+        Some(response) => format!(
+            " {} ({}, {}ms) ",
+            label, response.status_code, response.duration_ms
+        ),
+        None => format!(" {} ", label),
+    };
+    let block = Block::default()
+        .title(title)
+        .title_style(
+            Style::default()
+                .fg(border_color)
+                .add_modifier(Modifier::BOLD),
+        )
+        .borders(Borders::ALL)
+        .border_style(Style::default().fg(border_color))
+        .style(Style::default().bg(theme.void_black));
+    let inner = block.inner(area);
+
+    let scroll = if right {
+        app.compare_scroll.1
+    } else {
+        app.compare_scroll.0
+    };
+    let lines: Vec<Line> = app
+        .compare_rows
+        .iter()
+        .skip(scroll)
+        .take(inner.height as usize)
+        .map(|row| {
+            let text = if right { &row.new } else { &row.old };
+            let color = if row.is_changed() {
+                theme.amber_warning
+            } else {
+                theme.text_primary
+            };
+            Line::from(Span::styled(
+                format!(" {}", text.as_deref().unwrap_or("")),
+                Style::default().fg(color),
+            ))
+        })
+        .collect();
+
+    frame.render_widget(Paragraph::new(lines).block(block), area);
+    render_scrollbar(frame, inner, scroll, app.compare_rows.len(), theme);
+}
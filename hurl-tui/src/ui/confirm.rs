@@ -0,0 +1,125 @@
+//! Confirmation overlay
+//!
+//! Displays a centered yes/no prompt before destructive file operations
+//! and before unsaved edits would be discarded.
+
+use ratatui::{
+    layout::Alignment,
+    style::{Modifier, Style},
+    text::{Line, Span},
+    widgets::{Block, Borders, Clear, Paragraph},
+    Frame,
+};
+
+use super::layout::centered_rect;
+use crate::app::App;
+
+/// Render the delete confirmation overlay
+pub fn render_confirm_delete(frame: &mut Frame, app: &App) {
+    let theme = &app.active_theme;
+    let area = centered_rect(40, 20, frame.area());
+
+    // Clear the background
+    frame.render_widget(Clear, area);
+
+    let file_name = app
+        .delete_target
+        .as_ref()
+        .and_then(|p| p.file_name())
+        .map(|n| n.to_string_lossy().to_string())
+        .unwrap_or_default();
+
+    let block = Block::default()
+        .title(" Delete File ")
+        .title_style(
+            Style::default()
+                .fg(theme.neon_red)
+                .add_modifier(Modifier::BOLD),
+        )
+        .borders(Borders::ALL)
+        .border_style(Style::default().fg(theme.neon_red))
+        .style(Style::default().bg(theme.void_black));
+
+    let text = vec![
+        Line::from(""),
+        Line::from(vec![
+            Span::styled("Delete ", Style::default().fg(theme.text_primary)),
+            Span::styled(
+                file_name,
+                Style::default()
+                    .fg(theme.amber_warning)
+                    .add_modifier(Modifier::BOLD),
+            ),
+            Span::styled("?", Style::default().fg(theme.text_primary)),
+        ]),
+        Line::from(""),
+        Line::from(vec![
+            Span::styled("[y]", Style::default().fg(theme.neon_red)),
+            Span::styled("es / ", Style::default().fg(theme.text_secondary)),
+            Span::styled("[n]", Style::default().fg(theme.matrix_green)),
+            Span::styled("o", Style::default().fg(theme.text_secondary)),
+        ]),
+    ];
+
+    let paragraph = Paragraph::new(text)
+        .block(block)
+        .alignment(Alignment::Center);
+
+    frame.render_widget(paragraph, area);
+}
+
+/// Render the "save first?" overlay shown before unsaved edits would be discarded
+pub fn render_confirm_save(frame: &mut Frame, app: &App) {
+    let theme = &app.active_theme;
+    let area = centered_rect(40, 20, frame.area());
+
+    // Clear the background
+    frame.render_widget(Clear, area);
+
+    let file_name = app
+        .current_file_path
+        .as_ref()
+        .and_then(|p| p.file_name())
+        .map(|n| n.to_string_lossy().to_string())
+        .unwrap_or_else(|| "untitled".to_string());
+
+    let block = Block::default()
+        .title(" Unsaved Changes ")
+        .title_style(
+            Style::default()
+                .fg(theme.amber_warning)
+                .add_modifier(Modifier::BOLD),
+        )
+        .borders(Borders::ALL)
+        .border_style(Style::default().fg(theme.amber_warning))
+        .style(Style::default().bg(theme.void_black));
+
+    let text = vec![
+        Line::from(""),
+        Line::from(vec![
+            Span::styled("Save ", Style::default().fg(theme.text_primary)),
+            Span::styled(
+                file_name,
+                Style::default()
+                    .fg(theme.amber_warning)
+                    .add_modifier(Modifier::BOLD),
+            ),
+            Span::styled(" first?", Style::default().fg(theme.text_primary)),
+        ]),
+        Line::from(""),
+        Line::from(vec![
+            Span::styled("[y]", Style::default().fg(theme.matrix_green)),
+            Span::styled("es / ", Style::default().fg(theme.text_secondary)),
+            Span::styled("[n]", Style::default().fg(theme.neon_red)),
+            Span::styled("o / ", Style::default().fg(theme.text_secondary)),
+            Span::styled("[Esc]", Style::default().fg(theme.text_muted)),
+            Span::styled(" cancel", Style::default().fg(theme.text_secondary)),
+        ]),
+    ];
+
+    let paragraph = Paragraph::new(text)
+        .block(block)
+        .alignment(Alignment::Center);
+
+    frame.render_widget(paragraph, area);
+}
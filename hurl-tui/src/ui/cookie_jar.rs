@@ -0,0 +1,97 @@
+//! Cookie jar popup
+//!
+//! Lists the cookies stored in the cookie jar file between runs.
+
+use ratatui::{
+    layout::Constraint,
+    style::{Modifier, Style},
+    widgets::{Cell, Clear, Paragraph, Row, Table},
+    Frame,
+};
+
+use super::layout::centered_rect;
+use super::popup::{popup_block, popup_placeholder, popup_selected_style};
+use crate::app::App;
+
+/// Render the cookie jar popup
+pub fn render_cookie_jar(frame: &mut Frame, app: &App) {
+    let theme = &app.active_theme;
+    let area = centered_rect(80, 60, frame.area());
+
+    // Clear the background
+    frame.render_widget(Clear, area);
+
+    let block = popup_block(format!(" Cookie Jar ({}) ", app.cookie_jar.len()), theme);
+
+    if app.cookie_jar.is_empty() {
+        let placeholder =
+            Paragraph::new(popup_placeholder("No cookies stored", theme)).block(block);
+        frame.render_widget(placeholder, area);
+        return;
+    }
+
+    let header = Row::new(["Domain", "Path", "Name", "Value", "Expires", "Flags"]).style(
+        Style::default()
+            .fg(theme.syntax_section)
+            .add_modifier(Modifier::BOLD),
+    );
+
+    // Keep the selected cookie in view (borders and header take 3 rows)
+    let visible_height = area.height.saturating_sub(3) as usize;
+    let scroll = (app.cookie_jar_index + 1).saturating_sub(visible_height);
+
+    let rows: Vec<Row> = app
+        .cookie_jar
+        .iter()
+        .enumerate()
+        .skip(scroll)
+        .take(visible_height)
+        .map(|(index, (_, cookie))| {
+            // HttpOnly cookies usually hold session tokens
+            let value = if cookie.http_only {
+                "*".repeat(cookie.value.chars().count().clamp(1, 8))
+            } else {
+                cookie.value.clone()
+            };
+            let flags = [(cookie.secure, "Secure"), (cookie.http_only, "HttpOnly")]
+                .iter()
+                .filter(|(set, _)| *set)
+                .map(|(_, flag)| *flag)
+                .collect::<Vec<_>>()
+                .join(" ");
+
+            let row = Row::new([
+                Cell::from(cookie.domain.clone().unwrap_or_default()),
+                Cell::from(cookie.path.clone().unwrap_or_default()),
+                Cell::from(cookie.name.clone()).style(Style::default().fg(theme.syntax_header)),
+                Cell::from(value).style(Style::default().fg(theme.syntax_value)),
+                Cell::from(cookie.expires.clone().unwrap_or_default()),
+                Cell::from(flags).style(Style::default().fg(theme.cyber_cyan)),
+            ])
+            .style(Style::default().fg(theme.text_primary));
+
+            if index == app.cookie_jar_index {
+                row.style(popup_selected_style(theme))
+            } else {
+                row
+            }
+        })
+        .collect();
+
+    let table = Table::new(
+        rows,
+        [
+            Constraint::Percentage(20),
+            Constraint::Percentage(10),
+            Constraint::Percentage(18),
+            Constraint::Percentage(22),
+            Constraint::Percentage(16),
+            Constraint::Percentage(14),
+        ],
+    )
+    .header(header)
+    .column_spacing(1)
+    .block(block);
+
+    frame.render_widget(table, area);
+}
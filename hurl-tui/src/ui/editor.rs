@@ -6,12 +6,15 @@ use ratatui::{
     layout::{Constraint, Direction, Layout, Rect},
     style::{Modifier, Style},
     text::{Line, Span},
-    widgets::{Block, Borders, Paragraph, Tabs, Wrap},
+    widgets::{Block, Borders, Clear, Paragraph, Tabs, Wrap},
     Frame,
 };
 
-use super::theme::{BoxChars, HackerTheme};
-use crate::app::{ActivePanel, App, AppMode, VimMode};
+use super::scrollbar::render_scrollbar;
+use super::skip_columns;
+use super::theme::{BoxChars, Theme};
+use crate::app::{find_variable_at_cursor, is_secret_key, ActivePanel, App, AppMode, VimMode};
+use crate::parser::METHODS;
 
 /// Editor tab selection
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
@@ -33,16 +36,20 @@ impl EditorTab {
 }
 
 /// Render the editor panel
-pub fn render_editor(frame: &mut Frame, app: &App, area: Rect) {
+pub fn render_editor(frame: &mut Frame, app: &mut App, area: Rect) {
+    let theme = app.active_theme;
+    // Content height: the area minus the tab bar and borders
+    app.set_editor_visible_height(area.height.saturating_sub(3) as usize);
+
     let is_active = app.active_panel == ActivePanel::Editor;
-    let is_editing = app.mode == AppMode::Editing;
+    let is_editing = matches!(app.mode, AppMode::Editing | AppMode::FocusedEntry);
 
     let border_color = if is_editing {
-        HackerTheme::MODE_EDIT_FG
+        theme.mode_edit_fg
     } else if is_active {
-        HackerTheme::MATRIX_GREEN
+        theme.matrix_green
     } else {
-        HackerTheme::BORDER_DIM
+        theme.border_dim
     };
 
     // Create layout with tabs at the top
@@ -60,119 +67,82 @@ pub fn render_editor(frame: &mut Frame, app: &App, area: Rect) {
             " Hurl ",
             if app.editor_tab == EditorTab::Hurl {
                 Style::default()
-                    .fg(HackerTheme::TEXT_PRIMARY)
+                    .fg(theme.text_primary)
                     .add_modifier(Modifier::BOLD)
             } else {
-                Style::default().fg(HackerTheme::TEXT_MUTED)
+                Style::default().fg(theme.text_muted)
             },
         ),
         Span::styled(
             " Output ",
             if app.editor_tab == EditorTab::Output {
                 Style::default()
-                    .fg(HackerTheme::TEXT_PRIMARY)
+                    .fg(theme.text_primary)
                     .add_modifier(Modifier::BOLD)
             } else {
-                Style::default().fg(HackerTheme::TEXT_MUTED)
+                Style::default().fg(theme.text_muted)
             },
         ),
         Span::styled(
             " Preview ",
             if app.editor_tab == EditorTab::Preview {
                 Style::default()
-                    .fg(HackerTheme::TEXT_PRIMARY)
+                    .fg(theme.text_primary)
                     .add_modifier(Modifier::BOLD)
             } else {
-                Style::default().fg(HackerTheme::TEXT_MUTED)
+                Style::default().fg(theme.text_muted)
             },
         ),
     ];
 
     let tabs = Tabs::new(tab_titles)
-        .style(Style::default().bg(HackerTheme::VOID_BLACK))
-        .divider(Span::styled(
-            "│",
-            Style::default().fg(HackerTheme::BORDER_DIM),
-        ))
+        .style(Style::default().bg(theme.void_black))
+        .divider(Span::styled("│", Style::default().fg(theme.border_dim)))
         .select(app.editor_tab.index());
 
     frame.render_widget(tabs, chunks[0]);
 
     // Render content based on selected tab
     match app.editor_tab {
+        EditorTab::Hurl if app.is_env_file_open() => {
+            render_env_file(frame, app, chunks[1], is_active, is_editing)
+        }
         EditorTab::Hurl => render_hurl_content(frame, app, chunks[1], is_active, is_editing),
         EditorTab::Output => render_output_content(frame, app, chunks[1], is_active),
         EditorTab::Preview => render_preview_content(frame, app, chunks[1], is_active),
     }
+
+    if app.mode == AppMode::GotoLine {
+        render_goto_line_bar(frame, app, chunks[1]);
+    }
 }
 
 /// Render the Hurl file content (original editor behavior)
 fn render_hurl_content(
     frame: &mut Frame,
-    app: &App,
+    app: &mut App,
     area: Rect,
     is_active: bool,
     is_editing: bool,
 ) {
-    let vim_mode_str = match (is_editing, app.vim_mode) {
-        (true, VimMode::Normal) => "[VIM]",
-        (true, VimMode::Insert) => "[INSERT]",
-        _ => "",
-    };
-
-    let title = match (&app.current_file_path, is_editing) {
-        (Some(path), true) => format!(
-            " {} {} {} ",
-            BoxChars::TERMINAL_PROMPT,
-            path.file_name()
-                .map(|n| n.to_string_lossy().to_string())
-                .unwrap_or_else(|| "untitled".to_string()),
-            vim_mode_str
-        ),
-        (Some(path), false) => format!(
-            " {} {} ",
-            BoxChars::LAMBDA,
-            path.file_name()
-                .map(|n| n.to_string_lossy().to_string())
-                .unwrap_or_else(|| "untitled".to_string())
-        ),
-        (None, _) => format!(" {} Editor ", BoxChars::LAMBDA),
-    };
-
-    let border_color = if is_editing {
-        HackerTheme::MODE_EDIT_FG
-    } else if is_active {
-        HackerTheme::MATRIX_GREEN
-    } else {
-        HackerTheme::BORDER_DIM
-    };
-
-    let block = Block::default()
-        .title(title)
-        .title_style(
-            Style::default()
-                .fg(border_color)
-                .add_modifier(Modifier::BOLD),
-        )
-        .borders(Borders::ALL)
-        .border_style(Style::default().fg(border_color))
-        .style(Style::default().bg(HackerTheme::VOID_BLACK));
+    let theme = app.active_theme;
+    let block = editor_block(app, is_active, is_editing);
 
     if app.editor_content.is_empty() {
         let placeholder = Paragraph::new(vec![
             Line::from(""),
             Line::from(Span::styled(
                 format!("  {} No file loaded", BoxChars::DOT),
-                Style::default().fg(HackerTheme::TEXT_MUTED),
+                Style::default().fg(theme.text_muted),
             )),
             Line::from(""),
             Line::from(Span::styled(
                 format!("  {} Select a .hurl file to begin", BoxChars::ARROW_RIGHT),
-                Style::default().fg(HackerTheme::TEXT_SECONDARY),
+                Style::default().fg(theme.text_secondary),
             )),
             Line::from(Span::styled(
                 format!("  {} Press [Enter] to open", BoxChars::ARROW_RIGHT),
-                Style::default().fg(HackerTheme::TEXT_SECONDARY),
+                Style::default().fg(theme.text_secondary),
             )),
         ])
         .block(block);
@@ -180,25 +150,140 @@ fn render_hurl_content(
         return;
     }
 
-    // Calculate visible area
-    let inner_height = area.height.saturating_sub(2) as usize;
+    // Without word wrap, scroll horizontally to keep the cursor in view
+    if !app.editor_word_wrap && is_editing {
+        // Borders and line numbers take 9 columns
+        let width = (area.width.saturating_sub(9) as usize).max(1);
+        // The cursor column is a byte offset, the scroll offset counts characters
+        let (line, col) = app.editor_cursor;
+        let col = app.editor_content.get(line).map_or(col, |content| {
+            content[..col.min(content.len())].chars().count()
+        });
+        if col < app.editor_h_scroll {
+            app.editor_h_scroll = col;
+        } else if col >= app.editor_h_scroll + width {
+            app.editor_h_scroll = col + 1 - width;
+        }
+    }
+    let h_scroll = app.editor_h_scroll;
+
+    // Calculate visible area (the search bar takes the last line)
+    let show_search_bar =
+        is_editing && (app.editor_search_input || !app.editor_search_query.is_empty());
+    let focus = app.focus_bounds();
+    let inner_height = area
+        .height
+        .saturating_sub(if show_search_bar { 3 } else { 2 }) as usize;
+    // The request subtitle and the focused entry's header take the first lines
+    let header_rows = app.editor_header_rows();
+    let content_height = inner_height.saturating_sub(header_rows);
     let scroll = app.editor_scroll;
+    let selection = app.visual_selection();
+
+    // JSON bodies are highlighted as JSON; folded bodies collapse to their first line
+    let mut json_lines = vec![false; app.editor_content.len()];
+    let entries = app.editor_entries();
+    for (start, end) in entries.into_iter().filter_map(|(_, body_lines)| body_lines) {
+        if !app.editor_content[start].trim_start().starts_with("```") {
+            json_lines[start..=end].fill(true);
+        }
+    }
+    let folds: Vec<(usize, usize)> = app.folded_ranges();
+    let mut hidden_lines = vec![false; app.editor_content.len()];
+    for &(start, end) in &folds {
+        hidden_lines[start + 1..=end].fill(true);
+    }
 
-    // Build styled lines with line numbers
-    let lines: Vec<Line> = app
-        .editor_content
+    // Only the visible window is built; folded lines and lines outside a focused entry are skipped
+    let visible: Vec<usize> = (scroll..app.editor_content.len())
+        .filter(|line_num| !hidden_lines[*line_num])
+        .filter(|line_num| focus.is_none_or(|(start, end)| (start..=end).contains(line_num)))
+        .take(content_height)
+        .collect();
+
+    // Section lookups scan the whole file, so they only run after a change or while editing
+    let needs_highlight = visible
         .iter()
-        .enumerate()
-        .skip(scroll)
-        .take(inner_height)
-        .map(|(line_num, content)| {
-            let line_number = format!("{:4} {} ", line_num + 1, BoxChars::VERTICAL);
-            let styled_content = highlight_hurl_line(content);
+        .any(|line_num| !app.editor_line_cache.contains_key(line_num));
+    let param_lines = if needs_highlight || is_editing {
+        section_lines(&app.editor_content, PARAM_SECTIONS)
+    } else {
+        Vec::new()
+    };
+    let auth_lines = if needs_highlight || is_editing {
+        section_lines(&app.editor_content, &["[BasicAuth]"])
+    } else {
+        Vec::new()
+    };
+    if needs_highlight {
+        let option_lines = section_lines(&app.editor_content, &["[Options]"]);
+        let graphql = graphql_lines(&app.editor_content);
+        for &line_num in &visible {
+            if app.editor_line_cache.contains_key(&line_num) {
+                continue;
+            }
+            let content = &app.editor_content[line_num];
+            let spans = if let Some(part) = graphql[line_num] {
+                let color = match part {
+                    GraphQlPart::Query => theme.syntax_keyword,
+                    GraphQlPart::Variables => theme.syntax_data,
+                };
+                vec![Span::styled(content.clone(), Style::default().fg(color))]
+            } else if param_lines[line_num] {
+                highlight_param_spans(content, &theme)
+            } else if json_lines[line_num] {
+                highlight_json_line(content, &theme)
+            } else if auth_lines[line_num] {
+                highlight_basic_auth_spans(content, app.config.ui.mask_basic_auth, &theme)
+            } else if option_lines[line_num] {
+                highlight_option_spans(content, &theme)
+            } else {
+                highlight_hurl_line(content, &theme)
+            };
+            app.editor_line_cache.insert(line_num, spans);
+        }
+    }
+    let app = &*app;
 
-            let mut spans = vec![Span::styled(
-                line_number,
-                Style::default().fg(HackerTheme::TEXT_MUTED),
-            )];
+    // Build styled lines with line numbers
+    let mut lines: Vec<Line> = visible
+        .into_iter()
+        .map(|line_num| {
+            let content = &app.editor_content[line_num];
+            // Lines with a syntax error (hurl --dry-run) get a red marker in the gutter
+            let line_number = if app.editor_error_lines.contains(&line_num) {
+                Span::styled(
+                    format!(
+                        "{}{:3} {} ",
+                        BoxChars::SCANNER,
+                        line_num + 1,
+                        BoxChars::VERTICAL
+                    ),
+                    Style::default().fg(theme.neon_red),
+                )
+            } else {
+                Span::styled(
+                    format!("{:4} {} ", line_num + 1, BoxChars::VERTICAL),
+                    Style::default().fg(theme.text_muted),
+                )
+            };
+
+            if let Some(&(start, end)) = folds.iter().find(|(start, _)| *start == line_num) {
+                let mut style = Style::default()
+                    .fg(theme.text_muted)
+                    .add_modifier(Modifier::ITALIC);
+                if is_editing && app.editor_cursor.0 == line_num {
+                    style = style.bg(theme.cursor_bg);
+                }
+                let summary = Span::styled(fold_summary(&app.editor_content[start..=end]), style);
+                let mut spans = skip_columns(vec![summary], h_scroll);
+                spans.insert(0, line_number);
+                return Line::from(spans);
+            }
+
+            let is_param = param_lines.get(line_num).copied().unwrap_or(false);
+            let is_auth = auth_lines.get(line_num).copied().unwrap_or(false);
+            let mut spans = Vec::new();
 
             // If editing and cursor is on this line, show cursor
             if is_editing && line_num == app.editor_cursor.0 {
@@ -211,49 +296,684 @@ fn render_hurl_content(
                     ""
                 };
 
-                spans.extend(highlight_hurl_spans(before));
+                // The password under the cursor is never masked, so it can be edited
+                if is_param {
+                    spans.extend(highlight_param_spans(before, &theme));
+                } else if is_auth {
+                    spans.extend(highlight_basic_auth_spans(before, false, &theme));
+                } else {
+                    spans.extend(highlight_hurl_spans(before, &theme));
+                }
 
                 // Different cursor styles for vim modes
                 let cursor_style = match app.vim_mode {
-                    VimMode::Normal => {
+                    VimMode::Normal | VimMode::Visual => {
                         // Block cursor (highlighted background) for normal mode
                         Style::default()
-                            .fg(HackerTheme::CURSOR_FG)
-                            .bg(HackerTheme::CURSOR_BG)
+                            .fg(theme.cursor_fg)
+                            .bg(theme.cursor_bg)
                             .add_modifier(Modifier::BOLD)
                     }
                     VimMode::Insert => {
                         // Underline cursor for insert mode
                         Style::default()
-                            .fg(HackerTheme::MATRIX_GREEN_BRIGHT)
-                            .bg(HackerTheme::VOID_BLACK)
+                            .fg(theme.matrix_green_bright)
+                            .bg(theme.void_black)
                             .add_modifier(Modifier::UNDERLINED | Modifier::BOLD)
                     }
                 };
 
                 spans.push(Span::styled(cursor_char.to_string(), cursor_style));
-                spans.extend(highlight_hurl_spans(after));
+                if is_param && before.contains(':') {
+                    spans.push(Span::styled(
+                        after.to_string(),
+                        Style::default().fg(theme.syntax_value),
+                    ));
+                } else if is_param {
+                    spans.extend(highlight_param_spans(after, &theme));
+                } else if is_auth && before.contains(':') {
+                    spans.push(Span::styled(
+                        after.to_string(),
+                        Style::default().fg(theme.neon_red),
+                    ));
+                } else if is_auth {
+                    spans.extend(highlight_basic_auth_spans(after, false, &theme));
+                } else {
+                    spans.extend(highlight_hurl_spans(after, &theme));
+                }
+            } else if let Some(cached) = app.editor_line_cache.get(&line_num) {
+                spans.extend(cached.iter().cloned());
+            }
+
+            let match_cols: Vec<usize> = app
+                .editor_search_matches
+                .iter()
+                .filter(|(line, _)| *line == line_num)
+                .map(|(_, col)| *col)
+                .collect();
+            if !match_cols.is_empty() {
+                spans = highlight_search_matches(
+                    spans,
+                    &match_cols,
+                    app.editor_search_query.len(),
+                    &theme,
+                );
+            }
+            if let Some((_, col)) = app
+                .matching_bracket
+                .filter(|(line, _)| is_editing && *line == line_num)
+            {
+                spans = highlight_matching_bracket(spans, col, &theme);
+            }
+            if h_scroll > 0 {
+                spans = skip_columns(spans, h_scroll);
+            }
+            spans.insert(0, line_number);
+
+            let is_selected =
+                selection.is_some_and(|(start, end)| line_num >= start && line_num <= end);
+            let is_jump_target = app
+                .editor_highlight_line
+                .is_some_and(|(line, _)| line == line_num);
+            if is_selected || is_jump_target {
+                Line::from(spans).style(Style::default().bg(theme.selected_bg))
             } else {
-                spans.extend(styled_content);
+                Line::from(spans)
             }
+        })
+        .collect();
+
+    if let (Some(index), Some(file)) = (app.editor_focus_entry, &app.current_file) {
+        let header = format!(
+            "{} Entry {}/{} {}",
+            BoxChars::HORIZONTAL.repeat(3),
+            index + 1,
+            file.entries.len(),
+            BoxChars::HORIZONTAL.repeat(3)
+        );
+        lines.insert(
+            0,
+            Line::from(Span::styled(
+                header,
+                Style::default()
+                    .fg(theme.cyber_cyan)
+                    .add_modifier(Modifier::BOLD),
+            )),
+        );
+    }
+    if app.current_file.is_some() {
+        lines.insert(0, request_subtitle(app));
+    }
+
+    let mut paragraph = Paragraph::new(lines).block(block);
+    if app.editor_word_wrap {
+        paragraph = paragraph.wrap(Wrap { trim: false });
+    }
+
+    frame.render_widget(paragraph, area);
+    render_scrollbar(
+        frame,
+        Rect::new(
+            area.x + 1,
+            area.y + 1,
+            area.width.saturating_sub(2),
+            inner_height as u16,
+        ),
+        scroll,
+        app.editor_content.len(),
+        &theme,
+    );
+
+    if show_search_bar {
+        render_search_bar(frame, app, area);
+    }
+
+    if is_editing && !app.url_completions.is_empty() {
+        render_url_completions(frame, app, area, inner_height, header_rows, &hidden_lines);
+    } else if is_active && focus.is_none() {
+        render_variable_hover(frame, app, area, inner_height, header_rows, &hidden_lines);
+    }
+}
+
+/// The method and URL of the entry under the cursor (`→ GET https://...`)
+fn request_subtitle(app: &App) -> Line<'static> {
+    let theme = &app.active_theme;
+    let Some(entry) = app.entry_under_cursor() else {
+        return Line::from("");
+    };
+    Line::from(vec![
+        Span::styled("  → ", Style::default().fg(theme.text_muted)),
+        Span::styled(
+            entry.method.clone(),
+            Style::default()
+                .fg(theme.syntax_method)
+                .add_modifier(Modifier::BOLD),
+        ),
+        Span::raw(" "),
+        Span::styled(entry.url.clone(), Style::default().fg(theme.syntax_url)),
+    ])
+}
+
+/// Editor border with the file name, tab and entry position, vim mode and unsaved marker in the title
+fn editor_block(app: &App, is_active: bool, is_editing: bool) -> Block<'static> {
+    let theme = &app.active_theme;
+    let vim_mode_str = match (is_editing, app.vim_mode) {
+        (true, VimMode::Normal) => "[VIM]",
+        (true, VimMode::Insert) => "[INSERT]",
+        (true, VimMode::Visual) => "[VISUAL]",
+        _ => "",
+    };
+
+    // Show the tab position when the file is open as a tab
+    let tab_str = match &app.current_file_path {
+        Some(path) if app.open_tabs.contains(path) => {
+            format!(" [{}/{}]", app.active_tab + 1, app.open_tabs.len())
+        }
+        _ => String::new(),
+    };
+
+    // Show the selected entry when the file has several
+    let entry_str = match &app.current_file {
+        Some(file) if file.entries.len() > 1 => format!(
+            " (entry {}/{})",
+            app.selected_entry.min(file.entries.len() - 1) + 1,
+            file.entries.len()
+        ),
+        _ => String::new(),
+    };
 
+    let title = match (&app.current_file_path, is_editing) {
+        (Some(path), true) => format!(
+            " {} {}{}{} {} ",
+            BoxChars::TERMINAL_PROMPT,
+            path.file_name()
+                .map(|n| n.to_string_lossy().to_string())
+                .unwrap_or_else(|| "untitled".to_string()),
+            tab_str,
+            entry_str,
+            vim_mode_str
+        ),
+        (Some(path), false) => format!(
+            " {} {}{}{} ",
+            BoxChars::LAMBDA,
+            path.file_name()
+                .map(|n| n.to_string_lossy().to_string())
+                .unwrap_or_else(|| "untitled".to_string()),
+            tab_str,
+            entry_str
+        ),
+        (None, _) => format!(" {} Editor ", BoxChars::LAMBDA),
+    };
+
+    // Mark unsaved edits
+    let mut title_line = Line::from(title);
+    if app.editor_dirty {
+        title_line.push_span(Span::styled(
+            "[+] ",
+            Style::default()
+                .fg(theme.amber_warning)
+                .add_modifier(Modifier::BOLD),
+        ));
+    }
+
+    let border_color = if is_editing {
+        theme.mode_edit_fg
+    } else if is_active {
+        theme.matrix_green
+    } else {
+        theme.border_dim
+    };
+
+    Block::default()
+        .title(title_line)
+        .title_style(
+            Style::default()
+                .fg(border_color)
+                .add_modifier(Modifier::BOLD),
+        )
+        .borders(Borders::ALL)
+        .border_style(Style::default().fg(border_color))
+        .style(Style::default().bg(theme.void_black))
+}
+
+/// Render an open .env file, masking the values of secret-looking keys
+fn render_env_file(frame: &mut Frame, app: &App, area: Rect, is_active: bool, is_editing: bool) {
+    let theme = &app.active_theme;
+    let block = editor_block(app, is_active, is_editing);
+    let inner_height = area.height.saturating_sub(2) as usize;
+    let scroll = app.editor_scroll;
+
+    let lines: Vec<Line> = app
+        .editor_content
+        .iter()
+        .enumerate()
+        .skip(scroll)
+        .take(inner_height)
+        .map(|(line_num, content)| {
+            let is_cursor_line = is_editing && line_num == app.editor_cursor.0;
+            // The value stays visible while typing on its line
+            let masked = content
+                .split_once('=')
+                .is_some_and(|(key, value)| is_secret_key(key) && !value.trim().is_empty())
+                && !app.is_env_value_revealed(line_num)
+                && !(is_cursor_line && app.vim_mode == VimMode::Insert);
+
+            let gutter = if masked {
+                format!(
+                    "{}{:3} {} ",
+                    BoxChars::BLOCK_FULL,
+                    line_num + 1,
+                    BoxChars::VERTICAL
+                )
+            } else {
+                format!("{:4} {} ", line_num + 1, BoxChars::VERTICAL)
+            };
+            let mut spans = highlight_env_spans(content, masked, theme);
+            if is_cursor_line {
+                spans = env_cursor_spans(
+                    spans,
+                    content,
+                    masked,
+                    app.editor_cursor.1,
+                    app.vim_mode,
+                    theme,
+                );
+            }
+            spans.insert(
+                0,
+                Span::styled(gutter, Style::default().fg(theme.text_muted)),
+            );
             Line::from(spans)
         })
         .collect();
 
-    let paragraph = Paragraph::new(lines)
-        .block(block)
-        .wrap(Wrap { trim: false });
+    frame.render_widget(Paragraph::new(lines).block(block), area);
+    render_scrollbar(
+        frame,
+        Rect::new(
+            area.x + 1,
+            area.y + 1,
+            area.width.saturating_sub(2),
+            inner_height as u16,
+        ),
+        scroll,
+        app.editor_content.len(),
+        theme,
+    );
+}
 
-    frame.render_widget(paragraph, area);
+/// Highlight a `.env` line: comments muted, keys as variables, secret values replaced by dots
+fn highlight_env_spans(text: &str, masked: bool, theme: &Theme) -> Vec<Span<'static>> {
+    if text.trim_start().starts_with('#') {
+        return vec![Span::styled(
+            text.to_string(),
+            Style::default().fg(theme.text_comment),
+        )];
+    }
+    let Some((key, value)) = text.split_once('=') else {
+        return vec![Span::styled(
+            text.to_string(),
+            Style::default().fg(theme.text_primary),
+        )];
+    };
+
+    let value = if masked {
+        Span::styled("••••••", Style::default().fg(theme.text_muted))
+    } else {
+        Span::styled(value.to_string(), Style::default().fg(theme.syntax_value))
+    };
+    vec![
+        Span::styled(key.to_string(), Style::default().fg(theme.syntax_variable)),
+        Span::styled("=", Style::default().fg(theme.text_muted)),
+        value,
+    ]
+}
+
+/// Draw the editor cursor at byte column `col` of `content` on its highlighted spans.
+/// On a masked value the cursor sits on the first dot, so the hidden text is never shown.
+fn env_cursor_spans(
+    spans: Vec<Span<'static>>,
+    content: &str,
+    masked: bool,
+    col: usize,
+    vim_mode: VimMode,
+    theme: &Theme,
+) -> Vec<Span<'static>> {
+    let cursor_style = match vim_mode {
+        VimMode::Insert => Style::default()
+            .fg(theme.matrix_green_bright)
+            .add_modifier(Modifier::UNDERLINED | Modifier::BOLD),
+        VimMode::Normal | VimMode::Visual => Style::default()
+            .fg(theme.cursor_fg)
+            .bg(theme.cursor_bg)
+            .add_modifier(Modifier::BOLD),
+    };
+    let line_len: usize = spans.iter().map(|s| s.content.len()).sum();
+    // The dots of a masked value are not as long as the value they replace
+    let col = match content.split_once('=') {
+        Some((key, _)) if masked && col > key.len() && col < content.len() => key.len() + 1,
+        _ if col >= content.len() => line_len,
+        _ => col,
+    };
+    let mut result = Vec::new();
+    let mut pos = 0;
+
+    for span in spans {
+        let len = span.content.len();
+        if !(pos..pos + len).contains(&col) || !span.content.is_char_boundary(col - pos) {
+            pos += len;
+            result.push(span);
+            continue;
+        }
+
+        let text = span.content.to_string();
+        let (before, rest) = text.split_at(col - pos);
+        let cursor_len = rest.chars().next().map_or(0, char::len_utf8);
+        let (cursor, after) = rest.split_at(cursor_len);
+        result.extend(
+            [
+                Span::styled(before.to_string(), span.style),
+                Span::styled(cursor.to_string(), cursor_style),
+                Span::styled(after.to_string(), span.style),
+            ]
+            .into_iter()
+            .filter(|span| !span.content.is_empty()),
+        );
+        pos += len;
+    }
+
+    // Cursor past the end of the line
+    if col >= line_len {
+        result.push(Span::styled(" ", cursor_style));
+    }
+    result
+}
+
+/// One-line summary of a folded body, e.g. `{ ... 3 fields }`
+fn fold_summary(body: &[String]) -> String {
+    let indent: String = body[0].chars().take_while(|c| c.is_whitespace()).collect();
+    let summary = match serde_json::from_str::<serde_json::Value>(&body.join("\n")) {
+        Ok(serde_json::Value::Object(fields)) => format!("{{ ... {} fields }}", fields.len()),
+        Ok(serde_json::Value::Array(items)) => format!("[ ... {} items ]", items.len()),
+        _ => format!("{} ... {} lines", body[0].trim(), body.len()),
+    };
+    format!("{}{}", indent, summary)
+}
+
+/// Show the value of the `{{variable}}` under the cursor in a one-line popup below it
+fn render_variable_hover(
+    frame: &mut Frame,
+    app: &App,
+    area: Rect,
+    inner_height: usize,
+    header_rows: usize,
+    hidden_lines: &[bool],
+) {
+    let theme = &app.active_theme;
+    let (line, col) = app.editor_cursor;
+    let Some(content) = app.editor_content.get(line) else {
+        return;
+    };
+    let Some(name) = find_variable_at_cursor(content, col) else {
+        return;
+    };
+
+    // The popup goes on the row below the cursor, which must still be inside the panel
+    let row = (app.editor_scroll..line)
+        .filter(|l| !hidden_lines[*l])
+        .count()
+        + header_rows
+        + 1;
+    if line < app.editor_scroll || row >= inner_height {
+        return;
+    }
+
+    let value = match app.variables.iter().find(|v| v.name == name) {
+        Some(var) if var.is_secret => "••••••".to_string(),
+        Some(var) => var.value.clone(),
+        None => "(undefined)".to_string(),
+    };
+    let text = format!(" {} = {} ", name, value);
+
+    // Line numbers take 7 columns ("NNNN │ ")
+    let column = content[..col.min(content.len())].chars().count();
+    let cursor_x = area.x + 1 + 7 + column.saturating_sub(app.editor_h_scroll) as u16;
+    let width = (text.chars().count() as u16).min(area.width.saturating_sub(2));
+    let x = cursor_x.min(area.right().saturating_sub(width + 1));
+    let popup = Rect::new(x, area.y + 1 + row as u16, width, 1);
+
+    frame.render_widget(Clear, popup);
+    frame.render_widget(
+        Paragraph::new(text).block(
+            Block::default().style(
+                Style::default()
+                    .fg(theme.syntax_variable)
+                    .bg(theme.selected_bg),
+            ),
+        ),
+        popup,
+    );
+}
+
+/// Show the variable names completing the `{{` under the cursor in a list below it
+fn render_url_completions(
+    frame: &mut Frame,
+    app: &App,
+    area: Rect,
+    inner_height: usize,
+    header_rows: usize,
+    hidden_lines: &[bool],
+) {
+    let theme = &app.active_theme;
+    let (line, col) = app.editor_cursor;
+    let Some(content) = app.editor_content.get(line) else {
+        return;
+    };
+
+    // The list starts on the row below the cursor and is cut at the bottom of the panel
+    let row = (app.editor_scroll..line)
+        .filter(|l| !hidden_lines[*l])
+        .count()
+        + header_rows
+        + 1;
+    if line < app.editor_scroll || row >= inner_height {
+        return;
+    }
+    let height = app.url_completions.len().min(6).min(inner_height - row);
+    let selected = app.url_completion_index.unwrap_or(0);
+    let scroll = (selected + 1).saturating_sub(height);
+
+    let lines: Vec<Line> = app
+        .url_completions
+        .iter()
+        .enumerate()
+        .skip(scroll)
+        .take(height)
+        .map(|(index, name)| {
+            let style = if app.url_completion_index == Some(index) {
+                Style::default()
+                    .fg(theme.syntax_variable)
+                    .bg(theme.selected_bg)
+                    .add_modifier(Modifier::BOLD)
+            } else {
+                Style::default().fg(theme.text_primary).bg(theme.dark_bg)
+            };
+            Line::from(Span::styled(format!(" {} ", name), style))
+        })
+        .collect();
+
+    // Line numbers take 7 columns ("NNNN │ ")
+    let column = content[..col.min(content.len())].chars().count();
+    let cursor_x = area.x + 1 + 7 + column.saturating_sub(app.editor_h_scroll) as u16;
+    let width = app
+        .url_completions
+        .iter()
+        .map(|name| name.chars().count() as u16 + 2)
+        .max()
+        .unwrap_or(0)
+        .min(area.width.saturating_sub(2));
+    let x = cursor_x.min(area.right().saturating_sub(width + 1));
+    let popup = Rect::new(x, area.y + 1 + row as u16, width, height as u16);
+
+    frame.render_widget(Clear, popup);
+    frame.render_widget(
+        Paragraph::new(lines).style(Style::default().bg(theme.dark_bg)),
+        popup,
+    );
+}
+
+/// Render the search bar on the last line inside the editor border
+fn render_search_bar(frame: &mut Frame, app: &App, area: Rect) {
+    let theme = &app.active_theme;
+    if area.height < 3 {
+        return;
+    }
+    let bar_area = Rect {
+        x: area.x + 1,
+        y: area.y + area.height - 2,
+        width: area.width.saturating_sub(2),
+        height: 1,
+    };
+
+    let mut spans = vec![Span::styled(
+        format!("/{}", app.editor_search_query),
+        Style::default().fg(theme.mode_search_fg),
+    )];
+    if app.editor_search_input {
+        spans.push(Span::styled(
+            "_",
+            Style::default().fg(theme.matrix_green_bright),
+        ));
+    }
+    spans.push(Span::styled(
+        format!("  [{} matches]", app.editor_search_matches.len()),
+        Style::default().fg(theme.text_muted),
+    ));
+
+    let bar = Paragraph::new(Line::from(spans)).style(Style::default().bg(theme.mode_search_bg));
+    frame.render_widget(Clear, bar_area);
+    frame.render_widget(bar, bar_area);
+}
+
+/// Render the `Ctrl+g` go-to-line input over the last line of the editor
+fn render_goto_line_bar(frame: &mut Frame, app: &App, area: Rect) {
+    let theme = &app.active_theme;
+    if area.height < 3 {
+        return;
+    }
+    let bar_area = Rect {
+        x: area.x + 1,
+        y: area.y + area.height - 2,
+        width: area.width.saturating_sub(2),
+        height: 1,
+    };
+
+    let line = Line::from(vec![
+        Span::styled("Go to line: ", Style::default().fg(theme.mode_command_fg)),
+        Span::styled(
+            app.goto_line_input.clone(),
+            Style::default().fg(theme.text_primary),
+        ),
+        Span::styled("_", Style::default().fg(theme.matrix_green_bright)),
+        Span::styled(
+            format!("  [1-{}, line:col]", app.editor_content.len()),
+            Style::default().fg(theme.text_muted),
+        ),
+    ]);
+
+    let bar = Paragraph::new(line).style(Style::default().bg(theme.mode_command_bg));
+    frame.render_widget(Clear, bar_area);
+    frame.render_widget(bar, bar_area);
+}
+
+/// Give the byte ranges `col..col + len` a search match background, splitting spans as needed
+fn highlight_search_matches(
+    spans: Vec<Span<'static>>,
+    match_cols: &[usize],
+    len: usize,
+    theme: &Theme,
+) -> Vec<Span<'static>> {
+    let is_match = |pos: usize| match_cols.iter().any(|&col| pos >= col && pos < col + len);
+    let mut result = Vec::new();
+    let mut pos = 0;
+
+    for span in spans {
+        // Keep the cursor style intact
+        if span.style.bg.is_some() {
+            pos += span.content.len();
+            result.push(span);
+            continue;
+        }
+
+        let text = span.content.to_string();
+        let mut start = 0;
+        let mut current = is_match(pos);
+        for (i, _) in text.char_indices().skip(1) {
+            let matched = is_match(pos + i);
+            if matched != current {
+                result.push(search_span(&text[start..i], span.style, current, theme));
+                start = i;
+                current = matched;
+            }
+        }
+        if start < text.len() {
+            result.push(search_span(&text[start..], span.style, current, theme));
+        }
+        pos += text.len();
+    }
+
+    result
+}
+
+/// Highlight the bracket at byte column `col` that matches the one under the cursor
+fn highlight_matching_bracket(
+    spans: Vec<Span<'static>>,
+    col: usize,
+    theme: &Theme,
+) -> Vec<Span<'static>> {
+    let mut result = Vec::new();
+    let mut pos = 0;
+
+    for span in spans {
+        let len = span.content.len();
+        if !(pos..pos + len).contains(&col) || !span.content.is_char_boundary(col - pos) {
+            pos += len;
+            result.push(span);
+            continue;
+        }
+
+        let text = span.content.to_string();
+        let (before, rest) = text.split_at(col - pos);
+        let (bracket, after) = rest.split_at(1.min(rest.len()));
+        result.push(Span::styled(before.to_string(), span.style));
+        result.push(Span::styled(
+            bracket.to_string(),
+            span.style
+                .bg(theme.cursor_bg)
+                .add_modifier(Modifier::UNDERLINED),
+        ));
+        result.push(Span::styled(after.to_string(), span.style));
+        pos += len;
+    }
+
+    result
+}
+
+fn search_span(text: &str, style: Style, matched: bool, theme: &Theme) -> Span<'static> {
+    if matched {
+        Span::styled(text.to_string(), style.bg(theme.search_match_bg))
+    } else {
+        Span::styled(text.to_string(), style)
+    }
 }
 
 /// Render the output file content
 fn render_output_content(frame: &mut Frame, app: &App, area: Rect, is_active: bool) {
+    let theme = &app.active_theme;
     let border_color = if is_active {
-        HackerTheme::MATRIX_GREEN
+        theme.matrix_green
     } else {
-        HackerTheme::BORDER_DIM
+        theme.border_dim
     };
 
     // Compute output file path from current hurl file
@@ -304,7 +1024,7 @@ fn render_output_content(frame: &mut Frame, app: &App, area: Rect, is_active: bo
         )
         .borders(Borders::ALL)
         .border_style(Style::default().fg(border_color))
-        .style(Style::default().bg(HackerTheme::VOID_BLACK));
+        .style(Style::default().bg(theme.void_black));
 
     match content {
         Some(content) => {
@@ -313,7 +1033,7 @@ fn render_output_content(frame: &mut Frame, app: &App, area: Rect, is_active: bo
             let scroll = app.output_scroll;
 
             // Build lines with line numbers and syntax highlighting
-            let highlighted_lines = highlight_output_content(&content);
+            let highlighted_lines = highlight_output_content(&content, theme);
             let lines: Vec<Line> = highlighted_lines
                 .iter()
                 .enumerate()
@@ -323,7 +1043,7 @@ fn render_output_content(frame: &mut Frame, app: &App, area: Rect, is_active: bo
                     let line_number = format!("{:4} {} ", line_num + 1, BoxChars::VERTICAL);
                     let mut spans = vec![Span::styled(
                         line_number,
-                        Style::default().fg(HackerTheme::TEXT_MUTED),
+                        Style::default().fg(theme.text_muted),
                     )];
                     spans.extend(styled_spans.clone());
                     Line::from(spans)
@@ -335,13 +1055,25 @@ fn render_output_content(frame: &mut Frame, app: &App, area: Rect, is_active: bo
                 .wrap(Wrap { trim: false });
 
             frame.render_widget(paragraph, area);
+            render_scrollbar(
+                frame,
+                Rect::new(
+                    area.x + 1,
+                    area.y + 1,
+                    area.width.saturating_sub(2),
+                    inner_height as u16,
+                ),
+                scroll,
+                highlighted_lines.len(),
+                theme,
+            );
         }
         None => {
             let placeholder = Paragraph::new(vec![
                 Line::from(""),
                 Line::from(Span::styled(
                     format!("  {} No output file", BoxChars::DOT),
-                    Style::default().fg(HackerTheme::TEXT_MUTED),
+                    Style::default().fg(theme.text_muted),
                 )),
                 Line::from(""),
                 Line::from(Span::styled(
@@ -349,7 +1081,7 @@ fn render_output_content(frame: &mut Frame, app: &App, area: Rect, is_active: bo
                         "  {} Press [W] to run and write output",
                         BoxChars::ARROW_RIGHT
                     ),
-                    Style::default().fg(HackerTheme::TEXT_SECONDARY),
+                    Style::default().fg(theme.text_secondary),
                 )),
             ])
             .block(block);
@@ -360,10 +1092,11 @@ fn render_output_content(frame: &mut Frame, app: &App, area: Rect, is_active: bo
 
 /// Render the preview content (hurl file with variables resolved)
 fn render_preview_content(frame: &mut Frame, app: &App, area: Rect, is_active: bool) {
+    let theme = &app.active_theme;
     let border_color = if is_active {
-        HackerTheme::MATRIX_GREEN
+        theme.matrix_green
     } else {
-        HackerTheme::BORDER_DIM
+        theme.border_dim
     };
 
     let env_label = if app.current_environment.is_empty() {
@@ -383,14 +1116,14 @@ fn render_preview_content(frame: &mut Frame, app: &App, area: Rect, is_active: b
         )
         .borders(Borders::ALL)
         .border_style(Style::default().fg(border_color))
-        .style(Style::default().bg(HackerTheme::VOID_BLACK));
+        .style(Style::default().bg(theme.void_black));
 
     if app.preview_content.is_empty() {
         let placeholder = Paragraph::new(vec![
             Line::from(""),
             Line::from(Span::styled(
                 format!("  {} No preview available", BoxChars::DOT),
-                Style::default().fg(HackerTheme::TEXT_MUTED),
+                Style::default().fg(theme.text_muted),
             )),
             Line::from(""),
             Line::from(Span::styled(
@@ -398,7 +1131,7 @@ fn render_preview_content(frame: &mut Frame, app: &App, area: Rect, is_active: b
                     "  {} Open a .hurl file to preview with resolved variables",
                     BoxChars::ARROW_RIGHT
                 ),
-                Style::default().fg(HackerTheme::TEXT_SECONDARY),
+                Style::default().fg(theme.text_secondary),
             )),
         ])
         .block(block);
@@ -429,14 +1162,14 @@ fn render_preview_content(frame: &mut Frame, app: &App, area: Rect, is_active: b
 
             let styled_content = if original_line != resolved_line {
                 // This line had variable replacements - highlight the resolved values
-                highlight_preview_line(original_line, resolved_line, &app.variables)
+                highlight_preview_line(original_line, resolved_line, &app.variables, theme)
             } else {
-                highlight_hurl_line(resolved_line)
+                highlight_hurl_line(resolved_line, theme)
             };
 
             let mut spans = vec![Span::styled(
                 line_number,
-                Style::default().fg(HackerTheme::TEXT_MUTED),
+                Style::default().fg(theme.text_muted),
             )];
             spans.extend(styled_content);
 
@@ -449,6 +1182,18 @@ fn render_preview_content(frame: &mut Frame, app: &App, area: Rect, is_active: b
         .wrap(Wrap { trim: false });
 
     frame.render_widget(paragraph, area);
+    render_scrollbar(
+        frame,
+        Rect::new(
+            area.x + 1,
+            area.y + 1,
+            area.width.saturating_sub(2),
+            inner_height as u16,
+        ),
+        scroll,
+        app.preview_content.len(),
+        theme,
+    );
 }
 
 /// Highlight a preview line, showing resolved variable values in a distinct color.
@@ -458,6 +1203,7 @@ fn highlight_preview_line(
     original: &str,
     resolved: &str,
     variables: &[crate::app::Variable],
+    theme: &Theme,
 ) -> Vec<Span<'static>> {
     // Build a list of (placeholder, value) replacements that apply to this line
     let mut replacements: Vec<(&str, &str)> = Vec::new();
@@ -471,7 +1217,7 @@ fn highlight_preview_line(
 
     if replacements.is_empty() {
         // No replacements on this line - use normal highlighting
-        return highlight_hurl_line(resolved);
+        return highlight_hurl_line(resolved, theme);
     }
 
     // Walk through the resolved line and highlight the replaced values.
@@ -498,7 +1244,7 @@ fn highlight_preview_line(
                         spans.push(Span::styled(
                             value.to_string(),
                             Style::default()
-                                .fg(HackerTheme::MATRIX_GREEN_BRIGHT)
+                                .fg(theme.matrix_green_bright)
                                 .add_modifier(Modifier::BOLD | Modifier::UNDERLINED),
                         ));
                         res_pos += value.len();
@@ -514,7 +1260,7 @@ fn highlight_preview_line(
                     let ch = &resolved[res_pos..res_pos + 1];
                     spans.push(Span::styled(
                         ch.to_string(),
-                        Style::default().fg(HackerTheme::TEXT_PRIMARY),
+                        Style::default().fg(theme.text_primary),
                     ));
                     res_pos += 1;
                 }
@@ -534,7 +1280,7 @@ fn highlight_preview_line(
                     run_end_res += 1;
                 }
                 let segment = &resolved[res_pos..run_end_res];
-                spans.extend(highlight_hurl_spans(segment));
+                spans.extend(highlight_hurl_spans(segment, theme));
                 orig_pos = run_end_orig;
                 res_pos = run_end_res;
             } else {
@@ -545,14 +1291,14 @@ fn highlight_preview_line(
 
     // Any remaining text in resolved string
     if res_pos < resolved.len() {
-        spans.extend(highlight_hurl_spans(&resolved[res_pos..]));
+        spans.extend(highlight_hurl_spans(&resolved[res_pos..], theme));
     }
 
     spans
 }
 
 /// Highlight output content (JSON or plain text)
-fn highlight_output_content(content: &str) -> Vec<Vec<Span<'static>>> {
+fn highlight_output_content(content: &str, theme: &Theme) -> Vec<Vec<Span<'static>>> {
     let trimmed = content.trim();
 
     // Try to parse as JSON and pretty-print with highlighting
@@ -560,7 +1306,7 @@ fn highlight_output_content(content: &str) -> Vec<Vec<Span<'static>>> {
         if let Ok(pretty) = serde_json::to_string_pretty(&json) {
             return pretty
                 .lines()
-                .map(|line| highlight_json_line(line))
+                .map(|line| highlight_json_line(line, theme))
                 .collect();
         }
     }
@@ -577,14 +1323,14 @@ fn highlight_output_content(content: &str) -> Vec<Vec<Span<'static>>> {
                     for line in trimmed[..json_start].lines() {
                         lines.push(vec![Span::styled(
                             line.to_string(),
-                            Style::default().fg(HackerTheme::TEXT_SECONDARY),
+                            Style::default().fg(theme.text_secondary),
                         )]);
                     }
                 }
 
                 // Add formatted JSON
                 for line in pretty.lines() {
-                    lines.push(highlight_json_line(line));
+                    lines.push(highlight_json_line(line, theme));
                 }
 
                 return lines;
@@ -598,14 +1344,14 @@ fn highlight_output_content(content: &str) -> Vec<Vec<Span<'static>>> {
         .map(|line| {
             vec![Span::styled(
                 line.to_string(),
-                Style::default().fg(HackerTheme::TEXT_PRIMARY),
+                Style::default().fg(theme.text_primary),
             )]
         })
         .collect()
 }
 
 /// Highlight a single line of JSON
-fn highlight_json_line(line: &str) -> Vec<Span<'static>> {
+fn highlight_json_line(line: &str, theme: &Theme) -> Vec<Span<'static>> {
     let mut spans: Vec<Span<'static>> = Vec::new();
 
     let trimmed = line.trim_start();
@@ -628,9 +1374,9 @@ fn highlight_json_line(line: &str) -> Vec<Span<'static>> {
                 if in_string {
                     current.push(ch);
                     let color = if is_key {
-                        HackerTheme::SYNTAX_HEADER
+                        theme.syntax_header
                     } else {
-                        HackerTheme::SYNTAX_VALUE
+                        theme.syntax_value
                     };
                     spans.push(Span::styled(current.clone(), Style::default().fg(color)));
                     current.clear();
@@ -639,7 +1385,7 @@ fn highlight_json_line(line: &str) -> Vec<Span<'static>> {
                     if !current.is_empty() {
                         spans.push(Span::styled(
                             current.clone(),
-                            Style::default().fg(HackerTheme::TEXT_PRIMARY),
+                            Style::default().fg(theme.text_primary),
                         ));
                         current.clear();
                     }
@@ -651,46 +1397,46 @@ fn highlight_json_line(line: &str) -> Vec<Span<'static>> {
                 if !current.is_empty() {
                     spans.push(Span::styled(
                         current.clone(),
-                        Style::default().fg(HackerTheme::TEXT_PRIMARY),
+                        Style::default().fg(theme.text_primary),
                     ));
                     current.clear();
                 }
                 spans.push(Span::styled(
                     ":".to_string(),
-                    Style::default().fg(HackerTheme::TEXT_MUTED),
+                    Style::default().fg(theme.text_muted),
                 ));
                 is_key = false;
             }
             ',' if !in_string => {
                 if !current.is_empty() {
                     let color = if current.trim().parse::<f64>().is_ok() {
-                        HackerTheme::CYBER_CYAN
+                        theme.cyber_cyan
                     } else if current.trim() == "true" || current.trim() == "false" {
-                        HackerTheme::ELECTRIC_PURPLE
+                        theme.electric_purple
                     } else if current.trim() == "null" {
-                        HackerTheme::TEXT_MUTED
+                        theme.text_muted
                     } else {
-                        HackerTheme::TEXT_PRIMARY
+                        theme.text_primary
                     };
                     spans.push(Span::styled(current.clone(), Style::default().fg(color)));
                     current.clear();
                 }
                 spans.push(Span::styled(
                     ",".to_string(),
-                    Style::default().fg(HackerTheme::TEXT_MUTED),
+                    Style::default().fg(theme.text_muted),
                 ));
                 is_key = true;
             }
             '{' | '}' | '[' | ']' if !in_string => {
                 if !current.is_empty() {
                     let color = if current.trim().parse::<f64>().is_ok() {
-                        HackerTheme::CYBER_CYAN
+                        theme.cyber_cyan
                     } else if current.trim() == "true" || current.trim() == "false" {
-                        HackerTheme::ELECTRIC_PURPLE
+                        theme.electric_purple
                     } else if current.trim() == "null" {
-                        HackerTheme::TEXT_MUTED
+                        theme.text_muted
                     } else {
-                        HackerTheme::TEXT_PRIMARY
+                        theme.text_primary
                     };
                     spans.push(Span::styled(current.clone(), Style::default().fg(color)));
                     current.clear();
@@ -698,7 +1444,7 @@ fn highlight_json_line(line: &str) -> Vec<Span<'static>> {
                 spans.push(Span::styled(
                     ch.to_string(),
                     Style::default()
-                        .fg(HackerTheme::MATRIX_GREEN)
+                        .fg(theme.matrix_green)
                         .add_modifier(Modifier::BOLD),
                 ));
                 if ch == '{' || ch == '[' {
@@ -714,13 +1460,13 @@ fn highlight_json_line(line: &str) -> Vec<Span<'static>> {
     // Handle remaining content
     if !current.is_empty() {
         let color = if current.trim().parse::<f64>().is_ok() {
-            HackerTheme::CYBER_CYAN
+            theme.cyber_cyan
         } else if current.trim() == "true" || current.trim() == "false" {
-            HackerTheme::ELECTRIC_PURPLE
+            theme.electric_purple
         } else if current.trim() == "null" {
-            HackerTheme::TEXT_MUTED
+            theme.text_muted
         } else {
-            HackerTheme::TEXT_PRIMARY
+            theme.text_primary
         };
         spans.push(Span::styled(current, Style::default().fg(color)));
     }
@@ -729,21 +1475,252 @@ fn highlight_json_line(line: &str) -> Vec<Span<'static>> {
 }
 
 /// Highlight a Hurl line and return styled spans
-fn highlight_hurl_line(line: &str) -> Vec<Span<'static>> {
-    highlight_hurl_spans(line)
+fn highlight_hurl_line(line: &str, theme: &Theme) -> Vec<Span<'static>> {
+    highlight_hurl_spans(line, theme)
+}
+
+/// Sections whose lines are `key: value` request parameters
+const PARAM_SECTIONS: &[&str] = &[
+    "[QueryStringParams]",
+    "[Query]",
+    "[FormParams]",
+    "[Form]",
+    "[Cookies]",
+    "[MultipartFormData]",
+    "[Multipart]",
+];
+
+/// Mark the `key: value` lines that belong to one of `sections`
+fn section_lines(lines: &[String], sections: &[&str]) -> Vec<bool> {
+    let mut in_params = false;
+
+    lines
+        .iter()
+        .map(|line| {
+            let trimmed = line.trim();
+            let first_word = trimmed.split_whitespace().next().unwrap_or("");
+            if trimmed.starts_with('[') && trimmed.ends_with(']') {
+                in_params = sections.contains(&trimmed);
+                false
+            } else if trimmed.starts_with("HTTP") || METHODS.contains(&first_word) {
+                in_params = false;
+                false
+            } else {
+                in_params && trimmed.contains(':') && !trimmed.starts_with('#')
+            }
+        })
+        .collect()
+}
+
+/// Part of a GraphQL body a line belongs to
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum GraphQlPart {
+    Query,
+    Variables,
+}
+
+/// Mark the lines of `[GraphQL]` sections and ```` ```graphql ```` blocks
+fn graphql_lines(lines: &[String]) -> Vec<Option<GraphQlPart>> {
+    let mut part = None;
+    let mut fenced = false;
+
+    lines
+        .iter()
+        .map(|line| {
+            let trimmed = line.trim();
+            let first_word = trimmed.split_whitespace().next().unwrap_or("");
+            if fenced && trimmed.starts_with("```") {
+                fenced = false;
+                part = None;
+                None
+            } else if trimmed == "```graphql" {
+                fenced = true;
+                part = Some(GraphQlPart::Query);
+                None
+            } else if !fenced && trimmed.starts_with('[') && trimmed.ends_with(']') {
+                part = (trimmed == "[GraphQL]").then_some(GraphQlPart::Query);
+                None
+            } else if !fenced && (trimmed.starts_with("HTTP") || METHODS.contains(&first_word)) {
+                part = None;
+                None
+            } else {
+                if part.is_some() && first_word == "variables" {
+                    part = Some(GraphQlPart::Variables);
+                }
+                part.filter(|_| !trimmed.is_empty())
+            }
+        })
+        .collect()
+}
+
+/// Highlight a `key: value` parameter line (param name, then value).
+/// Multipart file uploads (`name: file,path; type`) get the `file,` prefix and path colored.
+fn highlight_param_spans(text: &str, theme: &Theme) -> Vec<Span<'static>> {
+    let (name, rest) = text.split_at(text.find(':').unwrap_or(text.len()));
+    let mut spans = vec![Span::styled(
+        name.to_string(),
+        Style::default().fg(theme.syntax_variable),
+    )];
+
+    let value_style = Style::default().fg(theme.syntax_value);
+    let value_start = rest.len() - rest.trim_start_matches([':', ' ']).len();
+    let (separator, value) = rest.split_at(value_start);
+    let Some(file) = value.strip_prefix("file,") else {
+        spans.push(Span::styled(rest.to_string(), value_style));
+        return spans;
+    };
+
+    let (path, content_type) = file.split_at(file.find(';').unwrap_or(file.len()));
+    spans.extend([
+        Span::styled(separator.to_string(), value_style),
+        Span::styled(
+            "file,".to_string(),
+            Style::default().fg(theme.syntax_keyword),
+        ),
+        Span::styled(path.to_string(), Style::default().fg(theme.syntax_url)),
+        Span::styled(content_type.to_string(), value_style),
+    ]);
+    spans
+}
+
+/// Highlight a `[BasicAuth]` `user: password` line, hiding the password when `mask` is set.
+/// The line under the cursor is left unmasked in edit mode so it can be edited.
+fn highlight_basic_auth_spans(text: &str, mask: bool, theme: &Theme) -> Vec<Span<'static>> {
+    let Some((user, password)) = text.split_once(':') else {
+        return highlight_hurl_spans(text, theme);
+    };
+    let padding = &password[..password.len() - password.trim_start().len()];
+    let password = if mask && !password.trim().is_empty() {
+        "••••••"
+    } else {
+        password.trim_start()
+    };
+
+    vec![
+        Span::styled(user.to_string(), Style::default().fg(theme.syntax_variable)),
+        Span::styled(
+            format!(":{}", padding),
+            Style::default().fg(theme.syntax_value),
+        ),
+        Span::styled(password.to_string(), Style::default().fg(theme.neon_red)),
+    ]
+}
+
+/// Keys of hurl's `[Options]` section. Not all of them can be passed on the command line
+/// (see `runner::is_known_option`), but all of them are valid in a file.
+const HURL_OPTION_KEYS: &[&str] = &[
+    "aws-sigv4",
+    "cacert",
+    "cert",
+    "compressed",
+    "connect-timeout",
+    "connect-to",
+    "delay",
+    "header",
+    "http1.0",
+    "http1.1",
+    "http2",
+    "http3",
+    "insecure",
+    "ipv4",
+    "ipv6",
+    "key",
+    "limit-rate",
+    "location",
+    "location-trusted",
+    "max-redirs",
+    "max-time",
+    "netrc",
+    "netrc-file",
+    "netrc-optional",
+    "output",
+    "path-as-is",
+    "pinnedpubkey",
+    "proxy",
+    "repeat",
+    "resolve",
+    "retry",
+    "retry-interval",
+    "skip",
+    "unix-socket",
+    "user",
+    "variable",
+    "verbose",
+    "very-verbose",
+];
+
+/// Highlight an `[Options]` `key: value` line.
+/// Keys hurl does not know are flagged, booleans and numbers get their own colors.
+fn highlight_option_spans(text: &str, theme: &Theme) -> Vec<Span<'static>> {
+    let Some((key, rest)) = text.split_once(':') else {
+        return highlight_hurl_spans(text, theme);
+    };
+    let key_color = if HURL_OPTION_KEYS.contains(&key.trim()) {
+        theme.syntax_keyword
+    } else {
+        theme.amber_warning
+    };
+    let value = rest.trim_start();
+    let padding = &rest[..rest.len() - value.len()];
+
+    // Durations such as `500ms` or `2s` count as numbers
+    let trimmed = value.trim();
+    let number = trimmed.trim_end_matches(|c: char| c.is_ascii_alphabetic());
+    let value_color = if trimmed == "true" || trimmed == "false" {
+        theme.electric_purple
+    } else if number.parse::<f64>().is_ok() {
+        theme.cyber_cyan
+    } else {
+        theme.syntax_value
+    };
+
+    let mut spans = vec![
+        Span::styled(key.to_string(), Style::default().fg(key_color)),
+        Span::styled(
+            format!(":{}", padding),
+            Style::default().fg(theme.syntax_value),
+        ),
+    ];
+    spans.extend(template_spans(
+        value,
+        Style::default().fg(value_color),
+        theme,
+    ));
+    spans
+}
+
+/// Split `text` into spans: `{{variable}}` templates in the variable color, the rest in `style`
+fn template_spans(text: &str, style: Style, theme: &Theme) -> Vec<Span<'static>> {
+    let mut spans = Vec::new();
+    let mut rest = text;
+    while let Some(start) = rest.find("{{") {
+        let Some(len) = rest[start..].find("}}") else {
+            break;
+        };
+        let end = start + len + 2;
+        if start > 0 {
+            spans.push(Span::styled(rest[..start].to_string(), style));
+        }
+        spans.push(Span::styled(
+            rest[start..end].to_string(),
+            Style::default().fg(theme.syntax_variable),
+        ));
+        rest = &rest[end..];
+    }
+    if !rest.is_empty() {
+        spans.push(Span::styled(rest.to_string(), style));
+    }
+    spans
 }
 
 /// Convert a string to highlighted spans for Hurl syntax
-fn highlight_hurl_spans(text: &str) -> Vec<Span<'static>> {
+fn highlight_hurl_spans(text: &str, theme: &Theme) -> Vec<Span<'static>> {
     let text = text.to_string();
     let trimmed = text.trim();
 
     // Comments
     if trimmed.starts_with('#') {
-        return vec![Span::styled(
-            text,
-            Style::default().fg(HackerTheme::TEXT_COMMENT),
-        )];
+        return vec![Span::styled(text, Style::default().fg(theme.text_comment))];
     }
 
     // HTTP methods
@@ -756,13 +1733,10 @@ fn highlight_hurl_spans(text: &str) -> Vec<Span<'static>> {
                 Span::styled(
                     method_part.to_string(),
                     Style::default()
-                        .fg(HackerTheme::SYNTAX_METHOD)
+                        .fg(theme.syntax_method)
                         .add_modifier(Modifier::BOLD),
                 ),
-                Span::styled(
-                    rest.to_string(),
-                    Style::default().fg(HackerTheme::SYNTAX_URL),
-                ),
+                Span::styled(rest.to_string(), Style::default().fg(theme.syntax_url)),
             ];
         }
     }
@@ -772,7 +1746,7 @@ fn highlight_hurl_spans(text: &str) -> Vec<Span<'static>> {
         return vec![Span::styled(
             text,
             Style::default()
-                .fg(HackerTheme::SYNTAX_STATUS)
+                .fg(theme.syntax_status)
                 .add_modifier(Modifier::BOLD),
         )];
     }
@@ -782,7 +1756,7 @@ fn highlight_hurl_spans(text: &str) -> Vec<Span<'static>> {
         return vec![Span::styled(
             text,
             Style::default()
-                .fg(HackerTheme::SYNTAX_SECTION)
+                .fg(theme.syntax_section)
                 .add_modifier(Modifier::BOLD),
         )];
     }
@@ -792,14 +1766,8 @@ fn highlight_hurl_spans(text: &str) -> Vec<Span<'static>> {
         if let Some(colon_pos) = trimmed.find(':') {
             let (name, rest) = trimmed.split_at(colon_pos);
             return vec![
-                Span::styled(
-                    name.to_string(),
-                    Style::default().fg(HackerTheme::SYNTAX_HEADER),
-                ),
-                Span::styled(
-                    rest.to_string(),
-                    Style::default().fg(HackerTheme::SYNTAX_VALUE),
-                ),
+                Span::styled(name.to_string(), Style::default().fg(theme.syntax_header)),
+                Span::styled(rest.to_string(), Style::default().fg(theme.syntax_value)),
             ];
         }
     }
@@ -821,7 +1789,7 @@ fn highlight_hurl_spans(text: &str) -> Vec<Span<'static>> {
         if trimmed.starts_with(keyword) {
             return vec![Span::styled(
                 text,
-                Style::default().fg(HackerTheme::SYNTAX_KEYWORD),
+                Style::default().fg(theme.syntax_keyword),
             )];
         }
     }
@@ -830,21 +1798,122 @@ fn highlight_hurl_spans(text: &str) -> Vec<Span<'static>> {
     if trimmed.contains("{{") && trimmed.contains("}}") {
         return vec![Span::styled(
             text,
-            Style::default().fg(HackerTheme::SYNTAX_VARIABLE),
+            Style::default().fg(theme.syntax_variable),
         )];
     }
 
     // JSON content
     if trimmed.starts_with('{') || trimmed.starts_with('[') || trimmed.starts_with('"') {
-        return vec![Span::styled(
-            text,
-            Style::default().fg(HackerTheme::SYNTAX_DATA),
-        )];
+        return vec![Span::styled(text, Style::default().fg(theme.syntax_data))];
     }
 
     // Default
-    vec![Span::styled(
-        text,
-        Style::default().fg(HackerTheme::TEXT_PRIMARY),
-    )]
+    vec![Span::styled(text, Style::default().fg(theme.text_primary))]
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn texts(spans: &[Span]) -> Vec<String> {
+        spans.iter().map(|span| span.content.to_string()).collect()
+    }
+
+    #[test]
+    fn test_highlight_basic_auth_spans() {
+        let theme = Theme::HACKER;
+
+        let masked = highlight_basic_auth_spans("bob: secret", true, &theme);
+        assert_eq!(texts(&masked), vec!["bob", ": ", "••••••"]);
+        assert_eq!(masked[2].style.fg, Some(theme.neon_red));
+
+        // The cursor line is drawn unmasked while editing
+        let shown = highlight_basic_auth_spans("bob: secret", false, &theme);
+        assert_eq!(texts(&shown), vec!["bob", ": ", "secret"]);
+        let after_cursor = highlight_basic_auth_spans("ob: secret", false, &theme);
+        assert_eq!(texts(&after_cursor), vec!["ob", ": ", "secret"]);
+
+        // An empty password has nothing to hide
+        let empty = highlight_basic_auth_spans("bob:", true, &theme);
+        assert_eq!(texts(&empty), vec!["bob", ":", ""]);
+    }
+
+    #[test]
+    fn test_env_cursor_spans() {
+        let theme = Theme::HACKER;
+        let cursor = |content: &str, masked: bool, col: usize| {
+            let spans = highlight_env_spans(content, masked, &theme);
+            texts(&env_cursor_spans(
+                spans,
+                content,
+                masked,
+                col,
+                VimMode::Normal,
+                &theme,
+            ))
+        };
+
+        assert_eq!(cursor("HOST=api", false, 0), vec!["H", "OST", "=", "api"]);
+        assert_eq!(
+            cursor("HOST=api", false, 6),
+            vec!["HOST", "=", "a", "p", "i"]
+        );
+        assert_eq!(cursor("HOST=api", false, 8), vec!["HOST", "=", "api", " "]);
+
+        // Anywhere in a masked value the cursor covers the first dot
+        let dots = vec!["API_TOKEN", "=", "•", "•••••"];
+        assert_eq!(cursor("API_TOKEN=abcdefghijkl", true, 10), dots);
+        assert_eq!(cursor("API_TOKEN=abcdefghijkl", true, 20), dots);
+        assert_eq!(
+            cursor("API_TOKEN=abcdefghijkl", true, 3),
+            vec!["API", "_", "TOKEN", "=", "••••••"]
+        );
+        assert_eq!(
+            cursor("API_TOKEN=abcdefghijkl", true, 22),
+            vec!["API_TOKEN", "=", "••••••", " "]
+        );
+    }
+
+    #[test]
+    fn test_highlight_matching_bracket() {
+        let theme = Theme::HACKER;
+        let spans = vec![
+            Span::styled("{\"ids\": ", Style::default().fg(theme.text_primary)),
+            Span::styled("[1]", Style::default().fg(theme.syntax_value)),
+        ];
+
+        let highlighted = highlight_matching_bracket(spans.clone(), 8, &theme);
+        assert_eq!(texts(&highlighted), vec!["{\"ids\": ", "", "[", "1]"]);
+        assert_eq!(highlighted[2].style.fg, Some(theme.syntax_value));
+        assert_eq!(highlighted[2].style.bg, Some(theme.cursor_bg));
+
+        // A column past the line leaves the spans alone
+        assert_eq!(
+            texts(&highlight_matching_bracket(spans, 20, &theme)),
+            vec!["{\"ids\": ", "[1]"]
+        );
+    }
+
+    #[test]
+    fn test_highlight_option_spans() {
+        let theme = Theme::HACKER;
+
+        // Valid in a file even though it has no matching CLI flag
+        let spans = highlight_option_spans("max-time: 2s", &theme);
+        assert_eq!(texts(&spans), vec!["max-time", ": ", "2s"]);
+        assert_eq!(spans[0].style.fg, Some(theme.syntax_keyword));
+        assert_eq!(spans[2].style.fg, Some(theme.cyber_cyan));
+
+        let spans = highlight_option_spans("colour: true", &theme);
+        assert_eq!(spans[0].style.fg, Some(theme.amber_warning));
+        assert_eq!(spans[2].style.fg, Some(theme.electric_purple));
+
+        let spans = highlight_option_spans("output: out/{{name}}.json", &theme);
+        assert_eq!(
+            texts(&spans),
+            vec!["output", ": ", "out/", "{{name}}", ".json"]
+        );
+        assert_eq!(spans[3].style.fg, Some(theme.syntax_variable));
+        assert_eq!(spans[4].style.fg, Some(theme.syntax_value));
+    }
 }
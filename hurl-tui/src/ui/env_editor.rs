@@ -0,0 +1,91 @@
+//! Environment editor popup
+//!
+//! Lists the variables of the current .env file with inline editing of names and values.
+
+use ratatui::{
+    style::Style,
+    text::{Line, Span},
+    widgets::{Clear, Paragraph},
+    Frame,
+};
+
+use super::layout::centered_rect;
+use super::popup::{popup_block, popup_list, popup_placeholder};
+use crate::app::{App, EnvEditorField};
+
+/// Render the environment editor popup
+pub fn render_env_editor(frame: &mut Frame, app: &App) {
+    let theme = &app.active_theme;
+    let area = centered_rect(60, 60, frame.area());
+
+    // Clear the background
+    frame.render_widget(Clear, area);
+
+    let block = popup_block(
+        format!(
+            " Environment: {}{} ",
+            app.current_environment,
+            if app.env_editor_modified { " *" } else { "" }
+        ),
+        theme,
+    );
+
+    let lines = if app.env_editor_vars.is_empty() {
+        popup_placeholder("No variables (n to add one)", theme)
+    } else {
+        let name_width = app
+            .env_editor_vars
+            .iter()
+            .map(|(name, _)| name.chars().count())
+            .max()
+            .unwrap_or(0);
+
+        popup_list(
+            area,
+            app.env_editor_vars.len(),
+            app.env_editor_index,
+            theme,
+            |index| {
+                let (name, value) = &app.env_editor_vars[index];
+                let editing = app
+                    .env_editor_editing
+                    .filter(|_| index == app.env_editor_index);
+                let input = format!("{}_", app.env_editor_input);
+
+                let name = match editing {
+                    Some(EnvEditorField::Name) => input.clone(),
+                    _ => name.clone(),
+                };
+                let value = match editing {
+                    Some(EnvEditorField::Value) => input,
+                    _ => value.clone(),
+                };
+                let input_style = Style::default().fg(theme.amber_warning);
+
+                Line::from(vec![
+                    Span::styled(
+                        format!("  {:<width$}", name, width = name_width),
+                        if editing == Some(EnvEditorField::Name) {
+                            input_style
+                        } else {
+                            Style::default().fg(theme.syntax_variable)
+                        },
+                    ),
+                    Span::styled(" = ", Style::default().fg(theme.text_muted)),
+                    Span::styled(
+                        value,
+                        if editing == Some(EnvEditorField::Value) {
+                            input_style
+                        } else {
+                            Style::default().fg(theme.text_primary)
+                        },
+                    ),
+                ])
+            },
+        )
+    };
+
+    let paragraph = Paragraph::new(lines).block(block);
+
+    frame.render_widget(paragraph, area);
+}
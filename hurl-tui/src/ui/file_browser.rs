@@ -3,36 +3,49 @@
 //! Displays the file tree of .hurl files with clean modern aesthetic.
 
 use ratatui::{
-    layout::Rect,
+    layout::{Constraint, Direction, Layout, Rect},
     style::{Modifier, Style},
     text::{Line, Span},
-    widgets::{Block, Borders, List, ListItem},
+    widgets::{Block, Borders, List, ListItem, Paragraph},
     Frame,
 };
 
-use super::theme::HackerTheme;
-use crate::app::{ActivePanel, App, AppMode};
+use std::path::PathBuf;
+use std::time::SystemTime;
+
+use super::scrollbar::render_scrollbar;
+use super::theme::BoxChars;
+use crate::app::{file_section_rows, ActivePanel, App, AppMode, FILE_SECTION_MAX_ENTRIES};
+use crate::util::full_breadcrumb_title;
 
 /// Render the file browser panel
 pub fn render_file_browser(frame: &mut Frame, app: &mut App, area: Rect) {
+    let theme = app.active_theme;
     let is_active = app.active_panel == ActivePanel::FileBrowser;
     let is_filtering = app.mode == AppMode::Filter;
 
     // Build title with filter indicator - clean style
-    let title = if is_filtering {
+    let title = if app.filter_is_regex && (is_filtering || !app.filter_query.is_empty()) {
+        let cursor = if is_filtering { "_" } else { "" };
+        format!(" Files [REGEX >{}{}] ", app.filter_query, cursor)
+    } else if is_filtering {
         format!(" Files: {}_ ", app.filter_query)
     } else if !app.filter_query.is_empty() {
         format!(" Files: {} ", app.filter_query)
+    } else if app.file_tree_loading {
+        format!(" {} Files ", BoxChars::spinner(app.spinner_frame))
+    } else if let Some(title) = breadcrumb_title(app, area.width.saturating_sub(2) as usize) {
+        title
     } else {
         " Files ".to_string()
     };
 
     let border_color = if is_filtering {
-        HackerTheme::MODE_FILTER_FG
+        theme.mode_filter_fg
     } else if is_active {
-        HackerTheme::MATRIX_GREEN
+        theme.matrix_green
     } else {
-        HackerTheme::BORDER_DIM
+        theme.border_dim
     };
 
     let block = Block::default()
@@ -44,9 +57,13 @@ pub fn render_file_browser(frame: &mut Frame, app: &mut App, area: Rect) {
         )
         .borders(Borders::ALL)
         .border_style(Style::default().fg(border_color))
-        .style(Style::default().bg(HackerTheme::VOID_BLACK));
+        .style(Style::default().bg(theme.void_black));
 
+    let inner = block.inner(area);
+    let show_metadata = app.config.ui.show_file_metadata;
+    let now = SystemTime::now();
     let visible_files = app.get_visible_files();
+    let visible_count = visible_files.len();
     let items: Vec<ListItem> = visible_files
         .iter()
         .enumerate()
@@ -70,28 +87,231 @@ pub fn render_file_browser(frame: &mut Frame, app: &mut App, area: Rect) {
             let display = format!("{}{}{}{}", indent, icon, name, dir_marker);
 
             let style = if idx == app.file_tree_index {
-                Style::default()
-                    .fg(HackerTheme::SELECTED_FG)
-                    .bg(HackerTheme::SELECTED_BG)
+                Style::default().fg(theme.selected_fg).bg(theme.selected_bg)
             } else if entry.is_dir {
-                Style::default().fg(HackerTheme::CYBER_CYAN)
+                Style::default().fg(theme.cyber_cyan)
             } else {
-                Style::default().fg(HackerTheme::TEXT_PRIMARY)
+                Style::default().fg(theme.text_primary)
             };
 
-            ListItem::new(Line::from(Span::styled(display, style)))
+            // Method badge, once the file has been opened
+            let mut spans = vec![Span::styled(display, style)];
+            if let Some(method) = app.file_method_cache.get(&entry.path) {
+                let color = match method.as_str() {
+                    "GET" => theme.status_2xx,
+                    "POST" => theme.cyber_cyan,
+                    "PUT" => theme.amber_warning,
+                    "DELETE" => theme.neon_red,
+                    _ => theme.text_muted,
+                };
+                spans.push(Span::styled(" ", style));
+                spans.push(Span::styled(
+                    format!("[{}]", method),
+                    style.fg(color).add_modifier(Modifier::BOLD),
+                ));
+            }
+
+            // Size and age, right-aligned (the last column is left to the scrollbar)
+            if show_metadata && entry.path.extension().is_some_and(|e| e == "hurl") {
+                let metadata = format!(
+                    "{:>5} {:>4}",
+                    compact_size(entry.file_size),
+                    age(entry.modified, now)
+                );
+                let used: usize = spans.iter().map(|s| s.content.chars().count()).sum();
+                let width = inner.width.saturating_sub(1) as usize;
+                if used + 1 + metadata.len() <= width {
+                    spans.push(Span::styled(
+                        " ".repeat(width - used - metadata.len()),
+                        style,
+                    ));
+                    spans.push(Span::styled(metadata, style.fg(theme.text_muted)));
+                }
+            }
+
+            ListItem::new(Line::from(spans))
         })
         .collect();
 
+    // Pinned files and bookmarks are listed above the tree
+    frame.render_widget(block, area);
+    let chunks = Layout::default()
+        .direction(Direction::Vertical)
+        .constraints([
+            Constraint::Length(file_section_rows(app.pinned_files.len())),
+            Constraint::Length(file_section_rows(app.bookmarks.len())),
+            Constraint::Min(0),
+        ])
+        .split(inner);
+    if !app.pinned_files.is_empty() {
+        render_file_section(
+            frame,
+            app,
+            chunks[0],
+            &app.pinned_files,
+            "◈",
+            "Pinned",
+            false,
+        );
+    }
+    if !app.bookmarks.is_empty() {
+        render_file_section(
+            frame,
+            app,
+            chunks[1],
+            &app.bookmarks,
+            "★",
+            "Bookmarks",
+            true,
+        );
+    }
+    let list_area = chunks[2];
+
     let list = List::new(items)
-        .block(block)
-        .style(Style::default().bg(HackerTheme::VOID_BLACK))
+        .style(Style::default().bg(theme.void_black))
         .highlight_style(
             Style::default()
-                .fg(HackerTheme::SELECTED_FG)
-                .bg(HackerTheme::SELECTED_BG)
+                .fg(theme.selected_fg)
+                .bg(theme.selected_bg)
                 .add_modifier(Modifier::BOLD),
         );
 
-    frame.render_stateful_widget(list, area, &mut app.file_tree_state);
+    frame.render_stateful_widget(list, list_area, &mut app.file_tree_state);
+    render_scrollbar(
+        frame,
+        list_area,
+        app.file_tree_state.offset(),
+        visible_count,
+        &theme,
+    );
+}
+
+/// Render a file section listed above the tree (pinned files or bookmarks): its first
+/// `FILE_SECTION_MAX_ENTRIES` files, then a `── label ──` divider counting the hidden ones.
+/// Bookmarks are `numbered` with the slot used to jump to them.
+fn render_file_section(
+    frame: &mut Frame,
+    app: &App,
+    area: Rect,
+    paths: &[PathBuf],
+    icon: &str,
+    label: &str,
+    numbered: bool,
+) {
+    let theme = &app.active_theme;
+    let mut lines: Vec<Line> = paths
+        .iter()
+        .take(FILE_SECTION_MAX_ENTRIES)
+        .enumerate()
+        .map(|(index, path)| {
+            let mut spans = vec![Span::styled(
+                format!("{} ", icon),
+                Style::default().fg(theme.cyber_cyan),
+            )];
+            if numbered {
+                let slot = if index < 9 {
+                    format!("{} ", index + 1)
+                } else {
+                    "  ".to_string()
+                };
+                spans.push(Span::styled(slot, Style::default().fg(theme.text_muted)));
+            }
+            let name = path
+                .file_name()
+                .map(|n| n.to_string_lossy().to_string())
+                .unwrap_or_default();
+            let style = if app.current_file_path.as_ref() == Some(path) {
+                Style::default()
+                    .fg(theme.text_primary)
+                    .add_modifier(Modifier::BOLD)
+            } else {
+                Style::default().fg(theme.text_primary)
+            };
+            spans.push(Span::styled(name, style));
+            Line::from(spans)
+        })
+        .collect();
+
+    let hidden = paths.len().saturating_sub(FILE_SECTION_MAX_ENTRIES);
+    let label = if hidden > 0 {
+        format!("{} +{}", label, hidden)
+    } else {
+        label.to_string()
+    };
+    lines.push(Line::from(Span::styled(
+        format!("{0}{0} {1} {0}{0}", BoxChars::HORIZONTAL, label),
+        Style::default().fg(theme.text_muted),
+    )));
+
+    frame.render_widget(Paragraph::new(lines), area);
+}
+
+/// Compact byte size (`512B`, `2.3K`, `1.5M`)
+fn compact_size(bytes: u64) -> String {
+    const KB: u64 = 1024;
+    const MB: u64 = 1024 * 1024;
+    if bytes >= MB {
+        format!("{:.1}M", bytes as f64 / MB as f64)
+    } else if bytes >= KB {
+        format!("{:.1}K", bytes as f64 / KB as f64)
+    } else {
+        format!("{}B", bytes)
+    }
+}
+
+/// Time since `modified` in its largest unit (`30s`, `5m`, `2h`, `3d`, `8w`)
+fn age(modified: SystemTime, now: SystemTime) -> String {
+    let secs = now
+        .duration_since(modified)
+        .map(|d| d.as_secs())
+        .unwrap_or(0);
+    match secs {
+        0..=59 => format!("{}s", secs),
+        60..=3_599 => format!("{}m", secs / 60),
+        3_600..=86_399 => format!("{}h", secs / 3_600),
+        86_400..=604_799 => format!("{}d", secs / 86_400),
+        _ => format!("{}w", secs / 604_800),
+    }
+}
+
+/// File browser title with the breadcrumb, cut from the left with `…` to fit in `width`
+fn breadcrumb_title(app: &App, width: usize) -> Option<String> {
+    let entry = app.get_selected_file_entry()?;
+    let (title, _) = full_breadcrumb_title(entry, &app.working_dir)?;
+    let len = title.chars().count();
+    if len <= width {
+        return Some(title);
+    }
+    let tail: String = title.chars().skip(len - width.saturating_sub(1)).collect();
+    Some(format!("…{}", tail))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::time::Duration;
+
+    #[test]
+    fn test_compact_size() {
+        assert_eq!(compact_size(0), "0B");
+        assert_eq!(compact_size(1023), "1023B");
+        assert_eq!(compact_size(1024), "1.0K");
+        assert_eq!(compact_size(2_400), "2.3K");
+        assert_eq!(compact_size(1_572_864), "1.5M");
+    }
+
+    #[test]
+    fn test_age() {
+        let now = SystemTime::UNIX_EPOCH + Duration::from_secs(10_000_000);
+        let ago = |secs| age(now - Duration::from_secs(secs), now);
+        assert_eq!(ago(30), "30s");
+        assert_eq!(ago(60), "1m");
+        assert_eq!(ago(3_599), "59m");
+        assert_eq!(ago(7_200), "2h");
+        assert_eq!(ago(3 * 86_400), "3d");
+        assert_eq!(ago(8 * 604_800), "8w");
+
+        // A modification time in the future counts as just now
+        assert_eq!(age(now + Duration::from_secs(60), now), "0s");
+    }
 }
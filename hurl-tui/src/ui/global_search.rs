@@ -0,0 +1,62 @@
+//! Global search popup
+//!
+//! Displays the query and the matching lines of all .hurl files.
+
+use ratatui::{
+    style::Style,
+    text::{Line, Span},
+    widgets::{Clear, Paragraph},
+    Frame,
+};
+
+use super::layout::centered_rect;
+use super::popup::{popup_block, popup_list, popup_placeholder};
+use crate::app::App;
+
+/// Render the global search popup
+pub fn render_global_search(frame: &mut Frame, app: &App) {
+    let theme = &app.active_theme;
+    let area = centered_rect(70, 60, frame.area());
+
+    // Clear the background
+    frame.render_widget(Clear, area);
+
+    let block = popup_block(format!(" Search: {}_ ", app.global_search_query), theme);
+
+    let results = &app.global_search_results;
+    let lines = if results.is_empty() {
+        let message = if app.global_search_query.is_empty() {
+            "Type to search all .hurl files"
+        } else {
+            "No matches"
+        };
+        popup_placeholder(message, theme)
+    } else {
+        popup_list(
+            area,
+            results.len(),
+            app.global_search_index,
+            theme,
+            |index| {
+                let result = &results[index];
+                let location = format!(
+                    " {}:{} ",
+                    result
+                        .path
+                        .strip_prefix(&app.working_dir)
+                        .unwrap_or(&result.path)
+                        .display(),
+                    result.line + 1
+                );
+                Line::from(vec![
+                    Span::styled(location, Style::default().fg(theme.syntax_url)),
+                    Span::styled(result.text.clone(), Style::default().fg(theme.text_primary)),
+                ])
+            },
+        )
+    };
+
+    let paragraph = Paragraph::new(lines).block(block);
+
+    frame.render_widget(paragraph, area);
+}
@@ -10,11 +10,21 @@ use ratatui::{
 };
 
 use super::layout::centered_rect;
-use super::theme::HackerTheme;
+use super::theme::Theme;
 use crate::app::App;
+use crate::config::Action;
 
 /// Render the help overlay
-pub fn render_help(frame: &mut Frame, _app: &App) {
+pub fn render_help(frame: &mut Frame, app: &App) {
+    let theme = &app.active_theme;
+    // Configurable keys are shown as currently bound
+    let key = |action: Action| {
+        app.keymap
+            .binding(action)
+            .map(|b| b.to_string())
+            .unwrap_or_default()
+    };
+
     let area = centered_rect(55, 70, frame.area());
 
     // Clear the background
@@ -24,58 +34,133 @@ pub fn render_help(frame: &mut Frame, _app: &App) {
         .title(" Help ")
         .title_style(
             Style::default()
-                .fg(HackerTheme::MATRIX_GREEN)
+                .fg(theme.matrix_green)
                 .add_modifier(Modifier::BOLD),
         )
         .borders(Borders::ALL)
-        .border_style(Style::default().fg(HackerTheme::BORDER_DIM))
-        .style(Style::default().bg(HackerTheme::VOID_BLACK));
+        .border_style(Style::default().fg(theme.border_dim))
+        .style(Style::default().bg(theme.void_black));
 
     let help_text = vec![
         Line::from(""),
-        section_header("Navigation"),
-        help_line("j/k", "Move down/up"),
-        help_line("h/l", "Switch panels"),
-        help_line("Tab", "Cycle panels"),
-        help_line("g/G", "Go to start/end"),
-        help_line("Ctrl+d/u", "Page down/up"),
+        section_header("Navigation", theme),
+        help_line("j/k", "Move down/up", theme),
+        help_line("h/l", "Switch panels", theme),
+        help_line("Tab", "Cycle panels", theme),
+        help_line("g/G", "Go to start/end", theme),
+        help_line("Ctrl+d/u", "Page down/up", theme),
+        help_line("Ctrl+g", "Go to line[:col]", theme),
+        Line::from(""),
+        section_header("Files", theme),
+        help_line("Enter", "Open file", theme),
+        help_line("Space", "Expand/collapse", theme),
+        help_line("R", "Refresh", theme),
+        help_line("Ctrl+f", "Search all files", theme),
+        help_line("Ctrl+k", "Cookie jar", theme),
+        help_line("f/F", "Filter / Clear (any case, all words)", theme),
+        help_line("Ctrl+x", "Regex filter (while filtering)", theme),
+        help_line("p/P", "Copy / Paste file", theme),
+        help_line("x", "Cut file (paste moves it)", theme),
+        help_line("n", "Rename", theme),
+        help_line("N", "New file", theme),
+        help_line("Ctrl+n", "New directory", theme),
+        help_line("Del", "Delete file", theme),
+        help_line("[/]", "Resize sidebar / Switch tab", theme),
+        help_line("Alt+l", "Cycle layout presets", theme),
+        help_line("Alt+w", "Toggle word wrap (editor/response)", theme),
+        help_line("Ctrl+z", "Toggle fullscreen for the active panel", theme),
+        help_line("Shift+←/→", "Scroll left/right (no wrap)", theme),
+        help_line("Ctrl+w", "Close tab", theme),
+        help_line("A", "Toggle assertions", theme),
+        help_line("D", "Toggle editor", theme),
+        help_line("S", "Toggle response", theme),
+        help_line("Ctrl+a", "Toggle animations", theme),
+        help_line("Ctrl+b", "Edit file in $EDITOR", theme),
+        help_line("Alt+i", "Toggle TLS verification", theme),
         Line::from(""),
-        section_header("Files"),
-        help_line("Enter", "Open file"),
-        help_line("Space", "Expand/collapse"),
-        help_line("R", "Refresh"),
-        help_line("f/F", "Filter / Clear"),
-        help_line("p/P", "Copy / Paste file"),
-        help_line("n", "Rename"),
-        help_line("[/]", "Resize sidebar"),
-        help_line("A", "Toggle assertions"),
-        help_line("D", "Toggle editor"),
-        help_line("S", "Toggle response"),
+        section_header("Actions", theme),
+        help_line(&key(Action::Run), "Run request", theme),
+        help_line("{/}", "Select entry", theme),
+        help_line("Ctrl+r", "Run selected entry", theme),
+        help_line("Ctrl+c", "Cancel running request", theme),
+        help_line("X", "Run all visible files", theme),
+        help_line("W", "Run & write output", theme),
+        help_line(&key(Action::Edit), "Edit mode", theme),
+        help_line("v", "Variables", theme),
+        help_line("E", "Cycle environment", theme),
+        help_line("Ctrl+e", "Edit environment", theme),
+        help_line("H", "Run history", theme),
+        help_line("m/M", "Toggle/manage bookmarks", theme),
+        help_line("'1-'9", "Jump to bookmark", theme),
+        help_line("Ctrl+p", "Pin/unpin file (file browser)", theme),
+        help_line("1/2/3", "Editor tabs (Hurl/Output/Preview)", theme),
+        help_line(
+            "1-7",
+            "Response tabs (4: Cookies, 5: Timing, 6: Request, 7: Diff)",
+            theme,
+        ),
+        help_line("z", "Collapse NDJSON objects (response)", theme),
+        help_line("Enter", "Expand/collapse JSON array (response)", theme),
+        help_line("Ctrl+p", "Pretty/raw JSON/XML body (response)", theme),
+        help_line("/ n/N", "Search body, next/prev match (response)", theme),
         Line::from(""),
-        section_header("Actions"),
-        help_line("r", "Run request"),
-        help_line("W", "Run & write output"),
-        help_line("e", "Edit mode"),
-        help_line("v", "Variables"),
-        help_line("E", "Cycle environment"),
-        help_line("1/2/3", "Editor tabs (Hurl/Output/Preview)"),
-        help_line("1/2/3", "Response tabs"),
+        section_header("Editor", theme),
+        help_line("u", "Undo", theme),
+        help_line("Ctrl+r", "Redo", theme),
+        help_line("y/d", "Yank / delete line", theme),
+        help_line("p/P", "Put below / above", theme),
+        help_line("]] / [[", "Next / previous entry", theme),
+        help_line("Ctrl+d", "Duplicate entry", theme),
+        help_line("gc", "Toggle comment (line / selection)", theme),
+        help_line("Enter", "Reveal .env secret (3s)", theme),
+        help_line("v", "Visual line select (y/d/p)", theme),
+        help_line("za", "Fold / unfold request body", theme),
+        help_line("zf", "Focus entry (]/[ cycle, Esc exit)", theme),
+        help_line("/", "Search (n/N next/prev)", theme),
+        help_line("Ctrl+s", "Insert snippet (insert mode)", theme),
+        help_line("Tab", "Complete {{variable}} in URL (insert)", theme),
         Line::from(""),
-        section_header("Clipboard"),
-        help_line("y", "Copy path"),
-        help_line("Y", "Copy response"),
-        help_line("x", "Copy request content"),
-        help_line("c", "Copy AI context"),
-        help_line("C", "Copy hurl command"),
+        section_header("Clipboard", theme),
+        help_line("y", "Copy path", theme),
+        help_line("Y", "Copy response", theme),
+        help_line(
+            "x",
+            "Copy request content (outside the file browser)",
+            theme,
+        ),
+        help_line("c", "Copy AI context", theme),
+        help_line("C", "Copy hurl command", theme),
         Line::from(""),
-        section_header("Commands"),
-        help_line(":w", "Save"),
-        help_line(":q", "Quit"),
-        help_line(":wq", "Save & quit"),
+        section_header("Commands", theme),
+        help_line(":w", "Save", theme),
+        help_line(":q", "Quit", theme),
+        help_line(":wq", "Save & quit", theme),
+        help_line(":s/a/b/[g]", "Find & replace", theme),
+        help_line(":42[col5]", "Go to line [column]", theme),
+        help_line(":bind[!] k a", "Bind key k to action a (! saves)", theme),
+        help_line(":curl [cmd]", "Import curl (clipboard if empty)", theme),
+        help_line(":w name", "Save as new file", theme),
+        help_line(":cookies", "Show cookie jar", theme),
+        help_line(":stats", "Run duration statistics", theme),
+        help_line(":theme name", "Switch color theme", theme),
+        help_line(":compare", "Previous vs current body side by side", theme),
+        help_line(":proxy [url]", "Set/reset session proxy", theme),
+        help_line(":setvar KEY VALUE", "Override a variable (session)", theme),
+        help_line(":insecure", "Toggle TLS verification", theme),
+        help_line(":export-har f", "Write response as HAR", theme),
+        help_line(":export-junit f", "Write results as JUnit XML", theme),
+        help_line(":export-env f", "Write variables as .env", theme),
+        help_line(":export-env-json f", "Write variables as JSON", theme),
+        help_line(":!N", "Run the Nth most recent command", theme),
+        help_line("Up/Down  ^r", "Command history / search it", theme),
         Line::from(""),
         Line::from(Span::styled(
-            "  Press q or ? to close",
-            Style::default().fg(HackerTheme::TEXT_MUTED),
+            format!(
+                "  Press {} or {} to close",
+                key(Action::Quit),
+                key(Action::Help)
+            ),
+            Style::default().fg(theme.text_muted),
         )),
     ];
 
@@ -87,26 +172,23 @@ pub fn render_help(frame: &mut Frame, _app: &App) {
 }
 
 /// Section header
-fn section_header(title: &str) -> Line<'static> {
+fn section_header(title: &str, theme: &Theme) -> Line<'static> {
     Line::from(Span::styled(
         format!("  {}", title),
         Style::default()
-            .fg(HackerTheme::SYNTAX_SECTION)
+            .fg(theme.syntax_section)
             .add_modifier(Modifier::BOLD),
     ))
 }
 
 /// Create a help line with key and description
-fn help_line(key: &str, desc: &str) -> Line<'static> {
+fn help_line(key: &str, desc: &str, theme: &Theme) -> Line<'static> {
     Line::from(vec![
         Span::styled("    ", Style::default()),
         Span::styled(
             format!("{:12}", key),
-            Style::default().fg(HackerTheme::MATRIX_GREEN),
-        ),
-        Span::styled(
-            desc.to_string(),
-            Style::default().fg(HackerTheme::TEXT_SECONDARY),
+            Style::default().fg(theme.matrix_green),
         ),
+        Span::styled(desc.to_string(), Style::default().fg(theme.text_secondary)),
     ])
 }
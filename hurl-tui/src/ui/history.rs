@@ -0,0 +1,109 @@
+//! Run history popup
+//!
+//! Lists the requests executed in this session, newest first.
+
+use ratatui::{
+    layout::Constraint,
+    style::{Modifier, Style},
+    widgets::{Cell, Clear, Paragraph, Row, Table},
+    Frame,
+};
+
+use super::layout::centered_rect;
+use super::popup::{popup_block, popup_placeholder, popup_selected_style};
+use super::theme::BoxChars;
+use crate::app::App;
+
+/// Render the run history popup
+pub fn render_history(frame: &mut Frame, app: &App) {
+    let theme = &app.active_theme;
+    let area = centered_rect(70, 60, frame.area());
+
+    // Clear the background
+    frame.render_widget(Clear, area);
+
+    let block = popup_block(format!(" Run History ({}) ", app.history.len()), theme);
+
+    if app.history.is_empty() {
+        let placeholder =
+            Paragraph::new(popup_placeholder("No requests run yet", theme)).block(block);
+        frame.render_widget(placeholder, area);
+        return;
+    }
+
+    let header = Row::new(["Time", "File", "Status", "Duration", ""]).style(
+        Style::default()
+            .fg(theme.syntax_section)
+            .add_modifier(Modifier::BOLD),
+    );
+
+    // Keep the selected entry in view (borders and header take 3 rows)
+    let visible_height = area.height.saturating_sub(3) as usize;
+    let scroll = (app.history_index + 1).saturating_sub(visible_height);
+
+    let rows: Vec<Row> = app
+        .history
+        .iter()
+        .enumerate()
+        .skip(scroll)
+        .take(visible_height)
+        .map(|(index, entry)| {
+            let time = entry
+                .timestamp
+                .with_timezone(&chrono::Local)
+                .format("%Y-%m-%d %H:%M:%S")
+                .to_string();
+            let file = entry
+                .file_path
+                .strip_prefix(&app.working_dir)
+                .unwrap_or(&entry.file_path)
+                .display()
+                .to_string();
+
+            let (status, status_color) = match entry.status_code {
+                Some(code @ 200..=299) => (code.to_string(), theme.status_2xx),
+                Some(code @ 300..=399) => (code.to_string(), theme.status_3xx),
+                Some(code @ 400..=499) => (code.to_string(), theme.status_4xx),
+                Some(code @ 500..=599) => (code.to_string(), theme.status_5xx),
+                Some(code) => (code.to_string(), theme.text_primary),
+                None => ("---".to_string(), theme.text_muted),
+            };
+            let (result, result_color) = if entry.success {
+                (BoxChars::CHECK, theme.matrix_green)
+            } else {
+                (BoxChars::CROSS, theme.neon_red)
+            };
+
+            let row = Row::new([
+                Cell::from(time).style(Style::default().fg(theme.text_muted)),
+                Cell::from(file).style(Style::default().fg(theme.syntax_url)),
+                Cell::from(status).style(Style::default().fg(status_color)),
+                Cell::from(format!("{}ms", entry.duration_ms)),
+                Cell::from(result).style(Style::default().fg(result_color)),
+            ])
+            .style(Style::default().fg(theme.text_primary));
+
+            if index == app.history_index {
+                row.style(popup_selected_style(theme))
+            } else {
+                row
+            }
+        })
+        .collect();
+
+    let table = Table::new(
+        rows,
+        [
+            Constraint::Length(19),
+            Constraint::Min(10),
+            Constraint::Length(6),
+            Constraint::Length(9),
+            Constraint::Length(2),
+        ],
+    )
+    .header(header)
+    .column_spacing(2)
+    .block(block);
+
+    frame.render_widget(table, area);
+}
@@ -4,6 +4,8 @@
 
 use ratatui::layout::{Constraint, Direction, Layout, Rect};
 
+use crate::app::ActivePanel;
+
 /// Layout areas for different UI panels
 pub struct AppLayout {
     /// File browser panel (left side)
@@ -20,6 +22,42 @@ pub struct AppLayout {
     pub status_bar: Rect,
 }
 
+/// Panel arrangement, cycled with `Alt+l`
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, serde::Serialize, serde::Deserialize)]
+pub enum LayoutPreset {
+    /// Editor above response and assertions side by side
+    #[default]
+    Default,
+    /// Narrower sidebar and a taller editor
+    WideEditor,
+    /// Shorter editor and a wider response panel
+    WideResponse,
+    /// Editor, response and assertions stacked vertically
+    Stacked,
+}
+
+impl LayoutPreset {
+    /// The preset after this one
+    pub fn next(self) -> Self {
+        match self {
+            Self::Default => Self::WideEditor,
+            Self::WideEditor => Self::WideResponse,
+            Self::WideResponse => Self::Stacked,
+            Self::Stacked => Self::Default,
+        }
+    }
+
+    /// Display name
+    pub fn name(self) -> &'static str {
+        match self {
+            Self::Default => "Default",
+            Self::WideEditor => "Wide editor",
+            Self::WideResponse => "Wide response",
+            Self::Stacked => "Stacked",
+        }
+    }
+}
+
 /// Panel visibility options
 #[derive(Default)]
 pub struct PanelVisibility {
@@ -29,14 +67,32 @@ pub struct PanelVisibility {
     pub show_editor: bool,
     /// Whether to show the response panel
     pub show_response: bool,
+    /// Panel shown alone over the whole content area (`Ctrl+z`)
+    pub fullscreen: Option<ActivePanel>,
 }
 
-/// Create the application layout with configurable sidebar width and panel visibility
-pub fn create_layout(area: Rect, sidebar_width: u16, visibility: &PanelVisibility) -> AppLayout {
+/// Create the application layout with configurable sidebar width, preset and panel visibility
+pub fn create_layout(
+    area: Rect,
+    sidebar_width: u16,
+    preset: LayoutPreset,
+    visibility: &PanelVisibility,
+) -> AppLayout {
     // Clamp sidebar width to reasonable bounds (10-50%)
-    let sidebar_pct = sidebar_width.clamp(10, 50);
+    let sidebar_pct = match preset {
+        LayoutPreset::WideEditor => (sidebar_width * 2 / 3).clamp(10, 50),
+        _ => sidebar_width.clamp(10, 50),
+    };
     let main_pct = 100 - sidebar_pct;
 
+    // Editor share of the main content and response share of the results area
+    let (editor_pct, response_pct) = match preset {
+        LayoutPreset::Default => (55, 60),
+        LayoutPreset::WideEditor => (70, 60),
+        LayoutPreset::WideResponse => (35, 75),
+        LayoutPreset::Stacked => (45, 65),
+    };
+
     // Main vertical split: content area and status bar
     let main_chunks = Layout::default()
         .direction(Direction::Vertical)
@@ -49,6 +105,10 @@ pub fn create_layout(area: Rect, sidebar_width: u16, visibility: &PanelVisibilit
     let content_area = main_chunks[0];
     let status_bar = main_chunks[1];
 
+    if let Some(panel) = visibility.fullscreen {
+        return fullscreen_layout(content_area, status_bar, panel);
+    }
+
     // Horizontal split: left sidebar and main content
     let horizontal_chunks = Layout::default()
         .direction(Direction::Horizontal)
@@ -77,12 +137,12 @@ pub fn create_layout(area: Rect, sidebar_width: u16, visibility: &PanelVisibilit
     // Adapt based on which panels are visible
     let (editor, results_area) = match (visibility.show_editor, visibility.show_response) {
         (true, true) => {
-            // Both visible: standard 55/45 split
+            // Both visible: split by the preset (55/45 by default)
             let chunks = Layout::default()
                 .direction(Direction::Vertical)
                 .constraints([
-                    Constraint::Percentage(55), // Editor
-                    Constraint::Percentage(45), // Results area
+                    Constraint::Percentage(editor_pct),       // Editor
+                    Constraint::Percentage(100 - editor_pct), // Results area
                 ])
                 .split(main_content);
             (chunks[0], chunks[1])
@@ -111,11 +171,16 @@ pub fn create_layout(area: Rect, sidebar_width: u16, visibility: &PanelVisibilit
         // Response hidden - both response and assertions get zero rects
         (Rect::default(), Rect::default())
     } else if visibility.show_assertions {
+        let direction = if preset == LayoutPreset::Stacked {
+            Direction::Vertical
+        } else {
+            Direction::Horizontal
+        };
         let results_chunks = Layout::default()
-            .direction(Direction::Horizontal)
+            .direction(direction)
             .constraints([
-                Constraint::Percentage(60), // Response
-                Constraint::Percentage(40), // Assertions
+                Constraint::Percentage(response_pct),       // Response
+                Constraint::Percentage(100 - response_pct), // Assertions
             ])
             .split(results_area);
         (results_chunks[0], results_chunks[1])
@@ -134,6 +199,26 @@ pub fn create_layout(area: Rect, sidebar_width: u16, visibility: &PanelVisibilit
     }
 }
 
+/// Layout giving the whole content area to `panel`; the other panels get empty areas
+fn fullscreen_layout(content_area: Rect, status_bar: Rect, panel: ActivePanel) -> AppLayout {
+    let area_of = |candidate: ActivePanel| {
+        if candidate == panel {
+            content_area
+        } else {
+            Rect::default()
+        }
+    };
+
+    AppLayout {
+        file_browser: area_of(ActivePanel::FileBrowser),
+        editor: area_of(ActivePanel::Editor),
+        variables: area_of(ActivePanel::Variables),
+        response: area_of(ActivePanel::Response),
+        assertions: area_of(ActivePanel::Assertions),
+        status_bar,
+    }
+}
+
 /// Create a centered popup area
 pub fn centered_rect(percent_x: u16, percent_y: u16, area: Rect) -> Rect {
     let popup_layout = Layout::default()
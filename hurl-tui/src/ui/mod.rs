@@ -3,58 +3,140 @@
 //! This module contains all UI rendering components for the Hurl TUI.
 
 mod assertions;
+mod bookmarks;
+mod command_history;
+mod compare;
+mod confirm;
+mod cookie_jar;
 mod editor;
+mod env_editor;
 mod file_browser;
+mod global_search;
 mod help;
+mod history;
 mod layout;
+mod popup;
 mod response;
+mod scrollbar;
+mod snippets;
+mod stats;
 mod status_bar;
 pub mod theme;
+mod variable_overrides;
 mod variables;
 
-use ratatui::Frame;
+use ratatui::{
+    layout::Rect,
+    style::{Modifier, Style},
+    text::Span,
+    Frame,
+};
 
-use crate::app::App;
+use crate::app::{ActivePanel, App, AppMode};
+use theme::Theme;
 
 pub use assertions::render_assertions;
+pub use bookmarks::render_bookmarks;
+pub use command_history::render_command_history;
+pub use compare::render_compare;
+pub use confirm::{render_confirm_delete, render_confirm_save};
+pub use cookie_jar::render_cookie_jar;
 pub use editor::{render_editor, EditorTab};
+pub use env_editor::render_env_editor;
 pub use file_browser::render_file_browser;
+pub use global_search::render_global_search;
 pub use help::render_help;
-pub use layout::{create_layout, AppLayout, PanelVisibility};
-pub use response::{render_response, ResponseTab};
+pub use history::render_history;
+pub use layout::{create_layout, AppLayout, LayoutPreset, PanelVisibility};
+pub use response::{
+    render_response, response_json_rows, BodyFormat, JsonRow, ResponseTab,
+};
+pub use snippets::render_snippets;
+pub use stats::render_stats;
 pub use status_bar::render_status_bar;
+pub use variable_overrides::render_variable_overrides;
 pub use variables::render_variables;
 
 /// Main draw function that renders the entire UI
 pub fn draw(frame: &mut Frame, app: &mut App) {
+    let theme = app.active_theme;
+
     let visibility = PanelVisibility {
         show_assertions: app.show_assertions,
         show_editor: app.show_editor,
         show_response: app.show_response,
+        fullscreen: app.fullscreen_panel,
     };
-    let layout = create_layout(frame.area(), app.sidebar_width, &visibility);
+    let layout = create_layout(
+        frame.area(),
+        app.sidebar_width,
+        app.current_layout,
+        &visibility,
+    );
+
+    // Remember panel areas for mouse hit-testing
+    app.panel_areas = vec![
+        (ActivePanel::FileBrowser, layout.file_browser),
+        (ActivePanel::Variables, layout.variables),
+    ];
+    if app.show_editor {
+        app.panel_areas.push((ActivePanel::Editor, layout.editor));
+    }
+    if app.show_response {
+        app.panel_areas
+            .push((ActivePanel::Response, layout.response));
+        if app.show_assertions {
+            app.panel_areas
+                .push((ActivePanel::Assertions, layout.assertions));
+        }
+    }
 
+    // The compare view replaces the panels, leaving only the status bar
+    if app.mode == AppMode::Compare {
+        let area = frame.area();
+        let height = layout.status_bar.y.saturating_sub(area.y);
+        render_compare(frame, app, Rect::new(area.x, area.y, area.width, height));
+        render_status_bar(frame, app, layout.status_bar);
+        return;
+    }
+
+    // Panels without an area are hidden behind a fullscreen panel
     // Render file browser (left panel)
-    render_file_browser(frame, app, layout.file_browser);
+    if !layout.file_browser.is_empty() {
+        render_file_browser(frame, app, layout.file_browser);
+    }
 
     // Render editor (top right) - only if visible
-    if app.show_editor {
+    if app.show_editor && !layout.editor.is_empty() {
         render_editor(frame, app, layout.editor);
     }
 
     // Render variables (bottom left)
-    render_variables(frame, app, layout.variables);
+    if !layout.variables.is_empty() {
+        render_variables(frame, app, layout.variables);
+    }
 
     // Render response (bottom center) - only if visible
-    if app.show_response {
+    if app.show_response && !layout.response.is_empty() {
         render_response(frame, app, layout.response);
     }
 
     // Render assertions (bottom right) - only if visible
-    if app.show_assertions && app.show_response {
+    if app.show_assertions && app.show_response && !layout.assertions.is_empty() {
         render_assertions(frame, app, layout.assertions);
     }
 
+    if let Some(panel) = app.fullscreen_panel {
+        let area = match panel {
+            ActivePanel::FileBrowser => layout.file_browser,
+            ActivePanel::Editor => layout.editor,
+            ActivePanel::Response => layout.response,
+            ActivePanel::Assertions => layout.assertions,
+            ActivePanel::Variables => layout.variables,
+        };
+        render_fullscreen_marker(frame, area, &theme);
+    }
+
     // Render status bar (bottom)
     render_status_bar(frame, app, layout.status_bar);
 
@@ -63,13 +145,67 @@ pub fn draw(frame: &mut Frame, app: &mut App) {
         render_help(frame, app);
     }
 
+    // Render delete confirmation overlay
+    if app.mode == AppMode::ConfirmDelete {
+        render_confirm_delete(frame, app);
+    }
+
+    // Render unsaved changes prompt
+    if app.mode == AppMode::ConfirmSave {
+        render_confirm_save(frame, app);
+    }
+
+    // Render global search popup
+    if app.mode == AppMode::GlobalSearch {
+        render_global_search(frame, app);
+    }
+
+    // Render cookie jar popup
+    if app.mode == AppMode::CookieJar {
+        render_cookie_jar(frame, app);
+    }
+
+    // Render environment editor popup
+    if app.mode == AppMode::EnvEditor {
+        render_env_editor(frame, app);
+    }
+
+    // Render run history popup
+    if app.mode == AppMode::History {
+        render_history(frame, app);
+    }
+
+    // Render command history search popup
+    if app.mode == AppMode::CommandHistorySearch {
+        render_command_history(frame, app);
+    }
+
+    // Render snippet picker popup
+    if app.mode == AppMode::Snippets {
+        render_snippets(frame, app);
+    }
+
+    // Render bookmark manager popup
+    if app.mode == AppMode::Bookmarks {
+        render_bookmarks(frame, app);
+    }
+
+    // Render variable override popup
+    if app.mode == AppMode::VariableOverride {
+        render_variable_overrides(frame, app);
+    }
+
+    // Render run statistics popup
+    if app.mode == AppMode::Stats {
+        render_stats(frame, app);
+    }
+
     // Process and render effects
     render_effects(frame, app, &layout);
 }
 
 /// Render all active effects
 fn render_effects(frame: &mut Frame, app: &mut App, layout: &AppLayout) {
-    use crate::app::ActivePanel;
     use crate::effects::EffectId;
     use tachyonfx::Shader;
 
@@ -121,3 +257,37 @@ fn render_effects(frame: &mut Frame, app: &mut App, layout: &AppLayout) {
     // Clean up completed effects
     app.effect_manager.process_effects();
 }
+
+/// Mark the fullscreen panel on the right of its top border
+fn render_fullscreen_marker(frame: &mut Frame, area: Rect, theme: &Theme) {
+    let label = " FULLSCREEN (Ctrl+z) ";
+    let width = label.chars().count() as u16;
+    if area.height == 0 || area.width < width + 4 {
+        return;
+    }
+
+    let style = Style::default()
+        .fg(theme.amber_warning)
+        .add_modifier(Modifier::BOLD);
+    frame
+        .buffer_mut()
+        .set_string(area.right() - width - 2, area.y, label, style);
+}
+
+/// Drop the first `columns` characters of a line's spans (horizontal scrolling without wrap)
+fn skip_columns(spans: Vec<Span<'_>>, columns: usize) -> Vec<Span<'_>> {
+    let mut remaining = columns;
+    spans
+        .into_iter()
+        .filter_map(|span| {
+            let len = span.content.chars().count();
+            if remaining >= len {
+                remaining -= len;
+                return None;
+            }
+            let content: String = span.content.chars().skip(remaining).collect();
+            remaining = 0;
+            Some(Span::styled(content, span.style))
+        })
+        .collect()
+}
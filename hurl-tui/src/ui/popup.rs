@@ -0,0 +1,68 @@
+//! Shared popup scaffold
+//!
+//! Border and list helpers used by the popups drawn over the panels.
+
+use ratatui::{
+    layout::Rect,
+    style::{Modifier, Style},
+    text::{Line, Span},
+    widgets::{Block, Borders},
+};
+
+use super::theme::{BoxChars, Theme};
+
+/// Bordered popup block titled `title`
+pub fn popup_block<'a>(title: impl Into<Line<'a>>, theme: &Theme) -> Block<'a> {
+    Block::default()
+        .title(title)
+        .title_style(
+            Style::default()
+                .fg(theme.cyber_cyan)
+                .add_modifier(Modifier::BOLD),
+        )
+        .borders(Borders::ALL)
+        .border_style(Style::default().fg(theme.cyber_cyan))
+        .style(Style::default().bg(theme.void_black))
+}
+
+/// Placeholder shown by a popup with nothing to list
+pub fn popup_placeholder(message: &str, theme: &Theme) -> Vec<Line<'static>> {
+    vec![
+        Line::from(""),
+        Line::from(Span::styled(
+            format!("  {} {}", BoxChars::DOT, message),
+            Style::default().fg(theme.text_muted),
+        )),
+    ]
+}
+
+/// Highlight for the selected row of a popup list or table
+pub fn popup_selected_style(theme: &Theme) -> Style {
+    Style::default()
+        .bg(theme.selected_bg)
+        .add_modifier(Modifier::BOLD)
+}
+
+/// Rows of a list of `len` items that fit inside the border of `area`, scrolled to keep
+/// `selected` in view and with the selected row highlighted
+pub fn popup_list<'a>(
+    area: Rect,
+    len: usize,
+    selected: usize,
+    theme: &Theme,
+    row: impl Fn(usize) -> Line<'a>,
+) -> Vec<Line<'a>> {
+    let visible_height = area.height.saturating_sub(2) as usize;
+    let scroll = (selected + 1).saturating_sub(visible_height);
+
+    (scroll..len.min(scroll + visible_height))
+        .map(|index| {
+            let line = row(index);
+            if index == selected {
+                line.style(popup_selected_style(theme))
+            } else {
+                line
+            }
+        })
+        .collect()
+}
@@ -4,14 +4,20 @@
 
 use ratatui::{
     layout::{Constraint, Direction, Layout, Rect},
-    style::{Modifier, Style},
+    style::{Color, Modifier, Style},
     text::{Line, Span},
-    widgets::{Block, Borders, Paragraph, Tabs, Wrap},
+    widgets::{Block, Borders, Cell, Paragraph, Row, Table, Tabs, Wrap},
     Frame,
 };
+use serde_json::Value;
+use std::collections::HashSet;
 
-use super::theme::{BoxChars, HackerTheme};
+use super::scrollbar::render_scrollbar;
+use super::skip_columns;
+use super::theme::{BoxChars, Theme};
 use crate::app::{ActivePanel, App};
+use crate::diff::DiffLine;
+use crate::util::COLLAPSIBLE_ARRAY_LEN;
 
 /// Response view tab
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
@@ -20,6 +26,10 @@ pub enum ResponseTab {
     Body,
     Headers,
     Raw,
+    Cookies,
+    Timing,
+    Request,
+    Diff,
 }
 
 impl ResponseTab {
@@ -28,18 +38,95 @@ impl ResponseTab {
             ResponseTab::Body => 0,
             ResponseTab::Headers => 1,
             ResponseTab::Raw => 2,
+            ResponseTab::Cookies => 3,
+            ResponseTab::Timing => 4,
+            ResponseTab::Request => 5,
+            ResponseTab::Diff => 6,
         }
     }
 }
 
+/// Format of a response body, detected from its Content-Type and content
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum BodyFormat {
+    #[default]
+    Text,
+    Json,
+    /// Newline-delimited JSON with the number of objects
+    Ndjson(usize),
+    Html,
+    Xml,
+    /// Binary content, shown as a hex dump
+    Binary,
+}
+
+/// Detect the format of a body. NDJSON needs every non-empty line to be a JSON object.
+pub fn detect_body_format(body: &str, content_type: Option<&str>) -> BodyFormat {
+    let trimmed = body.trim();
+
+    // Markup is chosen by Content-Type, or sniffed from the body when there is none
+    let lowercase_start = trimmed.chars().take(15).collect::<String>().to_lowercase();
+    match content_type {
+        Some(ct) if ct.starts_with("application/octet-stream") || ct.starts_with("image/") => {
+            return BodyFormat::Binary
+        }
+        Some(ct) if ct.contains("html") => return BodyFormat::Html,
+        Some(ct) if ct.contains("xml") => return BodyFormat::Xml,
+        None if lowercase_start.starts_with("<!doctype html")
+            || lowercase_start.starts_with("<html") =>
+        {
+            return BodyFormat::Html
+        }
+        None if lowercase_start.starts_with("<?xml") => return BodyFormat::Xml,
+        _ => {}
+    }
+
+    if serde_json::from_str::<serde_json::Value>(trimmed).is_ok() {
+        return BodyFormat::Json;
+    }
+
+    let mut objects = 0;
+    for line in trimmed.lines().map(str::trim).filter(|l| !l.is_empty()) {
+        match serde_json::from_str::<serde_json::Value>(line) {
+            Ok(serde_json::Value::Object(_)) => objects += 1,
+            _ => return BodyFormat::Text,
+        }
+    }
+    if objects > 0 {
+        BodyFormat::Ndjson(objects)
+    } else {
+        BodyFormat::Text
+    }
+}
+
+/// Content-Type header value (lowercased)
+fn content_type(headers: &[(String, String)]) -> Option<String> {
+    headers
+        .iter()
+        .find(|(name, _)| name.eq_ignore_ascii_case("content-type"))
+        .map(|(_, value)| value.to_lowercase())
+}
+
 /// Render the response panel
-pub fn render_response(frame: &mut Frame, app: &App, area: Rect) {
+pub fn render_response(frame: &mut Frame, app: &mut App, area: Rect) {
+    let theme = app.active_theme;
+    app.response_tab_areas.clear();
+    app.response_collapse_toggles.clear();
+    // Search matches are kept between frames and recomputed by the body tab when stale
+    if app
+        .execution_result
+        .as_ref()
+        .and_then(|result| result.response.as_ref())
+        .is_none()
+    {
+        app.response_search_matches.clear();
+    }
     let is_active = app.active_panel == ActivePanel::Response;
 
     let border_color = if is_active {
-        HackerTheme::MATRIX_GREEN
+        theme.matrix_green
     } else {
-        HackerTheme::BORDER_DIM
+        theme.border_dim
     };
 
     let block = Block::default()
@@ -51,7 +138,7 @@ pub fn render_response(frame: &mut Frame, app: &App, area: Rect) {
         )
         .borders(Borders::ALL)
         .border_style(Style::default().fg(border_color))
-        .style(Style::default().bg(HackerTheme::VOID_BLACK));
+        .style(Style::default().bg(theme.void_black));
 
     // Check if we have a response
     let Some(result) = &app.execution_result else {
@@ -59,7 +146,7 @@ pub fn render_response(frame: &mut Frame, app: &App, area: Rect) {
             Line::from(""),
             Line::from(Span::styled(
                 format!("  {} Awaiting response data...", BoxChars::DOT),
-                Style::default().fg(HackerTheme::TEXT_MUTED),
+                Style::default().fg(theme.text_muted),
             )),
             Line::from(""),
             Line::from(Span::styled(
@@ -67,7 +154,7 @@ pub fn render_response(frame: &mut Frame, app: &App, area: Rect) {
                     "  {} Press [r] to execute request",
                     BoxChars::TERMINAL_PROMPT
                 ),
-                Style::default().fg(HackerTheme::TEXT_SECONDARY),
+                Style::default().fg(theme.text_secondary),
             )),
         ])
         .block(block);
@@ -83,14 +170,14 @@ pub fn render_response(frame: &mut Frame, app: &App, area: Rect) {
             lines.push(Line::from(""));
             lines.push(Line::from(Span::styled(
                 format!("  {} Response data unavailable", BoxChars::DOT),
-                Style::default().fg(HackerTheme::TEXT_MUTED),
+                Style::default().fg(theme.text_muted),
             )));
         } else {
             // Show error header
             lines.push(Line::from(Span::styled(
                 format!(" {} ERROR", BoxChars::CROSS),
                 Style::default()
-                    .fg(HackerTheme::NEON_RED)
+                    .fg(theme.neon_red)
                     .add_modifier(Modifier::BOLD),
             )));
             lines.push(Line::from(""));
@@ -102,28 +189,28 @@ pub fn render_response(frame: &mut Frame, app: &App, area: Rect) {
                         Line::from(Span::styled(
                             format!(" {}", line),
                             Style::default()
-                                .fg(HackerTheme::NEON_RED)
+                                .fg(theme.neon_red)
                                 .add_modifier(Modifier::BOLD),
                         ))
                     } else if line.contains("-->") {
                         Line::from(Span::styled(
                             format!(" {}", line),
-                            Style::default().fg(HackerTheme::TEXT_COMMENT),
+                            Style::default().fg(theme.text_comment),
                         ))
                     } else if line.trim().starts_with('|') {
                         Line::from(Span::styled(
                             format!(" {}", line),
-                            Style::default().fg(HackerTheme::TEXT_PRIMARY),
+                            Style::default().fg(theme.text_primary),
                         ))
                     } else if line.contains("^^^") {
                         Line::from(Span::styled(
                             format!(" {}", line),
-                            Style::default().fg(HackerTheme::NEON_RED),
+                            Style::default().fg(theme.neon_red),
                         ))
                     } else {
                         Line::from(Span::styled(
                             format!(" {}", line),
-                            Style::default().fg(HackerTheme::TEXT_SECONDARY),
+                            Style::default().fg(theme.text_secondary),
                         ))
                     };
                     lines.push(styled_line);
@@ -131,7 +218,7 @@ pub fn render_response(frame: &mut Frame, app: &App, area: Rect) {
             } else {
                 lines.push(Line::from(Span::styled(
                     "  Request failed (no error details)",
-                    Style::default().fg(HackerTheme::NEON_RED),
+                    Style::default().fg(theme.neon_red),
                 )));
             }
         }
@@ -167,11 +254,11 @@ pub fn render_response(frame: &mut Frame, app: &App, area: Rect) {
 
     // Render status line
     let status_color = match response.status_code {
-        200..=299 => HackerTheme::STATUS_2XX,
-        300..=399 => HackerTheme::STATUS_3XX,
-        400..=499 => HackerTheme::STATUS_4XX,
-        500..=599 => HackerTheme::STATUS_5XX,
-        _ => HackerTheme::TEXT_PRIMARY,
+        200..=299 => theme.status_2xx,
+        300..=399 => theme.status_3xx,
+        400..=499 => theme.status_4xx,
+        500..=599 => theme.status_5xx,
+        _ => theme.text_primary,
     };
 
     let status_icon = match response.status_code {
@@ -187,7 +274,7 @@ pub fn render_response(frame: &mut Frame, app: &App, area: Rect) {
             format!(" {} ", status_icon),
             Style::default().fg(status_color),
         ),
-        Span::styled("STATUS ", Style::default().fg(HackerTheme::TEXT_MUTED)),
+        Span::styled("STATUS ", Style::default().fg(theme.text_muted)),
         Span::styled(
             format!("{}", response.status_code),
             Style::default()
@@ -197,35 +284,78 @@ pub fn render_response(frame: &mut Frame, app: &App, area: Rect) {
         Span::styled("    ", Style::default()),
         Span::styled(
             format!("{}", BoxChars::DOT),
-            Style::default().fg(HackerTheme::TEXT_MUTED),
+            Style::default().fg(theme.text_muted),
         ),
         Span::styled(
             format!(" {}ms", response.duration_ms),
-            Style::default().fg(HackerTheme::CYBER_CYAN),
+            Style::default().fg(theme.cyber_cyan),
         ),
     ]);
 
     let status_paragraph = Paragraph::new(status_line);
     frame.render_widget(status_paragraph, chunks[0]);
 
+    app.response_body_format = if response.body_bytes.is_some() {
+        BodyFormat::Binary
+    } else {
+        detect_body_format(&response.body, content_type(&response.headers).as_deref())
+    };
+    let mode = if app.response_pretty_print {
+        "[PRETTY]"
+    } else {
+        "[RAW]"
+    };
+    let body_title = match app.response_body_format {
+        BodyFormat::Ndjson(objects) => format!(" Body ({} objects) {} ", objects, mode),
+        BodyFormat::Json | BodyFormat::Xml => format!(" Body {} ", mode),
+        BodyFormat::Binary => format!(
+            " Body ({} bytes) ",
+            response
+                .body_bytes
+                .as_ref()
+                .map_or(response.body.len(), Vec::len)
+        ),
+        _ => " Body ".to_string(),
+    };
+
     // Render tabs - clean style
-    let tab_titles = vec![
-        format!(" Body "),
-        format!(" Headers ({}) ", response.headers.len()),
-        format!(" Raw "),
+    let mut tab_titles = vec![
+        (ResponseTab::Body, body_title),
+        (
+            ResponseTab::Headers,
+            format!(" Headers ({}) ", response.headers.len()),
+        ),
+        (ResponseTab::Raw, " Raw ".to_string()),
+        (
+            ResponseTab::Cookies,
+            format!(" Cookies ({}) ", response.cookies.len()),
+        ),
+        (ResponseTab::Timing, " Timing ".to_string()),
+        (ResponseTab::Request, " Request ".to_string()),
     ];
-    let tabs = Tabs::new(tab_titles)
+    if app.previous_execution_result.is_some() {
+        tab_titles.push((ResponseTab::Diff, " Diff ".to_string()));
+    }
+
+    // Remember where each title is drawn so tabs can be clicked
+    // (Tabs pads every title with one space on each side and puts a 1-column divider between)
+    let mut x = chunks[1].x;
+    for (tab, title) in &tab_titles {
+        let width = (title.chars().count() as u16 + 2).min(chunks[1].right().saturating_sub(x));
+        app.response_tab_areas
+            .push((*tab, Rect::new(x, chunks[1].y, width, 1)));
+        x = x.saturating_add(width + 1);
+    }
+
+    let tabs = Tabs::new(tab_titles.into_iter().map(|(_, title)| title))
         .select(app.response_tab.index())
-        .style(Style::default().fg(HackerTheme::TEXT_MUTED))
+        .style(Style::default().fg(theme.text_muted))
         .highlight_style(
             Style::default()
-                .fg(HackerTheme::TEXT_PRIMARY)
+                .fg(theme.text_primary)
                 .add_modifier(Modifier::BOLD),
         )
-        .divider(Span::styled(
-            "│",
-            Style::default().fg(HackerTheme::BORDER_DIM),
-        ));
+        .divider(Span::styled("│", Style::default().fg(theme.border_dim)));
 
     frame.render_widget(tabs, chunks[1]);
 
@@ -236,45 +366,190 @@ pub fn render_response(frame: &mut Frame, app: &App, area: Rect) {
 
     match app.response_tab {
         ResponseTab::Body => {
-            render_body_tab(frame, response, content_area, scroll, visible_height);
+            let targets =
+                render_body_tab(frame, app, response, content_area, scroll, visible_height);
+            app.response_collapse_toggles = targets.toggles;
+            if let Some(matches) = targets.matches {
+                app.response_search_matches = matches;
+                app.response_search_stale = false;
+            }
         }
         ResponseTab::Headers => {
-            render_headers_tab(frame, response, content_area, scroll, visible_height);
+            render_headers_tab(
+                frame,
+                response,
+                content_area,
+                scroll,
+                visible_height,
+                &theme,
+            );
         }
         ResponseTab::Raw => {
-            render_raw_tab(frame, result, content_area, scroll, visible_height);
+            render_raw_tab(frame, result, content_area, scroll, visible_height, &theme);
+        }
+        ResponseTab::Cookies => {
+            render_cookies_tab(frame, response, content_area, scroll, &theme);
+        }
+        ResponseTab::Timing => {
+            render_timing_tab(frame, response, content_area, &theme);
+        }
+        ResponseTab::Request => {
+            render_request_tab(
+                frame,
+                result.request.as_ref(),
+                content_area,
+                scroll,
+                visible_height,
+                &theme,
+            );
+        }
+        ResponseTab::Diff => {
+            let has_previous = app
+                .previous_execution_result
+                .as_ref()
+                .is_some_and(|r| r.response.is_some());
+            render_diff_tab(
+                frame,
+                has_previous,
+                app.body_diff.as_deref(),
+                content_area,
+                scroll,
+                visible_height,
+                &theme,
+            );
         }
     }
 }
 
+/// Body lines that keys act on, found while drawing the Body tab
+struct BodyTargets {
+    /// Toggle lines of collapsible JSON arrays in view as (line, path)
+    toggles: Vec<(usize, String)>,
+    /// Every match of the response search as (line, column), when searched again
+    matches: Option<Vec<(usize, usize)>>,
+}
+
 /// Render the Body tab content
 fn render_body_tab(
     frame: &mut Frame,
+    app: &App,
     response: &crate::runner::Response,
     area: Rect,
     scroll: usize,
     visible_height: usize,
-) {
+) -> BodyTargets {
+    let theme = &app.active_theme;
     let mut lines: Vec<Line> = Vec::new();
+    let mut toggles = Vec::new();
+    let mut matches = app.response_search_stale.then(Vec::new);
+
+    // A truncated body gets a warning banner on the last line
+    let (area, visible_height) = if response.body_truncated && area.height > 1 {
+        let shown = response
+            .body_bytes
+            .as_ref()
+            .map_or(response.body.len(), Vec::len);
+        let banner = Line::from(Span::styled(
+            format!(
+                " ⚠ Body truncated: showing {} of {}",
+                format_size(shown),
+                format_size(response.body_original_size)
+            ),
+            Style::default()
+                .fg(theme.amber_warning)
+                .add_modifier(Modifier::BOLD),
+        ));
+        let banner_area = Rect::new(area.x, area.bottom() - 1, area.width, 1);
+        frame.render_widget(Paragraph::new(banner), banner_area);
+        (
+            Rect::new(area.x, area.y, area.width, area.height - 1),
+            visible_height.saturating_sub(1),
+        )
+    } else {
+        (area, visible_height)
+    };
 
     if response.body.trim().is_empty() {
         lines.push(Line::from(""));
         lines.push(Line::from(Span::styled(
             format!("  {} No response body", BoxChars::DOT),
-            Style::default().fg(HackerTheme::TEXT_MUTED),
+            Style::default().fg(theme.text_muted),
         )));
     } else {
-        // Try to pretty-print and syntax highlight JSON
-        let body_lines = format_body_with_highlighting(&response.body);
+        // Pretty-print and syntax highlight JSON, XML or HTML; non-UTF-8 bodies are dumped as hex
+        // JSON rows are built once per response and collapse toggle (see `App::response_json_rows`)
+        let rows = app
+            .response_json_rows
+            .as_ref()
+            .filter(|_| app.response_pretty_print);
+        let body_lines = match (&response.body_bytes, rows) {
+            (Some(bytes), _) => format_hex_dump(bytes, theme),
+            (None, Some(rows)) => rows.iter().map(|row| row.to_line(theme)).collect(),
+            (None, None) => format_body_with_highlighting(
+                &response.body,
+                app.response_body_format,
+                app.ndjson_collapsed,
+                app.response_pretty_print,
+                theme,
+            ),
+        };
 
-        for line in body_lines
-            .iter()
-            .skip(scroll)
-            .take(visible_height.saturating_sub(1))
-        {
-            lines.push(line.clone());
+        let view = scroll..scroll + visible_height.saturating_sub(1);
+        if let Some(rows) = rows {
+            toggles = rows
+                .iter()
+                .enumerate()
+                .filter(|(index, _)| view.contains(index))
+                .filter_map(|(index, row)| match row {
+                    JsonRow::Toggle { path, .. } => Some((index, path.clone())),
+                    JsonRow::Text(_) => None,
+                })
+                .collect();
+        }
+
+        // Search the whole body so `n` / `N` can scroll to matches out of view; the matches
+        // are kept until the query or the displayed body changes
+        if app.response_search_stale {
+            let mut found = Vec::new();
+            if !app.response_search_query.is_empty() {
+                for (index, line) in body_lines.iter().enumerate() {
+                    let text: String = line.spans.iter().map(|s| s.content.as_ref()).collect();
+                    let columns = search_columns(&text, &app.response_search_query);
+                    found.extend(columns.into_iter().map(|column| (index, column)));
+                }
+            }
+            matches = Some(found);
+        }
+        let matches = matches.as_deref().unwrap_or(&app.response_search_matches);
+        let query_len = app.response_search_query.chars().count();
+        let current = matches.get(app.response_search_index).copied();
+
+        for (index, line) in body_lines.iter().enumerate().skip(scroll).take(view.len()) {
+            // Enter toggles the first collapsible array in view
+            let selected = app.active_panel == ActivePanel::Response
+                && toggles.first().is_some_and(|(line, _)| *line == index);
+            let mut line = line.clone();
+            let columns: Vec<usize> = matches
+                .iter()
+                .filter(|(line, _)| *line == index)
+                .map(|(_, column)| *column)
+                .collect();
+            if !columns.is_empty() {
+                let current = current.filter(|(line, _)| *line == index).map(|(_, c)| c);
+                line.spans =
+                    highlight_search_matches(line.spans, &columns, query_len, current, theme);
+            }
+            if !app.response_word_wrap {
+                line.spans = skip_columns(line.spans, app.response_h_scroll);
+            }
+            if selected {
+                line = line.style(Style::default().bg(theme.selected_bg));
+            }
+            lines.push(line);
         }
 
+        render_scrollbar(frame, area, scroll, body_lines.len(), theme);
+
         // Show scroll indicator if needed
         if body_lines.len() > visible_height {
             let total = body_lines.len();
@@ -287,13 +562,82 @@ fn render_body_tab(
                     visible_end,
                     total
                 ),
-                Style::default().fg(HackerTheme::TEXT_MUTED),
+                Style::default().fg(theme.text_muted),
             )));
         }
     }
 
-    let paragraph = Paragraph::new(lines).wrap(Wrap { trim: false });
+    let mut paragraph = Paragraph::new(lines);
+    if app.response_word_wrap {
+        paragraph = paragraph.wrap(Wrap { trim: false });
+    }
     frame.render_widget(paragraph, area);
+    BodyTargets { toggles, matches }
+}
+
+/// Character columns where `query` occurs in `text`, ignoring case
+fn search_columns(text: &str, query: &str) -> Vec<usize> {
+    let lowercase = |s: &str| -> Vec<char> {
+        s.chars()
+            .map(|c| c.to_lowercase().next().unwrap_or(c))
+            .collect()
+    };
+    let text = lowercase(text);
+    let query = lowercase(query);
+    if query.is_empty() || query.len() > text.len() {
+        return Vec::new();
+    }
+
+    (0..=text.len() - query.len())
+        .filter(|&start| text[start..start + query.len()] == query[..])
+        .collect()
+}
+
+/// Give the `len` characters at each of `columns` the search match background;
+/// the match at `current` (the one `n` / `N` moved to) is also bold and underlined
+fn highlight_search_matches(
+    spans: Vec<Span<'static>>,
+    columns: &[usize],
+    len: usize,
+    current: Option<usize>,
+    theme: &Theme,
+) -> Vec<Span<'static>> {
+    let mut highlighted: Vec<Span<'static>> = Vec::new();
+    let mut column = 0;
+    for span in spans {
+        for c in span.content.chars() {
+            let covers = |start: usize| (start..start + len).contains(&column);
+            // Where matches overlap, the current one wins
+            let style = if current.is_some_and(covers) {
+                span.style
+                    .bg(theme.search_match_bg)
+                    .add_modifier(Modifier::BOLD | Modifier::UNDERLINED)
+            } else if columns.iter().any(|&start| covers(start)) {
+                span.style.bg(theme.search_match_bg)
+            } else {
+                span.style
+            };
+            match highlighted.last_mut() {
+                Some(last) if last.style == style => last.content.to_mut().push(c),
+                _ => highlighted.push(Span::styled(c.to_string(), style)),
+            }
+            column += 1;
+        }
+    }
+    highlighted
+}
+
+/// Human-readable byte size (`512 KB`, `2.3 MB`)
+fn format_size(bytes: usize) -> String {
+    const KB: usize = 1024;
+    const MB: usize = 1024 * 1024;
+    if bytes >= MB {
+        format!("{:.1} MB", bytes as f64 / MB as f64)
+    } else if bytes >= KB {
+        format!("{} KB", bytes / KB)
+    } else {
+        format!("{} B", bytes)
+    }
 }
 
 /// Render the Headers tab content
@@ -303,6 +647,7 @@ fn render_headers_tab(
     area: Rect,
     scroll: usize,
     visible_height: usize,
+    theme: &Theme,
 ) {
     let mut lines: Vec<Line> = Vec::new();
 
@@ -310,7 +655,7 @@ fn render_headers_tab(
         lines.push(Line::from(""));
         lines.push(Line::from(Span::styled(
             format!("  {} No headers", BoxChars::DOT),
-            Style::default().fg(HackerTheme::TEXT_MUTED),
+            Style::default().fg(theme.text_muted),
         )));
     } else {
         // Build all header lines first
@@ -319,18 +664,15 @@ fn render_headers_tab(
             header_lines.push(Line::from(vec![
                 Span::styled(
                     format!(" {} ", BoxChars::DOT),
-                    Style::default().fg(HackerTheme::TEXT_MUTED),
+                    Style::default().fg(theme.text_muted),
                 ),
                 Span::styled(
                     format!("{}: ", name),
                     Style::default()
-                        .fg(HackerTheme::SYNTAX_HEADER)
+                        .fg(theme.syntax_header)
                         .add_modifier(Modifier::BOLD),
                 ),
-                Span::styled(
-                    value.clone(),
-                    Style::default().fg(HackerTheme::SYNTAX_VALUE),
-                ),
+                Span::styled(value.clone(), Style::default().fg(theme.syntax_value)),
             ]));
         }
 
@@ -343,6 +685,8 @@ fn render_headers_tab(
             lines.push(line.clone());
         }
 
+        render_scrollbar(frame, area, scroll, header_lines.len(), theme);
+
         // Show scroll indicator if needed
         if header_lines.len() > visible_height {
             let total = header_lines.len();
@@ -355,9 +699,92 @@ fn render_headers_tab(
                     visible_end,
                     total
                 ),
-                Style::default().fg(HackerTheme::TEXT_MUTED),
+                Style::default().fg(theme.text_muted),
+            )));
+        }
+    }
+
+    let paragraph = Paragraph::new(lines).wrap(Wrap { trim: false });
+    frame.render_widget(paragraph, area);
+}
+
+/// Render the Request tab content (headers and body that were sent)
+fn render_request_tab(
+    frame: &mut Frame,
+    request: Option<&crate::runner::Request>,
+    area: Rect,
+    scroll: usize,
+    visible_height: usize,
+    theme: &Theme,
+) {
+    let mut lines: Vec<Line> = Vec::new();
+
+    if let Some(request) = request {
+        let mut request_lines = vec![Line::from(vec![
+            Span::styled(
+                format!(" {} ", request.method),
+                Style::default()
+                    .fg(theme.syntax_method)
+                    .add_modifier(Modifier::BOLD),
+            ),
+            Span::styled(request.url.clone(), Style::default().fg(theme.syntax_url)),
+        ])];
+        request_lines.extend(request.headers.iter().map(|(name, value)| {
+            Line::from(vec![
+                Span::styled(
+                    format!(" {} ", BoxChars::ARROW_RIGHT),
+                    Style::default().fg(theme.text_muted),
+                ),
+                Span::styled(
+                    format!("{}: ", name),
+                    Style::default()
+                        .fg(theme.syntax_header)
+                        .add_modifier(Modifier::BOLD),
+                ),
+                Span::styled(value.clone(), Style::default().fg(theme.syntax_value)),
+            ])
+        }));
+
+        if let Some(body) = &request.body {
+            let content_type = content_type(&request.headers);
+            let format = detect_body_format(body, content_type.as_deref());
+            request_lines.push(Line::from(""));
+            request_lines.extend(format_body_with_highlighting(
+                body, format, false, true, theme,
+            ));
+        }
+
+        for line in request_lines
+            .iter()
+            .skip(scroll)
+            .take(visible_height.saturating_sub(1))
+        {
+            lines.push(line.clone());
+        }
+
+        render_scrollbar(frame, area, scroll, request_lines.len(), theme);
+
+        // Show scroll indicator if needed
+        if request_lines.len() > visible_height {
+            let total = request_lines.len();
+            let visible_end = (scroll + visible_height).min(total);
+            lines.push(Line::from(Span::styled(
+                format!(
+                    " {} [{}-{}/{}]",
+                    BoxChars::GLITCH_1,
+                    scroll + 1,
+                    visible_end,
+                    total
+                ),
+                Style::default().fg(theme.text_muted),
             )));
         }
+    } else {
+        lines.push(Line::from(""));
+        lines.push(Line::from(Span::styled(
+            format!("  {} No request details in the hurl output", BoxChars::DOT),
+            Style::default().fg(theme.text_muted),
+        )));
     }
 
     let paragraph = Paragraph::new(lines).wrap(Wrap { trim: false });
@@ -371,6 +798,7 @@ fn render_raw_tab(
     area: Rect,
     scroll: usize,
     visible_height: usize,
+    theme: &Theme,
 ) {
     let mut lines: Vec<Line> = Vec::new();
 
@@ -379,7 +807,7 @@ fn render_raw_tab(
         lines.push(Line::from(""));
         lines.push(Line::from(Span::styled(
             format!("  {} No raw output available", BoxChars::DOT),
-            Style::default().fg(HackerTheme::TEXT_MUTED),
+            Style::default().fg(theme.text_muted),
         )));
     } else {
         // Build all raw lines
@@ -389,15 +817,15 @@ fn render_raw_tab(
         if !result.stderr.is_empty() {
             for line in result.stderr.lines() {
                 let color = if line.starts_with('*') {
-                    HackerTheme::CYBER_CYAN
+                    theme.cyber_cyan
                 } else if line.starts_with('>') {
-                    HackerTheme::SYNTAX_HEADER
+                    theme.syntax_header
                 } else if line.starts_with('<') {
-                    HackerTheme::SYNTAX_VALUE
+                    theme.syntax_value
                 } else if line.starts_with("error:") {
-                    HackerTheme::NEON_RED
+                    theme.neon_red
                 } else {
-                    HackerTheme::TEXT_SECONDARY
+                    theme.text_secondary
                 };
                 raw_lines.push(Line::from(Span::styled(
                     format!(" {}", line),
@@ -413,14 +841,14 @@ fn render_raw_tab(
                 raw_lines.push(Line::from(Span::styled(
                     format!(" {} STDOUT", BoxChars::TRIANGLE_DOWN),
                     Style::default()
-                        .fg(HackerTheme::SYNTAX_SECTION)
+                        .fg(theme.syntax_section)
                         .add_modifier(Modifier::BOLD),
                 )));
             }
             for line in result.stdout.lines() {
                 raw_lines.push(Line::from(Span::styled(
                     format!(" {}", line),
-                    Style::default().fg(HackerTheme::TEXT_PRIMARY),
+                    Style::default().fg(theme.text_primary),
                 )));
             }
         }
@@ -434,6 +862,8 @@ fn render_raw_tab(
             lines.push(line.clone());
         }
 
+        render_scrollbar(frame, area, scroll, raw_lines.len(), theme);
+
         // Show scroll indicator if needed
         if raw_lines.len() > visible_height {
             let total = raw_lines.len();
@@ -446,7 +876,7 @@ fn render_raw_tab(
                     visible_end,
                     total
                 ),
-                Style::default().fg(HackerTheme::TEXT_MUTED),
+                Style::default().fg(theme.text_muted),
             )));
         }
     }
@@ -455,16 +885,267 @@ fn render_raw_tab(
     frame.render_widget(paragraph, area);
 }
 
-/// Format the response body with syntax highlighting for JSON
-fn format_body_with_highlighting(body: &str) -> Vec<Line<'static>> {
+/// Render the Cookies tab content (parsed `Set-Cookie` headers)
+fn render_cookies_tab(
+    frame: &mut Frame,
+    response: &crate::runner::Response,
+    area: Rect,
+    scroll: usize,
+    theme: &Theme,
+) {
+    if response.cookies.is_empty() {
+        let placeholder = Paragraph::new(vec![
+            Line::from(""),
+            Line::from(Span::styled(
+                format!("  {} No cookies set", BoxChars::DOT),
+                Style::default().fg(theme.text_muted),
+            )),
+        ]);
+        frame.render_widget(placeholder, area);
+        return;
+    }
+
+    let header = Row::new(["Name", "Value", "Domain", "Path", "Expires", "Flags"]).style(
+        Style::default()
+            .fg(theme.syntax_section)
+            .add_modifier(Modifier::BOLD),
+    );
+
+    let rows: Vec<Row> = response
+        .cookies
+        .iter()
+        .skip(scroll)
+        .map(|cookie| {
+            let value = if is_sensitive_cookie(&cookie.name, &cookie.value) {
+                "*".repeat(cookie.value.chars().count().clamp(1, 8))
+            } else {
+                cookie.value.clone()
+            };
+            let flags = [(cookie.secure, "Secure"), (cookie.http_only, "HttpOnly")]
+                .iter()
+                .filter(|(set, _)| *set)
+                .map(|(_, flag)| *flag)
+                .collect::<Vec<_>>()
+                .join(" ");
+
+            Row::new([
+                Cell::from(cookie.name.clone()).style(Style::default().fg(theme.syntax_header)),
+                Cell::from(value).style(Style::default().fg(theme.syntax_value)),
+                Cell::from(cookie.domain.clone().unwrap_or_default()),
+                Cell::from(cookie.path.clone().unwrap_or_default()),
+                Cell::from(cookie.expires.clone().unwrap_or_default()),
+                Cell::from(flags).style(Style::default().fg(theme.cyber_cyan)),
+            ])
+            .style(Style::default().fg(theme.text_primary))
+        })
+        .collect();
+
+    let table = Table::new(
+        rows,
+        [
+            Constraint::Percentage(18),
+            Constraint::Percentage(22),
+            Constraint::Percentage(16),
+            Constraint::Percentage(10),
+            Constraint::Percentage(20),
+            Constraint::Percentage(14),
+        ],
+    )
+    .header(header)
+    .column_spacing(1);
+
+    frame.render_widget(table, area);
+}
+
+/// Whether a cookie looks like a credential and its value should be masked
+fn is_sensitive_cookie(name: &str, value: &str) -> bool {
+    let name = name.to_lowercase();
+    let value = value.to_lowercase();
+    ["session", "token", "auth"]
+        .iter()
+        .any(|keyword| name.contains(keyword) || value.contains(keyword))
+}
+
+/// Render the Timing tab content as a horizontal bar chart relative to the total time
+fn render_timing_tab(
+    frame: &mut Frame,
+    response: &crate::runner::Response,
+    area: Rect,
+    theme: &Theme,
+) {
+    let mut lines: Vec<Line> = Vec::new();
+
+    let Some(timings) = response.timings.as_ref().filter(|t| t.total > 0.0) else {
+        lines.push(Line::from(""));
+        lines.push(Line::from(Span::styled(
+            format!("  {} No timing data available", BoxChars::DOT),
+            Style::default().fg(theme.text_muted),
+        )));
+        frame.render_widget(Paragraph::new(lines), area);
+        return;
+    };
+
+    let rows = [
+        ("namelookup", timings.namelookup),
+        ("connect", timings.connect),
+        ("appconnect", timings.appconnect),
+        ("pretransfer", timings.pretransfer),
+        ("starttransfer", timings.starttransfer),
+        ("total", timings.total),
+    ];
+
+    // Label (15) + value (12) + padding
+    let bar_width = (area.width as usize).saturating_sub(30).max(1);
+
+    lines.push(Line::from(""));
+    for (name, ms) in rows {
+        let filled = ((ms / timings.total) * bar_width as f64).round() as usize;
+        let filled = filled.min(bar_width);
+        let color = if name == "total" {
+            theme.cyber_cyan
+        } else {
+            theme.matrix_green
+        };
+
+        lines.push(Line::from(vec![
+            Span::styled(
+                format!(" {:<14}", name),
+                Style::default().fg(theme.text_secondary),
+            ),
+            Span::styled(
+                BoxChars::BLOCK_FULL.repeat(filled),
+                Style::default().fg(color),
+            ),
+            Span::styled(
+                BoxChars::BLOCK_LIGHT.repeat(bar_width - filled),
+                Style::default().fg(theme.border_dim),
+            ),
+            Span::styled(
+                format!(" {:>9.1} ms", ms),
+                Style::default().fg(theme.text_primary),
+            ),
+        ]));
+    }
+
+    frame.render_widget(Paragraph::new(lines), area);
+}
+
+/// Render the Diff tab content (previous run's body against the current one)
+fn render_diff_tab(
+    frame: &mut Frame,
+    has_previous: bool,
+    diff: Option<&[DiffLine]>,
+    area: Rect,
+    scroll: usize,
+    visible_height: usize,
+    theme: &Theme,
+) {
+    let mut lines: Vec<Line> = Vec::new();
+
+    let placeholder = if !has_previous {
+        Some(format!("  {} No previous run to compare", BoxChars::DOT))
+    } else if diff.is_none() {
+        Some(format!("  {} Bodies are too large to diff", BoxChars::DOT))
+    } else {
+        None
+    };
+
+    if let Some(placeholder) = placeholder {
+        lines.push(Line::from(""));
+        lines.push(Line::from(Span::styled(
+            placeholder,
+            Style::default().fg(theme.text_muted),
+        )));
+    } else if diff
+        .unwrap_or_default()
+        .iter()
+        .all(|l| matches!(l, DiffLine::Unchanged(_)))
+    {
+        lines.push(Line::from(""));
+        lines.push(Line::from(Span::styled(
+            format!(
+                "  {} Body unchanged since the previous run",
+                BoxChars::CHECK
+            ),
+            Style::default().fg(theme.text_muted),
+        )));
+    } else {
+        let diff = diff.unwrap_or_default();
+
+        // Only the visible lines are styled
+        lines.extend(
+            diff.iter()
+                .skip(scroll)
+                .take(visible_height.saturating_sub(1))
+                .map(|line| {
+                    let (prefix, text, color) = match line {
+                        DiffLine::Added(text) => ('+', text, theme.neon_green),
+                        DiffLine::Removed(text) => ('-', text, theme.neon_red),
+                        DiffLine::Unchanged(text) => (' ', text, theme.text_muted),
+                    };
+                    Line::from(Span::styled(
+                        format!(" {} {}", prefix, text),
+                        Style::default().fg(color),
+                    ))
+                }),
+        );
+
+        render_scrollbar(frame, area, scroll, diff.len(), theme);
+
+        // Show scroll indicator if needed
+        if diff.len() > visible_height {
+            let total = diff.len();
+            let visible_end = (scroll + visible_height).min(total);
+            lines.push(Line::from(Span::styled(
+                format!(
+                    " {} [{}-{}/{}]",
+                    BoxChars::GLITCH_1,
+                    scroll + 1,
+                    visible_end,
+                    total
+                ),
+                Style::default().fg(theme.text_muted),
+            )));
+        }
+    }
+
+    let paragraph = Paragraph::new(lines).wrap(Wrap { trim: false });
+    frame.render_widget(paragraph, area);
+}
+
+/// Format a body of the given format with syntax highlighting.
+/// With `collapse_ndjson`, each NDJSON object is shown on a single line;
+/// without `pretty`, JSON is highlighted as received instead of being re-indented.
+fn format_body_with_highlighting(
+    body: &str,
+    format: BodyFormat,
+    collapse_ndjson: bool,
+    pretty: bool,
+    theme: &Theme,
+) -> Vec<Line<'static>> {
     let trimmed = body.trim();
 
+    match format {
+        BodyFormat::Json | BodyFormat::Ndjson(_) if !pretty => {
+            return body
+                .lines()
+                .map(|line| highlight_json_line(line, theme))
+                .collect();
+        }
+        BodyFormat::Html => return format_html_body(body, theme),
+        BodyFormat::Xml if !pretty => return highlight_markup(body, &[], theme),
+        BodyFormat::Xml => return format_xml_body(body, theme),
+        BodyFormat::Ndjson(_) => return format_ndjson_body(trimmed, collapse_ndjson, theme),
+        BodyFormat::Binary => return format_binary_body(body, theme),
+        BodyFormat::Json | BodyFormat::Text => {}
+    }
+
     // Try to parse as JSON and pretty-print with highlighting
     if let Ok(json) = serde_json::from_str::<serde_json::Value>(trimmed) {
         if let Ok(pretty) = serde_json::to_string_pretty(&json) {
             return pretty
                 .lines()
-                .map(|line| highlight_json_line(line))
+                .map(|line| highlight_json_line(line, theme))
                 .collect();
         }
     }
@@ -482,14 +1163,14 @@ fn format_body_with_highlighting(body: &str) -> Vec<Line<'static>> {
                     for line in trimmed[..json_start].lines() {
                         lines.push(Line::from(Span::styled(
                             format!(" {}", line),
-                            Style::default().fg(HackerTheme::TEXT_SECONDARY),
+                            Style::default().fg(theme.text_secondary),
                         )));
                     }
                 }
 
                 // Add formatted JSON
                 for line in pretty.lines() {
-                    lines.push(highlight_json_line(line));
+                    lines.push(highlight_json_line(line, theme));
                 }
 
                 return lines;
@@ -497,53 +1178,386 @@ fn format_body_with_highlighting(body: &str) -> Vec<Line<'static>> {
         }
     }
 
-    // Try to handle multiple JSON objects (one per line - NDJSON format)
-    let mut all_lines: Vec<Line<'static>> = Vec::new();
-    let mut found_any_json = false;
+    // Fall back to raw body (plain text)
+    body.lines()
+        .map(|line| {
+            Line::from(Span::styled(
+                format!(" {}", line),
+                Style::default().fg(theme.text_primary),
+            ))
+        })
+        .collect()
+}
+
+/// Hex dump of a binary body known only as text.
+/// Text with replacement characters lost its original bytes, so it is not dumped.
+fn format_binary_body(body: &str, theme: &Theme) -> Vec<Line<'static>> {
+    if !body.contains(char::REPLACEMENT_CHARACTER) {
+        return format_hex_dump(body.as_bytes(), theme);
+    }
+    vec![
+        Line::from(""),
+        Line::from(Span::styled(
+            format!("  {} Binary body not available", BoxChars::DOT),
+            Style::default().fg(theme.text_muted),
+        )),
+        Line::from(Span::styled(
+            format!(
+                "  {} Its raw bytes were not captured",
+                BoxChars::ARROW_RIGHT
+            ),
+            Style::default().fg(theme.text_secondary),
+        )),
+    ]
+}
+
+/// Format bytes as a hex dump: offset, 16 bytes in hex and their printable ASCII characters
+pub fn format_hex_dump(bytes: &[u8], theme: &Theme) -> Vec<Line<'static>> {
+    bytes
+        .chunks(16)
+        .enumerate()
+        .map(|(row, chunk)| {
+            let mut hex = String::with_capacity(50);
+            for i in 0..16 {
+                // Extra gap between the two groups of 8 bytes
+                if i == 8 {
+                    hex.push(' ');
+                }
+                match chunk.get(i) {
+                    Some(byte) => hex.push_str(&format!("{:02x} ", byte)),
+                    None => hex.push_str("   "),
+                }
+            }
+            let ascii: String = chunk
+                .iter()
+                .map(|&byte| {
+                    if byte.is_ascii_graphic() || byte == b' ' {
+                        byte as char
+                    } else {
+                        '.'
+                    }
+                })
+                .collect();
+
+            Line::from(vec![
+                Span::styled(
+                    format!("{:08x}  ", row * 16),
+                    Style::default().fg(theme.text_muted),
+                ),
+                Span::styled(hex, Style::default().fg(theme.text_primary)),
+                Span::styled(" |", Style::default().fg(theme.border_dim)),
+                Span::styled(ascii, Style::default().fg(theme.syntax_data)),
+                Span::styled("|", Style::default().fg(theme.border_dim)),
+            ])
+        })
+        .collect()
+}
+
+/// Format NDJSON: one block per object, separated by a divider
+fn format_ndjson_body(body: &str, collapse: bool, theme: &Theme) -> Vec<Line<'static>> {
+    let divider = Line::from(Span::styled(
+        BoxChars::HORIZONTAL.repeat(6),
+        Style::default().fg(theme.text_muted),
+    ));
+    let mut lines: Vec<Line<'static>> = Vec::new();
+
+    for (index, line) in body
+        .lines()
+        .map(str::trim)
+        .filter(|l| !l.is_empty())
+        .enumerate()
+    {
+        if index > 0 && !collapse {
+            lines.push(divider.clone());
+        }
+        let Ok(json) = serde_json::from_str::<serde_json::Value>(line) else {
+            continue;
+        };
+        let formatted = if collapse {
+            serde_json::to_string(&json)
+        } else {
+            serde_json::to_string_pretty(&json)
+        };
+        if let Ok(formatted) = formatted {
+            lines.extend(
+                formatted
+                    .lines()
+                    .map(|line| highlight_json_line(line, theme)),
+            );
+        }
+    }
+
+    lines
+}
+
+/// Format an XML body re-indented with token-level highlighting.
+/// Malformed XML is highlighted as-is.
+fn format_xml_body(body: &str, theme: &Theme) -> Vec<Line<'static>> {
+    match pretty_print_xml(body) {
+        Some(pretty) => highlight_markup(&pretty, &[], theme),
+        None => highlight_markup(body, &[], theme),
+    }
+}
+
+/// Re-indent XML by tracking opening and closing tags (no DOM is built).
+/// Elements holding only text stay on one line. Returns `None` when tags don't match up.
+fn pretty_print_xml(body: &str) -> Option<String> {
+    let mut lines: Vec<String> = Vec::new();
+    let mut open_tags: Vec<&str> = Vec::new();
+    let mut rest = body.trim();
+
+    while !rest.is_empty() {
+        let indent = "  ".repeat(open_tags.len());
 
-    for line in body.lines() {
-        let line_trimmed = line.trim();
-        if line_trimmed.is_empty() {
-            all_lines.push(Line::from(""));
+        // Comments and CDATA are copied verbatim
+        let verbatim_end = if rest.starts_with("<!--") {
+            Some(rest.find("-->")? + 3)
+        } else if rest.starts_with("<![CDATA[") {
+            Some(rest.find("]]>")? + 3)
+        } else {
+            None
+        };
+        if let Some(end) = verbatim_end {
+            lines.push(format!("{}{}", indent, &rest[..end]));
+            rest = rest[end..].trim_start();
             continue;
         }
 
-        // Try to parse each line as JSON
-        if let Ok(json) = serde_json::from_str::<serde_json::Value>(line_trimmed) {
-            found_any_json = true;
-            if let Ok(pretty) = serde_json::to_string_pretty(&json) {
-                for pretty_line in pretty.lines() {
-                    all_lines.push(highlight_json_line(pretty_line));
-                }
-                // Add separator between JSON objects
-                all_lines.push(Line::from(""));
+        if !rest.starts_with('<') {
+            let end = rest.find('<').unwrap_or(rest.len());
+            lines.push(format!("{}{}", indent, rest[..end].trim()));
+            rest = &rest[end..];
+            continue;
+        }
+
+        let end = xml_tag_end(rest)? + 1;
+        let tag = &rest[..end];
+        rest = rest[end..].trim_start();
+
+        if let Some(name) = tag.strip_prefix("</") {
+            if open_tags.pop()? != name.trim_end_matches('>').trim() {
+                return None;
             }
+            lines.push(format!("{}{}", "  ".repeat(open_tags.len()), tag));
+        } else if tag.starts_with("<?") || tag.starts_with("<!") || tag.ends_with("/>") {
+            lines.push(format!("{}{}", indent, tag));
         } else {
-            // Not JSON, show as plain text
-            all_lines.push(Line::from(Span::styled(
-                format!(" {}", line),
-                Style::default().fg(HackerTheme::TEXT_PRIMARY),
-            )));
+            let name = tag[1..]
+                .split(|c: char| c.is_whitespace() || c == '>')
+                .next()
+                .unwrap_or_default();
+
+            // <name>text</name> stays on one line
+            let text_end = rest.find('<').unwrap_or(rest.len());
+            let closing = format!("</{}>", name);
+            if rest[text_end..].starts_with(&closing) {
+                lines.push(format!(
+                    "{}{}{}{}",
+                    indent,
+                    tag,
+                    rest[..text_end].trim(),
+                    closing
+                ));
+                rest = rest[text_end + closing.len()..].trim_start();
+                continue;
+            }
+
+            lines.push(format!("{}{}", indent, tag));
+            open_tags.push(name);
         }
     }
 
-    if found_any_json {
-        return all_lines;
+    open_tags.is_empty().then(|| lines.join("\n"))
+}
+
+/// Byte offset of the `>` closing the tag at the start of `text`, ignoring quoted attribute values
+fn xml_tag_end(text: &str) -> Option<usize> {
+    let mut quote = None;
+    for (i, c) in text.char_indices() {
+        match (quote, c) {
+            (None, '"' | '\'') => quote = Some(c),
+            (Some(q), c) if c == q => quote = None,
+            (None, '>') => return Some(i),
+            _ => {}
+        }
     }
+    None
+}
 
-    // Fall back to raw body (plain text)
-    body.lines()
-        .map(|line| {
-            Line::from(Span::styled(
-                format!(" {}", line),
-                Style::default().fg(HackerTheme::TEXT_PRIMARY),
-            ))
-        })
-        .collect()
+/// Format an HTML body with token-level highlighting.
+/// The content of `<script>` and `<style>` is kept as plain text.
+fn format_html_body(body: &str, theme: &Theme) -> Vec<Line<'static>> {
+    highlight_markup(body, &["script", "style"], theme)
+}
+
+/// Tokenizer state while highlighting markup
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum MarkupState {
+    Text,
+    Comment,
+    TagName,
+    Attributes,
+    Value(char),
+    /// Content of an element whose text is not parsed as markup (e.g. `<script>`)
+    RawText,
+}
+
+impl MarkupState {
+    fn color(self, theme: &Theme) -> Color {
+        match self {
+            MarkupState::Text | MarkupState::RawText => theme.text_primary,
+            MarkupState::Comment => theme.text_comment,
+            MarkupState::TagName => theme.syntax_method,
+            MarkupState::Attributes => theme.syntax_header,
+            MarkupState::Value(_) => theme.syntax_value,
+        }
+    }
+}
+
+/// Collects highlighted spans into lines
+struct MarkupWriter {
+    lines: Vec<Line<'static>>,
+    spans: Vec<Span<'static>>,
+    token: String,
+}
+
+impl MarkupWriter {
+    fn flush(&mut self, color: Color) {
+        if !self.token.is_empty() {
+            let text = std::mem::take(&mut self.token);
+            self.spans
+                .push(Span::styled(text, Style::default().fg(color)));
+        }
+    }
+
+    fn punctuation(&mut self, text: &str, theme: &Theme) {
+        self.spans.push(Span::styled(
+            text.to_string(),
+            Style::default().fg(theme.text_muted),
+        ));
+    }
+
+    fn newline(&mut self, color: Color) {
+        self.flush(color);
+        let spans = std::mem::replace(&mut self.spans, vec![Span::raw(" ")]);
+        self.lines.push(Line::from(spans));
+    }
+}
+
+/// Highlight XML/HTML: element names, attribute names and values, text and comments
+fn highlight_markup(body: &str, raw_text_elements: &[&str], theme: &Theme) -> Vec<Line<'static>> {
+    let body = body.trim();
+    let mut out = MarkupWriter {
+        lines: Vec::new(),
+        spans: vec![Span::raw(" ")],
+        token: String::new(),
+    };
+    let mut state = MarkupState::Text;
+    let mut tag_name = String::new();
+
+    for (i, c) in body.char_indices() {
+        let rest = &body[i..];
+
+        if c == '\r' {
+            continue;
+        }
+        if c == '\n' {
+            out.newline(state.color(theme));
+            continue;
+        }
+
+        match state {
+            MarkupState::Text if rest.starts_with("<!--") => {
+                out.flush(state.color(theme));
+                state = MarkupState::Comment;
+                out.token.push(c);
+            }
+            MarkupState::Text => {
+                if c == '<' {
+                    out.flush(state.color(theme));
+                    out.punctuation("<", theme);
+                    tag_name.clear();
+                    state = MarkupState::TagName;
+                } else {
+                    out.token.push(c);
+                }
+            }
+            MarkupState::RawText => {
+                let closes = rest.starts_with("</")
+                    && rest[2..]
+                        .get(..tag_name.len())
+                        .is_some_and(|name| name.eq_ignore_ascii_case(&tag_name));
+                if closes {
+                    out.flush(state.color(theme));
+                    out.punctuation("<", theme);
+                    tag_name.clear();
+                    state = MarkupState::TagName;
+                } else {
+                    out.token.push(c);
+                }
+            }
+            MarkupState::Comment => {
+                out.token.push(c);
+                if out.token.ends_with("-->") {
+                    out.flush(state.color(theme));
+                    state = MarkupState::Text;
+                }
+            }
+            MarkupState::TagName
+                if c.is_alphanumeric()
+                    || matches!(c, '-' | '_' | ':' | '.')
+                    || (tag_name.is_empty() && matches!(c, '/' | '?' | '!')) =>
+            {
+                out.token.push(c);
+                tag_name.push(c);
+            }
+            MarkupState::TagName | MarkupState::Attributes => {
+                if state == MarkupState::TagName {
+                    out.flush(state.color(theme));
+                    state = MarkupState::Attributes;
+                }
+                match c {
+                    '>' => {
+                        out.flush(state.color(theme));
+                        let self_closing = out.spans.last().is_some_and(|span| span.content == "/");
+                        out.punctuation(">", theme);
+                        let is_raw = raw_text_elements
+                            .iter()
+                            .any(|name| name.eq_ignore_ascii_case(&tag_name));
+                        state = if is_raw && !self_closing {
+                            MarkupState::RawText
+                        } else {
+                            MarkupState::Text
+                        };
+                    }
+                    '"' | '\'' => {
+                        out.flush(state.color(theme));
+                        out.token.push(c);
+                        state = MarkupState::Value(c);
+                    }
+                    '=' | '/' | '?' => {
+                        out.flush(state.color(theme));
+                        out.punctuation(&c.to_string(), theme);
+                    }
+                    _ => out.token.push(c),
+                }
+            }
+            MarkupState::Value(quote) => {
+                out.token.push(c);
+                if c == quote {
+                    out.flush(state.color(theme));
+                    state = MarkupState::Attributes;
+                }
+            }
+        }
+    }
+
+    out.newline(state.color(theme));
+    out.lines
 }
 
 /// Highlight a single line of JSON
-fn highlight_json_line(line: &str) -> Line<'static> {
+fn highlight_json_line(line: &str, theme: &Theme) -> Line<'static> {
     let mut spans: Vec<Span<'static>> = Vec::new();
     spans.push(Span::styled(" ", Style::default())); // Leading space
 
@@ -568,9 +1582,9 @@ fn highlight_json_line(line: &str) -> Line<'static> {
                     // End of string
                     current.push(ch);
                     let color = if is_key {
-                        HackerTheme::SYNTAX_HEADER // Keys in cyan-ish
+                        theme.syntax_header // Keys in cyan-ish
                     } else {
-                        HackerTheme::SYNTAX_VALUE // String values in green
+                        theme.syntax_value // String values in green
                     };
                     spans.push(Span::styled(current.clone(), Style::default().fg(color)));
                     current.clear();
@@ -580,7 +1594,7 @@ fn highlight_json_line(line: &str) -> Line<'static> {
                     if !current.is_empty() {
                         spans.push(Span::styled(
                             current.clone(),
-                            Style::default().fg(HackerTheme::TEXT_PRIMARY),
+                            Style::default().fg(theme.text_primary),
                         ));
                         current.clear();
                     }
@@ -592,13 +1606,13 @@ fn highlight_json_line(line: &str) -> Line<'static> {
                 if !current.is_empty() {
                     spans.push(Span::styled(
                         current.clone(),
-                        Style::default().fg(HackerTheme::TEXT_PRIMARY),
+                        Style::default().fg(theme.text_primary),
                     ));
                     current.clear();
                 }
                 spans.push(Span::styled(
                     ":".to_string(),
-                    Style::default().fg(HackerTheme::TEXT_MUTED),
+                    Style::default().fg(theme.text_muted),
                 ));
                 is_key = false; // Next string will be a value
             }
@@ -606,33 +1620,33 @@ fn highlight_json_line(line: &str) -> Line<'static> {
                 if !current.is_empty() {
                     // This could be a number or boolean
                     let color = if current.trim().parse::<f64>().is_ok() {
-                        HackerTheme::CYBER_CYAN // Numbers
+                        theme.cyber_cyan // Numbers
                     } else if current.trim() == "true" || current.trim() == "false" {
-                        HackerTheme::ELECTRIC_PURPLE // Booleans
+                        theme.electric_purple // Booleans
                     } else if current.trim() == "null" {
-                        HackerTheme::TEXT_MUTED // Null
+                        theme.text_muted // Null
                     } else {
-                        HackerTheme::TEXT_PRIMARY
+                        theme.text_primary
                     };
                     spans.push(Span::styled(current.clone(), Style::default().fg(color)));
                     current.clear();
                 }
                 spans.push(Span::styled(
                     ",".to_string(),
-                    Style::default().fg(HackerTheme::TEXT_MUTED),
+                    Style::default().fg(theme.text_muted),
                 ));
                 is_key = true; // Next string will be a key
             }
             '{' | '}' | '[' | ']' if !in_string => {
                 if !current.is_empty() {
                     let color = if current.trim().parse::<f64>().is_ok() {
-                        HackerTheme::CYBER_CYAN
+                        theme.cyber_cyan
                     } else if current.trim() == "true" || current.trim() == "false" {
-                        HackerTheme::ELECTRIC_PURPLE
+                        theme.electric_purple
                     } else if current.trim() == "null" {
-                        HackerTheme::TEXT_MUTED
+                        theme.text_muted
                     } else {
-                        HackerTheme::TEXT_PRIMARY
+                        theme.text_primary
                     };
                     spans.push(Span::styled(current.clone(), Style::default().fg(color)));
                     current.clear();
@@ -640,7 +1654,7 @@ fn highlight_json_line(line: &str) -> Line<'static> {
                 spans.push(Span::styled(
                     ch.to_string(),
                     Style::default()
-                        .fg(HackerTheme::MATRIX_GREEN)
+                        .fg(theme.matrix_green)
                         .add_modifier(Modifier::BOLD),
                 ));
                 if ch == '{' || ch == '[' {
@@ -656,13 +1670,13 @@ fn highlight_json_line(line: &str) -> Line<'static> {
     // Handle remaining content
     if !current.is_empty() {
         let color = if current.trim().parse::<f64>().is_ok() {
-            HackerTheme::CYBER_CYAN
+            theme.cyber_cyan
         } else if current.trim() == "true" || current.trim() == "false" {
-            HackerTheme::ELECTRIC_PURPLE
+            theme.electric_purple
         } else if current.trim() == "null" {
-            HackerTheme::TEXT_MUTED
+            theme.text_muted
         } else {
-            HackerTheme::TEXT_PRIMARY
+            theme.text_primary
         };
         spans.push(Span::styled(current, Style::default().fg(color)));
     }
@@ -670,15 +1684,333 @@ fn highlight_json_line(line: &str) -> Line<'static> {
     Line::from(spans)
 }
 
-/// Format the response body for display (plain text version)
-fn format_body(body: &str) -> Vec<String> {
-    // Try to parse and pretty-print JSON
-    if let Ok(json) = serde_json::from_str::<serde_json::Value>(body) {
-        if let Ok(pretty) = serde_json::to_string_pretty(&json) {
-            return pretty.lines().map(String::from).collect();
+/// Elements of a collapsible array shown before its toggle line
+const COLLAPSED_ARRAY_PREVIEW: usize = 5;
+
+/// Line of a pretty-printed JSON body
+#[derive(Debug, Clone, PartialEq)]
+pub enum JsonRow {
+    Text(String),
+    /// Expands or collapses the array at `path` (JSONPath-like, e.g. `$.items[2].tags`)
+    Toggle {
+        path: String,
+        indent: usize,
+        hidden: usize,
+        collapsed: bool,
+    },
+}
+
+impl JsonRow {
+    /// Highlighted line for the body view
+    fn to_line(&self, theme: &Theme) -> Line<'static> {
+        match self {
+            JsonRow::Text(text) => highlight_json_line(text, theme),
+            JsonRow::Toggle {
+                indent,
+                hidden,
+                collapsed,
+                ..
+            } => {
+                let label = if *collapsed {
+                    format!("{} [...{} more items]", BoxChars::TRIANGLE_DOWN, hidden)
+                } else {
+                    format!("▴ [collapse {} items]", hidden)
+                };
+                Line::from(Span::styled(
+                    format!(" {}{}", " ".repeat(*indent), label),
+                    Style::default()
+                        .fg(theme.cyber_cyan)
+                        .add_modifier(Modifier::BOLD),
+                ))
+            }
         }
     }
+}
 
-    // Fall back to raw body
-    body.lines().map(String::from).collect()
+/// Pretty-printed rows of a JSON response body, `None` for other bodies
+pub fn response_json_rows(
+    response: &crate::runner::Response,
+    collapsed: &HashSet<String>,
+) -> Option<Vec<JsonRow>> {
+    if response.body_bytes.is_some()
+        || detect_body_format(&response.body, content_type(&response.headers).as_deref())
+            != BodyFormat::Json
+    {
+        return None;
+    }
+    let json = serde_json::from_str::<Value>(response.body.trim()).ok()?;
+    Some(json_rows(&json, collapsed))
+}
+
+/// Pretty-print JSON like `serde_json::to_string_pretty`, adding a toggle line after
+/// the first elements of arrays longer than `COLLAPSIBLE_ARRAY_LEN`
+fn json_rows(json: &Value, collapsed: &HashSet<String>) -> Vec<JsonRow> {
+    let mut rows = Vec::new();
+    push_json_rows(json, "$", "", 0, "", collapsed, &mut rows);
+    rows
+}
+
+/// Add the rows of `value`, written after `prefix` (an object key) and before `suffix` (a comma)
+fn push_json_rows(
+    value: &Value,
+    path: &str,
+    prefix: &str,
+    indent: usize,
+    suffix: &str,
+    collapsed: &HashSet<String>,
+    rows: &mut Vec<JsonRow>,
+) {
+    let pad = " ".repeat(indent);
+    match value {
+        Value::Array(items) if !items.is_empty() => {
+            rows.push(JsonRow::Text(format!("{}{}[", pad, prefix)));
+            let is_collapsed = collapsed.contains(path);
+            for (index, item) in items.iter().enumerate() {
+                if items.len() > COLLAPSIBLE_ARRAY_LEN && index == COLLAPSED_ARRAY_PREVIEW {
+                    rows.push(JsonRow::Toggle {
+                        path: path.to_string(),
+                        indent: indent + 2,
+                        hidden: items.len() - COLLAPSED_ARRAY_PREVIEW,
+                        collapsed: is_collapsed,
+                    });
+                    if is_collapsed {
+                        break;
+                    }
+                }
+                let comma = if index + 1 < items.len() { "," } else { "" };
+                let item_path = format!("{}[{}]", path, index);
+                push_json_rows(item, &item_path, "", indent + 2, comma, collapsed, rows);
+            }
+            rows.push(JsonRow::Text(format!("{}]{}", pad, suffix)));
+        }
+        Value::Object(map) if !map.is_empty() => {
+            rows.push(JsonRow::Text(format!("{}{}{{", pad, prefix)));
+            for (index, (key, item)) in map.iter().enumerate() {
+                let comma = if index + 1 < map.len() { "," } else { "" };
+                let key_prefix = format!("{}: ", Value::String(key.clone()));
+                let item_path = format!("{}.{}", path, key);
+                push_json_rows(
+                    item,
+                    &item_path,
+                    &key_prefix,
+                    indent + 2,
+                    comma,
+                    collapsed,
+                    rows,
+                );
+            }
+            rows.push(JsonRow::Text(format!("{}}}{}", pad, suffix)));
+        }
+        _ => rows.push(JsonRow::Text(format!(
+            "{}{}{}{}",
+            pad, prefix, value, suffix
+        ))),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn text(line: &Line) -> String {
+        line.spans
+            .iter()
+            .map(|span| span.content.as_ref())
+            .collect()
+    }
+
+    #[test]
+    fn test_format_hex_dump() {
+        let theme = Theme::HACKER;
+        let bytes: Vec<u8> = (0x41..0x51).chain([0x00, 0xff]).collect();
+        let lines = format_hex_dump(&bytes, &theme);
+
+        assert_eq!(lines.len(), 2);
+        assert_eq!(
+            text(&lines[0]),
+            "00000000  41 42 43 44 45 46 47 48  49 4a 4b 4c 4d 4e 4f 50  |ABCDEFGHIJKLMNOP|"
+        );
+        assert_eq!(
+            text(&lines[1]),
+            format!("00000010  00 ff {}|..|", " ".repeat(44))
+        );
+        assert!(format_hex_dump(&[], &theme).is_empty());
+    }
+
+    #[test]
+    fn test_format_binary_body() {
+        let theme = Theme::HACKER;
+        assert!(text(&format_binary_body("AB", &theme)[0]).ends_with("|AB|"));
+        assert!(text(&format_binary_body("A\u{fffd}B", &theme)[1]).contains("not available"));
+    }
+
+    #[test]
+    fn test_search_columns() {
+        assert_eq!(search_columns("Token token TOKEN", "token"), vec![0, 6, 12]);
+        assert_eq!(search_columns("aaaa", "aa"), vec![0, 1, 2]);
+        // Columns count characters, not bytes
+        assert_eq!(search_columns("café Café", "café"), vec![0, 5]);
+        assert_eq!(search_columns("日本語の本", "本"), vec![1, 4]);
+        assert!(search_columns("abc", "").is_empty());
+        assert!(search_columns("ab", "abc").is_empty());
+    }
+
+    #[test]
+    fn test_highlight_search_matches() {
+        let theme = Theme::HACKER;
+        let spans = vec![
+            Span::raw("\"né"),
+            Span::styled("e\": 1", Style::default().fg(theme.cyber_cyan)),
+        ];
+        let highlighted = highlight_search_matches(spans, &[1, 2], 2, Some(2), &theme);
+
+        assert_eq!(text(&Line::from(highlighted.clone())), "\"née\": 1");
+        let styled: Vec<(&str, Option<Color>, bool)> = highlighted
+            .iter()
+            .map(|span| {
+                let current = span.style.add_modifier.contains(Modifier::UNDERLINED);
+                (span.content.as_ref(), span.style.bg, current)
+            })
+            .collect();
+        // Overlapping matches: the current one wins where they overlap
+        assert_eq!(
+            styled,
+            vec![
+                ("\"", None, false),
+                ("n", Some(theme.search_match_bg), false),
+                ("é", Some(theme.search_match_bg), true),
+                ("e", Some(theme.search_match_bg), true),
+                ("\": 1", None, false),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_format_html_body() {
+        let theme = Theme::HACKER;
+        let lines = format_html_body(
+            "<p class=\"x\">a</p>\n<script>if (a<b) go()</script>",
+            &theme,
+        );
+        assert_eq!(
+            lines.iter().map(text).collect::<Vec<_>>(),
+            vec![" <p class=\"x\">a</p>", " <script>if (a<b) go()</script>"]
+        );
+
+        // The `<b` inside the script is not taken for a tag
+        let script: Vec<_> = lines[1]
+            .spans
+            .iter()
+            .map(|s| s.content.to_string())
+            .collect();
+        assert_eq!(
+            script,
+            vec![
+                " ",
+                "<",
+                "script",
+                ">",
+                "if (a<b) go()",
+                "<",
+                "/script",
+                ">"
+            ]
+        );
+        assert_eq!(lines[1].spans[4].style.fg, Some(theme.text_primary));
+        assert_eq!(lines[0].spans[2].style.fg, Some(theme.syntax_method));
+    }
+
+    #[test]
+    fn test_pretty_print_xml() {
+        let body = concat!(
+            r#"<?xml version="1.0"?><root><item id="1">a</item>"#,
+            r#"<item id="2"><name> b </name><empty/><none></none></item>"#,
+            "<!-- a <note> -->\n  <![CDATA[x<y]]></root>",
+        );
+        assert_eq!(
+            pretty_print_xml(body).unwrap(),
+            [
+                r#"<?xml version="1.0"?>"#,
+                "<root>",
+                r#"  <item id="1">a</item>"#,
+                r#"  <item id="2">"#,
+                "    <name>b</name>",
+                "    <empty/>",
+                "    <none></none>",
+                "  </item>",
+                "  <!-- a <note> -->",
+                "  <![CDATA[x<y]]>",
+                "</root>",
+            ]
+            .join("\n")
+        );
+
+        // Text mixed with elements goes on its own lines
+        assert_eq!(
+            pretty_print_xml("<p>Hello <b>you</b> there</p>").unwrap(),
+            "<p>\n  Hello\n  <b>you</b>\n  there\n</p>"
+        );
+    }
+
+    #[test]
+    fn test_pretty_print_xml_malformed() {
+        assert!(pretty_print_xml("<a><b></a>").is_none());
+        assert!(pretty_print_xml("<a>").is_none());
+        assert!(pretty_print_xml("</a>").is_none());
+        assert!(pretty_print_xml(r#"<a href="x>"#).is_none());
+        assert!(pretty_print_xml("<a><!-- open</a>").is_none());
+        assert!(pretty_print_xml("<a><![CDATA[open</a>").is_none());
+    }
+
+    #[test]
+    fn test_xml_tag_end() {
+        assert_eq!(xml_tag_end("<a>text"), Some(2));
+        assert_eq!(xml_tag_end(r#"<a href="x>y">"#), Some(13));
+        assert_eq!(xml_tag_end("<a title='>' b=\"'\">"), Some(18));
+        assert_eq!(xml_tag_end("<a"), None);
+        assert_eq!(xml_tag_end(r#"<a b="c>"#), None);
+    }
+
+    fn row_texts(rows: &[JsonRow]) -> Vec<String> {
+        rows.iter()
+            .map(|row| match row {
+                JsonRow::Text(text) => text.clone(),
+                JsonRow::Toggle { path, .. } => format!("toggle {}", path),
+            })
+            .collect()
+    }
+
+    #[test]
+    fn test_json_rows_match_pretty_print() {
+        let json: Value =
+            serde_json::from_str(r#"{"a": [1, {"b": null}], "c": {}, "d": [], "e": "x\"y"}"#)
+                .unwrap();
+        let rows = json_rows(&json, &HashSet::new());
+        assert_eq!(
+            row_texts(&rows).join("\n"),
+            serde_json::to_string_pretty(&json).unwrap()
+        );
+    }
+
+    #[test]
+    fn test_json_rows_collapse() {
+        let json = serde_json::json!({ "items": [{ "tags": (0..25).collect::<Vec<_>>() }] });
+        let collapsed = HashSet::from(["$.items[0].tags".to_string()]);
+
+        let rows = row_texts(&json_rows(&json, &collapsed));
+        assert_eq!(rows.len(), 3 + 1 + 5 + 1 + 4);
+        assert_eq!(rows[9], "toggle $.items[0].tags");
+        assert_eq!(rows[10].trim(), "]");
+
+        let expanded = json_rows(&json, &HashSet::new());
+        assert_eq!(expanded.len(), rows.len() + 20);
+        assert!(matches!(
+            &expanded[9],
+            JsonRow::Toggle {
+                hidden: 20,
+                collapsed: false,
+                ..
+            }
+        ));
+    }
 }
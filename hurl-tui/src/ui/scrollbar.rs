@@ -0,0 +1,40 @@
+//! Scrollbar indicator
+//!
+//! Draws a one-column scrollbar over the right border of a scrollable panel.
+
+use ratatui::{layout::Rect, style::Style, Frame};
+
+use super::theme::{BoxChars, Theme};
+
+/// Render a scrollbar for content shown in `area`.
+///
+/// The bar is drawn in the column just right of `area` (the panel's right border),
+/// spanning the rows of `area`. Nothing is drawn when the content fits.
+pub fn render_scrollbar(
+    frame: &mut Frame,
+    area: Rect,
+    scroll_offset: usize,
+    total_lines: usize,
+    theme: &Theme,
+) {
+    let height = area.height as usize;
+    let x = area.right();
+    if height == 0 || total_lines <= height || x >= frame.area().right() {
+        return;
+    }
+
+    // Thumb size is proportional to the visible share of the content
+    let thumb_len = (height * height / total_lines).max(1);
+    let max_offset = total_lines - height;
+    let thumb_start = scroll_offset.min(max_offset) * (height - thumb_len) / max_offset;
+
+    let buf = frame.buffer_mut();
+    for row in 0..height {
+        let (symbol, color) = if (thumb_start..thumb_start + thumb_len).contains(&row) {
+            (BoxChars::BLOCK_DARK, theme.matrix_green)
+        } else {
+            (BoxChars::BLOCK_LIGHT, theme.border_dim)
+        };
+        buf.set_string(x, area.y + row as u16, symbol, Style::default().fg(color));
+    }
+}
@@ -0,0 +1,86 @@
+//! Snippet picker popup
+//!
+//! Lists the configured snippets with a preview of the selected one.
+
+use ratatui::{
+    layout::{Constraint, Direction, Layout},
+    style::Style,
+    text::{Line, Span},
+    widgets::{Block, Borders, Clear, Paragraph},
+    Frame,
+};
+
+use super::layout::centered_rect;
+use super::popup::{popup_block, popup_selected_style};
+use super::theme::BoxChars;
+use crate::app::App;
+
+/// Render the snippet picker popup
+pub fn render_snippets(frame: &mut Frame, app: &App) {
+    let theme = &app.active_theme;
+    let area = centered_rect(60, 50, frame.area());
+
+    // Clear the background
+    frame.render_widget(Clear, area);
+
+    let block = popup_block(" Snippets ", theme);
+    let inner = block.inner(area);
+    frame.render_widget(block, area);
+
+    let chunks = Layout::default()
+        .direction(Direction::Horizontal)
+        .constraints([Constraint::Percentage(35), Constraint::Percentage(65)])
+        .split(inner);
+
+    // Keep the selected snippet in view
+    let visible_height = chunks[0].height as usize;
+    let scroll = (app.snippet_index + 1).saturating_sub(visible_height);
+
+    let names: Vec<Line> = app
+        .config
+        .snippets
+        .iter()
+        .enumerate()
+        .skip(scroll)
+        .take(visible_height)
+        .map(|(index, snippet)| {
+            if index == app.snippet_index {
+                Line::from(Span::styled(
+                    format!(" {} {}", BoxChars::TRIANGLE_RIGHT, snippet.name),
+                    popup_selected_style(theme).fg(theme.text_primary),
+                ))
+            } else {
+                Line::from(Span::styled(
+                    format!("   {}", snippet.name),
+                    Style::default().fg(theme.text_secondary),
+                ))
+            }
+        })
+        .collect();
+    frame.render_widget(Paragraph::new(names), chunks[0]);
+
+    // Preview of the selected snippet
+    let preview: Vec<Line> = app
+        .config
+        .snippets
+        .get(app.snippet_index)
+        .map(|snippet| {
+            snippet
+                .content
+                .lines()
+                .map(|line| {
+                    Line::from(Span::styled(
+                        line.to_string(),
+                        Style::default().fg(theme.text_muted),
+                    ))
+                })
+                .collect()
+        })
+        .unwrap_or_default();
+    let preview = Paragraph::new(preview).block(
+        Block::default()
+            .borders(Borders::LEFT)
+            .border_style(Style::default().fg(theme.border_dim)),
+    );
+    frame.render_widget(preview, chunks[1]);
+}
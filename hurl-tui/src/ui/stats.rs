@@ -0,0 +1,100 @@
+//! Run statistics popup
+//!
+//! Summarizes the durations of the runs of the current file and charts the recent ones.
+
+use ratatui::{
+    style::Style,
+    text::{Line, Span},
+    widgets::{Clear, Paragraph},
+    Frame,
+};
+
+use super::layout::centered_rect;
+use super::popup::popup_block;
+use super::theme::BoxChars;
+use crate::app::App;
+
+/// Render the run statistics popup
+pub fn render_stats(frame: &mut Frame, app: &App) {
+    let theme = &app.active_theme;
+    let area = centered_rect(60, 60, frame.area());
+
+    // Clear the background
+    frame.render_widget(Clear, area);
+
+    let Some(path) = app.current_file_path.as_ref() else {
+        return;
+    };
+    let file_name = path
+        .file_name()
+        .map(|n| n.to_string_lossy().to_string())
+        .unwrap_or_default();
+    let stats = app.compute_stats_for_file(path);
+
+    let block = popup_block(format!(" Stats: {} ", file_name), theme);
+
+    let label = Style::default().fg(theme.text_muted);
+    let value = Style::default().fg(theme.text_primary);
+    let mut lines = vec![
+        Line::from(vec![
+            Span::styled("  Runs ", label),
+            Span::styled(stats.run_count.to_string(), value),
+            Span::styled("   Success ", label),
+            Span::styled(format!("{:.0}%", stats.success_rate * 100.0), value),
+        ]),
+        Line::from(vec![
+            Span::styled("  Avg ", label),
+            Span::styled(format!("{:.0}ms", stats.avg_ms), value),
+            Span::styled("   Min ", label),
+            Span::styled(format!("{}ms", stats.min_ms), value),
+            Span::styled("   Max ", label),
+            Span::styled(format!("{}ms", stats.max_ms), value),
+            Span::styled("   P95 ", label),
+            Span::styled(format!("{}ms", stats.p95_ms), value),
+        ]),
+        Line::from(""),
+    ];
+
+    // One bar per recent run, oldest at the top (borders and summary take 5 rows)
+    let rows = area.height.saturating_sub(5) as usize;
+    let bar_width = area.width.saturating_sub(26).max(1) as u64;
+    let mut runs: Vec<_> = app
+        .history
+        .iter()
+        .filter(|e| &e.file_path == path)
+        .take(rows)
+        .collect();
+    runs.reverse();
+
+    for entry in runs {
+        let color = if (entry.duration_ms as f64) < stats.avg_ms {
+            theme.matrix_green
+        } else if entry.duration_ms > stats.p95_ms {
+            theme.neon_red
+        } else {
+            theme.amber_warning
+        };
+        let width = (entry.duration_ms * bar_width / stats.max_ms.max(1)).max(1) as usize;
+        lines.push(Line::from(vec![
+            Span::styled(
+                format!(
+                    "  {} ",
+                    entry
+                        .timestamp
+                        .with_timezone(&chrono::Local)
+                        .format("%H:%M:%S")
+                ),
+                label,
+            ),
+            Span::styled(
+                BoxChars::BLOCK_FULL.repeat(width),
+                Style::default().fg(color),
+            ),
+            Span::styled(format!(" {}ms", entry.duration_ms), value),
+        ]));
+    }
+
+    let paragraph = Paragraph::new(lines).block(block);
+
+    frame.render_widget(paragraph, area);
+}
@@ -11,11 +11,11 @@ use ratatui::{
     Frame,
 };
 
-use super::theme::HackerTheme;
 use crate::app::{App, AppMode, StatusLevel, VimMode};
 
 /// Render the status bar
 pub fn render_status_bar(frame: &mut Frame, app: &App, area: Rect) {
+    let theme = &app.active_theme;
     let mut spans: Vec<Span> = Vec::new();
 
     // Mode indicator - clean minimal style
@@ -24,128 +24,269 @@ pub fn render_status_bar(frame: &mut Frame, app: &App, area: Rect) {
             spans.push(Span::styled(
                 " NORMAL ",
                 Style::default()
-                    .fg(HackerTheme::MODE_NORMAL_FG)
-                    .bg(HackerTheme::MODE_NORMAL_BG),
+                    .fg(theme.mode_normal_fg)
+                    .bg(theme.mode_normal_bg),
             ));
         }
         AppMode::Editing => {
             let (vim_label, fg, bg) = match app.vim_mode {
-                VimMode::Normal => (
-                    " NORMAL ",
-                    HackerTheme::MODE_NORMAL_FG,
-                    HackerTheme::MODE_NORMAL_BG,
-                ),
-                VimMode::Insert => (
-                    " INSERT ",
-                    HackerTheme::MODE_EDIT_FG,
-                    HackerTheme::MODE_EDIT_BG,
-                ),
+                VimMode::Normal => (" NORMAL ", theme.mode_normal_fg, theme.mode_normal_bg),
+                VimMode::Insert => (" INSERT ", theme.mode_edit_fg, theme.mode_edit_bg),
+                VimMode::Visual => (" VISUAL ", theme.mode_command_fg, theme.mode_command_bg),
             };
             spans.push(Span::styled(vim_label, Style::default().fg(fg).bg(bg)));
+            if let Some(key) = app.pending_key {
+                spans.push(Span::styled(
+                    format!(" [{}_]", key),
+                    Style::default().fg(theme.text_muted).bg(theme.dark_bg),
+                ));
+            }
         }
         AppMode::Search => {
             spans.push(Span::styled(
                 " SEARCH ",
                 Style::default()
-                    .fg(HackerTheme::MODE_SEARCH_FG)
-                    .bg(HackerTheme::MODE_SEARCH_BG),
+                    .fg(theme.mode_search_fg)
+                    .bg(theme.mode_search_bg),
             ));
+            spans.push(Span::styled("  ", Style::default().bg(theme.dark_bg)));
             spans.push(Span::styled(
-                "  ",
-                Style::default().bg(HackerTheme::DARK_BG),
+                format!("/{}_", app.search_query),
+                Style::default().fg(theme.cyber_cyan).bg(theme.dark_bg),
             ));
+        }
+        AppMode::ResponseSearch => {
             spans.push(Span::styled(
-                format!("/{}_", app.search_query),
+                " SEARCH ",
                 Style::default()
-                    .fg(HackerTheme::CYBER_CYAN)
-                    .bg(HackerTheme::DARK_BG),
+                    .fg(theme.mode_search_fg)
+                    .bg(theme.mode_search_bg),
+            ));
+            spans.push(Span::styled("  ", Style::default().bg(theme.dark_bg)));
+            spans.push(Span::styled(
+                format!("/{}_", app.response_search_query),
+                Style::default().fg(theme.cyber_cyan).bg(theme.dark_bg),
+            ));
+            spans.push(Span::styled(
+                format!("  [{} matches]", app.response_search_matches.len()),
+                Style::default().fg(theme.text_muted).bg(theme.dark_bg),
             ));
         }
-        AppMode::Command => {
+        AppMode::GlobalSearch => {
             spans.push(Span::styled(
-                " COMMAND ",
+                " SEARCH ALL ",
                 Style::default()
-                    .fg(HackerTheme::MODE_COMMAND_FG)
-                    .bg(HackerTheme::MODE_COMMAND_BG),
+                    .fg(theme.mode_search_fg)
+                    .bg(theme.mode_search_bg),
             ));
+        }
+        AppMode::CookieJar => {
             spans.push(Span::styled(
-                "  ",
-                Style::default().bg(HackerTheme::DARK_BG),
+                " COOKIES ",
+                Style::default()
+                    .fg(theme.mode_command_fg)
+                    .bg(theme.mode_command_bg),
             ));
+        }
+        AppMode::EnvEditor => {
             spans.push(Span::styled(
-                format!(":{}_", app.command_input),
+                " ENV ",
                 Style::default()
-                    .fg(HackerTheme::ELECTRIC_PURPLE)
-                    .bg(HackerTheme::DARK_BG),
+                    .fg(theme.mode_command_fg)
+                    .bg(theme.mode_command_bg),
             ));
         }
-        AppMode::Filter => {
+        AppMode::History | AppMode::CommandHistorySearch => {
             spans.push(Span::styled(
-                " FILTER ",
+                " HISTORY ",
                 Style::default()
-                    .fg(HackerTheme::MODE_FILTER_FG)
-                    .bg(HackerTheme::MODE_FILTER_BG),
+                    .fg(theme.mode_command_fg)
+                    .bg(theme.mode_command_bg),
             ));
+        }
+        AppMode::Snippets => {
             spans.push(Span::styled(
-                "  ",
-                Style::default().bg(HackerTheme::DARK_BG),
+                " SNIPPETS ",
+                Style::default()
+                    .fg(theme.mode_edit_fg)
+                    .bg(theme.mode_edit_bg),
             ));
+        }
+        AppMode::FocusedEntry => {
             spans.push(Span::styled(
-                format!("{}_", app.filter_query),
+                " FOCUS ",
+                Style::default()
+                    .fg(theme.mode_normal_fg)
+                    .bg(theme.mode_normal_bg),
+            ));
+        }
+        AppMode::Bookmarks => {
+            spans.push(Span::styled(
+                " BOOKMARKS ",
+                Style::default()
+                    .fg(theme.mode_command_fg)
+                    .bg(theme.mode_command_bg),
+            ));
+        }
+        AppMode::VariableOverride => {
+            spans.push(Span::styled(
+                " OVERRIDES ",
+                Style::default()
+                    .fg(theme.mode_command_fg)
+                    .bg(theme.mode_command_bg),
+            ));
+        }
+        AppMode::Compare => {
+            spans.push(Span::styled(
+                " COMPARE ",
+                Style::default()
+                    .fg(theme.mode_command_fg)
+                    .bg(theme.mode_command_bg),
+            ));
+        }
+        AppMode::Stats => {
+            spans.push(Span::styled(
+                " STATS ",
+                Style::default()
+                    .fg(theme.mode_command_fg)
+                    .bg(theme.mode_command_bg),
+            ));
+        }
+        AppMode::GotoLine => {
+            spans.push(Span::styled(
+                " GOTO ",
+                Style::default()
+                    .fg(theme.mode_command_fg)
+                    .bg(theme.mode_command_bg),
+            ));
+        }
+        AppMode::Command => {
+            spans.push(Span::styled(
+                " COMMAND ",
                 Style::default()
-                    .fg(HackerTheme::AMBER_WARNING)
-                    .bg(HackerTheme::DARK_BG),
+                    .fg(theme.mode_command_fg)
+                    .bg(theme.mode_command_bg),
+            ));
+            spans.push(Span::styled("  ", Style::default().bg(theme.dark_bg)));
+            spans.push(Span::styled(
+                format!(":{}_", app.command_input),
+                Style::default().fg(theme.electric_purple).bg(theme.dark_bg),
+            ));
+        }
+        AppMode::Filter => {
+            spans.push(Span::styled(
+                if app.filter_is_regex {
+                    " REGEX "
+                } else {
+                    " FILTER "
+                },
+                Style::default()
+                    .fg(theme.mode_filter_fg)
+                    .bg(theme.mode_filter_bg),
+            ));
+            spans.push(Span::styled("  ", Style::default().bg(theme.dark_bg)));
+            spans.push(Span::styled(
+                format!("{}_", app.filter_query),
+                Style::default().fg(theme.amber_warning).bg(theme.dark_bg),
             ));
         }
         AppMode::Rename => {
+            let label = if app.rename_is_new_dir {
+                " NEW DIR "
+            } else if app.new_file_dir.is_some() {
+                " NEW FILE "
+            } else {
+                " RENAME "
+            };
             spans.push(Span::styled(
-                " RENAME ",
+                label,
                 Style::default()
-                    .fg(HackerTheme::MODE_EDIT_FG)
-                    .bg(HackerTheme::MODE_EDIT_BG),
+                    .fg(theme.mode_edit_fg)
+                    .bg(theme.mode_edit_bg),
             ));
+            spans.push(Span::styled("  ", Style::default().bg(theme.dark_bg)));
+            // Split the input around the cursor so it can be highlighted
+            let input_style = Style::default().fg(theme.cyber_cyan).bg(theme.dark_bg);
+            let cursor = app.rename_cursor.min(app.rename_input.len());
+            let (before, rest) = app.rename_input.split_at(cursor);
+            let mut rest_chars = rest.chars();
+            let at_cursor = rest_chars.next().map_or(" ".to_string(), String::from);
+            spans.push(Span::styled(before.to_string(), input_style));
             spans.push(Span::styled(
-                "  ",
-                Style::default().bg(HackerTheme::DARK_BG),
+                at_cursor,
+                Style::default().fg(theme.cursor_fg).bg(theme.cursor_bg),
             ));
+            spans.push(Span::styled(rest_chars.as_str().to_string(), input_style));
+        }
+        AppMode::ConfirmDelete => {
             spans.push(Span::styled(
-                format!("{}_", app.rename_input),
+                " DELETE ",
+                Style::default().fg(theme.neon_red).bg(theme.mode_edit_bg),
+            ));
+        }
+        AppMode::ConfirmSave => {
+            spans.push(Span::styled(
+                " UNSAVED ",
                 Style::default()
-                    .fg(HackerTheme::CYBER_CYAN)
-                    .bg(HackerTheme::DARK_BG),
+                    .fg(theme.amber_warning)
+                    .bg(theme.mode_edit_bg),
             ));
         }
     }
 
-    spans.push(Span::styled(
-        "  ",
-        Style::default().bg(HackerTheme::DARK_BG),
-    ));
+    // Settings that change how requests are sent
+    if app.active_proxy().is_some() {
+        spans.push(Span::styled(
+            " [PROXY]",
+            Style::default().fg(theme.amber_warning).bg(theme.dark_bg),
+        ));
+    }
+    if app.fullscreen_panel.is_some() {
+        spans.push(Span::styled(
+            " [FULL]",
+            Style::default().fg(theme.amber_warning).bg(theme.dark_bg),
+        ));
+    }
+    if app.insecure_override {
+        spans.push(Span::styled(
+            " [INSECURE]",
+            Style::default()
+                .fg(theme.neon_red)
+                .bg(theme.dark_bg)
+                .add_modifier(Modifier::BOLD),
+        ));
+    }
+
+    spans.push(Span::styled("  ", Style::default().bg(theme.dark_bg)));
 
-    // Status message
-    if let Some((message, level)) = &app.status_message {
+    // Status message (a notice is shown when there is none)
+    if let Some((message, level)) = app.status_message.as_ref().or(app.status_notice.as_ref()) {
         let color = match level {
-            StatusLevel::Info => HackerTheme::TEXT_SECONDARY,
-            StatusLevel::Success => HackerTheme::NEON_GREEN,
-            StatusLevel::Warning => HackerTheme::AMBER_WARNING,
-            StatusLevel::Error => HackerTheme::NEON_RED,
+            StatusLevel::Info => theme.text_secondary,
+            StatusLevel::Muted => theme.text_muted,
+            StatusLevel::Success => theme.neon_green,
+            StatusLevel::Warning => theme.amber_warning,
+            StatusLevel::Error => theme.neon_red,
         };
         spans.push(Span::styled(
             message.clone(),
-            Style::default().fg(color).bg(HackerTheme::DARK_BG),
+            Style::default().fg(color).bg(theme.dark_bg),
         ));
     }
 
     // Running indicator
-    if app.is_running {
+    let collection_progress = app.collection_progress();
+    if app.is_running || collection_progress.is_some() {
         let spinner_chars = ["⠋", "⠙", "⠹", "⠸", "⠼", "⠴", "⠦", "⠧", "⠇", "⠏"];
         let spinner = spinner_chars[app.spinner_frame % spinner_chars.len()];
+        let label = match collection_progress {
+            Some((done, total)) => format!("Running {}/{} files…", (done + 1).min(total), total),
+            None => "Running...".to_string(),
+        };
 
         spans.push(Span::styled(
-            format!("  {} Running...", spinner),
-            Style::default()
-                .fg(HackerTheme::RUNNING)
-                .bg(HackerTheme::DARK_BG),
+            format!("  {} {}", spinner, label),
+            Style::default().fg(theme.running).bg(theme.dark_bg),
         ));
     }
 
@@ -155,7 +296,24 @@ pub fn render_status_bar(frame: &mut Frame, app: &App, area: Rect) {
         AppMode::Editing => match app.vim_mode {
             VimMode::Normal => " i:insert  q:quit ",
             VimMode::Insert => " Esc:normal ",
+            VimMode::Visual => " y:yank  d:delete  p:put  gc:comment  Esc:normal ",
         },
+        AppMode::ConfirmDelete => " y:delete  n:cancel ",
+        AppMode::ConfirmSave => " y:save  n:discard  Esc:cancel ",
+        AppMode::GlobalSearch => " Up/Down:select  Enter:open  Esc:close ",
+        AppMode::ResponseSearch => " Enter:find  Esc:cancel ",
+        AppMode::CookieJar | AppMode::VariableOverride => " j/k:select  d:delete  Esc:close ",
+        AppMode::EnvEditor if app.env_editor_editing.is_some() => " Enter:confirm  Esc:cancel ",
+        AppMode::EnvEditor => " Enter:edit  n:new  d:delete  ^s:save  Esc:close ",
+        AppMode::History => " j/k:select  Enter:load  d:delete  Esc:close ",
+        AppMode::Snippets => " j/k:select  Enter:insert  Esc:close ",
+        AppMode::GotoLine => " Enter:jump  Esc:cancel ",
+        AppMode::Command => " Up/Down:history  ^r:search  Enter:run  Esc:cancel ",
+        AppMode::CommandHistorySearch => " Up/Down:select  Enter:use  Esc:back ",
+        AppMode::Stats => " Esc:close ",
+        AppMode::Compare => " j/k:scroll  Tab:switch side  ]c/[c:next/prev change  Esc:close ",
+        AppMode::FocusedEntry => " ]/[:next/prev entry  Esc:exit ",
+        AppMode::Bookmarks => " j/k:select  J/K:move  Enter:open  d:delete  Esc:close ",
         _ => " Esc:back ",
     };
 
@@ -167,18 +325,15 @@ pub fn render_status_bar(frame: &mut Frame, app: &App, area: Rect) {
 
     spans.push(Span::styled(
         " ".repeat(padding),
-        Style::default().bg(HackerTheme::DARK_BG),
+        Style::default().bg(theme.dark_bg),
     ));
 
     spans.push(Span::styled(
         shortcuts,
-        Style::default()
-            .fg(HackerTheme::TEXT_MUTED)
-            .bg(HackerTheme::DARK_BG),
+        Style::default().fg(theme.text_muted).bg(theme.dark_bg),
     ));
 
-    let paragraph =
-        Paragraph::new(Line::from(spans)).style(Style::default().bg(HackerTheme::DARK_BG));
+    let paragraph = Paragraph::new(Line::from(spans)).style(Style::default().bg(theme.dark_bg));
 
     frame.render_widget(paragraph, area);
 }
@@ -1,8 +1,13 @@
 //! Modern Clean Theme
 //!
 //! A minimal, modern color palette inspired by popular code editors.
+//! Other themes, built-in or loaded from TOML files, are switched to with `:theme`.
 
+use anyhow::{anyhow, bail, Context, Result};
 use ratatui::style::Color;
+use std::path::Path;
+
+use crate::config::Config;
 
 /// Modern theme colors - Clean and minimal
 pub struct HackerTheme;
@@ -110,10 +115,332 @@ impl HackerTheme {
     /// Selected item background
     pub const SELECTED_BG: Color = Color::Rgb(55, 55, 60);
     pub const SELECTED_FG: Color = Color::Rgb(212, 212, 212);
+    /// Search match background
+    pub const SEARCH_MATCH_BG: Color = Color::Rgb(98, 80, 40);
     /// Running indicator
     pub const RUNNING: Color = Color::Rgb(220, 165, 80);
 }
 
+/// Colors the UI is drawn with: a built-in theme or one loaded from a TOML file
+///
+/// The fields mirror the [`HackerTheme`] constants. Theme files group them in sections
+/// (`[primary]`, `[accent]`, `[background]`, `[text]`, `[syntax]`, `[status]`, `[mode]`,
+/// `[assertions]` and `[special]`) with hex values, e.g. `matrix_green = "#569CD6"`;
+/// colors a file leaves out keep their `hacker` value.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Theme {
+    /// Primary accent - soft blue
+    pub matrix_green: Color,
+    /// Bright accent for highlights
+    pub matrix_green_bright: Color,
+    /// Dim accent for less important elements
+    pub matrix_green_dim: Color,
+    /// Dark accent for subtle elements
+    pub matrix_green_dark: Color,
+    /// Cyan for active/selected elements
+    pub cyber_cyan: Color,
+    /// Dim cyan for secondary highlights
+    pub cyber_cyan_dim: Color,
+    /// Magenta/pink for special elements
+    pub neon_pink: Color,
+    /// Purple for alternates
+    pub electric_purple: Color,
+    /// Warning orange
+    pub amber_warning: Color,
+    /// Error red
+    pub neon_red: Color,
+    /// Success green
+    pub neon_green: Color,
+    /// Deep background (transparent - inherits terminal background)
+    pub void_black: Color,
+    /// Slightly lighter background for panels (transparent - inherits terminal background)
+    pub dark_bg: Color,
+    /// Surface color for elevated elements
+    pub surface: Color,
+    /// Border color (dim)
+    pub border_dim: Color,
+    /// Border color (active)
+    pub border_active: Color,
+    /// Primary text - bright for readability
+    pub text_primary: Color,
+    /// Secondary text
+    pub text_secondary: Color,
+    /// Muted text for less important info
+    pub text_muted: Color,
+    /// Comment text
+    pub text_comment: Color,
+    /// HTTP methods (GET, POST, etc.)
+    pub syntax_method: Color,
+    /// URLs
+    pub syntax_url: Color,
+    /// Headers
+    pub syntax_header: Color,
+    /// Header values
+    pub syntax_value: Color,
+    /// Section markers [Asserts], etc.
+    pub syntax_section: Color,
+    /// Variables {{var}}
+    pub syntax_variable: Color,
+    /// Keywords
+    pub syntax_keyword: Color,
+    /// JSON/data content
+    pub syntax_data: Color,
+    /// Status line
+    pub syntax_status: Color,
+    /// 2xx success
+    pub status_2xx: Color,
+    /// 3xx redirect
+    pub status_3xx: Color,
+    /// 4xx client error
+    pub status_4xx: Color,
+    /// 5xx server error
+    pub status_5xx: Color,
+    pub mode_normal_bg: Color,
+    pub mode_normal_fg: Color,
+    pub mode_edit_bg: Color,
+    pub mode_edit_fg: Color,
+    pub mode_search_bg: Color,
+    pub mode_search_fg: Color,
+    pub mode_command_bg: Color,
+    pub mode_command_fg: Color,
+    pub mode_filter_bg: Color,
+    pub mode_filter_fg: Color,
+    pub assert_pass: Color,
+    pub assert_fail: Color,
+    pub assert_pending: Color,
+    /// Cursor color
+    pub cursor_bg: Color,
+    pub cursor_fg: Color,
+    /// Selected item background
+    pub selected_bg: Color,
+    pub selected_fg: Color,
+    /// Search match background
+    pub search_match_bg: Color,
+    /// Running indicator
+    pub running: Color,
+}
+
+impl Theme {
+    /// The built-in default theme
+    pub const HACKER: Theme = Theme {
+        matrix_green: HackerTheme::MATRIX_GREEN,
+        matrix_green_bright: HackerTheme::MATRIX_GREEN_BRIGHT,
+        matrix_green_dim: HackerTheme::MATRIX_GREEN_DIM,
+        matrix_green_dark: HackerTheme::MATRIX_GREEN_DARK,
+        cyber_cyan: HackerTheme::CYBER_CYAN,
+        cyber_cyan_dim: HackerTheme::CYBER_CYAN_DIM,
+        neon_pink: HackerTheme::NEON_PINK,
+        electric_purple: HackerTheme::ELECTRIC_PURPLE,
+        amber_warning: HackerTheme::AMBER_WARNING,
+        neon_red: HackerTheme::NEON_RED,
+        neon_green: HackerTheme::NEON_GREEN,
+        void_black: HackerTheme::VOID_BLACK,
+        dark_bg: HackerTheme::DARK_BG,
+        surface: HackerTheme::SURFACE,
+        border_dim: HackerTheme::BORDER_DIM,
+        border_active: HackerTheme::BORDER_ACTIVE,
+        text_primary: HackerTheme::TEXT_PRIMARY,
+        text_secondary: HackerTheme::TEXT_SECONDARY,
+        text_muted: HackerTheme::TEXT_MUTED,
+        text_comment: HackerTheme::TEXT_COMMENT,
+        syntax_method: HackerTheme::SYNTAX_METHOD,
+        syntax_url: HackerTheme::SYNTAX_URL,
+        syntax_header: HackerTheme::SYNTAX_HEADER,
+        syntax_value: HackerTheme::SYNTAX_VALUE,
+        syntax_section: HackerTheme::SYNTAX_SECTION,
+        syntax_variable: HackerTheme::SYNTAX_VARIABLE,
+        syntax_keyword: HackerTheme::SYNTAX_KEYWORD,
+        syntax_data: HackerTheme::SYNTAX_DATA,
+        syntax_status: HackerTheme::SYNTAX_STATUS,
+        status_2xx: HackerTheme::STATUS_2XX,
+        status_3xx: HackerTheme::STATUS_3XX,
+        status_4xx: HackerTheme::STATUS_4XX,
+        status_5xx: HackerTheme::STATUS_5XX,
+        mode_normal_bg: HackerTheme::MODE_NORMAL_BG,
+        mode_normal_fg: HackerTheme::MODE_NORMAL_FG,
+        mode_edit_bg: HackerTheme::MODE_EDIT_BG,
+        mode_edit_fg: HackerTheme::MODE_EDIT_FG,
+        mode_search_bg: HackerTheme::MODE_SEARCH_BG,
+        mode_search_fg: HackerTheme::MODE_SEARCH_FG,
+        mode_command_bg: HackerTheme::MODE_COMMAND_BG,
+        mode_command_fg: HackerTheme::MODE_COMMAND_FG,
+        mode_filter_bg: HackerTheme::MODE_FILTER_BG,
+        mode_filter_fg: HackerTheme::MODE_FILTER_FG,
+        assert_pass: HackerTheme::ASSERT_PASS,
+        assert_fail: HackerTheme::ASSERT_FAIL,
+        assert_pending: HackerTheme::ASSERT_PENDING,
+        cursor_bg: HackerTheme::CURSOR_BG,
+        cursor_fg: HackerTheme::CURSOR_FG,
+        selected_bg: HackerTheme::SELECTED_BG,
+        selected_fg: HackerTheme::SELECTED_FG,
+        search_match_bg: HackerTheme::SEARCH_MATCH_BG,
+        running: HackerTheme::RUNNING,
+    };
+
+    /// Solarized dark
+    pub const SOLARIZED: Theme = Theme {
+        matrix_green: Color::Rgb(38, 139, 210),
+        matrix_green_bright: Color::Rgb(75, 163, 227),
+        matrix_green_dim: Color::Rgb(32, 118, 179),
+        matrix_green_dark: Color::Rgb(26, 94, 143),
+        cyber_cyan: Color::Rgb(42, 161, 152),
+        cyber_cyan_dim: Color::Rgb(34, 135, 127),
+        neon_pink: Color::Rgb(211, 54, 130),
+        electric_purple: Color::Rgb(108, 113, 196),
+        amber_warning: Color::Rgb(181, 137, 0),
+        neon_red: Color::Rgb(220, 50, 47),
+        neon_green: Color::Rgb(133, 153, 0),
+        void_black: Color::Reset,
+        dark_bg: Color::Reset,
+        surface: Color::Rgb(7, 54, 66),
+        border_dim: Color::Rgb(88, 110, 117),
+        border_active: Color::Rgb(38, 139, 210),
+        text_primary: Color::Rgb(147, 161, 161),
+        text_secondary: Color::Rgb(131, 148, 150),
+        text_muted: Color::Rgb(88, 110, 117),
+        text_comment: Color::Rgb(88, 110, 117),
+        syntax_method: Color::Rgb(38, 139, 210),
+        syntax_url: Color::Rgb(42, 161, 152),
+        syntax_header: Color::Rgb(181, 137, 0),
+        syntax_value: Color::Rgb(42, 161, 152),
+        syntax_section: Color::Rgb(203, 75, 22),
+        syntax_variable: Color::Rgb(108, 113, 196),
+        syntax_keyword: Color::Rgb(133, 153, 0),
+        syntax_data: Color::Rgb(147, 161, 161),
+        syntax_status: Color::Rgb(38, 139, 210),
+        status_2xx: Color::Rgb(133, 153, 0),
+        status_3xx: Color::Rgb(181, 137, 0),
+        status_4xx: Color::Rgb(203, 75, 22),
+        status_5xx: Color::Rgb(220, 50, 47),
+        mode_normal_bg: Color::Rgb(7, 54, 66),
+        mode_normal_fg: Color::Rgb(38, 139, 210),
+        mode_edit_bg: Color::Rgb(7, 54, 66),
+        mode_edit_fg: Color::Rgb(181, 137, 0),
+        mode_search_bg: Color::Rgb(7, 54, 66),
+        mode_search_fg: Color::Rgb(42, 161, 152),
+        mode_command_bg: Color::Rgb(7, 54, 66),
+        mode_command_fg: Color::Rgb(108, 113, 196),
+        mode_filter_bg: Color::Rgb(7, 54, 66),
+        mode_filter_fg: Color::Rgb(203, 75, 22),
+        assert_pass: Color::Rgb(133, 153, 0),
+        assert_fail: Color::Rgb(220, 50, 47),
+        assert_pending: Color::Rgb(88, 110, 117),
+        cursor_bg: Color::Rgb(147, 161, 161),
+        cursor_fg: Color::Rgb(0, 43, 54),
+        selected_bg: Color::Rgb(7, 54, 66),
+        selected_fg: Color::Rgb(147, 161, 161),
+        search_match_bg: Color::Rgb(76, 64, 0),
+        running: Color::Rgb(181, 137, 0),
+    };
+
+    /// Look up a theme by name: `<name>.toml` in the themes directory, then the built-in
+    /// `hacker` (also `default`) and `solarized` themes
+    pub fn named(name: &str) -> Result<Theme> {
+        let path = Config::themes_dir().map(|dir| dir.join(format!("{}.toml", name)));
+        if let Some(path) = path.filter(|p| p.exists()) {
+            return Theme::load_from_file(&path);
+        }
+
+        match name {
+            "hacker" | "default" => Ok(Theme::HACKER),
+            "solarized" => Ok(Theme::SOLARIZED),
+            _ => bail!("Unknown theme: {}", name),
+        }
+    }
+
+    /// Load a theme file, starting from the `hacker` colors
+    pub fn load_from_file(path: &Path) -> Result<Theme> {
+        let content = std::fs::read_to_string(path)
+            .with_context(|| format!("Failed to read theme {}", path.display()))?;
+        Theme::parse(&content).with_context(|| format!("Invalid theme {}", path.display()))
+    }
+
+    /// Parse the sections of a theme file
+    fn parse(content: &str) -> Result<Theme> {
+        let table: toml::Table = toml::from_str(content)?;
+        let mut theme = Theme::HACKER;
+
+        for (section, values) in &table {
+            let Some(values) = values.as_table() else {
+                bail!("`{}` is not a section", section);
+            };
+            for (key, value) in values {
+                let Some(color) = theme.color_mut(section, key) else {
+                    bail!("Unknown color `{}` in [{}]", key, section);
+                };
+                let Some(value) = value.as_str() else {
+                    bail!("`{}` must be a color string", key);
+                };
+                *color = value
+                    .parse()
+                    .map_err(|_| anyhow!("Invalid color for `{}`: {}", key, value))?;
+            }
+        }
+
+        Ok(theme)
+    }
+
+    /// The field a theme file key sets
+    fn color_mut(&mut self, section: &str, key: &str) -> Option<&mut Color> {
+        let color = match (section, key) {
+            ("primary", "matrix_green") => &mut self.matrix_green,
+            ("primary", "matrix_green_bright") => &mut self.matrix_green_bright,
+            ("primary", "matrix_green_dim") => &mut self.matrix_green_dim,
+            ("primary", "matrix_green_dark") => &mut self.matrix_green_dark,
+            ("accent", "cyber_cyan") => &mut self.cyber_cyan,
+            ("accent", "cyber_cyan_dim") => &mut self.cyber_cyan_dim,
+            ("accent", "neon_pink") => &mut self.neon_pink,
+            ("accent", "electric_purple") => &mut self.electric_purple,
+            ("accent", "amber_warning") => &mut self.amber_warning,
+            ("accent", "neon_red") => &mut self.neon_red,
+            ("accent", "neon_green") => &mut self.neon_green,
+            ("background", "void_black") => &mut self.void_black,
+            ("background", "dark_bg") => &mut self.dark_bg,
+            ("background", "surface") => &mut self.surface,
+            ("background", "border_dim") => &mut self.border_dim,
+            ("background", "border_active") => &mut self.border_active,
+            ("text", "text_primary") => &mut self.text_primary,
+            ("text", "text_secondary") => &mut self.text_secondary,
+            ("text", "text_muted") => &mut self.text_muted,
+            ("text", "text_comment") => &mut self.text_comment,
+            ("syntax", "syntax_method") => &mut self.syntax_method,
+            ("syntax", "syntax_url") => &mut self.syntax_url,
+            ("syntax", "syntax_header") => &mut self.syntax_header,
+            ("syntax", "syntax_value") => &mut self.syntax_value,
+            ("syntax", "syntax_section") => &mut self.syntax_section,
+            ("syntax", "syntax_variable") => &mut self.syntax_variable,
+            ("syntax", "syntax_keyword") => &mut self.syntax_keyword,
+            ("syntax", "syntax_data") => &mut self.syntax_data,
+            ("syntax", "syntax_status") => &mut self.syntax_status,
+            ("status", "status_2xx") => &mut self.status_2xx,
+            ("status", "status_3xx") => &mut self.status_3xx,
+            ("status", "status_4xx") => &mut self.status_4xx,
+            ("status", "status_5xx") => &mut self.status_5xx,
+            ("mode", "mode_normal_bg") => &mut self.mode_normal_bg,
+            ("mode", "mode_normal_fg") => &mut self.mode_normal_fg,
+            ("mode", "mode_edit_bg") => &mut self.mode_edit_bg,
+            ("mode", "mode_edit_fg") => &mut self.mode_edit_fg,
+            ("mode", "mode_search_bg") => &mut self.mode_search_bg,
+            ("mode", "mode_search_fg") => &mut self.mode_search_fg,
+            ("mode", "mode_command_bg") => &mut self.mode_command_bg,
+            ("mode", "mode_command_fg") => &mut self.mode_command_fg,
+            ("mode", "mode_filter_bg") => &mut self.mode_filter_bg,
+            ("mode", "mode_filter_fg") => &mut self.mode_filter_fg,
+            ("assertions", "assert_pass") => &mut self.assert_pass,
+            ("assertions", "assert_fail") => &mut self.assert_fail,
+            ("assertions", "assert_pending") => &mut self.assert_pending,
+            ("special", "cursor_bg") => &mut self.cursor_bg,
+            ("special", "cursor_fg") => &mut self.cursor_fg,
+            ("special", "selected_bg") => &mut self.selected_bg,
+            ("special", "selected_fg") => &mut self.selected_fg,
+            ("special", "search_match_bg") => &mut self.search_match_bg,
+            ("special", "running") => &mut self.running,
+            _ => return None,
+        };
+        Some(color)
+    }
+}
+
 /// Box drawing characters for borders
 pub struct BoxChars;
 
@@ -157,6 +484,7 @@ impl BoxChars {
     pub const CHECK: &'static str = "✓";
     pub const CROSS: &'static str = "✕";
     pub const DOT: &'static str = "·";
+    pub const WARNING: &'static str = "⚠";
 
     // Spinner frames for loading animation
     pub const SPINNER: [&'static str; 10] = ["⠋", "⠙", "⠹", "⠸", "⠼", "⠴", "⠦", "⠧", "⠇", "⠏"];
@@ -185,3 +513,52 @@ impl BoxChars {
         Self::LOADING_BAR[frame % Self::LOADING_BAR.len()]
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_partial_theme_keeps_defaults() {
+        let theme = Theme::parse(
+            r##"
+[primary]
+matrix_green = "#569CD6"
+
+[text]
+text_primary = "#FFFFFF"
+"##,
+        )
+        .unwrap();
+
+        assert_eq!(theme.matrix_green, Color::Rgb(0x56, 0x9C, 0xD6));
+        assert_eq!(theme.text_primary, Color::Rgb(0xFF, 0xFF, 0xFF));
+        assert_eq!(theme.cyber_cyan, Theme::HACKER.cyber_cyan);
+        assert_eq!(theme.void_black, Theme::HACKER.void_black);
+        assert_eq!(Theme::parse("").unwrap(), Theme::HACKER);
+    }
+
+    #[test]
+    fn test_parse_unknown_key() {
+        let err = Theme::parse("[primary]\nmatrix_blue = \"#000000\"").unwrap_err();
+        assert_eq!(err.to_string(), "Unknown color `matrix_blue` in [primary]");
+    }
+
+    #[test]
+    fn test_parse_unknown_section() {
+        let err = Theme::parse("[colors]\nmatrix_green = \"#000000\"").unwrap_err();
+        assert_eq!(err.to_string(), "Unknown color `matrix_green` in [colors]");
+
+        let err = Theme::parse("matrix_green = \"#000000\"").unwrap_err();
+        assert_eq!(err.to_string(), "`matrix_green` is not a section");
+    }
+
+    #[test]
+    fn test_parse_bad_color() {
+        let err = Theme::parse("[primary]\nmatrix_green = \"#12345G\"").unwrap_err();
+        assert_eq!(err.to_string(), "Invalid color for `matrix_green`: #12345G");
+
+        let err = Theme::parse("[primary]\nmatrix_green = 42").unwrap_err();
+        assert_eq!(err.to_string(), "`matrix_green` must be a color string");
+    }
+}
@@ -0,0 +1,77 @@
+//! Variable override popup
+//!
+//! Lists the variables set with `:setvar`, which are passed to hurl as `--variable`
+//! and take precedence over the environment file.
+
+use ratatui::{
+    style::Style,
+    text::{Line, Span},
+    widgets::{Clear, Paragraph},
+    Frame,
+};
+
+use super::layout::centered_rect;
+use super::popup::{popup_block, popup_list, popup_placeholder};
+use crate::app::{is_secret_key, App};
+
+/// Render the variable override popup
+pub fn render_variable_overrides(frame: &mut Frame, app: &App) {
+    let theme = &app.active_theme;
+    let area = centered_rect(60, 50, frame.area());
+
+    // Clear the background
+    frame.render_widget(Clear, area);
+
+    let overrides = app.sorted_variable_overrides();
+    let block = popup_block(format!(" Variable Overrides ({}) ", overrides.len()), theme);
+
+    let name_width = overrides
+        .iter()
+        .map(|(name, _)| name.chars().count())
+        .max()
+        .unwrap_or(0);
+
+    let lines = if overrides.is_empty() {
+        popup_placeholder("No overrides (:setvar KEY VALUE to add one)", theme)
+    } else {
+        popup_list(
+            area,
+            overrides.len(),
+            app.variable_override_index,
+            theme,
+            |index| {
+                let (name, value) = overrides[index];
+                // The environment value being replaced, if any
+                let env_var = app.variables.iter().find(|v| &v.name == name);
+                let secret = is_secret_key(name) || env_var.is_some_and(|var| var.is_secret);
+                let shown = |value: &str| {
+                    if secret {
+                        "••••••".to_string()
+                    } else {
+                        value.to_string()
+                    }
+                };
+
+                let mut spans = vec![
+                    Span::styled(
+                        format!("  {:<width$}", name, width = name_width),
+                        Style::default().fg(theme.syntax_variable),
+                    ),
+                    Span::styled(" = ", Style::default().fg(theme.text_muted)),
+                    Span::styled(shown(value), Style::default().fg(theme.text_primary)),
+                ];
+                if let Some(var) = env_var {
+                    spans.push(Span::styled(
+                        format!("  (env: {})", shown(&var.value)),
+                        Style::default().fg(theme.text_muted),
+                    ));
+                }
+                Line::from(spans)
+            },
+        )
+    };
+
+    let paragraph = Paragraph::new(lines).block(block);
+
+    frame.render_widget(paragraph, area);
+}
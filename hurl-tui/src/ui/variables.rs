@@ -1,6 +1,7 @@
 //! Variables panel
 //!
-//! Displays environment variables with hacker terminal aesthetic.
+//! Displays environment variables and the values captured by the last run
+//! with hacker terminal aesthetic.
 
 use ratatui::{
     layout::Rect,
@@ -10,17 +11,18 @@ use ratatui::{
     Frame,
 };
 
-use super::theme::{BoxChars, HackerTheme};
+use super::theme::BoxChars;
 use crate::app::{ActivePanel, App};
 
 /// Render the variables panel
 pub fn render_variables(frame: &mut Frame, app: &App, area: Rect) {
+    let theme = &app.active_theme;
     let is_active = app.active_panel == ActivePanel::Variables;
 
     let border_color = if is_active {
-        HackerTheme::MATRIX_GREEN
+        theme.matrix_green
     } else {
-        HackerTheme::BORDER_DIM
+        theme.border_dim
     };
 
     let block = Block::default()
@@ -32,7 +34,7 @@ pub fn render_variables(frame: &mut Frame, app: &App, area: Rect) {
         )
         .borders(Borders::ALL)
         .border_style(Style::default().fg(border_color))
-        .style(Style::default().bg(HackerTheme::VOID_BLACK));
+        .style(Style::default().bg(theme.void_black));
 
     let mut lines: Vec<Line> = Vec::new();
 
@@ -40,12 +42,12 @@ pub fn render_variables(frame: &mut Frame, app: &App, area: Rect) {
     lines.push(Line::from(vec![
         Span::styled(
             format!(" {} ENV ", BoxChars::DIAMOND),
-            Style::default().fg(HackerTheme::TEXT_MUTED),
+            Style::default().fg(theme.text_muted),
         ),
         Span::styled(
             app.current_environment.clone(),
             Style::default()
-                .fg(HackerTheme::CYBER_CYAN)
+                .fg(theme.cyber_cyan)
                 .add_modifier(Modifier::BOLD),
         ),
     ]));
@@ -56,7 +58,7 @@ pub fn render_variables(frame: &mut Frame, app: &App, area: Rect) {
     if app.variables.is_empty() {
         lines.push(Line::from(Span::styled(
             format!("  {} No variables loaded", BoxChars::DOT),
-            Style::default().fg(HackerTheme::TEXT_MUTED),
+            Style::default().fg(theme.text_muted),
         )));
     } else {
         for var in &app.variables {
@@ -67,9 +69,9 @@ pub fn render_variables(frame: &mut Frame, app: &App, area: Rect) {
             };
 
             let value_color = if var.is_secret {
-                HackerTheme::NEON_RED
+                theme.neon_red
             } else {
-                HackerTheme::TEXT_PRIMARY
+                theme.text_primary
             };
 
             lines.push(Line::from(vec![
@@ -83,25 +85,59 @@ pub fn render_variables(frame: &mut Frame, app: &App, area: Rect) {
                         }
                     ),
                     Style::default().fg(if var.is_secret {
-                        HackerTheme::NEON_RED
+                        theme.neon_red
                     } else {
-                        HackerTheme::TEXT_MUTED
+                        theme.text_muted
                     }),
                 ),
                 Span::styled(
                     format!("{}: ", var.name),
-                    Style::default().fg(HackerTheme::SYNTAX_VARIABLE),
+                    Style::default().fg(theme.syntax_variable),
                 ),
                 Span::styled(value_display, Style::default().fg(value_color)),
             ]));
         }
     }
 
+    // Values captured by the last run
+    let captures = app
+        .execution_result
+        .as_ref()
+        .map(|r| r.captures.as_slice())
+        .unwrap_or_default();
+    if !captures.is_empty() {
+        lines.push(Line::from(""));
+        lines.push(Line::from(Span::styled(
+            format!(" {} CAPTURES", BoxChars::DIAMOND),
+            Style::default().fg(theme.text_muted),
+        )));
+        for capture in captures {
+            lines.push(Line::from(vec![
+                Span::styled(
+                    format!("  {} ", BoxChars::ARROW_RIGHT),
+                    Style::default().fg(theme.cyber_cyan),
+                ),
+                Span::styled(
+                    format!("{}: ", capture.name),
+                    Style::default().fg(theme.syntax_variable),
+                ),
+                Span::styled(
+                    truncate_value(&capture.value, 15),
+                    Style::default().fg(theme.text_primary),
+                ),
+            ]));
+        }
+    }
+
     // Hint
     lines.push(Line::from(""));
     lines.push(Line::from(Span::styled(
         format!("  {} [E] cycle env", BoxChars::TERMINAL_PROMPT),
-        Style::default().fg(HackerTheme::TEXT_MUTED),
+        Style::default().fg(theme.text_muted),
+    )));
+    lines.push(Line::from(Span::styled(
+        format!("  {} [^e] edit env", BoxChars::TERMINAL_PROMPT),
+        Style::default().fg(theme.text_muted),
     )));
 
     let paragraph = Paragraph::new(lines).block(block).wrap(Wrap { trim: true });
@@ -0,0 +1,188 @@
+//! Undo/redo history for the editor
+//!
+//! Snapshots are taken before each editor mutation. To keep memory bounded for
+//! large files, a snapshot is only diffed against the editor content when the
+//! next snapshot is taken (or on undo), and then stored as the changed line range.
+
+use std::collections::VecDeque;
+
+/// A reversible change: replacing `len` lines at `start` with `lines` restores the
+/// previous content.
+#[derive(Debug, Clone)]
+struct UndoEntry {
+    start: usize,
+    len: usize,
+    lines: Vec<String>,
+    cursor: (usize, usize),
+}
+
+impl UndoEntry {
+    /// Apply the change to `content`, returning the entry that reverts it
+    fn apply(self, content: &mut Vec<String>, cursor: (usize, usize)) -> UndoEntry {
+        let end = (self.start + self.len).min(content.len());
+        let start = self.start.min(end);
+        let inserted = self.lines.len();
+        let removed: Vec<String> = content.splice(start..end, self.lines).collect();
+
+        UndoEntry {
+            start,
+            len: inserted,
+            lines: removed,
+            cursor,
+        }
+    }
+}
+
+/// Bounded undo/redo stack for the editor buffer
+#[derive(Debug, Default)]
+pub struct UndoStack {
+    undo: VecDeque<UndoEntry>,
+    redo: Vec<UndoEntry>,
+    /// Full snapshot taken before the latest mutation, not yet diffed
+    pending: Option<(Vec<String>, (usize, usize))>,
+    limit: usize,
+}
+
+impl UndoStack {
+    /// Create an undo stack holding at most `limit` entries (0 disables undo)
+    pub fn new(limit: usize) -> Self {
+        Self {
+            limit,
+            ..Default::default()
+        }
+    }
+
+    /// Record the editor state before a mutation
+    pub fn record(&mut self, content: &[String], cursor: (usize, usize)) {
+        if self.limit == 0 {
+            return;
+        }
+        self.flush(content);
+        self.pending = Some((content.to_vec(), cursor));
+        self.redo.clear();
+    }
+
+    /// Undo the last change, returning the cursor position to restore
+    pub fn undo(
+        &mut self,
+        content: &mut Vec<String>,
+        cursor: (usize, usize),
+    ) -> Option<(usize, usize)> {
+        self.flush(content);
+        let entry = self.undo.pop_back()?;
+        let restored = entry.cursor;
+        self.redo.push(entry.apply(content, cursor));
+        Some(restored)
+    }
+
+    /// Redo the last undone change, returning the cursor position to restore
+    pub fn redo(
+        &mut self,
+        content: &mut Vec<String>,
+        cursor: (usize, usize),
+    ) -> Option<(usize, usize)> {
+        let entry = self.redo.pop()?;
+        let restored = entry.cursor;
+        self.push(entry.apply(content, cursor));
+        Some(restored)
+    }
+
+    /// Drop all history (e.g. when another file is opened)
+    pub fn clear(&mut self) {
+        self.undo.clear();
+        self.redo.clear();
+        self.pending = None;
+    }
+
+    /// Diff the pending snapshot against the current content and store the changed range
+    fn flush(&mut self, content: &[String]) {
+        let Some((old, cursor)) = self.pending.take() else {
+            return;
+        };
+
+        let prefix = old
+            .iter()
+            .zip(content.iter())
+            .take_while(|(a, b)| a == b)
+            .count();
+        if prefix == old.len() && prefix == content.len() {
+            return;
+        }
+
+        let max_suffix = old.len().min(content.len()) - prefix;
+        let suffix = old
+            .iter()
+            .rev()
+            .zip(content.iter().rev())
+            .take(max_suffix)
+            .take_while(|(a, b)| a == b)
+            .count();
+
+        let mut old = old;
+        old.truncate(old.len() - suffix);
+        old.drain(..prefix);
+
+        self.push(UndoEntry {
+            start: prefix,
+            len: content.len() - suffix - prefix,
+            lines: old,
+            cursor,
+        });
+    }
+
+    fn push(&mut self, entry: UndoEntry) {
+        if self.undo.len() >= self.limit {
+            self.undo.pop_front();
+        }
+        self.undo.push_back(entry);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn lines(text: &[&str]) -> Vec<String> {
+        text.iter().map(|s| s.to_string()).collect()
+    }
+
+    #[test]
+    fn test_undo_redo() {
+        let mut stack = UndoStack::new(10);
+        let mut content = lines(&["GET https://example.com", "HTTP 200"]);
+
+        stack.record(&content, (0, 0));
+        content.insert(1, "Accept: */*".to_string());
+        stack.record(&content, (1, 0));
+        content[2] = "HTTP 201".to_string();
+
+        assert_eq!(stack.undo(&mut content, (2, 0)), Some((1, 0)));
+        assert_eq!(content[2], "HTTP 200");
+        assert_eq!(stack.undo(&mut content, (1, 0)), Some((0, 0)));
+        assert_eq!(content, lines(&["GET https://example.com", "HTTP 200"]));
+        assert_eq!(stack.undo(&mut content, (0, 0)), None);
+
+        stack.redo(&mut content, (0, 0));
+        stack.redo(&mut content, (1, 0));
+        assert_eq!(
+            content,
+            lines(&["GET https://example.com", "Accept: */*", "HTTP 201"])
+        );
+    }
+
+    #[test]
+    fn test_undo_limit() {
+        let mut stack = UndoStack::new(2);
+        let mut content = lines(&["a"]);
+
+        for c in ["b", "c", "d"] {
+            stack.record(&content, (0, 0));
+            content[0] = c.to_string();
+        }
+
+        assert!(stack.undo(&mut content, (0, 0)).is_some());
+        assert!(stack.undo(&mut content, (0, 0)).is_some());
+        assert!(stack.undo(&mut content, (0, 0)).is_none());
+        assert_eq!(content, lines(&["b"]));
+    }
+}
@@ -0,0 +1,188 @@
+//! Shared helpers
+//!
+//! Pure functions used by both the app state and the UI.
+
+use serde_json::Value;
+use std::collections::HashSet;
+use std::path::{Path, PathBuf};
+
+use crate::app::FileEntry;
+
+/// Separator between breadcrumb segments
+const CRUMB_SEPARATOR: &str = " › ";
+
+/// Arrays with more elements than this can be collapsed
+pub const COLLAPSIBLE_ARRAY_LEN: usize = 20;
+
+/// Folders from the working directory down to the parent of `entry`
+fn breadcrumb_dirs(entry: &FileEntry, working_dir: &Path) -> Vec<PathBuf> {
+    let mut dirs: Vec<PathBuf> = entry
+        .path
+        .ancestors()
+        .skip(1)
+        .take_while(|dir| dir.starts_with(working_dir) && *dir != working_dir)
+        .map(Path::to_path_buf)
+        .collect();
+    dirs.reverse();
+    dirs
+}
+
+/// Breadcrumb trail from the working directory to the parent of `entry` (`api › auth`)
+pub fn get_breadcrumb(entry: &FileEntry, working_dir: &Path) -> String {
+    breadcrumb_dirs(entry, working_dir)
+        .iter()
+        .map(|dir| dir_name(dir))
+        .collect::<Vec<_>>()
+        .join(CRUMB_SEPARATOR)
+}
+
+/// Last component of a folder path
+fn dir_name(dir: &Path) -> String {
+    dir.file_name()
+        .map(|n| n.to_string_lossy().to_string())
+        .unwrap_or_default()
+}
+
+/// Untruncated file browser title with the breadcrumb of `entry`, when it is nested deeper than two levels
+pub fn full_breadcrumb_title(
+    entry: &FileEntry,
+    working_dir: &Path,
+) -> Option<(String, Vec<PathBuf>)> {
+    if entry.depth <= 2 {
+        return None;
+    }
+    let title = format!(
+        " Files{}{}{}",
+        CRUMB_SEPARATOR,
+        get_breadcrumb(entry, working_dir),
+        CRUMB_SEPARATOR
+    );
+    Some((title, breadcrumb_dirs(entry, working_dir)))
+}
+
+/// Folder of the breadcrumb segment at `column` of the title cut to `width` (0 = first character)
+pub fn breadcrumb_dir_at(
+    entry: &FileEntry,
+    working_dir: &Path,
+    width: usize,
+    column: usize,
+) -> Option<PathBuf> {
+    let (title, dirs) = full_breadcrumb_title(entry, working_dir)?;
+    let len = title.chars().count();
+
+    // Map the column back to the untruncated title, skipping the `…`
+    let column = if len > width {
+        if column == 0 {
+            return None;
+        }
+        column - 1 + len - width.saturating_sub(1)
+    } else {
+        column
+    };
+
+    let mut start = format!(" Files{}", CRUMB_SEPARATOR).chars().count();
+    for dir in dirs {
+        let end = start + dir_name(&dir).chars().count();
+        if (start..end).contains(&column) {
+            return Some(dir);
+        }
+        start = end + CRUMB_SEPARATOR.chars().count();
+    }
+    None
+}
+
+/// Paths of the collapsible arrays in a JSON body (collapsed when a response arrives)
+pub fn collapsible_json_paths(body: &str) -> HashSet<String> {
+    fn collect(value: &Value, path: String, paths: &mut HashSet<String>) {
+        match value {
+            Value::Array(items) => {
+                for (index, item) in items.iter().enumerate() {
+                    collect(item, format!("{}[{}]", path, index), paths);
+                }
+                if items.len() > COLLAPSIBLE_ARRAY_LEN {
+                    paths.insert(path);
+                }
+            }
+            Value::Object(map) => {
+                for (key, item) in map {
+                    collect(item, format!("{}.{}", path, key), paths);
+                }
+            }
+            _ => {}
+        }
+    }
+
+    let mut paths = HashSet::new();
+    if let Ok(json) = serde_json::from_str::<Value>(body.trim()) {
+        collect(&json, "$".to_string(), &mut paths);
+    }
+    paths
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn entry(path: &str, depth: usize) -> FileEntry {
+        FileEntry {
+            path: PathBuf::from(path),
+            name: String::new(),
+            is_dir: false,
+            is_expanded: false,
+            depth,
+            children: Vec::new(),
+            file_size: 0,
+            modified: std::time::SystemTime::UNIX_EPOCH,
+        }
+    }
+
+    #[test]
+    fn test_get_breadcrumb() {
+        let working_dir = Path::new("/work");
+        assert_eq!(
+            get_breadcrumb(&entry("/work/api/auth/v1/login.hurl", 3), working_dir),
+            "api › auth › v1"
+        );
+        assert_eq!(
+            get_breadcrumb(&entry("/work/login.hurl", 0), working_dir),
+            ""
+        );
+    }
+
+    #[test]
+    fn test_breadcrumb_dir_at() {
+        let working_dir = Path::new("/work");
+        let login = entry("/work/api/auth/v1/login.hurl", 3);
+
+        // " Files › api › auth › v1 › "
+        let at = |width, column| breadcrumb_dir_at(&login, working_dir, width, column);
+        assert_eq!(at(40, 9), Some(PathBuf::from("/work/api")));
+        assert_eq!(at(40, 18), Some(PathBuf::from("/work/api/auth")));
+        assert_eq!(at(40, 23), Some(PathBuf::from("/work/api/auth/v1")));
+        assert_eq!(at(40, 3), None);
+        assert_eq!(at(40, 12), None);
+
+        // Cut from the left to "… › api › auth › v1 › "
+        assert_eq!(at(20, 0), None);
+        assert_eq!(at(20, 2), Some(PathBuf::from("/work/api")));
+        assert_eq!(at(20, 8), Some(PathBuf::from("/work/api/auth")));
+
+        // Shallow entries have no breadcrumb
+        let shallow = entry("/work/api/auth/login.hurl", 2);
+        assert_eq!(breadcrumb_dir_at(&shallow, working_dir, 40, 9), None);
+    }
+
+    #[test]
+    fn test_collapsible_json_paths() {
+        let body = serde_json::json!({
+            "items": [{ "tags": vec![0; 21] }, { "tags": vec![0; 20] }],
+            "ids": vec![0; 30],
+        })
+        .to_string();
+        assert_eq!(
+            collapsible_json_paths(&body),
+            HashSet::from(["$.items[0].tags".to_string(), "$.ids".to_string()])
+        );
+        assert!(collapsible_json_paths("not json").is_empty());
+    }
+}